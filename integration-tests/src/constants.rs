@@ -311,11 +311,11 @@ pub mod polimec {
 
 	pub fn set_prices(prices: Prices) {
 		PolimecNet::execute_with(|| {
-			let dot = (AcceptedFundingAsset::DOT.id(), prices.dot);
-			let usdc = (AcceptedFundingAsset::USDC.id(), prices.usdc);
-			let usdt = (AcceptedFundingAsset::USDT.id(), prices.usdt);
+			let dot = (AcceptedFundingAsset::DOT.id().unwrap(), prices.dot);
+			let usdc = (AcceptedFundingAsset::USDC.id().unwrap(), prices.usdc);
+			let usdt = (AcceptedFundingAsset::USDT.id().unwrap(), prices.usdt);
 			let plmc = (Location::here(), prices.plmc);
-			let eth = (AcceptedFundingAsset::ETH.id(), prices.eth);
+			let eth = (AcceptedFundingAsset::ETH.id().unwrap(), prices.eth);
 
 			let values: BoundedVec<(Location, FixedU128), <PolimecRuntime as orml_oracle::Config>::MaxFeedValues> =
 				vec![dot, usdc, usdt, plmc, eth].try_into().expect("benchmarks can panic");
@@ -350,10 +350,10 @@ pub mod polimec {
 	}
 
 	pub fn genesis() -> Storage {
-		let dot_asset_id = AcceptedFundingAsset::DOT.id();
-		let usdt_asset_id = AcceptedFundingAsset::USDT.id();
-		let usdc_asset_id = AcceptedFundingAsset::USDC.id();
-		let eth_asset_id = AcceptedFundingAsset::ETH.id();
+		let dot_asset_id = AcceptedFundingAsset::DOT.id().unwrap();
+		let usdt_asset_id = AcceptedFundingAsset::USDT.id().unwrap();
+		let usdc_asset_id = AcceptedFundingAsset::USDC.id().unwrap();
+		let eth_asset_id = AcceptedFundingAsset::ETH.id().unwrap();
 
 		let ah_location: Location = (Parent, xcm::v4::Junction::Parachain(1000)).into();
 		let mut funded_accounts = vec![(
@@ -377,6 +377,7 @@ pub mod polimec {
 			system: Default::default(),
 			balances: polimec_runtime::BalancesConfig { balances: funded_accounts, dev_accounts: None },
 			contribution_tokens: Default::default(),
+			funding: Default::default(),
 			foreign_assets: polimec_runtime::ForeignAssetsConfig {
 				assets: vec![
 					(dot_asset_id.clone(), alice_account.clone(), true, 100_000_000),
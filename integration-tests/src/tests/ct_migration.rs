@@ -0,0 +1,165 @@
+// Polimec Blockchain – https://www.polimec.org/
+// Copyright (C) Polimec 2022. All rights reserved.
+
+// The Polimec Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Polimec Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{tests::defaults::*, *};
+use frame_support::traits::fungibles::Inspect;
+use macros::generate_accounts;
+use pallet_funding::{
+	ActiveMigrationQueue, ChannelStatus, CheckOutcome, Event as FundingEvent, FundingOutcome, HRMPChannelStatus,
+	PalletMigrations, ProjectId, ProjectStatus, UserMigrations,
+};
+use parity_scale_codec::Decode;
+use polimec_common::migration_types::{MigrationStatus, Migrations};
+use polimec_runtime::ContributionTokens;
+use sp_runtime::traits::TrailingZeroInput;
+
+generate_accounts!(ISSUER);
+
+/// The project's parachain is played by Asset Hub, which already has the HRMP channels open with Polimec in the
+/// emulated network.
+fn project_location() -> xcm::v5::Location {
+	xcm::v5::Location::new(1, [xcm::v5::Junction::Parachain(asset_hub::PARA_ID)])
+}
+
+fn create_settled_project(inst: &mut IntegrationInstantiator) -> ProjectId {
+	let project_metadata = default_project_metadata(ISSUER.into());
+	let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+	let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 90, 10);
+	let project_id = inst.create_finished_project(project_metadata, ISSUER.into(), None, evaluations, bids);
+	assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::SettlementStarted(FundingOutcome::Success));
+	inst.settle_project(project_id, true);
+	project_id
+}
+
+/// Drive the Polimec side of the pallet migration, answering in place of the project's parachain.
+fn prepare_pallet_migration(project_id: ProjectId) -> u8 {
+	let receiver_info = <PolimecRuntime as pallet_funding::Config>::PolimecReceiverInfo::get();
+
+	assert_ok!(PolimecFunding::do_start_pallet_migration(&ISSUER.into(), project_id, asset_hub::PARA_ID.into()));
+	assert_ok!(PolimecFunding::do_handle_channel_open_request(asset_hub::PARA_ID, 102_400, 1000));
+	assert_ok!(PolimecFunding::do_handle_channel_accepted(asset_hub::PARA_ID));
+	assert_eq!(
+		PalletMigrations::<PolimecRuntime>::get(project_id).unwrap().hrmp_channel_status,
+		HRMPChannelStatus { project_to_polimec: ChannelStatus::Open, polimec_to_project: ChannelStatus::Open }
+	);
+
+	assert_ok!(PolimecFunding::do_start_pallet_migration_readiness_check(&ISSUER.into(), project_id));
+	let check = PalletMigrations::<PolimecRuntime>::get(project_id).unwrap().migration_readiness_check.unwrap();
+	let total_cts = ContributionTokens::total_issuance(project_id);
+	let holdings = xcm::v5::Asset::from((project_location(), total_cts));
+	assert_ok!(PolimecFunding::do_pallet_migration_readiness_response(
+		project_location(),
+		check.holding_check.0,
+		xcm::v5::Response::Assets(holdings.into())
+	));
+	assert_ok!(PolimecFunding::do_pallet_migration_readiness_response(
+		project_location(),
+		check.pallet_check.0,
+		xcm::v5::Response::PalletsInfo(vec![receiver_info.clone()].try_into().unwrap())
+	));
+
+	let check = PalletMigrations::<PolimecRuntime>::get(project_id).unwrap().migration_readiness_check.unwrap();
+	assert_eq!(check.pallet_check.1, CheckOutcome::Passed(Some(receiver_info.index as u8)));
+	assert!(check.is_ready());
+
+	receiver_info.index as u8
+}
+
+#[test]
+fn pallet_migration_messages_match_the_receiver_pallet() {
+	let mut inst = IntegrationInstantiator::new(None);
+
+	PolimecNet::execute_with(|| {
+		let project_id = create_settled_project(&mut inst);
+		let receiver_pallet_index = prepare_pallet_migration(project_id);
+
+		let participants = UserMigrations::<PolimecRuntime>::iter_key_prefix((project_id,)).collect::<Vec<_>>();
+		for participant in participants {
+			let (_, migrations) = UserMigrations::<PolimecRuntime>::get((project_id, participant.clone())).unwrap();
+			let migrations = Migrations::from(migrations.into_inner());
+			assert_ok!(PolimecFunding::do_send_pallet_migration_for(project_id, participant.clone()));
+
+			let query_id = ActiveMigrationQueue::<PolimecRuntime>::iter()
+				.find_map(|(query_id, (_, account, _))| (account == participant).then_some(query_id))
+				.unwrap();
+			PolimecSystem::assert_has_event(
+				FundingEvent::PalletMigrationSent { project_id, account: participant.clone(), query_id }.into(),
+			);
+
+			// The `Transact` sent to the project calls `polimec_receiver::migrate_for_user` with the user migrations.
+			let xcm =
+				PolimecFunding::construct_migration_xcm_message(migrations.clone(), 0, query_id, receiver_pallet_index);
+			let call = xcm
+				.0
+				.iter()
+				.find_map(|instruction| match instruction {
+					xcm::v5::Instruction::Transact { origin_kind: xcm::v5::OriginKind::Xcm, call, .. } =>
+						Some(call.clone().into_encoded()),
+					_ => None,
+				})
+				.unwrap();
+			let (pallet_index, call_index, sent_migrations, resend_nonce) =
+				<(u8, u8, Migrations, u32)>::decode(&mut TrailingZeroInput::new(&call)).unwrap();
+			assert_eq!(pallet_index, receiver_pallet_index);
+			assert_eq!(call_index, 0);
+			assert_eq!(sent_migrations, migrations);
+			assert_eq!(resend_nonce, 0);
+
+			// The receiver pallet reports a successful dispatch back.
+			assert_ok!(PolimecFunding::do_confirm_pallet_migrations(
+				project_location(),
+				query_id,
+				xcm::v5::Response::DispatchResult(xcm::v5::MaybeErrorCode::Success)
+			));
+			assert_eq!(
+				UserMigrations::<PolimecRuntime>::get((project_id, participant)).unwrap().0,
+				MigrationStatus::Confirmed
+			);
+		}
+
+		let now = inst.current_block();
+		inst.jump_to_block(now + <PolimecRuntime as pallet_funding::Config>::MigrationDisputeWindow::get() + 1);
+		assert_ok!(PolimecFunding::do_mark_project_ct_migration_as_finished(project_id));
+		assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::CTMigrationFinished);
+	});
+}
+
+#[test]
+fn failed_pallet_migrations_can_be_sent_again() {
+	let mut inst = IntegrationInstantiator::new(None);
+
+	PolimecNet::execute_with(|| {
+		let project_id = create_settled_project(&mut inst);
+		prepare_pallet_migration(project_id);
+
+		let participant = UserMigrations::<PolimecRuntime>::iter_key_prefix((project_id,)).next().unwrap();
+		assert_ok!(PolimecFunding::do_send_pallet_migration_for(project_id, participant.clone()));
+		let (query_id, _) = ActiveMigrationQueue::<PolimecRuntime>::iter().next().unwrap();
+
+		// The receiver pallet reports a failed dispatch, i.e. the sovereign account ran out of tokens.
+		assert_ok!(PolimecFunding::do_confirm_pallet_migrations(
+			project_location(),
+			query_id,
+			xcm::v5::Response::DispatchResult(xcm::v5::MaybeErrorCode::Error(Default::default()))
+		));
+		assert_eq!(
+			UserMigrations::<PolimecRuntime>::get((project_id, participant.clone())).unwrap().0,
+			MigrationStatus::Failed
+		);
+
+		assert_ok!(PolimecFunding::do_send_pallet_migration_for(project_id, participant));
+	});
+}
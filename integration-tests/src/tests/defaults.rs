@@ -18,12 +18,12 @@ use frame_support::BoundedVec;
 pub use pallet_funding::instantiator::EvaluationParams;
 use pallet_funding::{
 	BiddingTicketSizes, CurrencyMetadata, ParticipantsAccountType, PriceProviderOf, ProjectMetadata, ProjectMetadataOf,
-	TicketSize,
+	SaleMode, TicketSize,
 };
 
 use macros::generate_accounts;
 use polimec_common::{
-	assets::AcceptedFundingAsset::{DOT, ETH, USDC, USDT},
+	assets::{DOT, ETH, USDC, USDT},
 	ProvideAssetPrice, USD_DECIMALS, USD_UNIT,
 };
 use polimec_runtime::AccountId;
@@ -75,6 +75,11 @@ pub fn default_project_metadata(issuer: AccountId) -> ProjectMetadataOf<polimec_
 		funding_destination_account: issuer,
 		policy_ipfs_cid: Some(ipfs_hash()),
 		participants_account_type: ParticipantsAccountType::Polkadot,
+		sale_mode: SaleMode::Auction,
+		ct_vesting: None,
+		funding_milestones: BoundedVec::new(),
+		evaluation_success_threshold: None,
+		funding_success_threshold: None,
 	}
 }
 
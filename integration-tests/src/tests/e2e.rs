@@ -27,10 +27,7 @@ use itertools::Itertools;
 use macros::generate_accounts;
 use pallet_funding::{traits::VestingDurationCalculation, *};
 use polimec_common::{
-	assets::{
-		AcceptedFundingAsset,
-		AcceptedFundingAsset::{DOT, USDC, USDT},
-	},
+	assets::{AcceptedFundingAsset, DOT, USDC, USDT},
 	credentials::InvestorType,
 	migration_types::{MigrationStatus, ParticipationType},
 	ProvideAssetPrice, USD_DECIMALS, USD_UNIT,
@@ -91,6 +88,11 @@ pub fn project_metadata() -> ProjectMetadataOf<PolimecRuntime> {
 		funding_destination_account: ISSUER.into(),
 		policy_ipfs_cid: Some(metadata_hash),
 		participants_account_type: ParticipantsAccountType::Polkadot,
+		sale_mode: SaleMode::Auction,
+		ct_vesting: None,
+		funding_milestones: BoundedVec::new(),
+		evaluation_success_threshold: None,
+		funding_success_threshold: None,
 	}
 }
 
@@ -276,7 +278,7 @@ fn participate_with_checks(
 
 	let plmc_ed = inst.get_ed();
 
-	let funding_asset_unit = 10u128.pow(PolimecForeignAssets::decimals(funding_asset.id()) as u32);
+	let funding_asset_unit = 10u128.pow(PolimecForeignAssets::decimals(funding_asset.id().unwrap()) as u32);
 	let funding_asset_ticket =
 		FixedU128::from_float(expected_funding_asset_ticket).saturating_mul_int(funding_asset_unit);
 	let plmc_bonded = FixedU128::from_float(expected_plmc_bonded).saturating_mul_int(PLMC);
@@ -284,20 +286,24 @@ fn participate_with_checks(
 	let user_jwt =
 		get_mock_jwt_with_cid(user.clone(), investor_type, generate_did_from_account(user.clone()), ipfs_hash());
 	// Add one more to account for rounding errors in the spreadsheet
-	inst.mint_funding_asset_to(vec![
-		(user.clone(), funding_asset_ticket + funding_asset_unit, funding_asset.id()).into()
-	]);
+	inst.mint_funding_asset_to(vec![(
+		user.clone(),
+		funding_asset_ticket + funding_asset_unit,
+		funding_asset.id().unwrap(),
+	)
+		.into()]);
 
 	if let ParticipationMode::Classic(..) = mode {
 		inst.mint_plmc_to(vec![(user.clone(), plmc_bonded + PLMC + plmc_ed).into()]);
 	} else {
-		let funding_asset_ed = inst.get_funding_asset_ed(funding_asset.id());
-		inst.mint_funding_asset_to(vec![(user.clone(), funding_asset_ed, funding_asset.id()).into()]);
+		let funding_asset_ed = inst.get_funding_asset_ed(funding_asset.id().unwrap());
+		inst.mint_funding_asset_to(vec![(user.clone(), funding_asset_ed, funding_asset.id().unwrap()).into()]);
 	}
 
 	let prev_participation_free_plmc = PolimecBalances::free_balance(user.clone());
 	let prev_participation_reserved_plmc = PolimecBalances::reserved_balance(user.clone());
-	let prev_participation_funding_asset_balance = PolimecForeignAssets::balance(funding_asset.id(), user.clone());
+	let prev_participation_funding_asset_balance =
+		PolimecForeignAssets::balance(funding_asset.id().unwrap(), user.clone());
 
 	let sub_account = polimec_runtime::ProxyBonding::get_bonding_account(project_id);
 	let prev_participation_treasury_held_plmc =
@@ -310,7 +316,8 @@ fn participate_with_checks(
 
 	let post_participation_free_plmc = PolimecBalances::free_balance(user.clone());
 	let post_participation_reserved_plmc = PolimecBalances::reserved_balance(user.clone());
-	let post_participation_funding_asset_balance = PolimecForeignAssets::balance(funding_asset.id(), user.clone());
+	let post_participation_funding_asset_balance =
+		PolimecForeignAssets::balance(funding_asset.id().unwrap(), user.clone());
 	let post_participation_treasury_held_plmc =
 		PolimecBalances::balance_on_hold(&HoldReason::Participation.into(), &sub_account);
 
@@ -412,18 +419,22 @@ fn e2e_test() {
 
 		let prev_bid_free_plmc = PolimecBalances::free_balance(rejected_bidder.clone());
 		let prev_bid_reserved_plmc = PolimecBalances::reserved_balance(rejected_bidder.clone());
-		let prev_bid_usdt_balance = PolimecForeignAssets::balance(USDT.id(), rejected_bidder.clone());
-		let prev_treasury_usdt_balance = PolimecForeignAssets::balance(USDT.id(), otm_project_sub_account.clone());
-		let prev_escrow_usdt_balance = PolimecForeignAssets::balance(USDT.id(), funding_escrow_account.clone());
+		let prev_bid_usdt_balance = PolimecForeignAssets::balance(USDT.id().unwrap(), rejected_bidder.clone());
+		let prev_treasury_usdt_balance =
+			PolimecForeignAssets::balance(USDT.id().unwrap(), otm_project_sub_account.clone());
+		let prev_escrow_usdt_balance =
+			PolimecForeignAssets::balance(USDT.id().unwrap(), funding_escrow_account.clone());
 
 		PolimecFunding::settle_bid(PolimecOrigin::signed(rejected_bidder.clone()), project_id, rejected_bid_id)
 			.unwrap();
 
 		let post_bid_free_plmc = PolimecBalances::free_balance(rejected_bidder.clone());
 		let post_bid_reserved_plmc = PolimecBalances::reserved_balance(rejected_bidder.clone());
-		let post_bid_funding_asset_balance = PolimecForeignAssets::balance(USDT.id(), rejected_bidder.clone());
-		let post_treasury_usdt_balance = PolimecForeignAssets::balance(USDT.id(), otm_project_sub_account.clone());
-		let post_escrow_usdt_balance = PolimecForeignAssets::balance(USDT.id(), funding_escrow_account.clone());
+		let post_bid_funding_asset_balance = PolimecForeignAssets::balance(USDT.id().unwrap(), rejected_bidder.clone());
+		let post_treasury_usdt_balance =
+			PolimecForeignAssets::balance(USDT.id().unwrap(), otm_project_sub_account.clone());
+		let post_escrow_usdt_balance =
+			PolimecForeignAssets::balance(USDT.id().unwrap(), funding_escrow_account.clone());
 
 		let free_plmc_delta = post_bid_free_plmc - prev_bid_free_plmc;
 		let reserved_plmc_delta = post_bid_reserved_plmc - prev_bid_reserved_plmc;
@@ -436,7 +447,7 @@ fn e2e_test() {
 		assert_close_enough!(reserved_plmc_delta, 0, Perquintill::from_float(0.9999));
 
 		// They should have gotten their USDT back
-		let usdt_unit = 10u128.pow(PolimecForeignAssets::decimals(USDT.id()) as u32);
+		let usdt_unit = 10u128.pow(PolimecForeignAssets::decimals(USDT.id().unwrap()) as u32);
 		let expected_usdt = FixedU128::from_float(85_260.0).saturating_mul_int(usdt_unit);
 		let expected_otm_fee = FixedU128::from_float(1260.0).saturating_mul_int(usdt_unit);
 
@@ -470,14 +481,15 @@ fn e2e_test() {
 		);
 
 		let issuer_usdt =
-			PolimecForeignAssets::balance(USDT.id(), project_metadata.funding_destination_account.clone());
+			PolimecForeignAssets::balance(USDT.id().unwrap(), project_metadata.funding_destination_account.clone());
 		let issuer_usdc =
-			PolimecForeignAssets::balance(USDC.id(), project_metadata.funding_destination_account.clone());
-		let issuer_dot = PolimecForeignAssets::balance(DOT.id(), project_metadata.funding_destination_account.clone());
+			PolimecForeignAssets::balance(USDC.id().unwrap(), project_metadata.funding_destination_account.clone());
+		let issuer_dot =
+			PolimecForeignAssets::balance(DOT.id().unwrap(), project_metadata.funding_destination_account.clone());
 
-		let usdt_unit = 10u128.pow(PolimecForeignAssets::decimals(USDT.id()) as u32);
-		let usdc_unit = 10u128.pow(PolimecForeignAssets::decimals(USDC.id()) as u32);
-		let dot_unit = 10u128.pow(PolimecForeignAssets::decimals(DOT.id()) as u32);
+		let usdt_unit = 10u128.pow(PolimecForeignAssets::decimals(USDT.id().unwrap()) as u32);
+		let usdc_unit = 10u128.pow(PolimecForeignAssets::decimals(USDC.id().unwrap()) as u32);
+		let dot_unit = 10u128.pow(PolimecForeignAssets::decimals(DOT.id().unwrap()) as u32);
 
 		assert_close_enough!(
 			issuer_usdt,
@@ -551,28 +563,28 @@ fn e2e_test() {
 			PolimecOrigin::signed(BOB.into()),
 			project_id,
 			HoldReason::Participation.into(),
-			USDT.id(),
+			USDT.id().unwrap(),
 		)
 		.unwrap();
 		polimec_runtime::ProxyBonding::transfer_fees_to_recipient(
 			PolimecOrigin::signed(BOB.into()),
 			project_id,
 			HoldReason::Participation.into(),
-			USDC.id(),
+			USDC.id().unwrap(),
 		)
 		.unwrap();
 		polimec_runtime::ProxyBonding::transfer_fees_to_recipient(
 			PolimecOrigin::signed(BOB.into()),
 			project_id,
 			HoldReason::Participation.into(),
-			DOT.id(),
+			DOT.id().unwrap(),
 		)
 		.unwrap();
 
 		let fee_recipient = <PolimecRuntime as pallet_proxy_bonding::Config>::FeeRecipient::get();
-		let fee_recipient_usdt_balance = PolimecForeignAssets::balance(USDT.id(), fee_recipient.clone());
-		let fee_recipient_usdc_balance = PolimecForeignAssets::balance(USDC.id(), fee_recipient.clone());
-		let fee_recipient_dot_balance = PolimecForeignAssets::balance(DOT.id(), fee_recipient.clone());
+		let fee_recipient_usdt_balance = PolimecForeignAssets::balance(USDT.id().unwrap(), fee_recipient.clone());
+		let fee_recipient_usdc_balance = PolimecForeignAssets::balance(USDC.id().unwrap(), fee_recipient.clone());
+		let fee_recipient_dot_balance = PolimecForeignAssets::balance(DOT.id().unwrap(), fee_recipient.clone());
 
 		assert_close_enough!(fee_recipient_usdt_balance, usdt_balance, Perquintill::from_float(0.999));
 		assert_close_enough!(fee_recipient_usdc_balance, usdc_balance, Perquintill::from_float(0.999));
@@ -622,6 +634,8 @@ fn e2e_test() {
 				user
 			));
 		}
+		let now = inst.current_block();
+		inst.jump_to_block(now + <PolimecRuntime as pallet_funding::Config>::MigrationDisputeWindow::get() + 1);
 		PolimecFunding::mark_project_ct_migration_as_finished(PolimecOrigin::signed(issuer.clone()), project_id)
 			.unwrap();
 
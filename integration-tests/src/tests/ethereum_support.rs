@@ -1,40 +1,167 @@
-use crate::*;
+use crate::{tests::defaults::IntegrationInstantiator, *};
 use hex_literal::hex;
+use pallet_funding::ParticipantsAccountType;
+use sp_core::H256;
 use sp_runtime::traits::Convert;
 
 macros::generate_accounts!(ETH_BUYER);
 
+// Inputs of the known-answer signatures below. The signature domain is the genesis hash of the chain, so we pin it
+// instead of relying on the one of the emulated network.
+const KNOWN_ANSWER_PROJECT_ID: u32 = 1;
+const KNOWN_ANSWER_EXPIRY: u32 = 1_000;
+const KNOWN_ANSWER_GENESIS_HASH: [u8; 32] = hex!("7eb9354488318e7549c722669dcbdcdc526f1fef1420e7944667212f3601fdbd");
+
+/// Pin the signature domain, and return the Polimec account the known-answer signatures were made for.
+fn setup_known_answer_inputs() -> PolimecAccountId {
+	frame_system::BlockHash::<PolimecRuntime>::insert(0, H256(KNOWN_ANSWER_GENESIS_HASH));
+	let polimec_account: PolimecAccountId = ETH_BUYER.into();
+	assert_eq!(
+		polimec_runtime::SS58Converter::convert(polimec_account.clone()),
+		"57qwwU823gziGcpWcQB8a3Pycp3a14xCUCPmd75jJXK5QArX"
+	);
+	polimec_account
+}
+
 #[test]
-fn test_hardcoded_signatures() {
+fn test_receiving_account_signatures() {
+	let mut inst = IntegrationInstantiator::new(None);
 	let polimec_account: PolimecAccountId = ETH_BUYER.into();
 	let project_id = 1;
 
-	// Values generated with `https://github.com/lrazovic/ethsigner`
-	let _polimec_account_ss58 = polimec_runtime::SS58Converter::convert(polimec_account.clone());
-	let ethereum_account: [u8; 20] = hex!("796afe7b8933ee8cf337f17887e5c19b657f9ab8");
-	let signature: [u8; 65] = hex!("952e312ac892fefc7c69051521e78a3bc2727fbb495585bdb5fb77e662b8a3de2b1254058d824e85034710e338c2590e2f92d74ce3c60292ed25c7537d94ed621b");
-
 	PolimecNet::execute_with(|| {
-		assert_ok!(PolimecFunding::verify_receiving_account_signature(
+		let expiry = inst.current_block() + 100;
+
+		let (ethereum_account, signature) =
+			inst.eth_key_and_sig_from("//ETH_BUYER", project_id, polimec_account.clone(), expiry);
+		assert_ok!(PolimecFunding::verify_account_type_signature(
+			&ParticipantsAccountType::Ethereum,
 			&polimec_account,
 			project_id,
-			&Junction::AccountKey20 { network: None, key: ethereum_account },
+			&ethereum_account,
+			expiry,
+			signature,
+		));
+
+		let (polkadot_account, signature) =
+			inst.dot_key_and_sig_from("//ETH_BUYER", project_id, polimec_account.clone(), expiry);
+		assert_ok!(PolimecFunding::verify_account_type_signature(
+			&ParticipantsAccountType::Polkadot,
+			&polimec_account,
+			project_id,
+			&polkadot_account,
+			expiry,
 			signature,
 		));
+
+		// The expiry is part of the signed message
+		assert_noop!(
+			PolimecFunding::verify_account_type_signature(
+				&ParticipantsAccountType::Polkadot,
+				&polimec_account,
+				project_id,
+				&polkadot_account,
+				expiry + 1,
+				signature,
+			),
+			pallet_funding::Error::<PolimecRuntime>::BadReceiverAccountSignature
+		);
 	});
+}
+
+#[test]
+fn test_ethereum_and_polkadot_known_answer_signatures() {
+	PolimecNet::execute_with(|| {
+		let polimec_account = setup_known_answer_inputs();
+
+		// EIP-712 typed data signed with `alloy_signer_local::PrivateKeySigner::sign_dynamic_typed_data_sync`
+		// (alloy-signer-local 1.8.3), with the secret key [0x55; 32], i.e. 0xe1fAE9b4fAB2F5726677ECfA912d96b0B683e6a9.
+		let ethereum_account = hex!("e1fae9b4fab2f5726677ecfa912d96b0b683e6a9");
+		let signature = hex!("850e96a130e8623d98f79189f24beeffc86b8deeadf9407f8bb649bbcb98cea1628f7ab08d48149aa0e003da9d5347584d176dd164f64363baea5ae87d884ebd1b");
+		assert_ok!(PolimecFunding::verify_account_type_signature(
+			&ParticipantsAccountType::Ethereum,
+			&polimec_account,
+			KNOWN_ANSWER_PROJECT_ID,
+			&Junction::AccountKey20 { network: None, key: ethereum_account },
+			KNOWN_ANSWER_EXPIRY,
+			signature,
+		));
 
-	let polkadot_signature: [u8; 64] = hex!("32b486f3944c6345295777c84113c5339b786a6c1a4505ab876349e07a7938646b01aff5c7ceda542af794cde03c14eb255d905da8019aa7264fa1a766ab0188");
-	let mut signature: [u8; 65] = [0u8; 65];
-	signature[..64].copy_from_slice(polkadot_signature.as_slice());
-	signature[64] = 0;
-	let polkadot_address: [u8; 32] = hex!("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d");
+		// Signed with schnorrkel 0.11.4 under the `substrate` signing context, with the mini secret key [0x33; 32].
+		let polkadot_account = hex!("e2717db1170eb082174cc611998c8dd2b510b037854a3b0cbd3d35e53f625502");
+		let mut signature = [0u8; 65];
+		signature[..64].copy_from_slice(&hex!("5cea569e26bfc63eeb193340e933d51dbb56299806b1e7048098436e47fbf967303caa1a1e4ec35b41d49e907dbe74b40858126db0909827a7c748a8e4f76f84"));
+		assert_ok!(PolimecFunding::verify_account_type_signature(
+			&ParticipantsAccountType::Polkadot,
+			&polimec_account,
+			KNOWN_ANSWER_PROJECT_ID,
+			&Junction::AccountId32 { network: None, id: polkadot_account },
+			KNOWN_ANSWER_EXPIRY,
+			signature,
+		));
+
+		// Signatures made for another chain are rejected
+		frame_system::BlockHash::<PolimecRuntime>::insert(0, H256([0u8; 32]));
+		assert_noop!(
+			PolimecFunding::verify_account_type_signature(
+				&ParticipantsAccountType::Polkadot,
+				&polimec_account,
+				KNOWN_ANSWER_PROJECT_ID,
+				&Junction::AccountId32 { network: None, id: polkadot_account },
+				KNOWN_ANSWER_EXPIRY,
+				signature,
+			),
+			pallet_funding::Error::<PolimecRuntime>::BadReceiverAccountSignature
+		);
+	});
+}
 
+#[test]
+fn test_solana_and_cosmos_known_answer_signatures() {
 	PolimecNet::execute_with(|| {
-		assert_ok!(PolimecFunding::verify_receiving_account_signature(
+		let polimec_account = setup_known_answer_inputs();
+
+		// Signed with `solana_offchain_message::OffchainMessage::sign` (solana-offchain-message 2.2.1), with the
+		// keypair of the secret key [0x42; 32], i.e. 3F5qRPtKg8GhGNnbd3qCj6nVJxWsGxq7pvH84okYLAqf.
+		let solana_account = hex!("2152f8d19b791d24453242e15f2eab6cb7cffa7b6a5ed30097960e069881db12");
+		let mut signature = [0u8; 65];
+		signature[..64].copy_from_slice(&hex!("8f0918fcae8480ead8a9c538283f1357364ea66d502d8c4d18e24ea62b5e163c06339ab860acc48c9c40306a595a07f62da15ace70277be3bea1ab0d12d8f700"));
+		assert_ok!(PolimecFunding::verify_account_type_signature(
+			&ParticipantsAccountType::Solana,
 			&polimec_account,
-			project_id,
-			&Junction::AccountId32 { network: None, id: polkadot_address },
+			KNOWN_ANSWER_PROJECT_ID,
+			&Junction::AccountId32 { network: None, id: solana_account },
+			KNOWN_ANSWER_EXPIRY,
 			signature,
 		));
+
+		// ADR-036 amino sign doc serialized with serde_json and the bech32 0.11 crate, signed with k256 0.13, with the
+		// secret key [0x24; 32], i.e. cosmos1yr9dx2zjg7rdla2dwc20za975w2gxtr4ktmk8l.
+		let cosmos_account = hex!("20cad328524786dff54d7614f174bea394832c75");
+		let mut signature = [0u8; 65];
+		signature[..64].copy_from_slice(&hex!("db7404db6e731817298a7a86f3c91f28ab3714e6f4cc389746a9387ad3602c1405e63bca0617ebf45b0760ac3b839afc9872d4612e99656c14fc207122f81197"));
+		let cosmos = ParticipantsAccountType::Cosmos { bech32_prefix: b"cosmos".to_vec().try_into().unwrap() };
+		assert_ok!(PolimecFunding::verify_account_type_signature(
+			&cosmos,
+			&polimec_account,
+			KNOWN_ANSWER_PROJECT_ID,
+			&Junction::AccountKey20 { network: None, key: cosmos_account },
+			KNOWN_ANSWER_EXPIRY,
+			signature,
+		));
+
+		// The bech32 prefix is part of the signed document
+		let osmosis = ParticipantsAccountType::Cosmos { bech32_prefix: b"osmo".to_vec().try_into().unwrap() };
+		assert_noop!(
+			PolimecFunding::verify_account_type_signature(
+				&osmosis,
+				&polimec_account,
+				KNOWN_ANSWER_PROJECT_ID,
+				&Junction::AccountKey20 { network: None, key: cosmos_account },
+				KNOWN_ANSWER_EXPIRY,
+				signature,
+			),
+			pallet_funding::Error::<PolimecRuntime>::BadReceiverAccountSignature
+		);
 	});
 }
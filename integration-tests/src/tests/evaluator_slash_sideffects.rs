@@ -113,14 +113,15 @@ fn evaluator_slash_reduces_vesting_schedules() {
 		let project_id = inst.create_evaluating_project(project_metadata, ISSUER.into(), None);
 		assert_ok!(inst.evaluate_for_users(project_id, vec![alice_evaluation.clone(), bob_evaluation.clone()]));
 		assert_eq!(ProjectStatus::AuctionRound, inst.go_to_next_state(project_id));
+		assert_eq!(ProjectStatus::CommunityRound, inst.go_to_next_state(project_id));
 		assert_eq!(ProjectStatus::FundingFailed, inst.go_to_next_state(project_id));
 		assert_eq!(ProjectStatus::SettlementStarted(FundingOutcome::Failure), inst.go_to_next_state(project_id));
 
-		const END_BLOCK: u32 = 19;
+		const END_BLOCK: u32 = 22;
 		assert_eq!(inst.current_block(), BlockNumberFor::<PolimecRuntime>::from(END_BLOCK));
 
-		// All schedules start at block 5, and funding ended at block 18
-		const TIME_PASSED: u128 = 14u128;
+		// All schedules start at block 5, and funding ended at block 21
+		const TIME_PASSED: u128 = 17u128;
 
 		let alice_account_data = Account::<PolimecRuntime>::get(&alice.clone()).data;
 		assert_eq!(
@@ -1,4 +1,4 @@
-use crate::{polimec::ED, *};
+use crate::{polimec::ED, tests::defaults::*, *};
 /// Tests for the oracle pallet integration.
 /// Alice, Bob, Charlie are members of the OracleProvidersMembers.
 /// Only members should be able to feed data into the oracle.
@@ -13,12 +13,14 @@ use frame_support::traits::{
 	fungible::InspectFreeze, tokens::Precision, Imbalance, LockableCurrency, ReservableCurrency, StorePreimage,
 };
 use pallet_democracy::{AccountVote, Conviction, GetElectorate, ReferendumInfo, Vote};
+use pallet_funding::{FundingOutcome, HaltedProjects, ProjectStatus, StringLimitOf};
 use pallet_vesting::VestingInfo;
 use polimec_runtime::{
-	Balances, Democracy, Elections, ParachainStaking, Preimage, RuntimeOrigin, Treasury, Vesting, PLMC,
+	Balances, Democracy, Elections, Funding, ParachainStaking, Preimage, RuntimeOrigin, Treasury, Vesting, PLMC,
 };
 use sp_core::crypto::get_public_from_string_or_panic;
-generate_accounts!(PEPE, CARLOS,);
+use sp_runtime::BoundedVec;
+generate_accounts!(PEPE, CARLOS, ISSUER,);
 
 /// Test that an account with vested tokens (a lock) can use those tokens for a hold.
 /// The hold can also be released or slashed while the lock is still in place.
@@ -310,6 +312,50 @@ fn user_can_vote_with_staked_balance() {
 // 	});
 // }
 
+/// Test that the council can halt the participations of a live project, and force it to fail. Evaluators get their
+/// whole bond back in the settlement.
+#[test]
+fn council_can_halt_and_fail_a_project() {
+	let mut inst = IntegrationInstantiator::new(None);
+	PolimecNet::execute_with(|| {
+		let council_origin: RuntimeOrigin =
+			pallet_collective::RawOrigin::<AccountId, pallet_collective::Instance1>::Members(3, 5).into();
+		let reason: BoundedVec<u8, StringLimitOf<PolimecRuntime>> = b"fraudulent issuer".to_vec().try_into().unwrap();
+
+		let project_metadata = default_project_metadata(ISSUER.into());
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+		let project_id = inst.create_auctioning_project(project_metadata, ISSUER.into(), None, evaluations);
+
+		// A minority of the council cannot halt a project
+		assert_noop!(
+			Funding::halt_project(
+				pallet_collective::RawOrigin::<AccountId, pallet_collective::Instance1>::Members(2, 5).into(),
+				project_id,
+				reason.clone()
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(Funding::halt_project(council_origin.clone(), project_id, reason.clone()));
+		assert!(HaltedProjects::<PolimecRuntime>::contains_key(project_id));
+
+		assert_ok!(Funding::force_project_failure(council_origin, project_id, reason));
+		assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::FundingFailed);
+		assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::SettlementStarted(FundingOutcome::Failure));
+
+		let evaluations = inst.get_evaluations(project_id);
+		let evaluators = evaluations.iter().map(|evaluation| evaluation.evaluator.clone()).collect::<Vec<_>>();
+		let prev_free_plmc = inst.get_free_plmc_balances_for(evaluators);
+		inst.settle_project(project_id, true);
+
+		for (evaluation, prev_balance) in evaluations.into_iter().zip(prev_free_plmc) {
+			assert_eq!(
+				Balances::balance(&evaluation.evaluator),
+				prev_balance.plmc_amount + evaluation.current_plmc_bond
+			);
+		}
+	});
+}
+
 /// Test that users can vote in the election-phragmen pallet with their staked balance.
 #[test]
 fn user_can_vote_in_election_with_staked_balance() {
@@ -15,22 +15,21 @@
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 use crate::*;
-use polimec_common::assets::AcceptedFundingAsset;
+use polimec_common::assets::{DOT, ETH, USDC, USDT};
 use polimec_runtime::{Oracle, RuntimeOrigin};
 use sp_runtime::{bounded_vec, BoundedVec, FixedU128};
 use std::collections::BTreeMap;
 use tests::defaults::*;
-use AcceptedFundingAsset::{DOT, ETH, USDC, USDT};
 
 fn values(
 	values: [f64; 5],
 ) -> BoundedVec<(Location, FixedU128), <polimec_runtime::Runtime as orml_oracle::Config<()>>::MaxFeedValues> {
 	let [dot, usdc, usdt, eth, plmc] = values;
 	bounded_vec![
-		(DOT.id(), FixedU128::from_float(dot)),
-		(USDC.id(), FixedU128::from_float(usdc)),
-		(USDT.id(), FixedU128::from_float(usdt)),
-		(ETH.id(), FixedU128::from_float(eth)),
+		(DOT.id().unwrap(), FixedU128::from_float(dot)),
+		(USDC.id().unwrap(), FixedU128::from_float(usdc)),
+		(USDT.id().unwrap(), FixedU128::from_float(usdt)),
+		(ETH.id().unwrap(), FixedU128::from_float(eth)),
 		(Location::here(), FixedU128::from_float(plmc))
 	]
 }
@@ -52,10 +51,10 @@ fn members_can_feed_data() {
 		assert_ok!(Oracle::feed_values(RuntimeOrigin::signed(charlie.clone()), values([4.84, 1.0, 1.0, 2500.0, 0.4])));
 
 		let expected_values = BTreeMap::from([
-			(DOT.id(), FixedU128::from_float(4.84)),
-			(USDC.id(), FixedU128::from_float(1.0)),
-			(USDT.id(), FixedU128::from_float(1.0)),
-			(ETH.id(), FixedU128::from_float(2500.0)),
+			(DOT.id().unwrap(), FixedU128::from_float(4.84)),
+			(USDC.id().unwrap(), FixedU128::from_float(1.0)),
+			(USDT.id().unwrap(), FixedU128::from_float(1.0)),
+			(ETH.id().unwrap(), FixedU128::from_float(2500.0)),
 			(Location::here(), FixedU128::from_float(0.4)),
 		]);
 
@@ -98,10 +97,10 @@ fn data_is_correctly_combined() {
 
 		// Default CombineData implementation is the median value
 		let expected_values = BTreeMap::from([
-			(DOT.id(), FixedU128::from_float(2.0)),
-			(USDC.id(), FixedU128::from_float(1.0)),
-			(USDT.id(), FixedU128::from_float(1.1)),
-			(ETH.id(), FixedU128::from_float(2500.0)),
+			(DOT.id().unwrap(), FixedU128::from_float(2.0)),
+			(USDC.id().unwrap(), FixedU128::from_float(1.0)),
+			(USDT.id().unwrap(), FixedU128::from_float(1.1)),
+			(ETH.id().unwrap(), FixedU128::from_float(2500.0)),
 			(Location::here(), FixedU128::from_float(0.22222)),
 		]);
 
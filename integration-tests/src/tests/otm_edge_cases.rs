@@ -35,7 +35,7 @@ fn otm_fee_below_min_amount_impossible() {
 			bounded_vec![AcceptedFundingAsset::USDT, AcceptedFundingAsset::USDC, AcceptedFundingAsset::DOT,];
 
 		let usdt_price = <PolimecRuntime as pallet_funding::Config>::PriceProvider::get_decimals_aware_price(
-			&AcceptedFundingAsset::USDT.id(),
+			&AcceptedFundingAsset::USDT.id().unwrap(),
 			6,
 		)
 		.unwrap();
@@ -51,7 +51,8 @@ fn otm_fee_below_min_amount_impossible() {
 		let min_usd_bond = otm_multiplier.calculate_usd_bonding_requirement::<PolimecRuntime>(min_usd_bid).unwrap();
 		let min_plmc_bond = plmc_price.reciprocal().unwrap().saturating_mul_int(min_usd_bond);
 		let min_usd_otm_fee =
-			polimec_runtime::ProxyBonding::calculate_fee(min_plmc_bond, AcceptedFundingAsset::USDT.id()).unwrap();
+			polimec_runtime::ProxyBonding::calculate_fee(min_plmc_bond, AcceptedFundingAsset::USDT.id().unwrap())
+				.unwrap();
 
 		let mut min_usdt_contribution = usdt_price.reciprocal().unwrap().saturating_mul_int(min_usd_bid);
 		while usdt_price.saturating_mul_int(min_usdt_contribution) < min_usd_bid {
@@ -60,7 +61,8 @@ fn otm_fee_below_min_amount_impossible() {
 
 		let min_usdt_contribution_otm_fee = usdt_price.reciprocal().unwrap().saturating_mul_int(min_usd_otm_fee);
 
-		let usdt_min_balance = inst.execute(|| PolimecForeignAssets::minimum_balance(AcceptedFundingAsset::USDT.id()));
+		let usdt_min_balance =
+			inst.execute(|| PolimecForeignAssets::minimum_balance(AcceptedFundingAsset::USDT.id().unwrap()));
 
 		assert!(min_usdt_contribution_otm_fee > usdt_min_balance);
 	});
@@ -85,7 +87,7 @@ fn after_otm_fee_user_goes_under_ed_reverts() {
 		)
 		.unwrap();
 		let usdt_price = <PolimecRuntime as pallet_funding::Config>::PriceProvider::get_decimals_aware_price(
-			&AcceptedFundingAsset::USDT.id(),
+			&AcceptedFundingAsset::USDT.id().unwrap(),
 			6,
 		)
 		.unwrap();
@@ -95,9 +97,9 @@ fn after_otm_fee_user_goes_under_ed_reverts() {
 		let usd_bond = otm_multiplier.calculate_usd_bonding_requirement::<PolimecRuntime>(usd_contribution).unwrap();
 		let plmc_bond = plmc_price.reciprocal().unwrap().saturating_mul_int(usd_bond);
 		let usd_otm_fee =
-			polimec_runtime::ProxyBonding::calculate_fee(plmc_bond, AcceptedFundingAsset::USDT.id()).unwrap();
+			polimec_runtime::ProxyBonding::calculate_fee(plmc_bond, AcceptedFundingAsset::USDT.id().unwrap()).unwrap();
 
-		let usdt_ed = inst.get_funding_asset_ed(AcceptedFundingAsset::USDT.id());
+		let usdt_ed = inst.get_funding_asset_ed(AcceptedFundingAsset::USDT.id().unwrap());
 		let usdt_contribution = usdt_price.reciprocal().unwrap().saturating_mul_int(usd_contribution);
 		let usdt_otm_fee = usdt_price.reciprocal().unwrap().saturating_mul_int(usd_otm_fee);
 
@@ -116,7 +118,7 @@ fn after_otm_fee_user_goes_under_ed_reverts() {
 		inst.mint_funding_asset_to(vec![(
 			bobert.clone(),
 			usdt_contribution + usdt_otm_fee,
-			AcceptedFundingAsset::USDT.id(),
+			AcceptedFundingAsset::USDT.id().unwrap(),
 		)
 			.into()]);
 
@@ -132,7 +134,7 @@ fn after_otm_fee_user_goes_under_ed_reverts() {
 			pallet_funding::Error::<PolimecRuntime>::ParticipantNotEnoughFunds
 		);
 
-		inst.mint_funding_asset_to(vec![(bobert.clone(), usdt_ed, AcceptedFundingAsset::USDT.id()).into()]);
+		inst.mint_funding_asset_to(vec![(bobert.clone(), usdt_ed, AcceptedFundingAsset::USDT.id().unwrap()).into()]);
 
 		assert_ok!(PolimecFunding::bid(
 			PolimecOrigin::signed(bobert.clone()),
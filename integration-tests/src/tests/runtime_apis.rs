@@ -1,7 +1,7 @@
 use crate::{constants::*, *};
 use assets_common::runtime_api::runtime_decl_for_fungibles_api::FungiblesApiV2;
 use frame_support::traits::{fungible::Mutate as FMutate, fungibles::Mutate};
-use polimec_common::assets::AcceptedFundingAsset;
+use polimec_common::assets::{AcceptedFundingAsset, ProvideFundingAssets};
 use sp_arithmetic::FixedU128;
 use xcm::{VersionedAssetId, VersionedAssets};
 use xcm_runtime_apis::fees::runtime_decl_for_xcm_payment_api::XcmPaymentApiV1;
@@ -14,7 +14,7 @@ mod xcm_payment_api {
 	fn query_acceptable_payment_assets() {
 		PolimecNet::execute_with(|| {
 			let accepted_payment_assets = PolimecRuntime::query_acceptable_payment_assets(5u32).unwrap();
-			let versioned_funding_assets = AcceptedFundingAsset::all_ids()
+			let versioned_funding_assets = PolimecFunding::enabled_funding_asset_locations()
 				.into_iter()
 				.map(|loc| AssetId::from(loc))
 				.map(|a| VersionedAssetId::V5(a.try_into().unwrap()))
@@ -85,20 +85,30 @@ mod fungibles_api {
 			let alice_account = PolimecNet::account_id_of(accounts::ALICE);
 
 			PolimecBalances::set_balance(&alice_account, 150_0_000_000_000_u128);
-			PolimecForeignAssets::set_balance(AcceptedFundingAsset::DOT.id(), &alice_account, 100_0_000_000_000_u128);
-			PolimecForeignAssets::set_balance(AcceptedFundingAsset::USDT.id(), &alice_account, 100_000_u128);
-			PolimecForeignAssets::set_balance(AcceptedFundingAsset::USDC.id(), &alice_account, 100_000_u128);
-			PolimecForeignAssets::set_balance(AcceptedFundingAsset::ETH.id(), &alice_account, 100_000_000_000_000_u128);
+			PolimecForeignAssets::set_balance(
+				AcceptedFundingAsset::DOT.id().unwrap(),
+				&alice_account,
+				100_0_000_000_000_u128,
+			);
+			PolimecForeignAssets::set_balance(AcceptedFundingAsset::USDT.id().unwrap(), &alice_account, 100_000_u128);
+			PolimecForeignAssets::set_balance(AcceptedFundingAsset::USDC.id().unwrap(), &alice_account, 100_000_u128);
+			PolimecForeignAssets::set_balance(
+				AcceptedFundingAsset::ETH.id().unwrap(),
+				&alice_account,
+				100_000_000_000_000_u128,
+			);
 
 			let alice_assets = PolimecRuntime::query_account_balances(alice_account).unwrap();
 
 			let expected_assets = VersionedAssets::V5(
 				vec![
 					Asset::from((Location::here(), 150_0_000_000_000_u128)).try_into().unwrap(),
-					Asset::from((AcceptedFundingAsset::DOT.id(), 100_0_000_000_000_u128)).try_into().unwrap(),
-					Asset::from((AcceptedFundingAsset::USDC.id(), 100_000_u128)).try_into().unwrap(),
-					Asset::from((AcceptedFundingAsset::USDT.id(), 100_000_u128)).try_into().unwrap(),
-					Asset::from((AcceptedFundingAsset::ETH.id(), 100_000_000_000_000_u128)).try_into().unwrap(),
+					Asset::from((AcceptedFundingAsset::DOT.id().unwrap(), 100_0_000_000_000_u128)).try_into().unwrap(),
+					Asset::from((AcceptedFundingAsset::USDC.id().unwrap(), 100_000_u128)).try_into().unwrap(),
+					Asset::from((AcceptedFundingAsset::USDT.id().unwrap(), 100_000_u128)).try_into().unwrap(),
+					Asset::from((AcceptedFundingAsset::ETH.id().unwrap(), 100_000_000_000_000_u128))
+						.try_into()
+						.unwrap(),
 				]
 				.into(),
 			);
@@ -64,7 +64,7 @@ fn fee_paid_with_foreign_assets() {
 		set_author(1u64);
 		assert_eq!(polimec_runtime::Authorship::author(), Some(block_author.clone()));
 
-		let usdt_id = AcceptedFundingAsset::USDT.id();
+		let usdt_id = AcceptedFundingAsset::USDT.id().unwrap();
 		let usdt_decimals =
 			<PolimecForeignAssets as fungibles::metadata::Inspect<PolimecAccountId>>::decimals(usdt_id.clone());
 		let usdt_unit = 10u128.pow(usdt_decimals as u32);
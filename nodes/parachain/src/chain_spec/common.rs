@@ -104,10 +104,10 @@ pub fn genesis_config(genesis_config_params: GenesisConfigParams) -> serde_json:
 	#[cfg(feature = "runtime-benchmarks")]
 	let staking_candidates: Vec<(AccountId, Balance)> = vec![];
 
-	let usdt_id = AcceptedFundingAsset::USDT.id();
-	let usdc_id = AcceptedFundingAsset::USDC.id();
-	let dot_id = AcceptedFundingAsset::DOT.id();
-	let eth_id = AcceptedFundingAsset::ETH.id();
+	let usdt_id = AcceptedFundingAsset::USDT.id().unwrap();
+	let usdc_id = AcceptedFundingAsset::USDC.id().unwrap();
+	let dot_id = AcceptedFundingAsset::DOT.id().unwrap();
+	let eth_id = AcceptedFundingAsset::ETH.id().unwrap();
 
 	serde_json::json!({
 		"balances": {
@@ -118,25 +118,25 @@ pub fn genesis_config(genesis_config_params: GenesisConfigParams) -> serde_json:
 		},
 		"foreignAssets":  {
 			"assets": vec![(
-				AcceptedFundingAsset::USDT.id(),
+				AcceptedFundingAsset::USDT.id().unwrap(),
 				&AccountIdConversion::<AccountId>::into_account_truncating(&<Runtime as pallet_funding::Config>::PalletId::get()),
 				true,
 				70000,
 			),
 			(
-				AcceptedFundingAsset::USDC.id(),
+				AcceptedFundingAsset::USDC.id().unwrap(),
 				&AccountIdConversion::<AccountId>::into_account_truncating(&<Runtime as pallet_funding::Config>::PalletId::get()),
 				true,
 				70000,
 			),
 			(
-				AcceptedFundingAsset::DOT.id(),
+				AcceptedFundingAsset::DOT.id().unwrap(),
 				&AccountIdConversion::<AccountId>::into_account_truncating(&<Runtime as pallet_funding::Config>::PalletId::get()),
 				true,
 				70000,
 			),
 			(
-				AcceptedFundingAsset::ETH.id(),
+				AcceptedFundingAsset::ETH.id().unwrap(),
 				&AccountIdConversion::<AccountId>::into_account_truncating(&<Runtime as pallet_funding::Config>::PalletId::get()),
 				true,
 				70000,
@@ -26,23 +26,21 @@ use frame_support::{
 	assert_ok,
 	dispatch::RawOrigin,
 	traits::{
-		fungibles::{metadata::MetadataDeposit, Inspect},
-		OriginTrait,
+		fungibles::{metadata::MetadataDeposit, Inspect, InspectFreeze},
+		EnsureOrigin, OriginTrait,
 	},
 	Parameter,
 };
 use itertools::Itertools;
 use parity_scale_codec::{Decode, Encode};
 use polimec_common::{
-	assets::{
-		AcceptedFundingAsset,
-		AcceptedFundingAsset::{DOT, ETH, USDC, USDT},
-	},
+	assets::{AcceptedFundingAsset, FundingAssetInfo, DOT, ETH, MAX_FUNDING_ASSETS, USDC, USDT},
 	credentials::InvestorType,
+	migration_types::Migrations,
 	ProvideAssetPrice, USD_DECIMALS, USD_UNIT,
 };
 use polimec_common_test_utils::{generate_did_from_account, get_mock_jwt_with_cid};
-use sp_arithmetic::Percent;
+use sp_arithmetic::{Percent, Perquintill};
 use sp_core::H256;
 use sp_io::hashing::blake2_256;
 use sp_runtime::traits::{Get, Member, TrailingZeroInput, Zero};
@@ -53,7 +51,7 @@ const CT_UNIT: u128 = 10u128.pow(CT_DECIMALS as u32);
 type BenchInstantiator<T> = Instantiator<T, <T as Config>::AllPalletsWithoutSystem, <T as Config>::RuntimeEvent>;
 
 pub fn usdt_id() -> Location {
-	AcceptedFundingAsset::USDT.id()
+	AcceptedFundingAsset::USDT.id().unwrap()
 }
 
 pub fn default_project_metadata<T: Config>(issuer: AccountIdOf<T>) -> ProjectMetadataOf<T>
@@ -81,6 +79,11 @@ where
 		funding_destination_account: issuer,
 		policy_ipfs_cid: Some(metadata_hash.into()),
 		participants_account_type: ParticipantsAccountType::Polkadot,
+		sale_mode: SaleMode::Auction,
+		ct_vesting: None,
+		funding_milestones: BoundedVec::new(),
+		evaluation_success_threshold: None,
+		funding_success_threshold: None,
 	}
 }
 
@@ -103,6 +106,7 @@ pub fn string_account<AccountId: Decode>(
 	T::Hash: From<H256>,
 	<T as frame_system::Config>::AccountId: Into<<<T as frame_system::Config>::RuntimeOrigin as OriginTrait>::AccountId> + core::fmt::Debug,
 	<T as pallet_balances::Config>::Balance: Into<Balance>,
+	<T as frame_system::Config>::RuntimeOrigin: From<pallet_xcm::Origin>,
 )]
 mod benchmarks {
 	use super::*;
@@ -237,6 +241,11 @@ mod benchmarks {
 			funding_destination_account: issuer_funding.clone().clone(),
 			policy_ipfs_cid: Some(BoundedVec::try_from(IPFS_CID.as_bytes().to_vec()).unwrap()),
 			participants_account_type: ParticipantsAccountType::Ethereum,
+			sale_mode: SaleMode::Auction,
+			ct_vesting: None,
+			funding_milestones: BoundedVec::new(),
+			evaluation_success_threshold: None,
+			funding_success_threshold: None,
 		};
 
 		let jwt = get_mock_jwt_with_cid(
@@ -261,6 +270,40 @@ mod benchmarks {
 		);
 	}
 
+	#[benchmark]
+	fn set_allowlist() {
+		// * setup *
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.advance_time(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		whitelist_account!(issuer);
+
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let project_id = inst.create_new_project(project_metadata.clone(), issuer.clone(), None);
+		let allowlist_root = Some([1u8; 32]);
+
+		let jwt = get_mock_jwt_with_cid(
+			issuer.clone(),
+			InvestorType::Institutional,
+			generate_did_from_account(issuer.clone()),
+			project_metadata.clone().policy_ipfs_cid.unwrap(),
+		);
+
+		#[extrinsic_call]
+		set_allowlist(RawOrigin::Signed(issuer), jwt, project_id, allowlist_root);
+
+		// * validity checks *
+		// Storage
+		assert_eq!(ProjectAllowlists::<T>::get(project_id), allowlist_root);
+
+		// Events
+		frame_system::Pallet::<T>::assert_last_event(Event::<T>::AllowlistSet { project_id, allowlist_root }.into());
+	}
+
 	#[benchmark]
 	fn start_evaluation() {
 		// * setup *
@@ -301,6 +344,7 @@ mod benchmarks {
 
 		assert_eq!(evaluation_transition_points.start(), Some(evaluation_start));
 		assert_eq!(evaluation_transition_points.end(), Some(evaluation_end));
+		assert_eq!(ProjectFeeConfigs::<T>::get(project_id), Some(FeeConfiguration::<T>::get()));
 
 		// Events
 		frame_system::Pallet::<T>::assert_last_event(
@@ -387,6 +431,68 @@ mod benchmarks {
 		);
 	}
 
+	// x: number of evaluations on the project, all of which are iterated to reassign the freed early USD amount
+	#[benchmark]
+	fn withdraw_evaluation(x: Linear<1, 100>) {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.advance_time(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let test_evaluator = account::<AccountIdOf<T>>("evaluator", 0, 0);
+		whitelist_account!(test_evaluator);
+
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let project_id = inst.create_evaluating_project(project_metadata.clone(), issuer, None);
+		let project_details = inst.get_project_details(project_id);
+		let early_evaluation_threshold_usd =
+			Pallet::<T>::evaluation_success_threshold(&project_metadata) * project_details.fundraising_target_usd;
+
+		// The withdrawn evaluation takes up the whole early amount, so every other evaluation gets some reassigned
+		let mut evaluations = vec![EvaluationParams::from((test_evaluator.clone(), early_evaluation_threshold_usd))];
+		for i in 0..x.saturating_sub(1) {
+			evaluations.push(EvaluationParams::from((
+				account::<AccountIdOf<T>>("other_evaluator", i, 0),
+				(1_000 * USD_UNIT).into(),
+			)));
+		}
+		let plmc_for_evaluating = inst.calculate_evaluation_plmc_spent(evaluations.clone());
+		inst.mint_plmc_ed_if_required(plmc_for_evaluating.accounts());
+		inst.mint_plmc_to(plmc_for_evaluating.clone());
+		inst.evaluate_for_users(project_id, evaluations).expect("All evaluations are accepted");
+
+		let stored_evaluation =
+			inst.execute(|| Evaluations::<T>::iter_prefix_values((project_id, test_evaluator.clone())).next().unwrap());
+		let withdrawn_plmc = stored_evaluation.current_plmc_bond;
+
+		#[extrinsic_call]
+		withdraw_evaluation(RawOrigin::Signed(test_evaluator.clone()), project_id, stored_evaluation.id, x);
+
+		// * validity checks *
+		// Storage
+		assert!(Evaluations::<T>::get((project_id, test_evaluator.clone(), stored_evaluation.id)).is_none());
+
+		// Balances
+		let bonded_plmc = inst
+			.get_reserved_plmc_balances_for(vec![test_evaluator.clone()], HoldReason::Evaluation.into())[0]
+			.plmc_amount;
+		assert_eq!(bonded_plmc, Zero::zero());
+
+		// Events
+		frame_system::Pallet::<T>::assert_last_event(
+			Event::<T>::EvaluationWithdrawn {
+				project_id,
+				evaluator: test_evaluator,
+				id: stored_evaluation.id,
+				plmc_amount: withdrawn_plmc,
+			}
+			.into(),
+		);
+	}
+
 	// There are 2 logic branches in end_evaluation
 	// 1. If the evaluation round is successful
 	// 2. If the evaluation round failed
@@ -404,11 +510,11 @@ mod benchmarks {
 		whitelist_account!(issuer);
 
 		let project_metadata = default_project_metadata::<T>(issuer.clone());
-		let project_id = inst.create_evaluating_project(project_metadata, issuer.clone(), None);
+		let project_id = inst.create_evaluating_project(project_metadata.clone(), issuer.clone(), None);
 		let project_details = inst.get_project_details(project_id);
 
 		let evaluation_usd_target =
-			<T as Config>::EvaluationSuccessThreshold::get() * project_details.fundraising_target_usd;
+			Pallet::<T>::evaluation_success_threshold(&project_metadata) * project_details.fundraising_target_usd;
 		// we only fund 50% of the minimum threshold for the evaluation round, since we want it to fail
 		let evaluations = vec![
 			EvaluationParams::from((
@@ -555,6 +661,155 @@ mod benchmarks {
 		}
 	}
 
+	#[benchmark]
+	fn contribute() {
+		// * setup *
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.advance_time(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let contributor = account::<AccountIdOf<T>>("contributor", 0, 0);
+		whitelist_account!(contributor);
+
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+		let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 50, 10);
+		let project_id =
+			inst.create_community_contributing_project(project_metadata.clone(), issuer, None, evaluations, bids);
+
+		let ct_amount = Percent::from_percent(10) * project_metadata.total_allocation_size;
+		let extrinsic_contribution = BidParams::from((
+			contributor.clone(),
+			Institutional,
+			ct_amount,
+			ParticipationMode::Classic(1u8),
+			AcceptedFundingAsset::USDT,
+		));
+		inst.mint_necessary_tokens_for_contributions(project_id, vec![extrinsic_contribution.clone()]);
+
+		let wap = inst.get_community_round_price(project_id);
+		let remaining_cts_before = inst.get_project_details(project_id).remaining_contribution_tokens;
+
+		let jwt = get_mock_jwt_with_cid(
+			contributor.clone(),
+			InvestorType::Institutional,
+			generate_did_from_account(contributor.clone()),
+			project_metadata.clone().policy_ipfs_cid.unwrap(),
+		);
+
+		#[extrinsic_call]
+		contribute(
+			RawOrigin::Signed(contributor.clone()),
+			jwt,
+			project_id,
+			extrinsic_contribution.amount,
+			extrinsic_contribution.mode,
+			extrinsic_contribution.asset,
+		);
+
+		// * validity checks *
+		// Storage
+		let stored_contribution = Contributions::<T>::iter_prefix_values(project_id)
+			.find(|contribution| contribution.contributor == contributor.clone())
+			.expect("contribution not found");
+		assert_eq!(stored_contribution.ct_amount, ct_amount);
+		assert_eq!(stored_contribution.ct_usd_price, wap);
+
+		let project_details = inst.get_project_details(project_id);
+		assert_eq!(project_details.remaining_contribution_tokens, remaining_cts_before - ct_amount);
+	}
+
+	// We benchmark the worst case, which is an oversubscribing bid being cancelled after all the bids it outbid
+	// were already processed, so they all need to be accepted again.
+	#[benchmark]
+	fn cancel_bid(
+		// Amount of outbid bids that are accepted again
+		x: Linear<1, 10>,
+	) {
+		// * setup *
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.advance_time(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let bidder = account::<AccountIdOf<T>>("bidder", 0, 0);
+		whitelist_account!(bidder);
+
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+		let project_id = inst.create_auctioning_project(project_metadata.clone(), issuer, None, evaluations);
+
+		// The last bids of the first bucket are the first ones to be outbid
+		let small_bid_amount = project_metadata.total_allocation_size / 200;
+		let outbid_amount = small_bid_amount * x as u128;
+		let mut first_bucket_bids = vec![BidParams::from((
+			account::<AccountIdOf<T>>("big_bidder", 0, 0),
+			Institutional,
+			project_metadata.total_allocation_size - outbid_amount,
+			ParticipationMode::Classic(1u8),
+			AcceptedFundingAsset::USDT,
+		))];
+		for i in 0..x {
+			first_bucket_bids.push(BidParams::from((
+				account::<AccountIdOf<T>>("small_bidder", i, 0),
+				Institutional,
+				small_bid_amount,
+				ParticipationMode::Classic(1u8),
+				AcceptedFundingAsset::USDT,
+			)));
+		}
+		inst.mint_necessary_tokens_for_bids(project_id, first_bucket_bids.clone());
+		inst.bid_for_users(project_id, first_bucket_bids).unwrap();
+
+		let oversubscribing_bid = BidParams::from((
+			bidder.clone(),
+			Institutional,
+			outbid_amount,
+			ParticipationMode::Classic(1u8),
+			AcceptedFundingAsset::USDT,
+		));
+		inst.mint_necessary_tokens_for_bids(project_id, vec![oversubscribing_bid.clone()]);
+		inst.bid_for_users(project_id, vec![oversubscribing_bid]).unwrap();
+		inst.process_oversubscribed_bids(project_id);
+
+		let bid_to_cancel = Bids::<T>::iter_prefix_values(project_id).find(|bid| bid.bidder == bidder).unwrap();
+		let outbid_bids = Bids::<T>::iter_prefix_values(project_id)
+			.filter(|bid| bid.status == BidStatus::Rejected)
+			.map(|bid| bid.id)
+			.collect_vec();
+		assert_eq!(outbid_bids.len(), x as usize);
+
+		#[extrinsic_call]
+		cancel_bid(RawOrigin::Signed(bidder.clone()), project_id, bid_to_cancel.id, x);
+
+		// * validity checks *
+		// Storage
+		assert!(Bids::<T>::get(project_id, bid_to_cancel.id).is_none());
+		for bid_id in outbid_bids {
+			assert_eq!(Bids::<T>::get(project_id, bid_id).unwrap().status, BidStatus::YetUnknown);
+		}
+		assert!(OutbidBidsCutoffs::<T>::get(project_id).is_none());
+		assert!(CTAmountOversubscribed::<T>::get(project_id).is_zero());
+
+		// Events
+		frame_system::Pallet::<T>::assert_last_event(
+			Event::BidCancelled {
+				project_id,
+				bidder,
+				id: bid_to_cancel.id,
+				ct_amount: bid_to_cancel.original_ct_amount,
+				funding_amount: bid_to_cancel.funding_asset_amount_locked,
+				plmc_bond: bid_to_cancel.plmc_bond,
+			}
+			.into(),
+		);
+	}
+
 	// We benchmark the worst case, which is a new cutoff being calculated.
 	// This doesn't happen when the first bid we read is partially accepted instead of rejected.
 	#[benchmark]
@@ -624,7 +879,7 @@ mod benchmarks {
 		let mut inst = BenchInstantiator::<T>::new(None);
 		<T as Config>::SetPrices::set_prices();
 
-		let eth_price = PriceProviderOf::<T>::get_price(ETH.id()).unwrap();
+		let eth_price = PriceProviderOf::<T>::get_price(ETH.id().unwrap()).unwrap();
 		log::info!("eth_price: {:?}", eth_price);
 
 		// We can't see events at block 0
@@ -656,6 +911,9 @@ mod benchmarks {
 		inst.mint_funding_asset_to(necessary_funding_asset.clone());
 		inst.bid_for_users(project_id, bids).unwrap();
 
+		// The auction did not sell out, so the funding only ends after the community round
+		assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::CommunityRound);
+
 		let end_block = inst.get_project_details(project_id).round_duration.end().unwrap();
 		inst.jump_to_block(end_block);
 
@@ -673,6 +931,52 @@ mod benchmarks {
 		));
 	}
 
+	#[benchmark]
+	fn decide_project_outcome() {
+		// * setup *
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.advance_time(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		whitelist_account!(issuer);
+
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+		// Between the issuer decision threshold and the funding success threshold
+		let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 30, 10);
+		let project_id =
+			inst.create_finished_project(project_metadata.clone(), issuer.clone(), None, evaluations, bids);
+		assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::AwaitingIssuerDecision);
+
+		let jwt = get_mock_jwt_with_cid(
+			issuer.clone(),
+			InvestorType::Institutional,
+			generate_did_from_account(issuer.clone()),
+			project_metadata.clone().policy_ipfs_cid.unwrap(),
+		);
+
+		// Accepting is the most expensive, since the evaluator rewards are calculated
+		#[extrinsic_call]
+		decide_project_outcome(RawOrigin::Signed(issuer), jwt, project_id, FundingOutcomeDecision::AcceptFunding);
+
+		// * validity checks *
+		// Storage
+		let project_details = inst.get_project_details(project_id);
+		assert_eq!(project_details.status, ProjectStatus::FundingSuccessful);
+		assert!(matches!(
+			project_details.evaluation_round_info.evaluators_outcome,
+			Some(EvaluatorsOutcome::Rewarded(_))
+		));
+
+		// Events
+		frame_system::Pallet::<T>::assert_last_event(
+			Event::<T>::ProjectOutcomeDecided { project_id, decision: FundingOutcomeDecision::AcceptFunding }.into(),
+		);
+	}
+
 	// Success case is the most expensive, so we always charge for that.
 	#[benchmark]
 	fn start_settlement() {
@@ -832,64 +1136,256 @@ mod benchmarks {
 		);
 	}
 
+	// We have 2 logic paths
+	// 1 - Funding successful (CTs minted, migration created, funding paid out)
+	// 2 - Funding failed (everything refunded)
+	// Path 1 is the most expensive, so we only benchmark and charge for this weight
 	#[benchmark]
-	fn mark_project_as_settled() {
+	fn settle_contribution() {
 		// setup
 		let mut inst = BenchInstantiator::<T>::new(None);
 		<T as Config>::SetPrices::set_prices();
 
+		// We can't see events at block 0
+		inst.advance_time(1u32.into());
+
 		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
-		let anyone = account::<AccountIdOf<T>>("anyone", 0, 0);
-		whitelist_account!(anyone);
+		let contributor = account::<AccountIdOf<T>>("contributor", 0, 0);
+		whitelist_account!(contributor);
 
 		let project_metadata = default_project_metadata::<T>(issuer.clone());
 		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 10);
-		let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 95, 30);
-
+		let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 50, 10);
 		let project_id =
-			inst.create_settled_project(project_metadata.clone(), issuer.clone(), None, evaluations, bids, false);
+			inst.create_community_contributing_project(project_metadata.clone(), issuer, None, evaluations, bids);
+
+		let ct_amount = Percent::from_percent(10) * project_metadata.total_allocation_size;
+		let contribution = BidParams::from((
+			contributor.clone(),
+			Institutional,
+			ct_amount,
+			ParticipationMode::Classic(1u8),
+			AcceptedFundingAsset::USDT,
+		));
+		inst.mint_necessary_tokens_for_contributions(project_id, vec![contribution.clone()]);
+		inst.contribute_for_users(project_id, vec![contribution]).unwrap();
+
+		assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::FundingSuccessful);
+		assert_ok!(<Pallet<T>>::do_start_settlement(project_id));
+
+		let contribution_to_settle = inst.get_contributions(project_id)[0].clone();
 
 		#[extrinsic_call]
-		mark_project_as_settled(RawOrigin::Signed(anyone), project_id);
+		settle_contribution(RawOrigin::Signed(contributor.clone()), project_id, contribution_to_settle.id);
 
 		// * validity checks *
-		let project_details = inst.get_project_details(project_id);
-		assert_eq!(project_details.status, ProjectStatus::SettlementFinished(FundingOutcome::Success));
+		// Storage
+		assert!(Contributions::<T>::get(project_id, contribution_to_settle.id).is_none());
+
+		// Balances
+		inst.assert_ct_balance(project_id, contributor.clone(), ct_amount);
+
+		// Events
+		frame_system::Pallet::<T>::assert_last_event(
+			Event::ContributionSettled {
+				project_id,
+				account: contributor.clone(),
+				id: contribution_to_settle.id,
+				ct_amount,
+			}
+			.into(),
+		);
 	}
 
 	#[benchmark]
-	fn start_offchain_migration() {
+	fn claim_vested_contribution_tokens() {
 		// setup
 		let mut inst = BenchInstantiator::<T>::new(None);
 		<T as Config>::SetPrices::set_prices();
 
+		// We can't see events at block 0
+		inst.advance_time(1u32.into());
+
 		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let contributor = account::<AccountIdOf<T>>("contributor", 0, 0);
+		whitelist_account!(contributor);
 
-		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let ct_vesting = ContributionTokenVesting { cliff: 10, duration: 100 };
+		let project_metadata =
+			ProjectMetadataOf::<T> { ct_vesting: Some(ct_vesting), ..default_project_metadata::<T>(issuer.clone()) };
 		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 10);
-		let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 34, 30);
+		let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 50, 10);
 		let project_id =
-			inst.create_settled_project(project_metadata.clone(), issuer.clone(), None, evaluations, bids, true);
+			inst.create_community_contributing_project(project_metadata.clone(), issuer, None, evaluations, bids);
 
-		let jwt = get_mock_jwt_with_cid(
-			issuer.clone(),
-			InvestorType::Institutional,
-			generate_did_from_account(issuer.clone()),
-			project_metadata.clone().policy_ipfs_cid.unwrap(),
-		);
+		let ct_amount = Percent::from_percent(10) * project_metadata.total_allocation_size;
+		let contribution = BidParams::from((
+			contributor.clone(),
+			Institutional,
+			ct_amount,
+			ParticipationMode::Classic(1u8),
+			AcceptedFundingAsset::USDT,
+		));
+		inst.mint_necessary_tokens_for_contributions(project_id, vec![contribution.clone()]);
+		inst.contribute_for_users(project_id, vec![contribution]).unwrap();
+
+		assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::FundingSuccessful);
+		assert_ok!(<Pallet<T>>::do_start_settlement(project_id));
+
+		let contribution_to_settle = inst.get_contributions(project_id)[0].clone();
+		assert_ok!(<Pallet<T>>::do_settle_contribution(project_id, contribution_to_settle.id));
+
+		// Claim halfway through the vesting, so the remaining tokens stay frozen
+		let funding_end_block = inst.get_project_details(project_id).funding_end_block.unwrap();
+		inst.jump_to_block(funding_end_block + 60u32.into());
+		let claimed_amount = ct_vesting.vested_amount(ct_amount, 60);
 
 		#[extrinsic_call]
-		start_offchain_migration(RawOrigin::Signed(issuer), jwt, project_id);
+		claim_vested_contribution_tokens(RawOrigin::Signed(contributor.clone()), project_id);
 
 		// * validity checks *
-		let project_details = inst.get_project_details(project_id);
-		assert_eq!(project_details.status, ProjectStatus::CTMigrationStarted);
-		assert_eq!(UnmigratedCounter::<T>::get(project_id), 40);
-	}
+		// Storage
+		assert_eq!(ContributionTokenVestings::<T>::get(project_id, contributor.clone()), ct_amount);
 
-	#[benchmark]
-	fn confirm_offchain_migration(
-		// Amount of migrations to confirm for a single user
+		// Balances
+		inst.assert_ct_balance(project_id, contributor.clone(), ct_amount);
+		let frozen_amount = T::ContributionTokenFreezer::balance_frozen(
+			project_id,
+			&FreezeReason::ContributionTokenVesting.into(),
+			&contributor,
+		);
+		assert_eq!(frozen_amount, ct_amount - claimed_amount);
+
+		// Events
+		frame_system::Pallet::<T>::assert_last_event(
+			Event::VestedContributionTokensClaimed { project_id, account: contributor, amount: claimed_amount }.into(),
+		);
+	}
+
+	#[benchmark]
+	fn decide_funding_milestone() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.advance_time(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let project_metadata = ProjectMetadataOf::<T> {
+			funding_milestones: vec![Percent::from_percent(50), Percent::from_percent(50)].try_into().unwrap(),
+			..default_project_metadata::<T>(issuer.clone())
+		};
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 10);
+		let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 95, 30);
+		let project_id =
+			inst.create_settled_project(project_metadata.clone(), issuer.clone(), None, evaluations, bids, true);
+
+		let origin = T::MilestoneOrigin::try_successful_origin().expect("MilestoneOrigin has no successful origin");
+
+		#[extrinsic_call]
+		decide_funding_milestone(origin as <T as frame_system::Config>::RuntimeOrigin, project_id, 0, true);
+
+		// * validity checks *
+		// Storage
+		assert_eq!(MilestoneOutcomes::<T>::get(project_id, 0), Some(MilestoneOutcome::Released));
+
+		// Events
+		frame_system::Pallet::<T>::assert_last_event(
+			Event::FundingMilestoneDecided { project_id, milestone_index: 0, outcome: MilestoneOutcome::Released }
+				.into(),
+		);
+	}
+
+	#[benchmark]
+	fn claim_milestone_refund() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.advance_time(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let project_metadata = ProjectMetadataOf::<T> {
+			funding_milestones: vec![Percent::from_percent(50), Percent::from_percent(50)].try_into().unwrap(),
+			..default_project_metadata::<T>(issuer.clone())
+		};
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 10);
+		let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 95, 30);
+		let bidder = bids[0].bidder.clone();
+		whitelist_account!(bidder);
+		let project_id =
+			inst.create_settled_project(project_metadata.clone(), issuer.clone(), None, evaluations, bids, true);
+
+		assert_ok!(<Pallet<T>>::do_decide_funding_milestone(project_id, 0, false));
+
+		#[extrinsic_call]
+		claim_milestone_refund(RawOrigin::Signed(bidder.clone()), project_id, 0);
+
+		// * validity checks *
+		// Storage
+		assert!(MilestoneRefundsClaimed::<T>::contains_key((project_id, 0, bidder)));
+	}
+
+	#[benchmark]
+	fn mark_project_as_settled() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let anyone = account::<AccountIdOf<T>>("anyone", 0, 0);
+		whitelist_account!(anyone);
+
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 10);
+		let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 95, 30);
+
+		let project_id =
+			inst.create_settled_project(project_metadata.clone(), issuer.clone(), None, evaluations, bids, false);
+
+		#[extrinsic_call]
+		mark_project_as_settled(RawOrigin::Signed(anyone), project_id);
+
+		// * validity checks *
+		let project_details = inst.get_project_details(project_id);
+		assert_eq!(project_details.status, ProjectStatus::SettlementFinished(FundingOutcome::Success));
+	}
+
+	#[benchmark]
+	fn start_offchain_migration() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 10);
+		let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 34, 30);
+		let project_id =
+			inst.create_settled_project(project_metadata.clone(), issuer.clone(), None, evaluations, bids, true);
+
+		let jwt = get_mock_jwt_with_cid(
+			issuer.clone(),
+			InvestorType::Institutional,
+			generate_did_from_account(issuer.clone()),
+			project_metadata.clone().policy_ipfs_cid.unwrap(),
+		);
+
+		#[extrinsic_call]
+		start_offchain_migration(RawOrigin::Signed(issuer), jwt, project_id);
+
+		// * validity checks *
+		let project_details = inst.get_project_details(project_id);
+		assert_eq!(project_details.status, ProjectStatus::CTMigrationStarted);
+		assert_eq!(UnmigratedCounter::<T>::get(project_id), 40);
+	}
+
+	#[benchmark]
+	fn confirm_offchain_migration(
+		// Amount of migrations to confirm for a single user
 		x: Linear<1, 100>,
 	) {
 		// setup
@@ -983,6 +1479,8 @@ mod benchmarks {
 			)
 			.unwrap()
 		}
+		let now = inst.current_block();
+		inst.jump_to_block(now + T::MigrationDisputeWindow::get() + One::one());
 
 		#[extrinsic_call]
 		mark_project_ct_migration_as_finished(RawOrigin::Signed(issuer), project_id);
@@ -998,4 +1496,904 @@ mod benchmarks {
 			true
 		);
 	}
+
+	#[benchmark]
+	fn start_pallet_migration() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 10);
+		let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 34, 30);
+		let project_id =
+			inst.create_settled_project(project_metadata.clone(), issuer.clone(), None, evaluations, bids, true);
+
+		let jwt = get_mock_jwt_with_cid(
+			issuer.clone(),
+			InvestorType::Institutional,
+			generate_did_from_account(issuer.clone()),
+			project_metadata.clone().policy_ipfs_cid.unwrap(),
+		);
+
+		#[extrinsic_call]
+		start_pallet_migration(RawOrigin::Signed(issuer), jwt, project_id, ParaId::from(6969u32));
+
+		// * validity checks *
+		let project_details = inst.get_project_details(project_id);
+		assert_eq!(project_details.status, ProjectStatus::CTMigrationStarted);
+		assert_eq!(PalletMigrations::<T>::get(project_id).unwrap().parachain_id, ParaId::from(6969u32));
+	}
+
+	#[benchmark]
+	fn start_pallet_migration_readiness_check() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let anyone = account::<AccountIdOf<T>>("anyone", 0, 0);
+
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 10);
+		let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 34, 30);
+		let project_id =
+			inst.create_settled_project(project_metadata.clone(), issuer.clone(), None, evaluations, bids, true);
+
+		let jwt = get_mock_jwt_with_cid(
+			issuer.clone(),
+			InvestorType::Institutional,
+			generate_did_from_account(issuer.clone()),
+			project_metadata.clone().policy_ipfs_cid.unwrap(),
+		);
+		let para_id = ParaId::from(6969u32);
+		<Pallet<T>>::start_pallet_migration(RawOrigin::Signed(issuer).into(), jwt, project_id, para_id).unwrap();
+		PalletMigrations::<T>::mutate(project_id, |info| {
+			info.as_mut().unwrap().hrmp_channel_status =
+				HRMPChannelStatus { project_to_polimec: ChannelStatus::Open, polimec_to_project: ChannelStatus::Open };
+		});
+		cumulus_pallet_parachain_system::Pallet::<T>::open_outbound_hrmp_channel_for_benchmarks_or_tests(para_id);
+
+		#[extrinsic_call]
+		start_pallet_migration_readiness_check(RawOrigin::Signed(anyone), project_id);
+
+		// * validity checks *
+		let readiness_check = PalletMigrations::<T>::get(project_id).unwrap().migration_readiness_check.unwrap();
+		assert_eq!(readiness_check.holding_check.1, CheckOutcome::AwaitingResponse);
+		assert_eq!(readiness_check.pallet_check.1, CheckOutcome::AwaitingResponse);
+	}
+
+	#[benchmark]
+	fn pallet_migration_readiness_response() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 10);
+		let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 34, 30);
+		let project_id =
+			inst.create_settled_project(project_metadata.clone(), issuer.clone(), None, evaluations, bids, true);
+
+		let jwt = get_mock_jwt_with_cid(
+			issuer.clone(),
+			InvestorType::Institutional,
+			generate_did_from_account(issuer.clone()),
+			project_metadata.clone().policy_ipfs_cid.unwrap(),
+		);
+		let para_id = ParaId::from(6969u32);
+		<Pallet<T>>::start_pallet_migration(RawOrigin::Signed(issuer.clone()).into(), jwt, project_id, para_id)
+			.unwrap();
+		PalletMigrations::<T>::mutate(project_id, |info| {
+			info.as_mut().unwrap().hrmp_channel_status =
+				HRMPChannelStatus { project_to_polimec: ChannelStatus::Open, polimec_to_project: ChannelStatus::Open };
+		});
+		cumulus_pallet_parachain_system::Pallet::<T>::open_outbound_hrmp_channel_for_benchmarks_or_tests(para_id);
+		<Pallet<T>>::start_pallet_migration_readiness_check(RawOrigin::Signed(issuer).into(), project_id).unwrap();
+
+		let project_location = xcm::v5::Location::new(1, [xcm::v5::Junction::Parachain(para_id.into())]);
+		let readiness_check = PalletMigrations::<T>::get(project_id).unwrap().migration_readiness_check.unwrap();
+		let total_cts_minted = <T as Config>::ContributionTokenCurrency::total_issuance(project_id);
+		let held_tokens: xcm::v5::Asset = (project_location.clone(), total_cts_minted).into();
+		let response = xcm::v5::Response::Assets(held_tokens.into());
+		let origin: <T as frame_system::Config>::RuntimeOrigin = pallet_xcm::Origin::Response(project_location).into();
+
+		#[extrinsic_call]
+		pallet_migration_readiness_response(
+			origin as <T as frame_system::Config>::RuntimeOrigin,
+			readiness_check.holding_check.0,
+			response,
+		);
+
+		// * validity checks *
+		let readiness_check = PalletMigrations::<T>::get(project_id).unwrap().migration_readiness_check.unwrap();
+		assert_eq!(readiness_check.holding_check.1, CheckOutcome::Passed(None));
+	}
+
+	#[benchmark]
+	fn send_pallet_migration_for() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 10);
+		let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 34, 30);
+		let project_id =
+			inst.create_settled_project(project_metadata.clone(), issuer.clone(), None, evaluations, bids, true);
+
+		let jwt = get_mock_jwt_with_cid(
+			issuer.clone(),
+			InvestorType::Institutional,
+			generate_did_from_account(issuer.clone()),
+			project_metadata.clone().policy_ipfs_cid.unwrap(),
+		);
+		let para_id = ParaId::from(6969u32);
+		<Pallet<T>>::start_pallet_migration(RawOrigin::Signed(issuer.clone()).into(), jwt, project_id, para_id)
+			.unwrap();
+		PalletMigrations::<T>::mutate(project_id, |info| {
+			let info = info.as_mut().unwrap();
+			info.hrmp_channel_status =
+				HRMPChannelStatus { project_to_polimec: ChannelStatus::Open, polimec_to_project: ChannelStatus::Open };
+			info.migration_readiness_check = Some(PalletMigrationReadinessCheck {
+				holding_check: (0, CheckOutcome::Passed(None)),
+				pallet_check: (1, CheckOutcome::Passed(Some(51))),
+			});
+		});
+		cumulus_pallet_parachain_system::Pallet::<T>::open_outbound_hrmp_channel_for_benchmarks_or_tests(para_id);
+
+		let participant = UserMigrations::<T>::iter_key_prefix((project_id,)).next().unwrap();
+
+		#[extrinsic_call]
+		send_pallet_migration_for(RawOrigin::Signed(issuer), project_id, participant.clone());
+
+		// * validity checks *
+		assert_eq!(
+			ActiveMigrationQueue::<T>::iter_values()
+				.map(|(project_id, participant, _)| (project_id, participant))
+				.collect_vec(),
+			vec![(project_id, participant.clone())]
+		);
+		assert_eq!(UserMigrations::<T>::get((project_id, participant)).unwrap().0, MigrationStatus::Sent);
+	}
+
+	#[benchmark]
+	fn confirm_pallet_migrations() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 10);
+		let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 34, 30);
+		let project_id =
+			inst.create_settled_project(project_metadata.clone(), issuer.clone(), None, evaluations, bids, true);
+
+		let jwt = get_mock_jwt_with_cid(
+			issuer.clone(),
+			InvestorType::Institutional,
+			generate_did_from_account(issuer.clone()),
+			project_metadata.clone().policy_ipfs_cid.unwrap(),
+		);
+		let para_id = ParaId::from(6969u32);
+		<Pallet<T>>::start_pallet_migration(RawOrigin::Signed(issuer.clone()).into(), jwt, project_id, para_id)
+			.unwrap();
+		PalletMigrations::<T>::mutate(project_id, |info| {
+			let info = info.as_mut().unwrap();
+			info.hrmp_channel_status =
+				HRMPChannelStatus { project_to_polimec: ChannelStatus::Open, polimec_to_project: ChannelStatus::Open };
+			info.migration_readiness_check = Some(PalletMigrationReadinessCheck {
+				holding_check: (0, CheckOutcome::Passed(None)),
+				pallet_check: (1, CheckOutcome::Passed(Some(51))),
+			});
+		});
+		cumulus_pallet_parachain_system::Pallet::<T>::open_outbound_hrmp_channel_for_benchmarks_or_tests(para_id);
+
+		let participant = UserMigrations::<T>::iter_key_prefix((project_id,)).next().unwrap();
+		<Pallet<T>>::send_pallet_migration_for(RawOrigin::Signed(issuer).into(), project_id, participant.clone())
+			.unwrap();
+		let query_id = ActiveMigrationQueue::<T>::iter_keys().next().unwrap();
+
+		let project_location = xcm::v5::Location::new(1, [xcm::v5::Junction::Parachain(para_id.into())]);
+		let response = xcm::v5::Response::DispatchResult(xcm::v5::MaybeErrorCode::Success);
+		let origin: <T as frame_system::Config>::RuntimeOrigin = pallet_xcm::Origin::Response(project_location).into();
+
+		#[extrinsic_call]
+		confirm_pallet_migrations(origin as <T as frame_system::Config>::RuntimeOrigin, query_id, response);
+
+		// * validity checks *
+		assert_eq!(UserMigrations::<T>::get((project_id, participant)).unwrap().0, MigrationStatus::Confirmed);
+		assert_eq!(UnmigratedCounter::<T>::get(project_id), 39);
+	}
+
+	#[benchmark]
+	fn fail_timed_out_pallet_migration() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 10);
+		let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 34, 30);
+		let project_id =
+			inst.create_settled_project(project_metadata.clone(), issuer.clone(), None, evaluations, bids, true);
+
+		let jwt = get_mock_jwt_with_cid(
+			issuer.clone(),
+			InvestorType::Institutional,
+			generate_did_from_account(issuer.clone()),
+			project_metadata.clone().policy_ipfs_cid.unwrap(),
+		);
+		let para_id = ParaId::from(6969u32);
+		<Pallet<T>>::start_pallet_migration(RawOrigin::Signed(issuer.clone()).into(), jwt, project_id, para_id)
+			.unwrap();
+		PalletMigrations::<T>::mutate(project_id, |info| {
+			let info = info.as_mut().unwrap();
+			info.hrmp_channel_status =
+				HRMPChannelStatus { project_to_polimec: ChannelStatus::Open, polimec_to_project: ChannelStatus::Open };
+			info.migration_readiness_check = Some(PalletMigrationReadinessCheck {
+				holding_check: (0, CheckOutcome::Passed(None)),
+				pallet_check: (1, CheckOutcome::Passed(Some(51))),
+			});
+		});
+		cumulus_pallet_parachain_system::Pallet::<T>::open_outbound_hrmp_channel_for_benchmarks_or_tests(para_id);
+
+		let participant = UserMigrations::<T>::iter_key_prefix((project_id,)).next().unwrap();
+		<Pallet<T>>::send_pallet_migration_for(
+			RawOrigin::Signed(issuer.clone()).into(),
+			project_id,
+			participant.clone(),
+		)
+		.unwrap();
+		let query_id = ActiveMigrationQueue::<T>::iter_keys().next().unwrap();
+
+		let (_, _, timeout) = ActiveMigrationQueue::<T>::get(query_id).unwrap();
+		frame_system::Pallet::<T>::set_block_number(timeout + One::one());
+
+		#[extrinsic_call]
+		fail_timed_out_pallet_migration(RawOrigin::Signed(issuer), query_id);
+
+		// * validity checks *
+		assert_eq!(UserMigrations::<T>::get((project_id, participant)).unwrap().0, MigrationStatus::Failed);
+		assert!(!ActiveMigrationQueue::<T>::contains_key(query_id));
+	}
+
+	#[benchmark]
+	fn submit_migration_confirmation_root() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 10);
+		let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 34, 30);
+		let project_id =
+			inst.create_settled_project(project_metadata.clone(), issuer.clone(), None, evaluations, bids, true);
+
+		let jwt = get_mock_jwt_with_cid(
+			issuer.clone(),
+			InvestorType::Institutional,
+			generate_did_from_account(issuer.clone()),
+			project_metadata.clone().policy_ipfs_cid.unwrap(),
+		);
+		<Pallet<T>>::start_offchain_migration(RawOrigin::Signed(issuer.clone()).into(), jwt, project_id).unwrap();
+
+		// Another batch was already submitted
+		<Pallet<T>>::submit_migration_confirmation_root(
+			RawOrigin::Signed(issuer.clone()).into(),
+			project_id,
+			[1u8; 32],
+			10,
+		)
+		.unwrap();
+
+		#[extrinsic_call]
+		submit_migration_confirmation_root(RawOrigin::Signed(issuer), project_id, [2u8; 32], 29);
+
+		// * validity checks *
+		assert_eq!(MigrationConfirmationRoots::<T>::get(project_id, [2u8; 32]), Some(29));
+	}
+
+	#[benchmark]
+	fn prove_offchain_migration() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 10);
+		let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 34, 30);
+		let project_id =
+			inst.create_settled_project(project_metadata.clone(), issuer.clone(), None, evaluations, bids, true);
+
+		let jwt = get_mock_jwt_with_cid(
+			issuer.clone(),
+			InvestorType::Institutional,
+			generate_did_from_account(issuer.clone()),
+			project_metadata.clone().policy_ipfs_cid.unwrap(),
+		);
+		<Pallet<T>>::start_offchain_migration(RawOrigin::Signed(issuer.clone()).into(), jwt, project_id).unwrap();
+
+		// Proof as long as the one of a tree with all the 40 participants of the project.
+		let leaves = <Pallet<T>>::migration_confirmation_leaves(project_id)
+			.into_iter()
+			.map(|(account, migration_info)| <Pallet<T>>::migration_confirmation_leaf(&account, &migration_info))
+			.collect_vec();
+		let participant = UserMigrations::<T>::iter_key_prefix((project_id,)).next().unwrap();
+		let (_, migrations) = UserMigrations::<T>::get((project_id, participant.clone())).unwrap();
+		let migration_info = <Pallet<T>>::migration_confirmation_info(&Migrations::from(migrations.into_inner()));
+		let leaf = <Pallet<T>>::migration_confirmation_leaf(&participant, &migration_info);
+		let proof: BoundedVec<[u8; 32], ConstU32<MAX_MIGRATION_PROOF_LENGTH>> =
+			leaves.into_iter().filter(|other| *other != leaf).take(6).collect_vec().try_into().unwrap();
+		let root = <Pallet<T>>::merkle_root(leaf, &proof);
+		<Pallet<T>>::submit_migration_confirmation_root(RawOrigin::Signed(issuer.clone()).into(), project_id, root, 2)
+			.unwrap();
+
+		#[extrinsic_call]
+		prove_offchain_migration(RawOrigin::Signed(issuer), project_id, participant.clone(), proof);
+
+		// * validity checks *
+		assert_eq!(UserMigrations::<T>::get((project_id, participant)).unwrap().0, MigrationStatus::Confirmed);
+		assert_eq!(MigrationConfirmationRoots::<T>::get(project_id, root), Some(1));
+	}
+
+	#[benchmark]
+	fn dispute_migration() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.advance_time(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 10);
+		let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 34, 30);
+		let project_id =
+			inst.create_settled_project(project_metadata.clone(), issuer.clone(), None, evaluations, bids, true);
+
+		let jwt = get_mock_jwt_with_cid(
+			issuer.clone(),
+			InvestorType::Institutional,
+			generate_did_from_account(issuer.clone()),
+			project_metadata.clone().policy_ipfs_cid.unwrap(),
+		);
+		<Pallet<T>>::start_offchain_migration(RawOrigin::Signed(issuer.clone()).into(), jwt, project_id).unwrap();
+
+		let participant = UserMigrations::<T>::iter_key_prefix((project_id,)).next().unwrap();
+		<Pallet<T>>::confirm_offchain_migration(RawOrigin::Signed(issuer).into(), project_id, participant.clone())
+			.unwrap();
+
+		#[extrinsic_call]
+		dispute_migration(RawOrigin::Signed(participant.clone()), project_id);
+
+		// * validity checks *
+		// Storage
+		assert_eq!(UserMigrations::<T>::get((project_id, participant.clone())).unwrap().0, MigrationStatus::Disputed);
+		assert_eq!(OpenMigrationDisputes::<T>::get(project_id), 1);
+
+		// Events
+		frame_system::Pallet::<T>::assert_last_event(
+			Event::MigrationStatusUpdated { project_id, account: participant, status: MigrationStatus::Disputed }
+				.into(),
+		);
+	}
+
+	// Upholding the dispute is the worst case, as the migration goes back to the unmigrated counter.
+	#[benchmark]
+	fn resolve_migration_dispute() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.advance_time(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 10);
+		let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 34, 30);
+		let project_id =
+			inst.create_settled_project(project_metadata.clone(), issuer.clone(), None, evaluations, bids, true);
+
+		let jwt = get_mock_jwt_with_cid(
+			issuer.clone(),
+			InvestorType::Institutional,
+			generate_did_from_account(issuer.clone()),
+			project_metadata.clone().policy_ipfs_cid.unwrap(),
+		);
+		<Pallet<T>>::start_offchain_migration(RawOrigin::Signed(issuer.clone()).into(), jwt, project_id).unwrap();
+
+		let participant = UserMigrations::<T>::iter_key_prefix((project_id,)).next().unwrap();
+		<Pallet<T>>::confirm_offchain_migration(RawOrigin::Signed(issuer).into(), project_id, participant.clone())
+			.unwrap();
+		<Pallet<T>>::dispute_migration(RawOrigin::Signed(participant.clone()).into(), project_id).unwrap();
+
+		let origin = T::MigrationDisputeOrigin::try_successful_origin()
+			.expect("MigrationDisputeOrigin has no successful origin");
+
+		#[extrinsic_call]
+		resolve_migration_dispute(
+			origin as <T as frame_system::Config>::RuntimeOrigin,
+			project_id,
+			participant.clone(),
+			true,
+		);
+
+		// * validity checks *
+		// Storage
+		assert_eq!(UserMigrations::<T>::get((project_id, participant.clone())).unwrap().0, MigrationStatus::NotStarted);
+		assert_eq!(OpenMigrationDisputes::<T>::get(project_id), 0);
+		assert_eq!(UnmigratedCounter::<T>::get(project_id), 40);
+
+		// Events
+		frame_system::Pallet::<T>::assert_last_event(
+			Event::MigrationStatusUpdated { project_id, account: participant, status: MigrationStatus::NotStarted }
+				.into(),
+		);
+	}
+
+	// Registering a new asset is the worst case, as the registry size has to be checked.
+	#[benchmark]
+	fn set_funding_asset() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+
+		// We can't see events at block 0
+		inst.advance_time(1u32.into());
+
+		let asset_info = |index: u8| {
+			let location = xcm::v4::Location::new(
+				1,
+				[
+					xcm::v4::Junction::Parachain(1000),
+					xcm::v4::Junction::PalletInstance(50),
+					xcm::v4::Junction::GeneralIndex(index.into()),
+				],
+			);
+			FundingAssetInfo { location: location.clone(), decimals: 6, oracle_key: location, enabled: true }
+		};
+		let registered = FundingAssets::<T>::iter_keys().count() as u8;
+		for index in registered..(MAX_FUNDING_ASSETS as u8 - 1) {
+			FundingAssets::<T>::insert(AcceptedFundingAsset(index), asset_info(index));
+		}
+		let asset = AcceptedFundingAsset(MAX_FUNDING_ASSETS as u8 - 1);
+		let info = asset_info(asset.0);
+
+		let origin =
+			T::FundingAssetOrigin::try_successful_origin().expect("FundingAssetOrigin has no successful origin");
+
+		#[extrinsic_call]
+		set_funding_asset(origin as <T as frame_system::Config>::RuntimeOrigin, asset, info.clone());
+
+		// * validity checks *
+		// Storage
+		assert_eq!(FundingAssets::<T>::get(asset), Some(info.clone()));
+		assert_eq!(FundingAssets::<T>::iter_keys().count(), MAX_FUNDING_ASSETS as usize);
+
+		// Events
+		frame_system::Pallet::<T>::assert_last_event(Event::FundingAssetSet { asset, info }.into());
+	}
+
+	#[benchmark]
+	fn propose_project_transfer() {
+		// * setup *
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.advance_time(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let new_issuer = account::<AccountIdOf<T>>("new_issuer", 0, 0);
+		whitelist_account!(issuer);
+
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let project_id = inst.create_new_project(project_metadata.clone(), issuer.clone(), None);
+
+		let jwt = get_mock_jwt_with_cid(
+			issuer.clone(),
+			InvestorType::Institutional,
+			generate_did_from_account(issuer.clone()),
+			project_metadata.clone().policy_ipfs_cid.unwrap(),
+		);
+
+		#[extrinsic_call]
+		propose_project_transfer(RawOrigin::Signed(issuer.clone()), jwt, project_id, new_issuer.clone());
+
+		// * validity checks *
+		// Storage
+		assert_eq!(PendingProjectTransfers::<T>::get(project_id), Some(new_issuer.clone()));
+
+		// Events
+		frame_system::Pallet::<T>::assert_last_event(
+			Event::<T>::ProjectTransferProposed { project_id, issuer, new_issuer }.into(),
+		);
+	}
+
+	#[benchmark]
+	fn accept_project_transfer() {
+		// * setup *
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.advance_time(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let new_issuer = account::<AccountIdOf<T>>("new_issuer", 0, 0);
+		let new_funding_destination = account::<AccountIdOf<T>>("new_funding_destination", 0, 0);
+		whitelist_account!(new_issuer);
+
+		// Worst case: the project is still active, so it moves to the active projects of the new DID.
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let project_id = inst.create_new_project(project_metadata.clone(), issuer.clone(), None);
+		PendingProjectTransfers::<T>::insert(project_id, new_issuer.clone());
+
+		let new_did = generate_did_from_account(new_issuer.clone());
+		let jwt = get_mock_jwt_with_cid(
+			new_issuer.clone(),
+			InvestorType::Institutional,
+			new_did.clone(),
+			project_metadata.clone().policy_ipfs_cid.unwrap(),
+		);
+
+		#[extrinsic_call]
+		accept_project_transfer(
+			RawOrigin::Signed(new_issuer.clone()),
+			jwt,
+			project_id,
+			new_funding_destination.clone(),
+		);
+
+		// * validity checks *
+		// Storage
+		let project_details = ProjectsDetails::<T>::get(project_id).unwrap();
+		assert_eq!(project_details.issuer_account, new_issuer);
+		assert_eq!(project_details.issuer_did, new_did);
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).unwrap();
+		assert_eq!(project_metadata.funding_destination_account, new_funding_destination);
+		assert!(PendingProjectTransfers::<T>::get(project_id).is_none());
+		assert!(DidWithActiveProjects::<T>::get(generate_did_from_account(issuer)).is_empty());
+		assert_eq!(DidWithActiveProjects::<T>::get(new_did.clone()).into_inner(), vec![project_id]);
+
+		// Events
+		frame_system::Pallet::<T>::assert_last_event(
+			Event::<T>::ProjectTransferred {
+				project_id,
+				issuer: new_issuer,
+				issuer_did: new_did,
+				funding_destination_account: new_funding_destination,
+			}
+			.into(),
+		);
+	}
+
+	#[benchmark]
+	fn halt_project() {
+		// * setup *
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.advance_time(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let project_id = inst.create_evaluating_project(project_metadata, issuer, None);
+		let reason: BoundedVec<u8, StringLimitOf<T>> = vec![1u8; T::StringLimit::get() as usize].try_into().unwrap();
+
+		let origin = T::EmergencyOrigin::try_successful_origin().expect("EmergencyOrigin has no successful origin");
+
+		#[extrinsic_call]
+		halt_project(origin as <T as frame_system::Config>::RuntimeOrigin, project_id, reason.clone());
+
+		// * validity checks *
+		// Storage
+		assert_eq!(HaltedProjects::<T>::get(project_id), Some(reason.clone()));
+
+		// Events
+		frame_system::Pallet::<T>::assert_last_event(Event::<T>::ProjectHalted { project_id, reason }.into());
+	}
+
+	#[benchmark]
+	fn resume_project() {
+		// * setup *
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.advance_time(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let project_id = inst.create_evaluating_project(project_metadata, issuer, None);
+		HaltedProjects::<T>::insert(project_id, BoundedVec::<u8, StringLimitOf<T>>::new());
+
+		let origin = T::EmergencyOrigin::try_successful_origin().expect("EmergencyOrigin has no successful origin");
+
+		#[extrinsic_call]
+		resume_project(origin as <T as frame_system::Config>::RuntimeOrigin, project_id);
+
+		// * validity checks *
+		// Storage
+		assert!(HaltedProjects::<T>::get(project_id).is_none());
+
+		// Events
+		frame_system::Pallet::<T>::assert_last_event(Event::<T>::ProjectResumed { project_id }.into());
+	}
+
+	#[benchmark]
+	fn force_project_failure() {
+		// * setup *
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.advance_time(1u32.into());
+
+		// Worst case: a halted project in the auction round
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+		let project_id = inst.create_auctioning_project(project_metadata, issuer, None, evaluations);
+		let reason: BoundedVec<u8, StringLimitOf<T>> = vec![1u8; T::StringLimit::get() as usize].try_into().unwrap();
+		HaltedProjects::<T>::insert(project_id, reason.clone());
+
+		let origin = T::EmergencyOrigin::try_successful_origin().expect("EmergencyOrigin has no successful origin");
+
+		#[extrinsic_call]
+		force_project_failure(origin as <T as frame_system::Config>::RuntimeOrigin, project_id, reason.clone());
+
+		// * validity checks *
+		// Storage
+		let project_details = ProjectsDetails::<T>::get(project_id).unwrap();
+		assert_eq!(project_details.status, ProjectStatus::FundingFailed);
+		assert!(project_details.evaluation_round_info.evaluators_outcome.is_none());
+		assert!(ProjectsInAuctionRound::<T>::get(project_id).is_none());
+		assert!(HaltedProjects::<T>::get(project_id).is_none());
+
+		// Events
+		frame_system::Pallet::<T>::assert_last_event(Event::<T>::ProjectForcedToFail { project_id, reason }.into());
+	}
+
+	#[benchmark]
+	fn set_fee_config() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+
+		// We can't see events at block 0
+		inst.advance_time(1u32.into());
+
+		let fee_config = FeeConfig { evaluator_rewards: Perquintill::from_percent(25), ..Default::default() };
+		let origin = T::FeeConfigOrigin::try_successful_origin().expect("FeeConfigOrigin has no successful origin");
+
+		#[extrinsic_call]
+		set_fee_config(origin as <T as frame_system::Config>::RuntimeOrigin, fee_config.clone());
+
+		// * validity checks *
+		// Storage
+		assert_eq!(FeeConfiguration::<T>::get(), fee_config);
+
+		// Events
+		frame_system::Pallet::<T>::assert_last_event(Event::<T>::FeeConfigSet { fee_config }.into());
+	}
+
+	#[benchmark]
+	fn bind_receiving_account() {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.advance_time(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let participant = account::<AccountIdOf<T>>("participant", 0, 0);
+		whitelist_account!(participant);
+
+		// Cosmos accounts are the most expensive to verify, since both recovery ids might need to be tried
+		let bech32_prefix = b"osmo";
+		let mut project_metadata = default_project_metadata::<T>(issuer.clone());
+		project_metadata.participants_account_type =
+			ParticipantsAccountType::Cosmos { bech32_prefix: BoundedVec::truncate_from(bech32_prefix.to_vec()) };
+		let project_id = inst.create_evaluating_project(project_metadata, issuer, None);
+
+		let expiry = inst.current_block() + 10u32.into();
+		let (receiving_account, signature) =
+			inst.cosmos_key_and_sig_from("//PARTICIPANT", bech32_prefix, project_id, participant.clone(), expiry);
+
+		#[extrinsic_call]
+		bind_receiving_account(
+			RawOrigin::Signed(participant.clone()),
+			project_id,
+			receiving_account,
+			expiry,
+			signature,
+		);
+
+		// * validity checks *
+		// Storage
+		assert_eq!(ReceivingAccounts::<T>::get(project_id, participant.clone()), Some(receiving_account));
+
+		// Events
+		frame_system::Pallet::<T>::assert_last_event(
+			Event::<T>::ReceivingAccountBound { project_id, account: participant, receiving_account }.into(),
+		);
+	}
+	#[benchmark]
+	fn schedule_bid() {
+		// * setup *
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.advance_time(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let bidder = account::<AccountIdOf<T>>("bidder", 0, 0);
+		whitelist_account!(bidder);
+
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+		let project_id = inst.create_auctioning_project(project_metadata.clone(), issuer, None, evaluations);
+
+		let ct_amount = Percent::from_percent(10) * project_metadata.total_allocation_size;
+		let max_price = project_metadata.minimum_price;
+		let scheduled_bid = BidParams::from((
+			bidder.clone(),
+			Institutional,
+			ct_amount,
+			ParticipationMode::Classic(1u8),
+			AcceptedFundingAsset::USDT,
+		));
+		let necessary_plmc =
+			inst.calculate_auction_plmc_charged_with_given_price(&vec![scheduled_bid.clone()], max_price);
+		let necessary_usdt =
+			inst.calculate_auction_funding_asset_charged_with_given_price(&vec![scheduled_bid.clone()], max_price);
+		inst.mint_plmc_ed_if_required(necessary_plmc.accounts());
+		inst.mint_plmc_to(necessary_plmc);
+		inst.mint_funding_asset_ed_if_required(necessary_usdt.to_account_asset_map());
+		inst.mint_funding_asset_to(necessary_usdt);
+
+		let execution_block = inst.current_block() + 1u32.into();
+		// Worst case: the execution block has every slot but one taken by other DIDs, which are all checked
+		let other_bids = Pallet::<T>::scheduled_bids_per_block().saturating_sub(1);
+		for i in 0..other_bids {
+			let other_bidder = account::<AccountIdOf<T>>("other_bidder", i, 0);
+			let id = u32::MAX - i;
+			ScheduledBids::<T>::insert(
+				id,
+				ScheduledBidInfoOf::<T> {
+					id,
+					project_id,
+					bidder: other_bidder.clone(),
+					did: generate_did_from_account(other_bidder),
+					investor_type: InvestorType::Retail,
+					whitelisted_policy: project_metadata.clone().policy_ipfs_cid.unwrap(),
+					ct_amount,
+					max_price,
+					mode: ParticipationMode::Classic(1u8),
+					funding_asset: AcceptedFundingAsset::USDT,
+					execution_block,
+					plmc_held: Zero::zero(),
+					funding_asset_held: Zero::zero(),
+				},
+			);
+			ScheduledBidQueue::<T>::mutate(execution_block, |ids| ids.try_push(id).unwrap());
+		}
+		let jwt = get_mock_jwt_with_cid(
+			bidder.clone(),
+			InvestorType::Institutional,
+			generate_did_from_account(bidder.clone()),
+			project_metadata.clone().policy_ipfs_cid.unwrap(),
+		);
+
+		#[extrinsic_call]
+		schedule_bid(
+			RawOrigin::Signed(bidder.clone()),
+			jwt,
+			project_id,
+			ct_amount,
+			ParticipationMode::Classic(1u8),
+			AcceptedFundingAsset::USDT,
+			max_price,
+			execution_block,
+		);
+
+		// * validity checks *
+		// Storage
+		let stored_scheduled_bid = ScheduledBids::<T>::get(0).unwrap();
+		assert_eq!(stored_scheduled_bid.bidder, bidder);
+		assert_eq!(stored_scheduled_bid.ct_amount, ct_amount);
+		assert_eq!(ScheduledBidQueue::<T>::get(execution_block).last(), Some(&0));
+
+		// Events
+		frame_system::Pallet::<T>::assert_last_event(
+			Event::<T>::BidScheduled { project_id, bidder, id: 0, ct_amount, max_price, execution_block }.into(),
+		);
+	}
+
+	#[benchmark]
+	fn cancel_scheduled_bid() {
+		// * setup *
+		let mut inst = BenchInstantiator::<T>::new(None);
+		<T as Config>::SetPrices::set_prices();
+
+		// We can't see events at block 0
+		inst.advance_time(1u32.into());
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let bidder = account::<AccountIdOf<T>>("bidder", 0, 0);
+		whitelist_account!(bidder);
+
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+		let project_id = inst.create_auctioning_project(project_metadata.clone(), issuer, None, evaluations);
+
+		let ct_amount = Percent::from_percent(10) * project_metadata.total_allocation_size;
+		let max_price = project_metadata.minimum_price;
+		let scheduled_bid = BidParams::from((
+			bidder.clone(),
+			Institutional,
+			ct_amount,
+			ParticipationMode::Classic(1u8),
+			AcceptedFundingAsset::USDT,
+		));
+		let necessary_plmc =
+			inst.calculate_auction_plmc_charged_with_given_price(&vec![scheduled_bid.clone()], max_price);
+		let necessary_usdt =
+			inst.calculate_auction_funding_asset_charged_with_given_price(&vec![scheduled_bid.clone()], max_price);
+		inst.mint_plmc_ed_if_required(necessary_plmc.accounts());
+		inst.mint_plmc_to(necessary_plmc);
+		inst.mint_funding_asset_ed_if_required(necessary_usdt.to_account_asset_map());
+		inst.mint_funding_asset_to(necessary_usdt);
+
+		let execution_block = inst.current_block() + 1u32.into();
+		inst.execute(|| {
+			Pallet::<T>::do_schedule_bid(DoScheduleBidParams::<T> {
+				bidder: bidder.clone(),
+				project_id,
+				ct_amount,
+				mode: ParticipationMode::Classic(1u8),
+				funding_asset: AcceptedFundingAsset::USDT,
+				did: generate_did_from_account(bidder.clone()),
+				investor_type: InvestorType::Institutional,
+				whitelisted_policy: project_metadata.clone().policy_ipfs_cid.unwrap(),
+				max_price,
+				execution_block,
+			})
+			.unwrap()
+		});
+
+		#[extrinsic_call]
+		cancel_scheduled_bid(RawOrigin::Signed(bidder.clone()), 0);
+
+		// * validity checks *
+		// Storage
+		assert!(ScheduledBids::<T>::get(0).is_none());
+		assert!(ScheduledBidQueue::<T>::get(execution_block).is_empty());
+
+		// Events
+		frame_system::Pallet::<T>::assert_last_event(
+			Event::<T>::ScheduledBidCancelled { project_id, bidder, id: 0 }.into(),
+		);
+	}
 }
@@ -10,7 +10,9 @@ impl<T: Config> Pallet<T> {
 		issuer: AccountIdOf<T>,
 		did: Did,
 	) -> Result<(ProjectDetailsOf<T>, BucketOf<T>), DispatchError> {
-		if let Err(error) = project_metadata.is_valid() {
+		if let Err(error) = project_metadata
+			.is_valid(T::EvaluationSuccessThresholdBounds::get(), T::FundingSuccessThresholdBounds::get())
+		{
 			let pallet_error = match error {
 				MetadataError::PriceTooLow => Error::<T>::PriceTooLow,
 				MetadataError::TicketSizeError => Error::<T>::TicketSizeError,
@@ -22,9 +24,18 @@ impl<T: Config> Pallet<T> {
 				MetadataError::CidNotProvided => Error::<T>::CidNotProvided,
 				MetadataError::BadDecimals => Error::<T>::BadDecimals,
 				MetadataError::BadTokenomics => Error::<T>::BadTokenomics,
+				MetadataError::SaleModeError => Error::<T>::SaleModeError,
+				MetadataError::CtVestingError => Error::<T>::CtVestingError,
+				MetadataError::FundingMilestonesError => Error::<T>::FundingMilestonesError,
+				MetadataError::SuccessThresholdError => Error::<T>::SuccessThresholdError,
+				MetadataError::ParticipantsAccountTypeError => Error::<T>::ParticipantsAccountTypeError,
 			};
 			return Err(pallet_error.into());
 		}
+		ensure!(
+			project_metadata.participation_currencies.iter().all(|asset| Self::is_funding_asset_enabled(*asset)),
+			Error::<T>::FundingAssetNotAccepted
+		);
 		let total_allocation_size = project_metadata.total_allocation_size;
 
 		let fundraising_target =
@@ -62,10 +73,10 @@ impl<T: Config> Pallet<T> {
 	) -> DispatchResultWithPostInfo {
 		// * Get variables *
 		let project_id = NextProjectId::<T>::get();
-		let maybe_active_project = DidWithActiveProjects::<T>::get(did.clone());
+		let mut active_projects = DidWithActiveProjects::<T>::get(did.clone());
 
 		// * Validity checks *
-		ensure!(maybe_active_project.is_none(), Error::<T>::HasActiveProject);
+		active_projects.try_push(project_id).map_err(|_| Error::<T>::TooManyActiveProjects)?;
 
 		let (project_details, bucket) = Self::project_validation(&project_metadata, issuer.clone(), did.clone())?;
 
@@ -86,7 +97,7 @@ impl<T: Config> Pallet<T> {
 		ProjectsDetails::<T>::insert(project_id, project_details);
 		Buckets::<T>::insert(project_id, bucket);
 		NextProjectId::<T>::mutate(|n| n.saturating_inc());
-		DidWithActiveProjects::<T>::set(did, Some(project_id));
+		DidWithActiveProjects::<T>::insert(did, active_projects);
 
 		// * Emit events *
 		Self::deposit_event(Event::ProjectCreated { project_id, issuer: issuer.clone(), metadata: project_metadata });
@@ -123,6 +134,29 @@ impl<T: Config> Pallet<T> {
 		Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::No })
 	}
 
+	/// Set or remove the merkle root of the DIDs allowed to participate, before the raise started.
+	#[transactional]
+	pub fn do_set_allowlist(
+		issuer: AccountIdOf<T>,
+		project_id: ProjectId,
+		allowlist_root: Option<[u8; 32]>,
+	) -> DispatchResult {
+		// * Get variables *
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+
+		// * Validity checks *
+		ensure!(project_details.issuer_account == issuer, Error::<T>::NotIssuer);
+		ensure!(!project_details.is_frozen, Error::<T>::ProjectIsFrozen);
+
+		// * Update storage *
+		ProjectAllowlists::<T>::set(project_id, allowlist_root);
+
+		// * Emit events *
+		Self::deposit_event(Event::AllowlistSet { project_id, allowlist_root });
+
+		Ok(())
+	}
+
 	/// Remove the project before the raise started.
 	#[transactional]
 	pub fn do_remove_project(issuer: AccountIdOf<T>, project_id: ProjectId, did: Did) -> DispatchResultWithPostInfo {
@@ -136,8 +170,9 @@ impl<T: Config> Pallet<T> {
 		// * Update storage *
 		ProjectsDetails::<T>::remove(project_id);
 		ProjectsMetadata::<T>::remove(project_id);
-		DidWithActiveProjects::<T>::set(did, None);
+		Self::remove_active_project(did, project_id);
 		Buckets::<T>::remove(project_id);
+		ProjectAllowlists::<T>::remove(project_id);
 
 		// * Emit events *
 		Self::deposit_event(Event::ProjectRemoved { project_id, issuer });
@@ -16,6 +16,7 @@ impl<T: Config> Pallet<T> {
 
 		// * Update storage *
 		project_details.is_frozen = true;
+		ProjectFeeConfigs::<T>::insert(project_id, FeeConfiguration::<T>::get());
 
 		// * Transition Round *
 		Self::transition_project(
@@ -34,12 +35,13 @@ impl<T: Config> Pallet<T> {
 	#[transactional]
 	pub fn do_end_evaluation(project_id: ProjectId) -> DispatchResult {
 		// * Get variables *
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
 		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
 
 		// * Calculate new variables *
 		let usd_total_amount_bonded = project_details.evaluation_round_info.total_bonded_usd;
 		let evaluation_target_usd =
-			<T as Config>::EvaluationSuccessThreshold::get() * project_details.fundraising_target_usd;
+			Self::evaluation_success_threshold(&project_metadata) * project_details.fundraising_target_usd;
 
 		// Check which logic path to follow
 		let is_funded = usd_total_amount_bonded >= evaluation_target_usd;
@@ -59,7 +61,7 @@ impl<T: Config> Pallet<T> {
 		// Unsuccessful path
 		} else {
 			let issuer_did = project_details.issuer_did.clone();
-			DidWithActiveProjects::<T>::set(issuer_did, None);
+			Self::remove_active_project(issuer_did, project_id);
 			Self::transition_project(
 				project_id,
 				project_details,
@@ -89,7 +91,7 @@ impl<T: Config> Pallet<T> {
 		let plmc_usd_price = <PriceProviderOf<T>>::get_decimals_aware_price(&Location::here(), PLMC_DECIMALS)
 			.ok_or(Error::<T>::PriceNotFound)?;
 		let early_evaluation_reward_threshold_usd =
-			T::EvaluationSuccessThreshold::get() * project_details.fundraising_target_usd;
+			Self::evaluation_success_threshold(&project_metadata) * project_details.fundraising_target_usd;
 		let evaluation_round_info = &mut project_details.evaluation_round_info;
 		let project_policy = project_metadata.policy_ipfs_cid.ok_or(Error::<T>::ImpossibleState)?;
 		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
@@ -98,6 +100,7 @@ impl<T: Config> Pallet<T> {
 		ensure!(project_policy == whitelisted_policy, Error::<T>::PolicyMismatch);
 		ensure!(usd_amount >= T::MinUsdPerEvaluation::get(), Error::<T>::TooLow);
 		ensure!(project_details.issuer_did != did, Error::<T>::ParticipationToOwnProject);
+		ensure!(!HaltedProjects::<T>::contains_key(project_id), Error::<T>::ProjectHalted);
 		ensure!(project_details.status == ProjectStatus::EvaluationRound, Error::<T>::IncorrectRound);
 		ensure!(
 			project_details.round_duration.started(now) && !project_details.round_duration.ended(now),
@@ -107,6 +110,7 @@ impl<T: Config> Pallet<T> {
 			project_metadata.participants_account_type.junction_is_supported(&receiving_account),
 			Error::<T>::UnsupportedReceiverAccountJunction
 		);
+		Self::ensure_allowlisted(project_id, &did, None)?;
 
 		let plmc_bond = plmc_usd_price
 			.reciprocal()
@@ -141,6 +145,7 @@ impl<T: Config> Pallet<T> {
 
 		T::NativeCurrency::hold(&HoldReason::Evaluation.into(), evaluator, plmc_bond)?;
 		Evaluations::<T>::insert((project_id, evaluator, evaluation_id), new_evaluation);
+		EvaluationBondedUSD::<T>::mutate((project_id, did), |amount| *amount = amount.saturating_add(usd_amount));
 		NextEvaluationId::<T>::set(evaluation_id.saturating_add(One::one()));
 		evaluation_round_info.total_bonded_usd = evaluation_round_info.total_bonded_usd.saturating_add(usd_amount);
 		evaluation_round_info.total_bonded_plmc = evaluation_round_info.total_bonded_plmc.saturating_add(plmc_bond);
@@ -156,4 +161,172 @@ impl<T: Config> Pallet<T> {
 
 		Ok(())
 	}
+
+	/// Add more USD to an existing evaluation while the evaluation round is running.
+	/// The added amount is treated like a new evaluation made now, so it only counts as early up to the space
+	/// left below the early evaluation threshold.
+	#[transactional]
+	pub fn do_increase_evaluation(
+		evaluator: &AccountIdOf<T>,
+		project_id: ProjectId,
+		evaluation_id: u32,
+		usd_amount: Balance,
+		did: Did,
+		whitelisted_policy: Cid,
+	) -> DispatchResult {
+		// * Get variables *
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
+		let mut project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let mut evaluation =
+			Evaluations::<T>::get((project_id, evaluator, evaluation_id)).ok_or(Error::<T>::ParticipationNotFound)?;
+		let now = <T as Config>::BlockNumberProvider::current_block_number();
+		let plmc_usd_price = <PriceProviderOf<T>>::get_decimals_aware_price(&Location::here(), PLMC_DECIMALS)
+			.ok_or(Error::<T>::PriceNotFound)?;
+		let early_evaluation_reward_threshold_usd =
+			Self::evaluation_success_threshold(&project_metadata) * project_details.fundraising_target_usd;
+		let evaluation_round_info = &mut project_details.evaluation_round_info;
+		let project_policy = project_metadata.policy_ipfs_cid.ok_or(Error::<T>::ImpossibleState)?;
+
+		// * Validity Checks *
+		ensure!(project_policy == whitelisted_policy, Error::<T>::PolicyMismatch);
+		ensure!(evaluation.did == did, Error::<T>::NotAllowed);
+		ensure!(usd_amount >= T::MinUsdPerEvaluation::get(), Error::<T>::TooLow);
+		ensure!(!HaltedProjects::<T>::contains_key(project_id), Error::<T>::ProjectHalted);
+		ensure!(project_details.status == ProjectStatus::EvaluationRound, Error::<T>::IncorrectRound);
+		ensure!(
+			project_details.round_duration.started(now) && !project_details.round_duration.ended(now),
+			Error::<T>::IncorrectRound
+		);
+
+		// * Calculate new variables *
+		let plmc_bond = plmc_usd_price
+			.reciprocal()
+			.ok_or(Error::<T>::BadMath)?
+			.checked_mul_int(usd_amount)
+			.ok_or(Error::<T>::BadMath)?;
+		let remaining_bond_to_reach_threshold =
+			early_evaluation_reward_threshold_usd.saturating_sub(evaluation_round_info.total_bonded_usd);
+		let early_usd_amount = usd_amount.min(remaining_bond_to_reach_threshold);
+		let late_usd_amount = usd_amount.checked_sub(early_usd_amount).ok_or(Error::<T>::BadMath)?;
+
+		// * Update storage *
+		T::NativeCurrency::hold(&HoldReason::Evaluation.into(), evaluator, plmc_bond)?;
+		evaluation.original_plmc_bond = evaluation.original_plmc_bond.saturating_add(plmc_bond);
+		evaluation.current_plmc_bond = evaluation.current_plmc_bond.saturating_add(plmc_bond);
+		evaluation.early_usd_amount = evaluation.early_usd_amount.saturating_add(early_usd_amount);
+		evaluation.late_usd_amount = evaluation.late_usd_amount.saturating_add(late_usd_amount);
+		Evaluations::<T>::insert((project_id, evaluator, evaluation_id), evaluation);
+		EvaluationBondedUSD::<T>::mutate((project_id, did), |amount| *amount = amount.saturating_add(usd_amount));
+		evaluation_round_info.total_bonded_usd = evaluation_round_info.total_bonded_usd.saturating_add(usd_amount);
+		evaluation_round_info.total_bonded_plmc = evaluation_round_info.total_bonded_plmc.saturating_add(plmc_bond);
+		ProjectsDetails::<T>::insert(project_id, project_details);
+
+		// * Emit events *
+		Self::deposit_event(Event::EvaluationIncreased {
+			project_id,
+			evaluator: evaluator.clone(),
+			id: evaluation_id,
+			plmc_amount: plmc_bond,
+		});
+
+		Ok(())
+	}
+
+	/// Withdraw an evaluation while the evaluation round is running, and release its PLMC bond.
+	/// The early USD amount freed up by the withdrawal is given to the next evaluations in order. At most
+	/// `max_evaluations_read` evaluations of the project are read for it, as the call was charged for that amount.
+	#[transactional]
+	pub fn do_withdraw_evaluation(
+		evaluator: &AccountIdOf<T>,
+		project_id: ProjectId,
+		evaluation_id: u32,
+		max_evaluations_read: u32,
+	) -> DispatchResultWithPostInfo {
+		// * Get variables *
+		let mut project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let evaluation =
+			Evaluations::<T>::get((project_id, evaluator, evaluation_id)).ok_or(Error::<T>::ParticipationNotFound)?;
+		let now = <T as Config>::BlockNumberProvider::current_block_number();
+		let evaluation_round_info = &mut project_details.evaluation_round_info;
+
+		// * Validity Checks *
+		ensure!(project_details.status == ProjectStatus::EvaluationRound, Error::<T>::IncorrectRound);
+		ensure!(
+			project_details.round_duration.started(now) && !project_details.round_duration.ended(now),
+			Error::<T>::IncorrectRound
+		);
+
+		// * Calculate new variables *
+		let usd_amount = evaluation.early_usd_amount.saturating_add(evaluation.late_usd_amount);
+		let total_bonded_usd = evaluation_round_info.total_bonded_usd.saturating_sub(usd_amount);
+		// All the freed early USD is offered to the next evaluations. If the total left bonded is below the threshold,
+		// there is not enough late USD to take all of it, and the reassignment stops once the late USD runs out.
+		let early_usd_to_reassign = evaluation.early_usd_amount;
+
+		// * Update storage *
+		T::NativeCurrency::release(
+			&HoldReason::Evaluation.into(),
+			evaluator,
+			evaluation.current_plmc_bond,
+			Precision::Exact,
+		)?;
+		Evaluations::<T>::remove((project_id, evaluator, evaluation_id));
+		EvaluationBondedUSD::<T>::mutate((project_id, evaluation.did.clone()), |amount| {
+			*amount = amount.saturating_sub(usd_amount)
+		});
+		evaluation_round_info.total_bonded_usd = total_bonded_usd;
+		evaluation_round_info.total_bonded_plmc =
+			evaluation_round_info.total_bonded_plmc.saturating_sub(evaluation.original_plmc_bond);
+		ProjectsDetails::<T>::insert(project_id, project_details);
+
+		let evaluations_read =
+			Self::reassign_early_evaluation_usd(project_id, early_usd_to_reassign, max_evaluations_read)?;
+
+		// * Emit events *
+		Self::deposit_event(Event::EvaluationWithdrawn {
+			project_id,
+			evaluator: evaluator.clone(),
+			id: evaluation_id,
+			plmc_amount: evaluation.current_plmc_bond,
+		});
+
+		Ok(PostDispatchInfo {
+			actual_weight: Some(<T as Config>::WeightInfo::withdraw_evaluation(evaluations_read)),
+			pays_fee: Pays::Yes,
+		})
+	}
+
+	/// Turn up to `usd_amount` of late evaluation USD into early USD, starting from the oldest evaluation.
+	/// Fails if the project has more than `max_evaluations_read` evaluations. Returns the amount of evaluations read.
+	fn reassign_early_evaluation_usd(
+		project_id: ProjectId,
+		mut usd_amount: Balance,
+		max_evaluations_read: u32,
+	) -> Result<u32, DispatchError> {
+		if usd_amount.is_zero() {
+			return Ok(0);
+		}
+		let mut evaluations = Evaluations::<T>::iter_prefix_values((project_id,))
+			.take(max_evaluations_read.saturating_add(1) as usize)
+			.collect::<Vec<_>>();
+		let evaluations_read = evaluations.len() as u32;
+		ensure!(evaluations_read <= max_evaluations_read, Error::<T>::TooManyEvaluationsToReassign);
+		evaluations.sort_by_key(|evaluation| evaluation.id);
+
+		for mut evaluation in evaluations {
+			if usd_amount.is_zero() {
+				break;
+			}
+			let reassigned = evaluation.late_usd_amount.min(usd_amount);
+			if reassigned.is_zero() {
+				continue;
+			}
+			evaluation.late_usd_amount.saturating_reduce(reassigned);
+			evaluation.early_usd_amount.saturating_accrue(reassigned);
+			usd_amount.saturating_reduce(reassigned);
+			Evaluations::<T>::insert((project_id, evaluation.evaluator.clone(), evaluation.id), evaluation);
+		}
+
+		Ok(evaluations_read)
+	}
 }
@@ -16,6 +16,7 @@ impl<T: Config> Pallet<T> {
 			did,
 			whitelisted_policy,
 			receiving_account,
+			max_price,
 		} = params;
 		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
 		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
@@ -23,6 +24,14 @@ impl<T: Config> Pallet<T> {
 		// Fetch current bucket details and other required info
 		let mut current_bucket = Buckets::<T>::get(project_id).ok_or(Error::<T>::BucketNotFound)?;
 		let now = <T as Config>::BlockNumberProvider::current_block_number();
+		Self::refresh_bucket_price(&project_metadata, &project_details, &mut current_bucket, now);
+
+		// Descending auctions sell at most the tokens left, so the bid is checked and placed for that amount only.
+		let unfilled_ct_amount = match project_metadata.sale_mode {
+			SaleMode::DutchAuction { .. } => ct_amount.saturating_sub(current_bucket.amount_left),
+			_ => Zero::zero(),
+		};
+		let ct_amount = ct_amount.saturating_sub(unfilled_ct_amount);
 		let mut amount_to_bid = ct_amount;
 		let project_policy = project_metadata.policy_ipfs_cid.ok_or(Error::<T>::ImpossibleState)?;
 
@@ -43,15 +52,20 @@ impl<T: Config> Pallet<T> {
 
 		// * Validity checks *
 		ensure!(project_policy == whitelisted_policy, Error::<T>::PolicyMismatch);
+		if let SaleMode::DutchAuction { .. } = project_metadata.sale_mode {
+			ensure!(!current_bucket.amount_left.is_zero(), Error::<T>::ProjectSoldOut);
+		}
 		ensure!(ct_amount > Zero::zero(), Error::<T>::TooLow);
 		ensure!(did != project_details.issuer_did, Error::<T>::ParticipationToOwnProject);
+		ensure!(!HaltedProjects::<T>::contains_key(project_id), Error::<T>::ProjectHalted);
 		ensure!(matches!(project_details.status, ProjectStatus::AuctionRound), Error::<T>::IncorrectRound);
 		ensure!(
 			project_details.round_duration.started(now) && !project_details.round_duration.ended(now),
 			Error::<T>::IncorrectRound
 		);
 		ensure!(
-			project_metadata.participation_currencies.contains(&funding_asset),
+			project_metadata.participation_currencies.contains(&funding_asset) &&
+				Self::is_funding_asset_enabled(funding_asset),
 			Error::<T>::FundingAssetNotAccepted
 		);
 
@@ -67,11 +81,80 @@ impl<T: Config> Pallet<T> {
 			project_metadata.participants_account_type.junction_is_supported(&receiving_account),
 			Error::<T>::UnsupportedReceiverAccountJunction
 		);
+		if let Some(max_price) = max_price {
+			ensure!(current_bucket.current_price <= max_price, Error::<T>::PriceAboveMaxPrice);
+		}
+
+		// Pro-rata sales have a fixed price, so the bid is never split. The final allocation is computed at the end.
+		if project_metadata.sale_mode == SaleMode::ProRata {
+			let bid_id = NextBidId::<T>::get();
+			let perform_params = DoPerformBidParams {
+				bidder,
+				project_id,
+				ct_amount,
+				ct_usd_price: project_metadata.minimum_price,
+				mode,
+				funding_asset,
+				bid_id,
+				now,
+				did,
+				metadata_ticket_size_bounds,
+				receiving_account,
+				auction_oversubscribed: false,
+			};
+			Self::add_bid_to_bucket_bounds(project_id, project_metadata.minimum_price, bid_id);
+
+			Self::do_perform_bid(perform_params)?;
+			ProRataBidCTAmount::<T>::mutate(project_id, |amount| amount.saturating_accrue(ct_amount));
+
+			return Ok(PostDispatchInfo { actual_weight: Some(<T as Config>::WeightInfo::bid(1)), pays_fee: Pays::No });
+		}
+
+		// Descending auctions sell at the current price until the allocation runs out, which ends the round.
+		if let SaleMode::DutchAuction { .. } = project_metadata.sale_mode {
+			let bid_id = NextBidId::<T>::get();
+			let perform_params = DoPerformBidParams {
+				bidder: bidder.clone(),
+				project_id,
+				ct_amount,
+				ct_usd_price: current_bucket.current_price,
+				mode,
+				funding_asset,
+				bid_id,
+				now,
+				did,
+				metadata_ticket_size_bounds,
+				receiving_account,
+				auction_oversubscribed: false,
+			};
+			Self::add_bid_to_bucket_bounds(project_id, current_bucket.current_price, bid_id);
+
+			Self::do_perform_bid(perform_params)?;
+			project_metadata.sale_mode.price_strategy().update(&mut current_bucket, ct_amount);
+			Buckets::<T>::insert(project_id, current_bucket);
+
+			if !unfilled_ct_amount.is_zero() {
+				Self::deposit_event(Event::BidCappedByAmountLeft {
+					project_id,
+					bidder,
+					filled_ct_amount: ct_amount,
+					unfilled_ct_amount,
+				});
+			}
+			if current_bucket.amount_left.is_zero() {
+				Self::end_sold_out_auction(project_id, project_details, now);
+			}
+
+			return Ok(PostDispatchInfo { actual_weight: Some(<T as Config>::WeightInfo::bid(1)), pays_fee: Pays::No });
+		}
 
 		let mut perform_bid_calls = 0u8;
 
-		// While there's a remaining amount to bid for
+		// While there's a remaining amount to bid for, and the price is within the limit of the bidder
 		while !amount_to_bid.is_zero() {
+			if max_price.is_some_and(|max_price| current_bucket.current_price > max_price) {
+				break;
+			}
 			perform_bid_calls.saturating_accrue(1);
 
 			let ct_amount = if amount_to_bid <= current_bucket.amount_left {
@@ -99,13 +182,7 @@ impl<T: Config> Pallet<T> {
 				auction_oversubscribed,
 			};
 
-			BidsBucketBounds::<T>::mutate(project_id, current_bucket.current_price, |maybe_indexes| {
-				if let Some(bucket_bounds) = maybe_indexes {
-					bucket_bounds.last_bid_index = bid_id;
-				} else {
-					*maybe_indexes = Some(BidBucketBounds { first_bid_index: bid_id, last_bid_index: bid_id });
-				}
-			});
+			Self::add_bid_to_bucket_bounds(project_id, current_bucket.current_price, bid_id);
 
 			Self::do_perform_bid(perform_params)?;
 
@@ -119,12 +196,256 @@ impl<T: Config> Pallet<T> {
 		// Note: If the bucket has been exhausted, the 'update' function has already made the 'current_bucket' point to the next one.
 		Buckets::<T>::insert(project_id, current_bucket);
 
+		if let Some(max_price) = max_price {
+			if !amount_to_bid.is_zero() {
+				Self::deposit_event(Event::BidCappedByMaxPrice {
+					project_id,
+					bidder,
+					max_price,
+					unfilled_ct_amount: amount_to_bid,
+				});
+			}
+		}
+
 		Ok(PostDispatchInfo {
 			actual_weight: Some(<T as Config>::WeightInfo::bid(perform_bid_calls as u32)),
 			pays_fee: Pays::No,
 		})
 	}
 
+	/// Place several bids on a project in the auction round. The credential of the bidder is checked once by the
+	/// caller, and each entry is then placed through [`Self::do_bid`]. If any of the entries fails, none are placed.
+	#[transactional]
+	pub fn do_bid_batch(params: DoBidBatchParams<T>) -> DispatchResultWithPostInfo {
+		let DoBidBatchParams { bidder, project_id, entries, did, investor_type, whitelisted_policy, receiving_account } =
+			params;
+		ensure!(!entries.is_empty(), Error::<T>::EmptyBidBatch);
+
+		let mut actual_weight = Weight::zero();
+		for BidBatchEntry { ct_amount, mode, funding_asset } in entries {
+			let post_info = Self::do_bid(DoBidParams::<T> {
+				bidder: bidder.clone(),
+				project_id,
+				ct_amount,
+				mode,
+				funding_asset,
+				did: did.clone(),
+				investor_type,
+				whitelisted_policy: whitelisted_policy.clone(),
+				receiving_account,
+				max_price: None,
+			})?;
+			actual_weight.saturating_accrue(post_info.actual_weight.unwrap_or(<T as Config>::WeightInfo::bid(10)));
+		}
+
+		Ok(PostDispatchInfo { actual_weight: Some(actual_weight), pays_fee: Pays::No })
+	}
+
+	/// Commit a bid to be placed at a later block of the auction round. The funds to pay for the whole amount at
+	/// `max_price` are held from now on, and the bid is placed from the hooks with that price cap.
+	#[transactional]
+	pub fn do_schedule_bid(params: DoScheduleBidParams<T>) -> DispatchResult {
+		// * Get variables *
+		let DoScheduleBidParams {
+			bidder,
+			project_id,
+			ct_amount,
+			mode,
+			funding_asset,
+			did,
+			investor_type,
+			whitelisted_policy,
+			max_price,
+			execution_block,
+		} = params;
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let project_policy = project_metadata.policy_ipfs_cid.ok_or(Error::<T>::ImpossibleState)?;
+		let now = <T as Config>::BlockNumberProvider::current_block_number();
+		let max_multiplier = match investor_type {
+			InvestorType::Professional => PROFESSIONAL_MAX_MULTIPLIER,
+			InvestorType::Institutional => INSTITUTIONAL_MAX_MULTIPLIER,
+			InvestorType::Retail => RETAIL_MAX_MULTIPLIER,
+		};
+
+		// * Validity checks *
+		// The rest of the checks of `do_bid` are done at the execution block. A bid failing them there is refunded.
+		ensure!(project_policy == whitelisted_policy, Error::<T>::PolicyMismatch);
+		ensure!(ct_amount > Zero::zero(), Error::<T>::TooLow);
+		ensure!(did != project_details.issuer_did, Error::<T>::ParticipationToOwnProject);
+		ensure!(!HaltedProjects::<T>::contains_key(project_id), Error::<T>::ProjectHalted);
+		ensure!(matches!(project_details.status, ProjectStatus::AuctionRound), Error::<T>::IncorrectRound);
+		ensure!(
+			execution_block > now && project_details.round_duration.end().is_some_and(|end| execution_block < end),
+			Error::<T>::InvalidExecutionBlock
+		);
+		ensure!(
+			project_metadata.participation_currencies.contains(&funding_asset) &&
+				Self::is_funding_asset_enabled(funding_asset),
+			Error::<T>::FundingAssetNotAccepted
+		);
+		ensure!(mode.multiplier() <= max_multiplier && mode.multiplier() > 0u8, Error::<T>::ForbiddenMultiplier);
+		ensure!(ct_amount <= project_metadata.total_allocation_size, Error::<T>::TooHigh);
+		// A full block could otherwise be carried over past the end of the auction, and a single DID could take all
+		// the slots of the last blocks.
+		let scheduled_bid_ids = ScheduledBidQueue::<T>::get(execution_block);
+		ensure!((scheduled_bid_ids.len() as u32) < Self::scheduled_bids_per_block(), Error::<T>::TooManyScheduledBids);
+		ensure!(
+			!scheduled_bid_ids.iter().any(|id| ScheduledBids::<T>::get(id).is_some_and(|bid| bid.did == did)),
+			Error::<T>::DidAlreadyScheduledAtBlock
+		);
+
+		// * Calculate new variables *
+		let usd_ticket_size = max_price.checked_mul_int(ct_amount).ok_or(Error::<T>::BadMath)?;
+		let multiplier: MultiplierOf<T> = mode.multiplier().try_into().map_err(|_| Error::<T>::BadMath)?;
+		let plmc_bond = Self::calculate_plmc_bond(usd_ticket_size, multiplier)?;
+		let funding_asset_location = Self::funding_asset_location(funding_asset)?;
+		let mut funding_asset_held = Self::calculate_funding_asset_amount(usd_ticket_size, funding_asset)?;
+		let id = NextScheduledBidId::<T>::get();
+
+		// * Hold the funds *
+		let plmc_held = match mode {
+			ParticipationMode::Classic(_) => {
+				T::NativeCurrency::hold(&HoldReason::ScheduledBid.into(), &bidder, plmc_bond)
+					.map_err(|_| Error::<T>::ParticipantNotEnoughFunds)?;
+				plmc_bond
+			},
+			// The PLMC is bonded by the treasury at execution, for a fee paid in the funding asset.
+			ParticipationMode::OTM => {
+				let otm_fee =
+					pallet_proxy_bonding::Pallet::<T>::calculate_fee(plmc_bond, funding_asset_location.clone())?;
+				funding_asset_held.saturating_accrue(otm_fee);
+				Zero::zero()
+			},
+		};
+		Self::try_funding_asset_hold(&bidder, project_id, funding_asset_held, funding_asset_location)?;
+
+		// * Update storage *
+		ScheduledBidQueue::<T>::try_mutate(execution_block, |ids| ids.try_push(id))
+			.map_err(|_| Error::<T>::TooManyScheduledBids)?;
+		NextScheduledBidId::<T>::set(id.saturating_add(1));
+		ScheduledBids::<T>::insert(
+			id,
+			ScheduledBidInfoOf::<T> {
+				id,
+				project_id,
+				bidder: bidder.clone(),
+				did,
+				investor_type,
+				whitelisted_policy,
+				ct_amount,
+				max_price,
+				mode,
+				funding_asset,
+				execution_block,
+				plmc_held,
+				funding_asset_held,
+			},
+		);
+
+		Self::deposit_event(Event::BidScheduled { project_id, bidder, id, ct_amount, max_price, execution_block });
+
+		Ok(())
+	}
+
+	/// Cancel a scheduled bid before its execution block, and return its held funds.
+	#[transactional]
+	pub fn do_cancel_scheduled_bid(bidder: &AccountIdOf<T>, scheduled_bid_id: u32) -> DispatchResult {
+		let scheduled_bid = ScheduledBids::<T>::get(scheduled_bid_id).ok_or(Error::<T>::ParticipationNotFound)?;
+		ensure!(scheduled_bid.bidder == *bidder, Error::<T>::NotAllowed);
+
+		ScheduledBids::<T>::remove(scheduled_bid_id);
+		ScheduledBidQueue::<T>::mutate(scheduled_bid.execution_block, |ids| ids.retain(|id| *id != scheduled_bid_id));
+		Self::release_scheduled_bid_funds(&scheduled_bid)?;
+
+		Self::deposit_event(Event::ScheduledBidCancelled {
+			project_id: scheduled_bid.project_id,
+			bidder: scheduled_bid.bidder,
+			id: scheduled_bid_id,
+		});
+
+		Ok(())
+	}
+
+	/// Place a scheduled bid at its execution block. The held funds go back to the bidder first, and `do_bid` then
+	/// takes what the bid actually costs. A failed bid leaves the bidder with all of its funds. The scheduled bid is
+	/// only removed once its funds are no longer held, so a bidder can still cancel it if they could not be released.
+	pub(crate) fn execute_scheduled_bid(scheduled_bid_id: u32) {
+		let Some(scheduled_bid) = ScheduledBids::<T>::get(scheduled_bid_id) else { return };
+		let project_id = scheduled_bid.project_id;
+
+		let bid_result = with_transaction(|| {
+			let result = Self::release_scheduled_bid_funds(&scheduled_bid).and_then(|()| {
+				Self::do_bid(DoBidParams::<T> {
+					bidder: scheduled_bid.bidder.clone(),
+					project_id,
+					ct_amount: scheduled_bid.ct_amount,
+					mode: scheduled_bid.mode,
+					funding_asset: scheduled_bid.funding_asset,
+					did: scheduled_bid.did.clone(),
+					investor_type: scheduled_bid.investor_type,
+					whitelisted_policy: scheduled_bid.whitelisted_policy.clone(),
+					receiving_account: Self::receiving_account_of(project_id, &scheduled_bid.bidder),
+					max_price: Some(scheduled_bid.max_price),
+				})
+				.map(|_| ())
+				.map_err(|error| error.error)
+			});
+			match result {
+				Ok(()) => TransactionOutcome::Commit(result),
+				Err(_) => TransactionOutcome::Rollback(result),
+			}
+		});
+
+		let error = match bid_result {
+			Ok(()) => {
+				ScheduledBids::<T>::remove(scheduled_bid_id);
+				Self::deposit_event(Event::ScheduledBidExecuted {
+					project_id,
+					bidder: scheduled_bid.bidder,
+					id: scheduled_bid_id,
+				});
+				return;
+			},
+			Err(error) => error,
+		};
+
+		let release_result = with_transaction(|| {
+			let result = Self::release_scheduled_bid_funds(&scheduled_bid);
+			match result {
+				Ok(()) => TransactionOutcome::Commit(result),
+				Err(_) => TransactionOutcome::Rollback(result),
+			}
+		});
+		if release_result.is_ok() {
+			ScheduledBids::<T>::remove(scheduled_bid_id);
+		}
+		Self::deposit_event(Event::ScheduledBidFailed {
+			project_id,
+			bidder: scheduled_bid.bidder,
+			id: scheduled_bid_id,
+			error,
+		});
+	}
+
+	fn release_scheduled_bid_funds(scheduled_bid: &ScheduledBidInfoOf<T>) -> DispatchResult {
+		Self::release_funding_asset(
+			scheduled_bid.project_id,
+			&scheduled_bid.bidder,
+			scheduled_bid.funding_asset_held,
+			scheduled_bid.funding_asset,
+		)?;
+		if !scheduled_bid.plmc_held.is_zero() {
+			T::NativeCurrency::release(
+				&HoldReason::ScheduledBid.into(),
+				&scheduled_bid.bidder,
+				scheduled_bid.plmc_held,
+				Precision::Exact,
+			)?;
+		}
+		Ok(())
+	}
+
 	/// Inner function to perform bids within a bucket. do_bid makes sure to split the bid into buckets and call this as
 	/// many times as necessary
 	#[transactional]
@@ -153,6 +474,7 @@ impl<T: Config> Pallet<T> {
 				.usd_ticket_below_maximum_per_did(total_usd_bid_by_did.saturating_add(usd_ticket_size)),
 			Error::<T>::TooHigh
 		);
+		Self::ensure_allowlisted(project_id, &did, Some(total_usd_bid_by_did.saturating_add(usd_ticket_size)))?;
 
 		// * Calculate new variables *
 		let plmc_bond = Self::calculate_plmc_bond(usd_ticket_size, multiplier).map_err(|_| Error::<T>::BadMath)?;
@@ -175,7 +497,12 @@ impl<T: Config> Pallet<T> {
 		};
 
 		Self::bond_plmc_with_mode(&bidder, project_id, plmc_bond, mode, funding_asset)?;
-		Self::try_funding_asset_hold(&bidder, project_id, funding_asset_amount_locked, funding_asset.id())?;
+		Self::try_funding_asset_hold(
+			&bidder,
+			project_id,
+			funding_asset_amount_locked,
+			Self::funding_asset_location(funding_asset)?,
+		)?;
 
 		Bids::<T>::insert(project_id, bid_id, &new_bid);
 		NextBidId::<T>::set(bid_id.saturating_add(One::one()));
@@ -200,6 +527,301 @@ impl<T: Config> Pallet<T> {
 		Ok(new_bid)
 	}
 
+	/// End the round of a descending auction as soon as its allocation is sold out. The round end is moved to the
+	/// current block, and the funding end is rescheduled for the next one.
+	fn end_sold_out_auction(project_id: ProjectId, mut project_details: ProjectDetailsOf<T>, now: BlockNumberFor<T>) {
+		if let Some(round_end) = project_details.round_duration.end() {
+			ProjectTransitionQueue::<T>::remove(round_end.saturating_add(One::one()), project_id);
+		}
+		project_details.round_duration = BlockNumberPair::new(project_details.round_duration.start(), Some(now));
+		ProjectsDetails::<T>::insert(project_id, project_details);
+		ProjectTransitionQueue::<T>::insert(now.saturating_add(One::one()), project_id, ());
+	}
+
+	/// Reduce the CT amount of a bid while the auction is still running, or cancel it entirely if `ct_amount` equals
+	/// the full bid amount. The PLMC bond (or OTM fee) and funding assets of the removed amount are returned to the bidder.
+	///
+	/// Only bids placed in the current bucket can be reduced. Bids in lower buckets were already used to move the
+	/// price up, so removing them would require repricing every bid that came after.
+	/// If the freed tokens were oversubscribed and the outbid bids were already processed, those are accepted again.
+	/// At most `max_restored_bids` outbid bids are read for it, as the call was charged for that amount.
+	#[transactional]
+	pub fn do_decrease_bid(
+		bidder: &AccountIdOf<T>,
+		project_id: ProjectId,
+		bid_id: u32,
+		ct_amount: Balance,
+		max_restored_bids: u32,
+	) -> DispatchResultWithPostInfo {
+		// * Get variables *
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let mut current_bucket = Buckets::<T>::get(project_id).ok_or(Error::<T>::BucketNotFound)?;
+		let mut bid = Bids::<T>::get(project_id, bid_id).ok_or(Error::<T>::ParticipationNotFound)?;
+		let now = <T as Config>::BlockNumberProvider::current_block_number();
+		Self::refresh_bucket_price(&project_metadata, &project_details, &mut current_bucket, now);
+
+		// * Validity checks *
+		ensure!(bid.bidder == *bidder, Error::<T>::NotAllowed);
+		ensure!(matches!(project_details.status, ProjectStatus::AuctionRound), Error::<T>::IncorrectRound);
+		ensure!(
+			project_details.round_duration.started(now) && !project_details.round_duration.ended(now),
+			Error::<T>::IncorrectRound
+		);
+		ensure!(ct_amount > Zero::zero(), Error::<T>::TooLow);
+		ensure!(ct_amount <= bid.original_ct_amount, Error::<T>::TooHigh);
+		ensure!(
+			bid.status == BidStatus::YetUnknown && bid.original_ct_usd_price == current_bucket.current_price,
+			Error::<T>::BidNotCancellable
+		);
+
+		// * Calculate new variables *
+		let remaining_ct_amount = bid.original_ct_amount.saturating_sub(ct_amount);
+		let removed_ratio = Perquintill::from_rational(ct_amount, bid.original_ct_amount);
+		let (refunded_plmc, refunded_funding_asset_amount) = if remaining_ct_amount.is_zero() {
+			(bid.plmc_bond, bid.funding_asset_amount_locked)
+		} else {
+			(removed_ratio * bid.plmc_bond, removed_ratio * bid.funding_asset_amount_locked)
+		};
+		let old_usd_ticket_size =
+			bid.original_ct_usd_price.checked_mul_int(bid.original_ct_amount).ok_or(Error::<T>::BadMath)?;
+		let new_usd_ticket_size =
+			bid.original_ct_usd_price.checked_mul_int(remaining_ct_amount).ok_or(Error::<T>::BadMath)?;
+
+		// * Return the funds *
+		Self::release_funding_asset(project_id, bidder, refunded_funding_asset_amount, bid.funding_asset)?;
+		if bid.mode == ParticipationMode::OTM {
+			if refunded_plmc > T::NativeCurrency::minimum_balance() {
+				<pallet_proxy_bonding::Pallet<T>>::refund_fee(
+					project_id,
+					bidder,
+					refunded_plmc,
+					Self::funding_asset_location(bid.funding_asset)?,
+				)?;
+			}
+		} else {
+			Self::release_participation_bond_for(bidder, refunded_plmc)?;
+		}
+
+		// * Update storage *
+		AuctionBoughtUSD::<T>::mutate((project_id, bid.did.clone()), |amount| {
+			*amount = amount.saturating_sub(old_usd_ticket_size.saturating_sub(new_usd_ticket_size))
+		});
+
+		// The tokens go back to the current bucket, so the price does not move. Pro-rata sales never use the buckets.
+		if project_metadata.sale_mode == SaleMode::ProRata {
+			ProRataBidCTAmount::<T>::mutate(project_id, |amount| amount.saturating_reduce(ct_amount));
+		} else {
+			current_bucket.amount_left.saturating_accrue(ct_amount);
+			Buckets::<T>::insert(project_id, current_bucket);
+		}
+
+		// If the removed tokens were oversubscribed, they no longer need to outbid lower bids. First cancel out the amount
+		// still pending to be processed, and then accept again the bids that were already marked as outbid.
+		// Dutch auctions are never oversubscribed, and their price goes down from the ceiling instead of up from the
+		// initial price.
+		let mut restored_bids = 0u32;
+		if project_metadata.sale_mode == SaleMode::Auction && bid.original_ct_usd_price > current_bucket.initial_price {
+			let ct_amount_oversubscribed = CTAmountOversubscribed::<T>::get(project_id);
+			CTAmountOversubscribed::<T>::insert(project_id, ct_amount_oversubscribed.saturating_sub(ct_amount));
+			let ct_amount_to_restore = ct_amount.saturating_sub(ct_amount_oversubscribed);
+			if ct_amount_to_restore > Zero::zero() {
+				restored_bids = Self::restore_outbid_bids(
+					project_id,
+					ct_amount_to_restore,
+					current_bucket.delta_price,
+					project_metadata.minimum_price,
+					max_restored_bids,
+				)?;
+			}
+		}
+
+		if remaining_ct_amount.is_zero() {
+			Bids::<T>::remove(project_id, bid_id);
+			Self::remove_bid_from_bucket_bounds(project_id, bid.original_ct_usd_price, bid_id);
+		} else {
+			bid.original_ct_amount = remaining_ct_amount;
+			bid.plmc_bond.saturating_reduce(refunded_plmc);
+			bid.funding_asset_amount_locked.saturating_reduce(refunded_funding_asset_amount);
+			Bids::<T>::insert(project_id, bid_id, &bid);
+		}
+
+		Self::deposit_event(Event::BidCancelled {
+			project_id,
+			bidder: bidder.clone(),
+			id: bid_id,
+			ct_amount,
+			funding_amount: refunded_funding_asset_amount,
+			plmc_bond: refunded_plmc,
+		});
+
+		Ok(PostDispatchInfo {
+			actual_weight: Some(<T as Config>::WeightInfo::cancel_bid(restored_bids)),
+			pays_fee: Pays::Yes,
+		})
+	}
+
+	/// Accept again `ct_amount` of tokens from the bids that were outbid, walking back from the current cutoff.
+	/// Fails if more than `max_restored_bids` bids need to be read. Returns the number of bids that were read.
+	fn restore_outbid_bids(
+		project_id: ProjectId,
+		mut ct_amount: Balance,
+		delta_price: PriceOf<T>,
+		minimum_price: PriceOf<T>,
+		max_restored_bids: u32,
+	) -> Result<u32, DispatchError> {
+		let mut restored_bids = 0u32;
+
+		while !ct_amount.is_zero() {
+			// Running out of outbid bids means some of them were already refunded, so the tokens cannot be given back.
+			let cutoff = OutbidBidsCutoffs::<T>::get(project_id).ok_or(Error::<T>::BidNotCancellable)?;
+			ensure!(restored_bids < max_restored_bids, Error::<T>::TooManyBidsToRestore);
+			restored_bids.saturating_accrue(1);
+
+			// Bids settled early are removed from their bucket, so the cutoff always points to a stored bid.
+			let mut outbid_bid = Bids::<T>::get(project_id, cutoff.bid_index).ok_or(Error::<T>::ImpossibleState)?;
+			let accepted_amount = match outbid_bid.status {
+				BidStatus::PartiallyAccepted(amount) => amount,
+				BidStatus::Rejected => Zero::zero(),
+				_ => return Err(Error::<T>::ImpossibleState.into()),
+			};
+			let restored_amount = outbid_bid.original_ct_amount.saturating_sub(accepted_amount).min(ct_amount);
+			let new_accepted_amount = accepted_amount.saturating_add(restored_amount);
+			ct_amount.saturating_reduce(restored_amount);
+
+			outbid_bid.status = if new_accepted_amount == outbid_bid.original_ct_amount {
+				BidStatus::YetUnknown
+			} else {
+				BidStatus::PartiallyAccepted(new_accepted_amount)
+			};
+			Bids::<T>::insert(project_id, outbid_bid.id, &outbid_bid);
+
+			// The cutoff bid is still partially outbid, so it remains the cutoff.
+			if new_accepted_amount < outbid_bid.original_ct_amount {
+				break;
+			}
+
+			let previous_cutoff =
+				Self::get_previous_cutoff(project_id, delta_price, minimum_price, cutoff.bid_price, cutoff.bid_index)?;
+			OutbidBidsCutoffs::<T>::set(project_id, previous_cutoff);
+		}
+
+		Ok(restored_bids)
+	}
+
+	/// Append a new bid to the bids of its price, after the bid placed last at that price.
+	fn add_bid_to_bucket_bounds(project_id: ProjectId, price: PriceOf<T>, bid_id: u32) {
+		BidsBucketBounds::<T>::mutate(project_id, price, |maybe_bounds| {
+			if let Some(bounds) = maybe_bounds {
+				BidsBucketNeighbours::<T>::mutate(project_id, bounds.last_bid_index, |maybe_neighbours| {
+					if let Some(neighbours) = maybe_neighbours {
+						neighbours.next_bid_index = Some(bid_id);
+					}
+				});
+				BidsBucketNeighbours::<T>::insert(
+					project_id,
+					bid_id,
+					BidBucketNeighbours { previous_bid_index: Some(bounds.last_bid_index), next_bid_index: None },
+				);
+				bounds.last_bid_index = bid_id;
+			} else {
+				BidsBucketNeighbours::<T>::insert(project_id, bid_id, BidBucketNeighbours::default());
+				*maybe_bounds = Some(BidBucketBounds { first_bid_index: bid_id, last_bid_index: bid_id });
+			}
+		});
+	}
+
+	/// Unlink a removed bid from the bids of its price, and shrink the bucket bounds or remove them if no bids remain.
+	pub(crate) fn remove_bid_from_bucket_bounds(project_id: ProjectId, price: PriceOf<T>, bid_id: u32) {
+		let neighbours = BidsBucketNeighbours::<T>::take(project_id, bid_id).unwrap_or_default();
+		if let Some(previous_bid_index) = neighbours.previous_bid_index {
+			BidsBucketNeighbours::<T>::mutate(project_id, previous_bid_index, |maybe_previous| {
+				if let Some(previous) = maybe_previous {
+					previous.next_bid_index = neighbours.next_bid_index;
+				}
+			});
+		}
+		if let Some(next_bid_index) = neighbours.next_bid_index {
+			BidsBucketNeighbours::<T>::mutate(project_id, next_bid_index, |maybe_next| {
+				if let Some(next) = maybe_next {
+					next.previous_bid_index = neighbours.previous_bid_index;
+				}
+			});
+		}
+
+		BidsBucketBounds::<T>::mutate(project_id, price, |maybe_bounds| {
+			let Some(bounds) = maybe_bounds else { return };
+			match (neighbours.previous_bid_index, neighbours.next_bid_index) {
+				(None, None) => *maybe_bounds = None,
+				(None, Some(next_bid_index)) => bounds.first_bid_index = next_bid_index,
+				(Some(previous_bid_index), None) => bounds.last_bid_index = previous_bid_index,
+				(Some(_), Some(_)) => {},
+			}
+		});
+	}
+
+	/// Remove a rejected bid that is settled while the auction is still running. If it was the last bid to be outbid,
+	/// the cutoff goes back to the bid outbid before it, so the next bid to outbid is still found from it.
+	pub(crate) fn remove_early_settled_bid(project_id: ProjectId, bid: &BidInfoOf<T>) -> DispatchResult {
+		if let Some(cutoff) = OutbidBidsCutoffs::<T>::get(project_id) {
+			if cutoff.bid_index == bid.id {
+				let project_metadata =
+					ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
+				let bucket = Buckets::<T>::get(project_id).ok_or(Error::<T>::BucketNotFound)?;
+				let previous_cutoff = Self::get_previous_cutoff(
+					project_id,
+					bucket.delta_price,
+					project_metadata.minimum_price,
+					cutoff.bid_price,
+					cutoff.bid_index,
+				)?;
+				OutbidBidsCutoffs::<T>::set(project_id, previous_cutoff);
+			}
+		}
+		Self::remove_bid_from_bucket_bounds(project_id, bid.original_ct_usd_price, bid.id);
+		Ok(())
+	}
+
+	/// Process the oversubscribed bids of every project in `ProjectsInAuctionRound` while there is weight left.
+	pub(crate) fn process_oversubscribed_bids_on_idle(available_weight: Weight) -> Weight {
+		// Early return if no projects in auction round
+		if ProjectsInAuctionRound::<T>::iter_keys().next().is_none() {
+			return <T as frame_system::Config>::DbWeight::get().reads(1);
+		}
+
+		let mut weight_consumed = <T as frame_system::Config>::DbWeight::get().reads(1);
+		let read_weight = <T as frame_system::Config>::DbWeight::get().reads(1);
+		let process_weight = <T as Config>::WeightInfo::process_next_oversubscribed_bid();
+
+		// Iterate over all projects in auction round
+		for (project_id, _) in ProjectsInAuctionRound::<T>::iter() {
+			// Add weight for reading the storage item
+			weight_consumed.saturating_accrue(read_weight);
+
+			// Check if we have enough weight to continue
+			if weight_consumed.saturating_add(process_weight).all_gt(available_weight) {
+				return weight_consumed;
+			}
+
+			loop {
+				// Check weight before processing each bid
+				if weight_consumed.saturating_add(process_weight).all_gt(available_weight) {
+					return weight_consumed;
+				}
+
+				weight_consumed.saturating_accrue(process_weight);
+				match Self::do_process_next_oversubscribed_bid(project_id) {
+					// Returns Ok if a bid was processed successfully
+					Ok(_) => continue,
+					// Returns Err if there are no more bids to process, so we go to next project
+					Err(_) => break,
+				}
+			}
+		}
+
+		weight_consumed
+	}
+
 	/// Process a bid that was outbid by a new bid. This will set it to Rejected so the user can get their funds back with `settle_bid` and bid again.
 	pub fn do_process_next_oversubscribed_bid(project_id: ProjectId) -> DispatchResult {
 		// Load and validate initial state
@@ -212,20 +834,29 @@ impl<T: Config> Pallet<T> {
 		// Determine the current cutoff
 		let current_cutoff = match OutbidBidsCutoffs::<T>::get(project_id) {
 			Some(cutoff @ OutbidBidsCutoff { bid_price, bid_index }) => {
-				let bid = Bids::<T>::get(project_id, bid_index).ok_or(Error::<T>::ImpossibleState)?;
-				if matches!(bid.status, BidStatus::PartiallyAccepted(_)) {
+				// The cutoff bid is only reprocessed if it was partially outbid
+				let maybe_bid = Bids::<T>::get(project_id, bid_index);
+				if matches!(maybe_bid, Some(BidInfo { status: BidStatus::PartiallyAccepted(_), .. })) {
 					cutoff
 				} else {
-					let (new_price, new_index) =
-						Self::get_next_cutoff(project_id, bucket.delta_price, bid_price, bid_index)?;
+					let (new_price, new_index) = Self::get_next_cutoff(
+						project_id,
+						bucket.delta_price,
+						bucket.current_price,
+						bid_price,
+						bid_index,
+					)?;
 					OutbidBidsCutoff { bid_price: new_price, bid_index: new_index }
 				}
 			},
 			None => {
-				let first_price = project_metadata.minimum_price;
-				let first_bounds =
-					BidsBucketBounds::<T>::get(project_id, first_price).ok_or(Error::<T>::ImpossibleState)?;
-				OutbidBidsCutoff { bid_price: first_price, bid_index: first_bounds.last_bid_index }
+				let (first_price, first_index) = Self::get_last_bid_from_price(
+					project_id,
+					bucket.delta_price,
+					project_metadata.minimum_price,
+					bucket.current_price,
+				)?;
+				OutbidBidsCutoff { bid_price: first_price, bid_index: first_index }
 			},
 		};
 
@@ -256,20 +887,70 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
-	/// Get the next bid that should be processed by do_process_next_oversubscribed_bid
+	/// Get the next bid that should be processed by do_process_next_oversubscribed_bid: the bid placed before the
+	/// current one at the same price, or the last bid of the next price that still holds bids.
 	pub fn get_next_cutoff(
 		project_id: ProjectId,
 		delta_price: PriceOf<T>,
+		max_price: PriceOf<T>,
 		current_price: PriceOf<T>,
 		current_index: u32,
 	) -> Result<(PriceOf<T>, u32), DispatchError> {
-		let bounds = BidsBucketBounds::<T>::get(project_id, current_price).ok_or(Error::<T>::ImpossibleState)?;
-		if current_index == bounds.first_bid_index {
-			let new_price = current_price.saturating_add(delta_price);
-			let new_bounds = BidsBucketBounds::<T>::get(project_id, new_price).ok_or(Error::<T>::ImpossibleState)?;
-			Ok((new_price, new_bounds.last_bid_index))
-		} else {
-			Ok((current_price, current_index.saturating_sub(1)))
+		let neighbours =
+			BidsBucketNeighbours::<T>::get(project_id, current_index).ok_or(Error::<T>::ImpossibleState)?;
+		match neighbours.previous_bid_index {
+			Some(previous_bid_index) => Ok((current_price, previous_bid_index)),
+			None => Self::get_last_bid_from_price(
+				project_id,
+				delta_price,
+				current_price.saturating_add(delta_price),
+				max_price,
+			),
+		}
+	}
+
+	/// Get the bid that was processed by do_process_next_oversubscribed_bid right before the given one, or `None`
+	/// if the given bid was the first one to be outbid.
+	pub fn get_previous_cutoff(
+		project_id: ProjectId,
+		delta_price: PriceOf<T>,
+		minimum_price: PriceOf<T>,
+		current_price: PriceOf<T>,
+		current_index: u32,
+	) -> Result<Option<OutbidBidsCutoff<PriceOf<T>>>, DispatchError> {
+		let neighbours =
+			BidsBucketNeighbours::<T>::get(project_id, current_index).ok_or(Error::<T>::ImpossibleState)?;
+		if let Some(next_bid_index) = neighbours.next_bid_index {
+			return Ok(Some(OutbidBidsCutoff { bid_price: current_price, bid_index: next_bid_index }));
+		}
+
+		// Prices whose bids were all settled early no longer have bucket bounds, and are skipped.
+		let mut price = current_price;
+		while price > minimum_price && !delta_price.is_zero() {
+			price = price.saturating_sub(delta_price);
+			if let Some(bounds) = BidsBucketBounds::<T>::get(project_id, price) {
+				return Ok(Some(OutbidBidsCutoff { bid_price: price, bid_index: bounds.first_bid_index }));
+			}
+		}
+		Ok(None)
+	}
+
+	/// Get the last bid of the first price from `price` up to `max_price` that still holds bids.
+	fn get_last_bid_from_price(
+		project_id: ProjectId,
+		delta_price: PriceOf<T>,
+		mut price: PriceOf<T>,
+		max_price: PriceOf<T>,
+	) -> Result<(PriceOf<T>, u32), DispatchError> {
+		while price <= max_price {
+			if let Some(bounds) = BidsBucketBounds::<T>::get(project_id, price) {
+				return Ok((price, bounds.last_bid_index));
+			}
+			if delta_price.is_zero() {
+				break;
+			}
+			price = price.saturating_add(delta_price);
 		}
+		Err(Error::<T>::ImpossibleState.into())
 	}
 }
@@ -0,0 +1,129 @@
+#[allow(clippy::wildcard_imports)]
+use super::*;
+
+impl<T: Config> Pallet<T> {
+	/// Buy contribution tokens left unsold after the auction. The price is fixed to the weighted average price of the auction.
+	#[transactional]
+	pub fn do_contribute(params: DoContributeParams<T>) -> DispatchResultWithPostInfo {
+		// * Get variables *
+		let DoContributeParams {
+			contributor,
+			project_id,
+			ct_amount,
+			mode,
+			funding_asset,
+			investor_type,
+			did,
+			whitelisted_policy,
+			receiving_account,
+		} = params;
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
+		let mut project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let bucket = Buckets::<T>::get(project_id).ok_or(Error::<T>::BucketNotFound)?;
+		let now = <T as Config>::BlockNumberProvider::current_block_number();
+		let project_policy = project_metadata.policy_ipfs_cid.ok_or(Error::<T>::ImpossibleState)?;
+		let contribution_id = NextContributionId::<T>::get();
+
+		let wap = bucket.calculate_wap(project_metadata.total_allocation_size);
+		let usd_ticket_size = wap.checked_mul_int(ct_amount).ok_or(Error::<T>::BadMath)?;
+		let total_usd_bought_by_did = AuctionBoughtUSD::<T>::get((project_id, did.clone()));
+
+		let metadata_ticket_size_bounds = match investor_type {
+			InvestorType::Institutional => project_metadata.bidding_ticket_sizes.institutional,
+			InvestorType::Professional => project_metadata.bidding_ticket_sizes.professional,
+			InvestorType::Retail => project_metadata.bidding_ticket_sizes.retail,
+		};
+		let max_multiplier = match investor_type {
+			InvestorType::Professional => PROFESSIONAL_MAX_MULTIPLIER,
+			InvestorType::Institutional => INSTITUTIONAL_MAX_MULTIPLIER,
+			InvestorType::Retail => RETAIL_MAX_MULTIPLIER,
+		};
+
+		// * Validity checks *
+		ensure!(project_policy == whitelisted_policy, Error::<T>::PolicyMismatch);
+		ensure!(ct_amount > Zero::zero(), Error::<T>::TooLow);
+		ensure!(did != project_details.issuer_did, Error::<T>::ParticipationToOwnProject);
+		ensure!(!HaltedProjects::<T>::contains_key(project_id), Error::<T>::ProjectHalted);
+		ensure!(matches!(project_details.status, ProjectStatus::CommunityRound), Error::<T>::IncorrectRound);
+		ensure!(
+			project_details.round_duration.started(now) && !project_details.round_duration.ended(now),
+			Error::<T>::IncorrectRound
+		);
+		ensure!(project_details.remaining_contribution_tokens > Zero::zero(), Error::<T>::ProjectSoldOut);
+		ensure!(ct_amount <= project_details.remaining_contribution_tokens, Error::<T>::TooHigh);
+		ensure!(
+			project_metadata.participation_currencies.contains(&funding_asset) &&
+				Self::is_funding_asset_enabled(funding_asset),
+			Error::<T>::FundingAssetNotAccepted
+		);
+		ensure!(
+			metadata_ticket_size_bounds.usd_ticket_above_minimum_per_participation(usd_ticket_size),
+			Error::<T>::TooLow
+		);
+		ensure!(
+			metadata_ticket_size_bounds
+				.usd_ticket_below_maximum_per_did(total_usd_bought_by_did.saturating_add(usd_ticket_size)),
+			Error::<T>::TooHigh
+		);
+		// Contributions count towards the same allowlist cap as the bids
+		Self::ensure_allowlisted(project_id, &did, Some(total_usd_bought_by_did.saturating_add(usd_ticket_size)))?;
+		ensure!(mode.multiplier() <= max_multiplier && mode.multiplier() > 0u8, Error::<T>::ForbiddenMultiplier);
+		ensure!(
+			project_metadata.participants_account_type.junction_is_supported(&receiving_account),
+			Error::<T>::UnsupportedReceiverAccountJunction
+		);
+
+		// * Calculate new variables *
+		let multiplier: MultiplierOf<T> = mode.multiplier().try_into().map_err(|_| Error::<T>::BadMath)?;
+		let plmc_bond = Self::calculate_plmc_bond(usd_ticket_size, multiplier)?;
+		let funding_asset_amount = Self::calculate_funding_asset_amount(usd_ticket_size, funding_asset)?;
+
+		let new_contribution = ContributionInfoOf::<T> {
+			id: contribution_id,
+			project_id,
+			contributor: contributor.clone(),
+			did: did.clone(),
+			ct_amount,
+			usd_contribution_amount: usd_ticket_size,
+			ct_usd_price: wap,
+			funding_asset,
+			funding_asset_amount,
+			mode,
+			plmc_bond,
+			when: now,
+			receiving_account,
+		};
+
+		// * Update storage *
+		Self::bond_plmc_with_mode(&contributor, project_id, plmc_bond, mode, funding_asset)?;
+		Self::try_funding_asset_hold(
+			&contributor,
+			project_id,
+			funding_asset_amount,
+			Self::funding_asset_location(funding_asset)?,
+		)?;
+
+		Contributions::<T>::insert(project_id, contribution_id, &new_contribution);
+		NextContributionId::<T>::set(contribution_id.saturating_add(One::one()));
+		AuctionBoughtUSD::<T>::mutate((project_id, did), |amount| *amount = amount.saturating_add(usd_ticket_size));
+
+		project_details.remaining_contribution_tokens.saturating_reduce(ct_amount);
+		project_details.funding_amount_reached_usd.saturating_accrue(usd_ticket_size);
+		ProjectsDetails::<T>::insert(project_id, project_details);
+
+		// * Emit events *
+		Self::deposit_event(Event::Contribution {
+			project_id,
+			contributor,
+			id: contribution_id,
+			ct_amount,
+			ct_price: wap,
+			funding_asset,
+			funding_amount: funding_asset_amount,
+			plmc_bond,
+			mode,
+		});
+
+		Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::No })
+	}
+}
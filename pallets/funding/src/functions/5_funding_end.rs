@@ -0,0 +1,190 @@
+#[allow(clippy::wildcard_imports)]
+use super::*;
+
+impl<T: Config> Pallet<T> {
+	/// End the auction round, and start the community round if some tokens were left unsold.
+	/// Otherwise, or when called at the end of the community round, end the fundraise and check if it was successful or not.
+	#[transactional]
+	pub fn do_end_funding(project_id: ProjectId) -> DispatchResult {
+		// * Get variables *
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
+		let mut project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let mut bucket = Buckets::<T>::get(project_id).ok_or(Error::<T>::BucketNotFound)?;
+		let now = <T as Config>::BlockNumberProvider::current_block_number();
+		let issuer_did = project_details.issuer_did.clone();
+		let ct_amount_oversubscribed = CTAmountOversubscribed::<T>::get(project_id);
+
+		// * Validity checks *
+		ensure!(
+			project_details.round_duration.ended(now) &&
+				matches!(project_details.status, ProjectStatus::AuctionRound | ProjectStatus::CommunityRound),
+			Error::<T>::TooEarlyForRound
+		);
+		ensure!(ct_amount_oversubscribed.is_zero(), Error::<T>::OversubscribedBidsRemaining);
+
+		let auction_allocation_size = project_metadata.total_allocation_size;
+
+		if project_details.status == ProjectStatus::AuctionRound {
+			ProjectsInAuctionRound::<T>::remove(project_id);
+
+			match project_metadata.sale_mode {
+				SaleMode::ProRata => {
+					// Everyone bid at the minimum price. If more tokens were bid than available, each bid only gets its share.
+					let ct_amount_bid = ProRataBidCTAmount::<T>::get(project_id);
+					let ct_amount_sold = ct_amount_bid.min(auction_allocation_size);
+					if ct_amount_bid > auction_allocation_size {
+						ProRataAcceptedRatio::<T>::insert(
+							project_id,
+							Perquintill::from_rational(auction_allocation_size, ct_amount_bid),
+						);
+					}
+					project_details.funding_amount_reached_usd =
+						project_metadata.minimum_price.checked_mul_int(ct_amount_sold).ok_or(Error::<T>::BadMath)?;
+					project_details.remaining_contribution_tokens =
+						auction_allocation_size.saturating_sub(ct_amount_sold);
+				},
+				SaleMode::DutchAuction { .. } => {
+					// Everyone pays the price of the last block of the round. If the allocation sold out, the round
+					// ended on that same block.
+					let round_end = project_details.round_duration.end().ok_or(Error::<T>::ImpossibleState)?;
+					Self::refresh_bucket_price(&project_metadata, &project_details, &mut bucket, round_end);
+					let clearing_price = bucket.current_price;
+					let ct_amount_sold = auction_allocation_size.saturating_sub(bucket.amount_left);
+					DutchAuctionClearingPrice::<T>::insert(project_id, clearing_price);
+					project_details.funding_amount_reached_usd =
+						clearing_price.checked_mul_int(ct_amount_sold).ok_or(Error::<T>::BadMath)?;
+					project_details.remaining_contribution_tokens = bucket.amount_left;
+
+					// From now on the bucket only holds the clearing price, which the community round also sells at.
+					bucket = Bucket::new(bucket.amount_left, clearing_price, bucket.delta_price, bucket.delta_amount);
+					Buckets::<T>::insert(project_id, bucket);
+				},
+				SaleMode::Auction => {
+					let usd_raised = bucket.calculate_usd_raised(auction_allocation_size);
+					project_details.funding_amount_reached_usd = usd_raised;
+					project_details.remaining_contribution_tokens =
+						if bucket.current_price == bucket.initial_price { bucket.amount_left } else { Zero::zero() };
+				},
+			}
+
+			if project_details.remaining_contribution_tokens > Zero::zero() {
+				Self::transition_project(
+					project_id,
+					project_details,
+					ProjectStatus::AuctionRound,
+					ProjectStatus::CommunityRound,
+					Some(T::CommunityRoundDuration::get()),
+					true,
+				)?;
+				return Ok(());
+			}
+		}
+
+		let bucket_price_higher_than_initial = bucket.current_price > bucket.initial_price;
+		let sold_percent = Perquintill::from_rational(
+			auction_allocation_size.saturating_sub(project_details.remaining_contribution_tokens),
+			auction_allocation_size,
+		);
+		let threshold = Self::funding_success_threshold(&project_metadata);
+		let sold_more_than_min = sold_percent >= threshold;
+
+		let funding_successful = bucket_price_higher_than_initial || sold_more_than_min;
+		let issuer_decides = !funding_successful && sold_percent >= T::IssuerDecisionThreshold::get();
+
+		Self::remove_active_project(issuer_did, project_id);
+
+		ProjectsDetails::<T>::insert(project_id, project_details.clone());
+
+		// * Update project status *
+		if issuer_decides {
+			return Self::transition_project(
+				project_id,
+				project_details.clone(),
+				project_details.status,
+				ProjectStatus::AwaitingIssuerDecision,
+				Some(T::IssuerDecisionDuration::get()),
+				true,
+			);
+		}
+		let decision = if funding_successful {
+			FundingOutcomeDecision::AcceptFunding
+		} else {
+			FundingOutcomeDecision::RejectFunding
+		};
+
+		Self::finish_funding(project_id, project_details, decision)
+	}
+
+	/// Accept or reject the funding of a project awaiting the decision of its issuer.
+	#[transactional]
+	pub fn do_decide_project_outcome(
+		issuer: AccountIdOf<T>,
+		project_id: ProjectId,
+		decision: FundingOutcomeDecision,
+	) -> DispatchResult {
+		// * Get variables *
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let now = <T as Config>::BlockNumberProvider::current_block_number();
+
+		// * Validity checks *
+		ensure!(project_details.issuer_account == issuer, Error::<T>::NotIssuer);
+		ensure!(project_details.status == ProjectStatus::AwaitingIssuerDecision, Error::<T>::IncorrectRound);
+		ensure!(!project_details.round_duration.ended(now), Error::<T>::TooLateForRound);
+
+		// * Update storage *
+		// The default decision is no longer needed
+		if let Some(round_end) = project_details.round_duration.end() {
+			ProjectTransitionQueue::<T>::remove(round_end.saturating_add(One::one()), project_id);
+		}
+		Self::finish_funding(project_id, project_details, decision)?;
+
+		// * Emit events *
+		Self::deposit_event(Event::ProjectOutcomeDecided { project_id, decision });
+
+		Ok(())
+	}
+
+	/// Apply `DefaultFundingOutcomeDecision` to a project whose issuer did not decide before the deadline.
+	#[transactional]
+	pub fn do_apply_default_project_outcome(project_id: ProjectId) -> DispatchResult {
+		// * Get variables *
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let now = <T as Config>::BlockNumberProvider::current_block_number();
+		let decision = T::DefaultFundingOutcomeDecision::get();
+
+		// * Validity checks *
+		ensure!(project_details.status == ProjectStatus::AwaitingIssuerDecision, Error::<T>::IncorrectRound);
+		ensure!(project_details.round_duration.ended(now), Error::<T>::TooEarlyForRound);
+
+		// * Update storage *
+		Self::finish_funding(project_id, project_details, decision)?;
+
+		// * Emit events *
+		Self::deposit_event(Event::ProjectOutcomeDecided { project_id, decision });
+
+		Ok(())
+	}
+
+	/// Set the evaluators outcome that follows the decision, and move the project to `FundingSuccessful` or
+	/// `FundingFailed`, from where the settlement starts.
+	fn finish_funding(
+		project_id: ProjectId,
+		mut project_details: ProjectDetailsOf<T>,
+		decision: FundingOutcomeDecision,
+	) -> DispatchResult {
+		let next_status = match decision {
+			FundingOutcomeDecision::AcceptFunding => {
+				let reward_info = Self::generate_evaluator_rewards_info(project_id)?;
+				project_details.evaluation_round_info.evaluators_outcome =
+					Some(EvaluatorsOutcome::Rewarded(reward_info));
+				ProjectStatus::FundingSuccessful
+			},
+			FundingOutcomeDecision::RejectFunding => {
+				project_details.evaluation_round_info.evaluators_outcome = Some(EvaluatorsOutcome::Slashed);
+				ProjectStatus::FundingFailed
+			},
+		};
+
+		Self::transition_project(project_id, project_details.clone(), project_details.status, next_status, None, true)
+	}
+}
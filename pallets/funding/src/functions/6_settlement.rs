@@ -0,0 +1,904 @@
+#[allow(clippy::wildcard_imports)]
+use super::*;
+use crate::{traits::VestingDurationCalculation, Balance};
+use frame_support::{
+	dispatch::DispatchResult,
+	ensure,
+	storage::with_storage_layer,
+	traits::{
+		fungible::{Inspect, MutateHold as FungibleMutateHold},
+		fungibles::{InspectFreeze, Mutate as FungiblesMutate, MutateFreeze},
+		tokens::{Fortitude, Precision, Preservation, Restriction},
+		Get,
+	},
+};
+use on_slash_vesting::OnSlash;
+use pallet_proxy_bonding::ReleaseType;
+use polimec_common::{
+	assets::AcceptedFundingAsset,
+	migration_types::{MigrationInfo, MigrationOrigin, MigrationStatus, ParticipationType},
+	ReleaseSchedule,
+};
+use sp_runtime::{
+	traits::{SaturatedConversion, Zero},
+	PerThing, Perquintill,
+};
+
+impl<T: Config> Pallet<T> {
+	/// Start the settlement round. Now users can mint their contribution tokens or get their funds back, and the issuer
+	/// will get the funds in their funding account.
+	#[transactional]
+	pub fn do_start_settlement(project_id: ProjectId) -> DispatchResult {
+		let mut project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let token_information =
+			ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?.token_information;
+		let now = <T as Config>::BlockNumberProvider::current_block_number();
+
+		project_details.funding_end_block = Some(now);
+
+		let escrow_account = Self::fund_account_id(project_id);
+		if project_details.status == ProjectStatus::FundingSuccessful {
+			let otm_release_type = {
+				let multiplier: MultiplierOf<T> =
+					ParticipationMode::OTM.multiplier().try_into().map_err(|_| Error::<T>::ImpossibleState)?;
+				let duration = multiplier.calculate_vesting_duration::<T>();
+				let now = <T as Config>::BlockNumberProvider::current_block_number();
+				ReleaseType::Locked(duration.saturating_add(now))
+			};
+			<pallet_proxy_bonding::Pallet<T>>::set_release_type(
+				project_id,
+				HoldReason::Participation.into(),
+				otm_release_type,
+			);
+
+			T::ContributionTokenCurrency::create(project_id, escrow_account.clone(), false, 1_u32.into())?;
+			T::ContributionTokenCurrency::set(
+				project_id,
+				&escrow_account,
+				token_information.name.into(),
+				token_information.symbol.into(),
+				token_information.decimals,
+			)?;
+
+			let contribution_token_treasury_account = T::ContributionTreasury::get();
+			T::ContributionTokenCurrency::touch(
+				project_id,
+				&contribution_token_treasury_account,
+				&contribution_token_treasury_account,
+			)?;
+
+			let (liquidity_pools_ct_amount, long_term_holder_bonus_ct_amount) =
+				Self::generate_liquidity_pools_and_long_term_holder_rewards(project_id)?;
+
+			T::ContributionTokenCurrency::mint_into(
+				project_id,
+				&contribution_token_treasury_account,
+				long_term_holder_bonus_ct_amount,
+			)?;
+			T::ContributionTokenCurrency::mint_into(
+				project_id,
+				&contribution_token_treasury_account,
+				liquidity_pools_ct_amount,
+			)?;
+
+			Self::transition_project(
+				project_id,
+				project_details,
+				ProjectStatus::FundingSuccessful,
+				ProjectStatus::SettlementStarted(FundingOutcome::Success),
+				None,
+				false,
+			)?;
+		} else {
+			let otm_release_type = ReleaseType::Refunded;
+			<pallet_proxy_bonding::Pallet<T>>::set_release_type(
+				project_id,
+				HoldReason::Participation.into(),
+				otm_release_type,
+			);
+
+			Self::transition_project(
+				project_id,
+				project_details,
+				ProjectStatus::FundingFailed,
+				ProjectStatus::SettlementStarted(FundingOutcome::Failure),
+				None,
+				false,
+			)?;
+		}
+
+		ProjectsInSettlement::<T>::insert(project_id, SettlementCursor::default());
+
+		Ok(())
+	}
+
+	/// Settle an evaluation, by maybe minting CTs, and releasing the PLMC bond.
+	pub fn do_settle_evaluation(evaluation: EvaluationInfoOf<T>, project_id: ProjectId) -> DispatchResult {
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+
+		ensure!(
+			matches!(project_details.status, ProjectStatus::SettlementStarted(..)),
+			Error::<T>::SettlementNotStarted
+		);
+
+		let (plmc_released, ct_rewarded): (Balance, Balance) =
+			match project_details.evaluation_round_info.evaluators_outcome {
+				Some(EvaluatorsOutcome::Slashed) => (Self::slash_evaluator(&evaluation)?, Zero::zero()),
+				Some(EvaluatorsOutcome::Rewarded(info)) => Self::reward_evaluator(project_id, &evaluation, &info)?,
+				None => (evaluation.current_plmc_bond, Zero::zero()),
+			};
+
+		// Release the held PLMC bond
+		T::NativeCurrency::release(
+			&HoldReason::Evaluation.into(),
+			&evaluation.evaluator,
+			plmc_released,
+			Precision::Exact,
+		)?;
+
+		// Create Migration
+		if ct_rewarded > Zero::zero() {
+			let multiplier = MultiplierOf::<T>::try_from(1u8).map_err(|_| Error::<T>::BadMath)?;
+			let duration = multiplier.calculate_vesting_duration::<T>();
+			Self::create_migration(
+				project_id,
+				&evaluation.evaluator,
+				ParticipationType::Evaluation,
+				ct_rewarded,
+				duration,
+				evaluation.receiving_account,
+			)?;
+		}
+		Evaluations::<T>::remove((project_id, evaluation.evaluator.clone(), evaluation.id));
+
+		Self::deposit_event(Event::EvaluationSettled {
+			project_id,
+			account: evaluation.evaluator,
+			id: evaluation.id,
+			plmc_released,
+			ct_rewarded,
+		});
+
+		Ok(())
+	}
+
+	/// Settle a bid. If bid was successful mint the CTs and release the PLMC bond (if multiplier > 1 and mode is Classic).
+	/// If was unsuccessful, release the PLMC bond and refund the funds.
+	/// If the project was successful, the issuer will get the funds.
+	pub fn do_settle_bid(project_id: ProjectId, bid_id: u32) -> DispatchResult {
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
+		let funding_success =
+			matches!(project_details.status, ProjectStatus::SettlementStarted(FundingOutcome::Success));
+		let mut bid = Bids::<T>::get(project_id, bid_id).ok_or(Error::<T>::ParticipationNotFound)?;
+
+		ensure!(
+			matches!(project_details.status, ProjectStatus::SettlementStarted(..)) || bid.status == BidStatus::Rejected,
+			Error::<T>::SettlementNotStarted
+		);
+
+		if bid.status == BidStatus::YetUnknown {
+			bid.status = BidStatus::Accepted;
+		}
+
+		// Return the full bid amount to refund if bid is rejected or project failed,
+		// Return a partial amount if the project succeeded, and the wap > paid price or bid is partially accepted
+		let BidRefund { final_ct_amount, refunded_plmc, refunded_funding_asset_amount } =
+			Self::calculate_refund(&bid, funding_success)?;
+
+		Self::release_funding_asset(project_id, &bid.bidder, refunded_funding_asset_amount, bid.funding_asset)?;
+
+		if bid.mode == ParticipationMode::OTM {
+			if refunded_plmc > T::NativeCurrency::minimum_balance() {
+				<pallet_proxy_bonding::Pallet<T>>::refund_fee(
+					project_id,
+					&bid.bidder,
+					refunded_plmc,
+					Self::funding_asset_location(bid.funding_asset)?,
+				)?;
+			}
+		} else {
+			Self::release_participation_bond_for(&bid.bidder, refunded_plmc)?;
+		}
+
+		if funding_success {
+			let ct_vesting_duration = Self::set_plmc_bond_release_with_mode(
+				bid.bidder.clone(),
+				bid.plmc_bond.saturating_sub(refunded_plmc),
+				bid.mode,
+				project_details.funding_end_block.ok_or(Error::<T>::ImpossibleState)?,
+			)?;
+
+			Self::mint_bought_contribution_tokens(
+				project_id,
+				project_metadata.ct_vesting,
+				&bid.bidder,
+				final_ct_amount,
+			)?;
+
+			Self::create_migration(
+				project_id,
+				&bid.bidder,
+				ParticipationType::Bid,
+				final_ct_amount,
+				Self::migration_vesting_time(ct_vesting_duration, project_metadata.ct_vesting),
+				bid.receiving_account,
+			)?;
+
+			Self::pay_out_funding_asset(
+				project_id,
+				&project_metadata,
+				&bid.bidder,
+				bid.funding_asset_amount_locked.saturating_sub(refunded_funding_asset_amount),
+				bid.funding_asset,
+			)?;
+		}
+
+		// Bids rejected before the settlement leave their bucket, as the auction might still be outbidding bids.
+		if matches!(project_details.status, ProjectStatus::SettlementStarted(..)) {
+			BidsBucketNeighbours::<T>::remove(project_id, bid.id);
+		} else {
+			Self::remove_early_settled_bid(project_id, &bid)?;
+		}
+		Bids::<T>::remove(project_id, bid.id);
+
+		Self::deposit_event(Event::BidSettled {
+			project_id,
+			account: bid.bidder,
+			id: bid.id,
+			status: bid.status,
+			final_ct_amount,
+		});
+
+		Ok(())
+	}
+
+	/// Settle a contribution made in the community round. Contributions are always fully accepted at the fixed price,
+	/// so if the project was successful, mint the CTs, set the PLMC release schedule and pay out the funding assets to the issuer.
+	/// If it failed, release the PLMC bond and refund the funds.
+	pub fn do_settle_contribution(project_id: ProjectId, contribution_id: u32) -> DispatchResult {
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
+		let funding_success =
+			matches!(project_details.status, ProjectStatus::SettlementStarted(FundingOutcome::Success));
+		let contribution =
+			Contributions::<T>::get(project_id, contribution_id).ok_or(Error::<T>::ParticipationNotFound)?;
+
+		ensure!(
+			matches!(project_details.status, ProjectStatus::SettlementStarted(..)),
+			Error::<T>::SettlementNotStarted
+		);
+
+		let final_ct_amount = if funding_success {
+			let ct_vesting_duration = Self::set_plmc_bond_release_with_mode(
+				contribution.contributor.clone(),
+				contribution.plmc_bond,
+				contribution.mode,
+				project_details.funding_end_block.ok_or(Error::<T>::ImpossibleState)?,
+			)?;
+
+			Self::mint_bought_contribution_tokens(
+				project_id,
+				project_metadata.ct_vesting,
+				&contribution.contributor,
+				contribution.ct_amount,
+			)?;
+
+			// Contributions are migrated the same way as bids, since they only differ in the round they were made in.
+			Self::create_migration(
+				project_id,
+				&contribution.contributor,
+				ParticipationType::Bid,
+				contribution.ct_amount,
+				Self::migration_vesting_time(ct_vesting_duration, project_metadata.ct_vesting),
+				contribution.receiving_account,
+			)?;
+
+			Self::pay_out_funding_asset(
+				project_id,
+				&project_metadata,
+				&contribution.contributor,
+				contribution.funding_asset_amount,
+				contribution.funding_asset,
+			)?;
+
+			contribution.ct_amount
+		} else {
+			Self::release_funding_asset(
+				project_id,
+				&contribution.contributor,
+				contribution.funding_asset_amount,
+				contribution.funding_asset,
+			)?;
+
+			if contribution.mode == ParticipationMode::OTM {
+				if contribution.plmc_bond > T::NativeCurrency::minimum_balance() {
+					<pallet_proxy_bonding::Pallet<T>>::refund_fee(
+						project_id,
+						&contribution.contributor,
+						contribution.plmc_bond,
+						Self::funding_asset_location(contribution.funding_asset)?,
+					)?;
+				}
+			} else {
+				Self::release_participation_bond_for(&contribution.contributor, contribution.plmc_bond)?;
+			}
+
+			Zero::zero()
+		};
+
+		Contributions::<T>::remove(project_id, contribution.id);
+
+		Self::deposit_event(Event::ContributionSettled {
+			project_id,
+			account: contribution.contributor,
+			id: contribution.id,
+			ct_amount: final_ct_amount,
+		});
+
+		Ok(())
+	}
+
+	/// Unfreeze the contribution tokens of a participant that vested since their last claim.
+	/// Vesting starts at the project's `funding_end_block`, which is the block its settlement started.
+	pub fn do_claim_vested_contribution_tokens(participant: &AccountIdOf<T>, project_id: ProjectId) -> DispatchResult {
+		// * Get variables *
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
+		let total_amount = ContributionTokenVestings::<T>::get(project_id, participant);
+		ensure!(!total_amount.is_zero(), Error::<T>::NoVestedContributionTokens);
+		let ct_vesting = project_metadata.ct_vesting.ok_or(Error::<T>::ImpossibleState)?;
+		let vesting_start = project_details.funding_end_block.ok_or(Error::<T>::ImpossibleState)?;
+		let now = <T as Config>::BlockNumberProvider::current_block_number();
+		let blocks_elapsed: u32 = now.saturating_sub(vesting_start).saturated_into();
+		let freeze_reason: <T as Config>::RuntimeFreezeReason = FreezeReason::ContributionTokenVesting.into();
+
+		let still_frozen_amount = total_amount.saturating_sub(ct_vesting.vested_amount(total_amount, blocks_elapsed));
+		let frozen_amount = T::ContributionTokenFreezer::balance_frozen(project_id, &freeze_reason, participant);
+		let unfrozen_amount = frozen_amount.saturating_sub(still_frozen_amount);
+
+		// * Validity checks *
+		ensure!(!unfrozen_amount.is_zero(), Error::<T>::NoVestedContributionTokens);
+
+		// * Update storage *
+		if still_frozen_amount.is_zero() {
+			T::ContributionTokenFreezer::thaw(project_id, &freeze_reason, participant)?;
+			ContributionTokenVestings::<T>::remove(project_id, participant);
+		} else {
+			T::ContributionTokenFreezer::set_freeze(project_id, &freeze_reason, participant, still_frozen_amount)?;
+		}
+
+		// * Emit events *
+		Self::deposit_event(Event::VestedContributionTokensClaimed {
+			project_id,
+			account: participant.clone(),
+			amount: unfrozen_amount,
+		});
+
+		Ok(())
+	}
+
+	/// Approve or reject the next funding milestone of a settled project. Approving it pays its tranche of the escrowed
+	/// funds out to the issuer, rejecting it lets each participant claim back the same share of what they paid.
+	pub fn do_decide_funding_milestone(project_id: ProjectId, milestone_index: u32, approve: bool) -> DispatchResult {
+		// * Get variables *
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
+		ensure!((milestone_index as usize) < project_metadata.funding_milestones.len(), Error::<T>::MilestoneNotFound);
+
+		// * Validity checks *
+		ensure!(
+			matches!(
+				project_details.status,
+				ProjectStatus::SettlementFinished(FundingOutcome::Success) |
+					ProjectStatus::CTMigrationStarted |
+					ProjectStatus::CTMigrationFinished
+			),
+			Error::<T>::SettlementNotComplete
+		);
+		ensure!(
+			!MilestoneOutcomes::<T>::contains_key(project_id, milestone_index),
+			Error::<T>::MilestoneAlreadyDecided
+		);
+		ensure!(
+			milestone_index == 0 || MilestoneOutcomes::<T>::contains_key(project_id, milestone_index - 1),
+			Error::<T>::PreviousMilestoneNotDecided
+		);
+
+		// * Update storage *
+		let outcome = if approve {
+			for (funding_asset, escrowed_amount) in MilestoneEscrowTotals::<T>::iter_prefix(project_id) {
+				Self::release_funding_asset(
+					project_id,
+					&project_metadata.funding_destination_account,
+					Self::milestone_tranche_amount(
+						&project_metadata.funding_milestones,
+						milestone_index,
+						escrowed_amount,
+					),
+					funding_asset,
+				)?;
+			}
+			MilestoneOutcome::Released
+		} else {
+			MilestoneOutcome::Rejected
+		};
+		MilestoneOutcomes::<T>::insert(project_id, milestone_index, outcome);
+
+		// * Emit events *
+		Self::deposit_event(Event::FundingMilestoneDecided { project_id, milestone_index, outcome });
+
+		Ok(())
+	}
+
+	/// Refund a participant their share of the tranche of a rejected funding milestone, in each funding asset they paid
+	/// with.
+	pub fn do_claim_milestone_refund(
+		participant: &AccountIdOf<T>,
+		project_id: ProjectId,
+		milestone_index: u32,
+	) -> DispatchResult {
+		// * Get variables *
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
+		ensure!((milestone_index as usize) < project_metadata.funding_milestones.len(), Error::<T>::MilestoneNotFound);
+		let refunds = MilestoneEscrowContributions::<T>::iter_prefix((project_id, participant.clone()))
+			.map(|(funding_asset, escrowed_amount)| {
+				(
+					funding_asset,
+					Self::milestone_tranche_amount(
+						&project_metadata.funding_milestones,
+						milestone_index,
+						escrowed_amount,
+					),
+				)
+			})
+			.filter(|(_, amount)| !amount.is_zero())
+			.collect::<Vec<_>>();
+
+		// * Validity checks *
+		ensure!(
+			MilestoneOutcomes::<T>::get(project_id, milestone_index) == Some(MilestoneOutcome::Rejected),
+			Error::<T>::MilestoneNotRejected
+		);
+		ensure!(
+			!MilestoneRefundsClaimed::<T>::contains_key((project_id, milestone_index, participant.clone())),
+			Error::<T>::MilestoneRefundAlreadyClaimed
+		);
+		ensure!(!refunds.is_empty(), Error::<T>::NoMilestoneRefund);
+
+		// * Update storage *
+		MilestoneRefundsClaimed::<T>::insert((project_id, milestone_index, participant.clone()), ());
+		for (funding_asset, amount) in refunds {
+			Self::release_funding_asset(project_id, participant, amount, funding_asset)?;
+
+			// * Emit events *
+			Self::deposit_event(Event::MilestoneRefundClaimed {
+				project_id,
+				milestone_index,
+				account: participant.clone(),
+				funding_asset,
+				amount,
+			});
+		}
+
+		Ok(())
+	}
+
+	/// Calculate the amount of funds the bidder should receive back based on the original bid
+	/// amount and price compared to the final bid amount and price.
+	fn calculate_refund(bid: &BidInfoOf<T>, funding_success: bool) -> Result<BidRefund, DispatchError> {
+		let multiplier: MultiplierOf<T> = bid.mode.multiplier().try_into().map_err(|_| Error::<T>::BadMath)?;
+		let mut ct_price = bid.original_ct_usd_price;
+
+		// On oversubscribed pro-rata sales, accepted bids only get their share of the allocation.
+		let mut status = match (bid.status, ProRataAcceptedRatio::<T>::get(bid.project_id)) {
+			(BidStatus::Accepted, Some(accepted_ratio)) =>
+				BidStatus::PartiallyAccepted(accepted_ratio.mul_floor(bid.original_ct_amount)),
+			(status, _) => status,
+		};
+
+		// On descending auctions every bid is accepted at the clearing price, and the difference is refunded.
+		if let Some(clearing_price) = DutchAuctionClearingPrice::<T>::get(bid.project_id) {
+			if status == BidStatus::Accepted && clearing_price < ct_price {
+				ct_price = clearing_price;
+				status = BidStatus::PartiallyAccepted(bid.original_ct_amount);
+			}
+		}
+
+		match status {
+			BidStatus::Accepted if funding_success => Ok(BidRefund {
+				final_ct_amount: bid.original_ct_amount,
+				refunded_plmc: Zero::zero(),
+				refunded_funding_asset_amount: Zero::zero(),
+			}),
+			BidStatus::PartiallyAccepted(accepted_amount) if funding_success => {
+				let new_ticket_size = ct_price.checked_mul_int(accepted_amount).ok_or(Error::<T>::BadMath)?;
+				let new_plmc_bond = Self::calculate_plmc_bond(new_ticket_size, multiplier)?;
+				let new_funding_asset_amount =
+					Self::calculate_funding_asset_amount(new_ticket_size, bid.funding_asset)?;
+				let refunded_plmc = bid.plmc_bond.saturating_sub(new_plmc_bond);
+				let refunded_funding_asset_amount =
+					bid.funding_asset_amount_locked.saturating_sub(new_funding_asset_amount);
+				Ok(BidRefund { final_ct_amount: accepted_amount, refunded_plmc, refunded_funding_asset_amount })
+			},
+			_ => Ok(BidRefund {
+				final_ct_amount: Zero::zero(),
+				refunded_plmc: bid.plmc_bond,
+				refunded_funding_asset_amount: bid.funding_asset_amount_locked,
+			}),
+		}
+	}
+
+	/// Mark a project as fully settled. Only once this is done we can mark migrations as completed.
+	pub fn do_mark_project_as_settled(project_id: ProjectId) -> DispatchResult {
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let outcome = match project_details.status {
+			ProjectStatus::SettlementStarted(ref outcome) => outcome.clone(),
+			_ => return Err(Error::<T>::IncorrectRound.into()),
+		};
+
+		// We use closers to do an early return if just one of these storage iterators returns a value.
+		let no_evaluations_remaining = || Evaluations::<T>::iter_prefix((project_id,)).next().is_none();
+		let no_bids_remaining = || Bids::<T>::iter_prefix(project_id).next().is_none();
+		let no_contributions_remaining = || Contributions::<T>::iter_prefix(project_id).next().is_none();
+
+		// Check if there are any evaluations, bids or contributions remaining
+		ensure!(
+			no_evaluations_remaining() && no_bids_remaining() && no_contributions_remaining(),
+			Error::<T>::SettlementNotComplete
+		);
+
+		// Mark the project as settled
+		ProjectsInSettlement::<T>::remove(project_id);
+		Self::transition_project(
+			project_id,
+			project_details,
+			ProjectStatus::SettlementStarted(outcome.clone()),
+			ProjectStatus::SettlementFinished(outcome),
+			None,
+			false,
+		)?;
+
+		Ok(())
+	}
+
+	/// Settle the participations of projects in `ProjectsInSettlement` while there is weight left, one project at a
+	/// time. Evaluations are settled first, then bids, then contributions, and once all of them were tried the project
+	/// is marked as settled. Participations that fail to settle are skipped, and can still be settled manually.
+	pub(crate) fn settle_projects_on_idle(available_weight: Weight) -> Weight {
+		let read_weight = <T as frame_system::Config>::DbWeight::get().reads(1);
+		let write_weight = <T as frame_system::Config>::DbWeight::get().writes(1);
+		let mut weight_consumed = Weight::zero();
+
+		loop {
+			weight_consumed.saturating_accrue(read_weight);
+			let Some((project_id, mut cursor)) = ProjectsInSettlement::<T>::iter().next() else {
+				return weight_consumed;
+			};
+
+			loop {
+				let settle_weight = match cursor.phase {
+					SettlementPhase::Evaluations => <T as Config>::WeightInfo::settle_rewarded_evaluation(),
+					SettlementPhase::Bids => <T as Config>::WeightInfo::settle_accepted_bid_with_refund(),
+					SettlementPhase::Contributions => <T as Config>::WeightInfo::settle_contribution(),
+				}
+				.saturating_add(read_weight);
+
+				// Keep enough weight to store the cursor, so we can resume from here on the next block
+				if weight_consumed.saturating_add(settle_weight).saturating_add(write_weight).all_gt(available_weight) {
+					ProjectsInSettlement::<T>::insert(project_id, cursor);
+					return weight_consumed.saturating_add(write_weight);
+				}
+				weight_consumed.saturating_accrue(settle_weight);
+
+				let last_key = cursor.last_key.take().map(|key| key.into_inner());
+				let settled_key = match cursor.phase {
+					SettlementPhase::Evaluations => Self::settle_next_evaluation(project_id, last_key),
+					SettlementPhase::Bids => Self::settle_next_bid(project_id, last_key),
+					SettlementPhase::Contributions => Self::settle_next_contribution(project_id, last_key),
+				};
+
+				match settled_key.map(BoundedVec::try_from) {
+					Some(Ok(key)) => cursor.last_key = Some(key),
+					// The phase has no participations left, or the key cannot be stored and we cannot resume from it
+					_ => match cursor.phase {
+						SettlementPhase::Evaluations => cursor.phase = SettlementPhase::Bids,
+						SettlementPhase::Bids => cursor.phase = SettlementPhase::Contributions,
+						SettlementPhase::Contributions => break,
+					},
+				}
+			}
+
+			let mark_weight = <T as Config>::WeightInfo::mark_project_as_settled().saturating_add(write_weight);
+			if weight_consumed.saturating_add(mark_weight).all_gt(available_weight) {
+				ProjectsInSettlement::<T>::insert(project_id, cursor);
+				return weight_consumed.saturating_add(write_weight);
+			}
+			weight_consumed.saturating_accrue(mark_weight);
+
+			// If some participations could not be settled, the project stays in `SettlementStarted` until they are
+			// settled manually, and `mark_project_as_settled` has to be called again.
+			let _ = with_storage_layer(|| Self::do_mark_project_as_settled(project_id));
+			ProjectsInSettlement::<T>::remove(project_id);
+		}
+	}
+
+	/// Try to settle the first evaluation of the project stored after `last_key`, and return its raw storage key.
+	/// Returns `None` if there are no evaluations left.
+	fn settle_next_evaluation(project_id: ProjectId, last_key: Option<Vec<u8>>) -> Option<Vec<u8>> {
+		let mut evaluations = match last_key {
+			Some(key) => Evaluations::<T>::iter_prefix_from((project_id,), key),
+			None => Evaluations::<T>::iter_prefix((project_id,)),
+		};
+		let (_, evaluation) = evaluations.next()?;
+		let key = Evaluations::<T>::hashed_key_for((project_id, evaluation.evaluator.clone(), evaluation.id));
+		let _ = with_storage_layer(|| Self::do_settle_evaluation(evaluation, project_id));
+
+		Some(key)
+	}
+
+	/// Try to settle the first bid of the project stored after `last_key`, and return its raw storage key.
+	/// Returns `None` if there are no bids left.
+	fn settle_next_bid(project_id: ProjectId, last_key: Option<Vec<u8>>) -> Option<Vec<u8>> {
+		let mut bids = match last_key {
+			Some(key) => Bids::<T>::iter_prefix_from(project_id, key),
+			None => Bids::<T>::iter_prefix(project_id),
+		};
+		let (bid_id, _) = bids.next()?;
+		let _ = with_storage_layer(|| Self::do_settle_bid(project_id, bid_id));
+
+		Some(Bids::<T>::hashed_key_for(project_id, bid_id))
+	}
+
+	/// Try to settle the first contribution of the project stored after `last_key`, and return its raw storage key.
+	/// Returns `None` if there are no contributions left.
+	fn settle_next_contribution(project_id: ProjectId, last_key: Option<Vec<u8>>) -> Option<Vec<u8>> {
+		let mut contributions = match last_key {
+			Some(key) => Contributions::<T>::iter_prefix_from(project_id, key),
+			None => Contributions::<T>::iter_prefix(project_id),
+		};
+		let (contribution_id, _) = contributions.next()?;
+		let _ = with_storage_layer(|| Self::do_settle_contribution(project_id, contribution_id));
+
+		Some(Contributions::<T>::hashed_key_for(project_id, contribution_id))
+	}
+
+	/// Helper function to Mint CTs and handle the payment of new storage with "touch"
+	fn mint_contribution_tokens(
+		project_id: ProjectId,
+		participant: &AccountIdOf<T>,
+		amount: Balance,
+	) -> DispatchResult {
+		if !T::ContributionTokenCurrency::contains(&project_id, participant) {
+			T::ContributionTokenCurrency::touch(project_id, participant, participant)?;
+		}
+		T::ContributionTokenCurrency::mint_into(project_id, participant, amount)?;
+		Ok(())
+	}
+
+	/// Mint the CTs bought by a bidder or contributor. If the project vests them, they are also frozen in the participant
+	/// account, and the participant unfreezes them as they vest.
+	fn mint_bought_contribution_tokens(
+		project_id: ProjectId,
+		ct_vesting: Option<ContributionTokenVesting>,
+		participant: &AccountIdOf<T>,
+		amount: Balance,
+	) -> DispatchResult {
+		Self::mint_contribution_tokens(project_id, participant, amount)?;
+		if ct_vesting.is_none() || amount.is_zero() {
+			return Ok(());
+		}
+
+		T::ContributionTokenFreezer::increase_frozen(
+			project_id,
+			&FreezeReason::ContributionTokenVesting.into(),
+			participant,
+			amount,
+		)?;
+		ContributionTokenVestings::<T>::mutate(project_id, participant, |total_amount| {
+			*total_amount = total_amount.saturating_add(amount)
+		});
+		Ok(())
+	}
+
+	/// The CTs need to stay locked on mainnet for as long as the PLMC bond, and for as long as the project vests them.
+	fn migration_vesting_time(
+		plmc_vesting_duration: BlockNumberFor<T>,
+		ct_vesting: Option<ContributionTokenVesting>,
+	) -> BlockNumberFor<T> {
+		match ct_vesting {
+			Some(ct_vesting) => plmc_vesting_duration.max(ct_vesting.total_duration().into()),
+			None => plmc_vesting_duration,
+		}
+	}
+
+	/// Pay the funding assets of a participation out to the issuer. If the project releases its funds with milestones,
+	/// keep them in the project account instead, and remember who paid them for eventual refunds.
+	fn pay_out_funding_asset(
+		project_id: ProjectId,
+		project_metadata: &ProjectMetadataOf<T>,
+		participant: &AccountIdOf<T>,
+		amount: Balance,
+		asset: AcceptedFundingAsset,
+	) -> DispatchResult {
+		if project_metadata.funding_milestones.is_empty() {
+			return Self::release_funding_asset(
+				project_id,
+				&project_metadata.funding_destination_account,
+				amount,
+				asset,
+			);
+		}
+		if amount.is_zero() {
+			return Ok(());
+		}
+
+		MilestoneEscrowTotals::<T>::mutate(project_id, asset, |total| *total = total.saturating_add(amount));
+		MilestoneEscrowContributions::<T>::mutate((project_id, participant, asset), |escrowed| {
+			*escrowed = escrowed.saturating_add(amount)
+		});
+		Ok(())
+	}
+
+	/// The share of `escrowed_amount` released by a funding milestone. The final milestone releases whatever the previous
+	/// tranches left after rounding down, so no funds stay in escrow.
+	fn milestone_tranche_amount(
+		funding_milestones: &[Percent],
+		milestone_index: u32,
+		escrowed_amount: Balance,
+	) -> Balance {
+		let milestone_index = milestone_index as usize;
+		if milestone_index.saturating_add(1) < funding_milestones.len() {
+			return funding_milestones.get(milestone_index).map_or(0, |tranche| tranche.mul_floor(escrowed_amount));
+		}
+		let previous_tranches = funding_milestones
+			.iter()
+			.take(milestone_index)
+			.fold(Balance::zero(), |total, tranche| total.saturating_add(tranche.mul_floor(escrowed_amount)));
+		escrowed_amount.saturating_sub(previous_tranches)
+	}
+
+	/// Helper function to release the funding asset to the participant
+	pub(crate) fn release_funding_asset(
+		project_id: ProjectId,
+		participant: &AccountIdOf<T>,
+		amount: Balance,
+		asset: AcceptedFundingAsset,
+	) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(());
+		}
+		let project_pot = Self::fund_account_id(project_id);
+		let asset_location = Self::funding_asset_location(asset)?;
+		T::FundingCurrency::transfer(asset_location, &project_pot, participant, amount, Preservation::Expendable)?;
+		Ok(())
+	}
+
+	/// Helper function to release the PLMC bond to the participant
+	pub(crate) fn release_participation_bond_for(participant: &AccountIdOf<T>, amount: Balance) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(());
+		}
+		// Release the held PLMC bond
+		T::NativeCurrency::release(&HoldReason::Participation.into(), participant, amount, Precision::Exact)?;
+		Ok(())
+	}
+
+	/// Set the PLMC release schedule if mode was `Classic`. Return the schedule either way.
+	fn set_plmc_bond_release_with_mode(
+		participant: AccountIdOf<T>,
+		plmc_amount: Balance,
+		mode: ParticipationMode,
+		funding_end_block: BlockNumberFor<T>,
+	) -> Result<BlockNumberFor<T>, DispatchError> {
+		let multiplier: MultiplierOf<T> = mode.multiplier().try_into().map_err(|_| Error::<T>::ImpossibleState)?;
+		match mode {
+			ParticipationMode::OTM => Ok(multiplier.calculate_vesting_duration::<T>()),
+			ParticipationMode::Classic(_) =>
+				Self::set_release_schedule_for(&participant, plmc_amount, multiplier, funding_end_block),
+		}
+	}
+
+	/// Calculate the vesting info and add the PLMC release schedule to the user, or fully release the funds if possible.
+	fn set_release_schedule_for(
+		participant: &AccountIdOf<T>,
+		plmc_amount: Balance,
+		multiplier: MultiplierOf<T>,
+		funding_end_block: BlockNumberFor<T>,
+	) -> Result<BlockNumberFor<T>, DispatchError> {
+		// Calculate the vesting info and add the release schedule
+		let vesting_info = Self::calculate_vesting_info(participant, multiplier, plmc_amount)?;
+
+		if vesting_info.duration == 1u32.into() {
+			Self::release_participation_bond_for(participant, vesting_info.total_amount)?;
+		} else {
+			VestingOf::<T>::add_release_schedule(
+				participant,
+				vesting_info.total_amount,
+				vesting_info.amount_per_block,
+				funding_end_block,
+				HoldReason::Participation.into(),
+			)?;
+		}
+
+		Ok(vesting_info.duration)
+	}
+
+	/// Slash an evaluator and transfer funds to the treasury.
+	fn slash_evaluator(evaluation: &EvaluationInfoOf<T>) -> Result<Balance, DispatchError> {
+		let slash_percentage = T::EvaluatorSlash::get();
+		let treasury_account = T::BlockchainOperationTreasury::get();
+
+		// * Calculate variables *
+		// We need to make sure that the current PLMC bond is always >= than the slash amount.
+		let slashed_amount = slash_percentage * evaluation.original_plmc_bond;
+
+		T::NativeCurrency::transfer_on_hold(
+			&HoldReason::Evaluation.into(),
+			&evaluation.evaluator,
+			&treasury_account,
+			slashed_amount,
+			Precision::Exact,
+			Restriction::Free,
+			Fortitude::Force,
+		)?;
+
+		T::OnSlash::on_slash(&evaluation.evaluator, &slashed_amount);
+
+		Ok(evaluation.current_plmc_bond.saturating_sub(slashed_amount))
+	}
+
+	/// Reward an evaluator and mint CTs.
+	fn reward_evaluator(
+		project_id: ProjectId,
+		evaluation: &EvaluationInfoOf<T>,
+		info: &RewardInfo,
+	) -> Result<(Balance, Balance), DispatchError> {
+		let reward = Self::calculate_evaluator_reward(evaluation, info);
+		Self::mint_contribution_tokens(project_id, &evaluation.evaluator, reward)?;
+
+		Ok((evaluation.current_plmc_bond, reward))
+	}
+
+	pub fn calculate_evaluator_reward(evaluation: &EvaluationInfoOf<T>, info: &RewardInfo) -> Balance {
+		let early_reward_weight =
+			Perquintill::from_rational(evaluation.early_usd_amount, info.early_evaluator_total_bonded_usd);
+		let normal_reward_weight = Perquintill::from_rational(
+			evaluation.late_usd_amount.saturating_add(evaluation.early_usd_amount),
+			info.normal_evaluator_total_bonded_usd,
+		);
+		let early_evaluators_rewards = early_reward_weight * info.early_evaluator_reward_pot;
+		let normal_evaluators_rewards = normal_reward_weight * info.normal_evaluator_reward_pot;
+		early_evaluators_rewards.saturating_add(normal_evaluators_rewards)
+	}
+
+	pub fn create_migration(
+		project_id: ProjectId,
+		origin: &AccountIdOf<T>,
+		participation_type: ParticipationType,
+		ct_amount: Balance,
+		vesting_time: BlockNumberFor<T>,
+		receiving_account: Junction,
+	) -> DispatchResult {
+		let (status, user_migrations) = UserMigrations::<T>::get((project_id, origin))
+			.unwrap_or((MigrationStatus::NotStarted, WeakBoundedVec::<_, ConstU32<10_000>>::force_from(vec![], None)));
+
+		if user_migrations.is_empty() {
+			UnmigratedCounter::<T>::mutate(project_id, |counter| *counter = counter.saturating_add(1));
+		}
+
+		let mut user_migrations = user_migrations.to_vec();
+		let migration_origin = MigrationOrigin { user: receiving_account, participation_type };
+		let vesting_time: u64 = vesting_time.try_into().map_err(|_| Error::<T>::BadMath)?;
+		let migration_info: MigrationInfo = (ct_amount, vesting_time).into();
+		let migration = Migration::new(migration_origin, migration_info);
+		user_migrations.push(migration);
+
+		UserMigrations::<T>::insert(
+			(project_id, origin),
+			(status, WeakBoundedVec::<_, ConstU32<10_000>>::force_from(user_migrations, None)),
+		);
+
+		Ok(())
+	}
+}
@@ -0,0 +1,663 @@
+#[allow(clippy::wildcard_imports)]
+use super::*;
+use frame_support::traits::fungibles::Inspect;
+use polimec_common::migration_types::{MigrationInfo, Migrations};
+use sp_core::keccak_256;
+
+/// Index of the HRMP pallet in the Polkadot relay chain runtime.
+const RELAY_HRMP_PALLET_INDEX: u8 = 60;
+/// Index of `hrmp_init_open_channel` in the relay HRMP pallet.
+const HRMP_INIT_OPEN_CHANNEL_CALL_INDEX: u8 = 0;
+/// Index of `hrmp_accept_open_channel` in the relay HRMP pallet.
+const HRMP_ACCEPT_OPEN_CHANNEL_CALL_INDEX: u8 = 1;
+/// DOT withdrawn from Polimec's sovereign account on the relay to pay for the HRMP calls. The surplus is refunded.
+const RELAY_EXECUTION_FEE: u128 = 1_000_000_000;
+/// Max weight of each HRMP call sent to the relay.
+const RELAY_TRANSACT_MAX_WEIGHT: Weight = Weight::from_parts(20_000_000_000, 1_000_000);
+/// Index of `migrate_for_user` in the receiver pallet.
+const MIGRATE_FOR_USER_CALL_INDEX: u8 = 0;
+/// Max weight of the `migrate_for_user` call on the project's parachain.
+const MIGRATION_MAX_WEIGHT: Weight = Weight::from_parts(10_000_000_000, 1_000_000);
+/// Max weight of the query responses the project's parachain sends back.
+const MAX_RESPONSE_WEIGHT: Weight = Weight::from_parts(1_000_000_000, 200_000);
+/// Blocks after which a query to the project's parachain times out.
+const QUERY_RESPONSE_TIME_WINDOW_BLOCKS: u32 = 20;
+
+impl<T: Config> Pallet<T> {
+	/// Mark a project as ready for offchain migration confirmations.
+	#[transactional]
+	pub fn do_start_offchain_migration(project_id: ProjectId, caller: AccountIdOf<T>) -> DispatchResultWithPostInfo {
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+
+		ensure!(project_details.issuer_account == caller, Error::<T>::NotIssuer);
+
+		Self::transition_project(
+			project_id,
+			project_details,
+			ProjectStatus::SettlementFinished(FundingOutcome::Success),
+			ProjectStatus::CTMigrationStarted,
+			None,
+			false,
+		)?;
+
+		Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::No })
+	}
+
+	/// Confirm a user's migrations as completed
+	#[transactional]
+	pub fn do_confirm_offchain_migration(
+		project_id: ProjectId,
+		caller: AccountIdOf<T>,
+		participant: AccountIdOf<T>,
+	) -> DispatchResult {
+		// * Get variables *
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		// * Validity checks *
+		ensure!(project_details.status == ProjectStatus::CTMigrationStarted, Error::<T>::IncorrectRound);
+		ensure!(project_details.issuer_account == caller, Error::<T>::NotIssuer);
+		ensure!(!PalletMigrations::<T>::contains_key(project_id), Error::<T>::NotAllowed);
+
+		// * Update storage *
+		Self::change_migration_status(project_id, participant.clone(), MigrationStatus::Confirmed)?;
+
+		Ok(())
+	}
+
+	/// Submit the merkle root of a batch of participants whose mainnet transfers were completed offchain. Each
+	/// participant of the batch can then be confirmed by anyone with a merkle proof, instead of one issuer
+	/// transaction per participant.
+	#[transactional]
+	pub fn do_submit_migration_confirmation_root(
+		project_id: ProjectId,
+		caller: AccountIdOf<T>,
+		root: [u8; 32],
+		count: u32,
+	) -> DispatchResult {
+		// * Get variables *
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let unmigrated_participants = UnmigratedCounter::<T>::get(project_id);
+		let pending_confirmations = MigrationConfirmationRoots::<T>::iter_prefix_values(project_id).sum::<u32>();
+
+		// * Validity checks *
+		ensure!(project_details.status == ProjectStatus::CTMigrationStarted, Error::<T>::IncorrectRound);
+		ensure!(project_details.issuer_account == caller, Error::<T>::NotIssuer);
+		ensure!(!PalletMigrations::<T>::contains_key(project_id), Error::<T>::NotAllowed);
+		ensure!(!MigrationConfirmationRoots::<T>::contains_key(project_id, root), Error::<T>::NotAllowed);
+		ensure!(
+			count > 0 && pending_confirmations.saturating_add(count) <= unmigrated_participants,
+			Error::<T>::InvalidMigrationConfirmationCount
+		);
+
+		// * Update storage *
+		MigrationConfirmationRoots::<T>::insert(project_id, root, count);
+
+		// * Emit events *
+		Self::deposit_event(Event::MigrationConfirmationRootSubmitted { project_id, root, count });
+
+		Ok(())
+	}
+
+	/// Confirm the migrations of a participant by proving it is part of a batch submitted by the issuer.
+	#[transactional]
+	pub fn do_prove_offchain_migration(
+		project_id: ProjectId,
+		participant: AccountIdOf<T>,
+		proof: BoundedVec<[u8; 32], ConstU32<MAX_MIGRATION_PROOF_LENGTH>>,
+	) -> DispatchResult {
+		// * Get variables *
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let (_, migrations) =
+			UserMigrations::<T>::get((project_id, participant.clone())).ok_or(Error::<T>::NoMigrationsFound)?;
+		let migration_info = Self::migration_confirmation_info(&Migrations::from(migrations.into_inner()));
+		let leaf = Self::migration_confirmation_leaf(&participant, &migration_info);
+		let root = Self::merkle_root(leaf, &proof);
+		let remaining = MigrationConfirmationRoots::<T>::get(project_id, root)
+			.ok_or(Error::<T>::InvalidMigrationConfirmationProof)?;
+
+		// * Validity checks *
+		ensure!(project_details.status == ProjectStatus::CTMigrationStarted, Error::<T>::IncorrectRound);
+
+		// * Update storage *
+		Self::change_migration_status(project_id, participant.clone(), MigrationStatus::Confirmed)?;
+		if remaining > 1 {
+			MigrationConfirmationRoots::<T>::insert(project_id, root, remaining - 1);
+		} else {
+			MigrationConfirmationRoots::<T>::remove(project_id, root);
+		}
+
+		// * Emit events *
+		Self::deposit_event(Event::MigrationStatusUpdated {
+			project_id,
+			account: participant,
+			status: MigrationStatus::Confirmed,
+		});
+
+		Ok(())
+	}
+
+	/// Single `MigrationInfo` summarizing the migrations of a participant: the total CT amount, and the longest
+	/// vesting time.
+	pub fn migration_confirmation_info(migrations: &Migrations) -> MigrationInfo {
+		MigrationInfo {
+			contribution_token_amount: migrations.total_ct_amount(),
+			vesting_time: migrations.biggest_vesting_time(),
+		}
+	}
+
+	/// Leaf of a batch of migration confirmations for a participant.
+	pub fn migration_confirmation_leaf(participant: &AccountIdOf<T>, migration_info: &MigrationInfo) -> [u8; 32] {
+		keccak_256(&(participant, migration_info).encode())
+	}
+
+	/// Start the CT migration to the project's own parachain. The HRMP channels are opened when the parachain
+	/// requests a channel to Polimec.
+	#[transactional]
+	pub fn do_start_pallet_migration(
+		caller: &AccountIdOf<T>,
+		project_id: ProjectId,
+		para_id: ParaId,
+	) -> DispatchResultWithPostInfo {
+		// * Get variables *
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+
+		// * Validity checks *
+		ensure!(&project_details.issuer_account == caller, Error::<T>::NotIssuer);
+		ensure!(!PalletMigrationProjectsByParaId::<T>::contains_key(para_id), Error::<T>::WrongParaId);
+
+		// * Update storage *
+		Self::transition_project(
+			project_id,
+			project_details,
+			ProjectStatus::SettlementFinished(FundingOutcome::Success),
+			ProjectStatus::CTMigrationStarted,
+			None,
+			false,
+		)?;
+		let pallet_migration_info = PalletMigrationInfo {
+			parachain_id: para_id,
+			hrmp_channel_status: HRMPChannelStatus {
+				project_to_polimec: ChannelStatus::Closed,
+				polimec_to_project: ChannelStatus::Closed,
+			},
+			migration_readiness_check: None,
+		};
+		PalletMigrations::<T>::insert(project_id, pallet_migration_info);
+		PalletMigrationProjectsByParaId::<T>::insert(para_id, project_id);
+
+		// * Emit events *
+		Self::deposit_event(Event::PalletMigrationStarted { project_id, para_id });
+
+		Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::No })
+	}
+
+	/// Accept the HRMP channel requested by a project's parachain, and request the channel back to it.
+	pub fn do_handle_channel_open_request(sender: u32, max_message_size: u32, max_capacity: u32) -> xcm::v5::Result {
+		use xcm::v5::prelude::*;
+
+		ensure!(
+			T::MaxMessageSizeThresholds::get().contains(&max_message_size) &&
+				T::MaxCapacityThresholds::get().contains(&max_capacity),
+			XcmError::Unimplemented
+		);
+
+		let project_id = PalletMigrationProjectsByParaId::<T>::get(ParaId::from(sender)).ok_or(XcmError::BadOrigin)?;
+		let mut migration_info = PalletMigrations::<T>::get(project_id).ok_or(XcmError::BadOrigin)?;
+		ensure!(migration_info.hrmp_channel_status.project_to_polimec == ChannelStatus::Closed, XcmError::BadOrigin);
+
+		let accept_channel_relay_call =
+			(RELAY_HRMP_PALLET_INDEX, HRMP_ACCEPT_OPEN_CHANNEL_CALL_INDEX, ParaId::from(sender)).encode();
+		let request_channel_relay_call = (
+			RELAY_HRMP_PALLET_INDEX,
+			HRMP_INIT_OPEN_CHANNEL_CALL_INDEX,
+			ParaId::from(sender),
+			T::RequiredMaxCapacity::get(),
+			T::RequiredMaxMessageSize::get(),
+		)
+			.encode();
+
+		let execution_dot: Asset = (Here, RELAY_EXECUTION_FEE).into();
+		let xcm: Xcm<()> = Xcm(vec![
+			WithdrawAsset(execution_dot.clone().into()),
+			BuyExecution { fees: execution_dot, weight_limit: Unlimited },
+			Transact {
+				origin_kind: OriginKind::Native,
+				fallback_max_weight: Some(RELAY_TRANSACT_MAX_WEIGHT),
+				call: accept_channel_relay_call.into(),
+			},
+			Transact {
+				origin_kind: OriginKind::Native,
+				fallback_max_weight: Some(RELAY_TRANSACT_MAX_WEIGHT),
+				call: request_channel_relay_call.into(),
+			},
+			RefundSurplus,
+			DepositAsset { assets: Wild(All), beneficiary: Location::new(0, [Parachain(Self::polimec_para_id())]) },
+		]);
+		pallet_xcm::Pallet::<T>::send_xcm(Here, Parent, xcm).map_err(|_| XcmError::Unroutable)?;
+
+		migration_info.hrmp_channel_status = HRMPChannelStatus {
+			project_to_polimec: ChannelStatus::Open,
+			polimec_to_project: ChannelStatus::AwaitingAcceptance,
+		};
+		let para_id = migration_info.parachain_id;
+		PalletMigrations::<T>::insert(project_id, migration_info);
+
+		Self::deposit_event(Event::HrmpChannelAccepted { project_id, para_id });
+
+		Ok(())
+	}
+
+	/// Mark the channel from Polimec to the project's parachain as open, once the relay notifies us of its acceptance.
+	pub fn do_handle_channel_accepted(recipient: u32) -> xcm::v5::Result {
+		use xcm::v5::prelude::*;
+
+		let project_id =
+			PalletMigrationProjectsByParaId::<T>::get(ParaId::from(recipient)).ok_or(XcmError::BadOrigin)?;
+		let mut migration_info = PalletMigrations::<T>::get(project_id).ok_or(XcmError::BadOrigin)?;
+		ensure!(
+			migration_info.hrmp_channel_status.polimec_to_project == ChannelStatus::AwaitingAcceptance,
+			XcmError::BadOrigin
+		);
+
+		migration_info.hrmp_channel_status.polimec_to_project = ChannelStatus::Open;
+		let para_id = migration_info.parachain_id;
+		PalletMigrations::<T>::insert(project_id, migration_info);
+
+		Self::deposit_event(Event::HrmpChannelEstablished { project_id, para_id });
+
+		Ok(())
+	}
+
+	/// Query the project's parachain for the mainnet tokens held by Polimec's sovereign account, and for the
+	/// receiver pallet. Can be called again if any of the checks failed.
+	#[transactional]
+	pub fn do_start_pallet_migration_readiness_check(caller: &AccountIdOf<T>, project_id: ProjectId) -> DispatchResult {
+		use xcm::v5::prelude::*;
+
+		// * Get variables *
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let mut migration_info = PalletMigrations::<T>::get(project_id).ok_or(Error::<T>::NotAllowed)?;
+		let project_location = Location::new(1, [Parachain(migration_info.parachain_id.into())]);
+		let polimec_location = Location::new(1, [Parachain(Self::polimec_para_id())]);
+		let now = frame_system::Pallet::<T>::block_number();
+		let timeout = now.saturating_add(QUERY_RESPONSE_TIME_WINDOW_BLOCKS.into());
+
+		// * Validity checks *
+		ensure!(project_details.status == ProjectStatus::CTMigrationStarted, Error::<T>::IncorrectRound);
+		ensure!(
+			migration_info.hrmp_channel_status ==
+				HRMPChannelStatus {
+					project_to_polimec: ChannelStatus::Open,
+					polimec_to_project: ChannelStatus::Open
+				},
+			Error::<T>::ChannelNotOpen
+		);
+		ensure!(
+			!migration_info.migration_readiness_check.is_some_and(|check| check.is_ready()),
+			Error::<T>::NotAllowed
+		);
+
+		// * Update storage *
+		let call: <T as Config>::RuntimeCall = Call::<T>::pallet_migration_readiness_response {
+			query_id: Default::default(),
+			response: Default::default(),
+		}
+		.into();
+		let query_id_holdings =
+			pallet_xcm::Pallet::<T>::new_notify_query(project_location.clone(), call.clone(), timeout, Here);
+		let query_id_pallet = pallet_xcm::Pallet::<T>::new_notify_query(project_location.clone(), call, timeout, Here);
+
+		if let Some(previous_check) = migration_info.migration_readiness_check {
+			PalletMigrationProjectsByQueryId::<T>::remove(previous_check.holding_check.0);
+			PalletMigrationProjectsByQueryId::<T>::remove(previous_check.pallet_check.0);
+		}
+		PalletMigrationProjectsByQueryId::<T>::insert(query_id_holdings, project_id);
+		PalletMigrationProjectsByQueryId::<T>::insert(query_id_pallet, project_id);
+		migration_info.migration_readiness_check = Some(PalletMigrationReadinessCheck {
+			holding_check: (query_id_holdings, CheckOutcome::AwaitingResponse),
+			pallet_check: (query_id_pallet, CheckOutcome::AwaitingResponse),
+		});
+		PalletMigrations::<T>::insert(project_id, migration_info);
+
+		// * Send the readiness queries *
+		let total_cts_minted = <T as Config>::ContributionTokenCurrency::total_issuance(project_id);
+		let expected_tokens: Asset = (Here, total_cts_minted).into();
+		let xcm = Xcm(vec![
+			UnpaidExecution { weight_limit: Unlimited, check_origin: None },
+			WithdrawAsset(expected_tokens.into()),
+			ReportHolding {
+				response_info: QueryResponseInfo {
+					destination: polimec_location.clone(),
+					query_id: query_id_holdings,
+					max_weight: MAX_RESPONSE_WEIGHT,
+				},
+				assets: Wild(All),
+			},
+			QueryPallet {
+				module_name: T::PolimecReceiverInfo::get().module_name.into_inner(),
+				response_info: QueryResponseInfo {
+					destination: polimec_location.clone(),
+					query_id: query_id_pallet,
+					max_weight: MAX_RESPONSE_WEIGHT,
+				},
+			},
+			DepositAsset { assets: Wild(All), beneficiary: polimec_location },
+		]);
+		pallet_xcm::Pallet::<T>::send_xcm(Here, project_location, xcm).map_err(|_| Error::<T>::XcmFailed)?;
+
+		// * Emit events *
+		Self::deposit_event(Event::MigrationReadinessCheckStarted { project_id, caller: caller.clone() });
+
+		Ok(())
+	}
+
+	/// Process the response of the project's parachain to one of the readiness queries.
+	#[transactional]
+	pub fn do_pallet_migration_readiness_response(
+		location: xcm::v5::Location,
+		query_id: QueryId,
+		response: xcm::v5::Response,
+	) -> DispatchResult {
+		use xcm::v5::prelude::*;
+
+		// * Get variables *
+		let project_id = PalletMigrationProjectsByQueryId::<T>::get(query_id).ok_or(Error::<T>::NotAllowed)?;
+		let mut migration_info = PalletMigrations::<T>::get(project_id).ok_or(Error::<T>::NotAllowed)?;
+		let para_id = match location.unpack() {
+			(1, [Parachain(para_id)]) => ParaId::from(*para_id),
+			_ => return Err(Error::<T>::WrongParaId.into()),
+		};
+		let project_location = Location::new(1, [Parachain(para_id.into())]);
+		let total_cts_minted = <T as Config>::ContributionTokenCurrency::total_issuance(project_id);
+		let mut check = migration_info.migration_readiness_check.ok_or(Error::<T>::ImpossibleState)?;
+
+		// * Validity checks *
+		ensure!(migration_info.parachain_id == para_id, Error::<T>::WrongParaId);
+
+		// * Process data *
+		let outcome = match (&response, query_id) {
+			(Response::Assets(assets), id) if id == check.holding_check.0 => {
+				let holds_enough_tokens = assets.inner().iter().any(|asset| match asset {
+					Asset { id: AssetId(asset_location), fun: Fungible(amount) } =>
+						asset_location == &project_location && *amount >= total_cts_minted,
+					_ => false,
+				});
+				check.holding_check.1 =
+					if holds_enough_tokens { CheckOutcome::Passed(None) } else { CheckOutcome::Failed };
+				check.holding_check.1
+			},
+			(Response::PalletsInfo(pallets_info), id) if id == check.pallet_check.0 => {
+				let expected_info = T::PolimecReceiverInfo::get();
+				check.pallet_check.1 = match pallets_info.first() {
+					Some(info)
+						if pallets_info.len() == 1 &&
+							info.module_name == expected_info.module_name &&
+							info.major == expected_info.major =>
+						info.index.try_into().map_or(CheckOutcome::Failed, |index| CheckOutcome::Passed(Some(index))),
+					_ => CheckOutcome::Failed,
+				};
+				check.pallet_check.1
+			},
+			_ => return Err(Error::<T>::NotAllowed.into()),
+		};
+
+		// * Update storage *
+		migration_info.migration_readiness_check = Some(check);
+		PalletMigrations::<T>::insert(project_id, migration_info);
+
+		// * Emit events *
+		if outcome == CheckOutcome::Failed {
+			Self::deposit_event(Event::MigrationCheckResponseRejected { project_id, query_id, response });
+		} else {
+			Self::deposit_event(Event::MigrationCheckResponseAccepted { project_id, query_id, response });
+		}
+
+		Ok(())
+	}
+
+	/// Send the CT migrations of a participant to the receiver pallet of the project's parachain.
+	#[transactional]
+	pub fn do_send_pallet_migration_for(project_id: ProjectId, participant: AccountIdOf<T>) -> DispatchResult {
+		use xcm::v5::prelude::*;
+
+		// * Get variables *
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let migration_info = PalletMigrations::<T>::get(project_id).ok_or(Error::<T>::NotAllowed)?;
+		let readiness_check = migration_info.migration_readiness_check.ok_or(Error::<T>::ChannelNotReady)?;
+		let (status, migrations) =
+			UserMigrations::<T>::get((project_id, participant.clone())).ok_or(Error::<T>::NoMigrationsFound)?;
+		let project_location = Location::new(1, [Parachain(migration_info.parachain_id.into())]);
+		let resend_nonce = MigrationResendNonces::<T>::get(project_id, participant.clone());
+		let now = frame_system::Pallet::<T>::block_number();
+
+		// * Validity checks *
+		ensure!(project_details.status == ProjectStatus::CTMigrationStarted, Error::<T>::IncorrectRound);
+		ensure!(readiness_check.is_ready(), Error::<T>::ChannelNotReady);
+		ensure!(status != MigrationStatus::Sent, Error::<T>::MigrationAlreadySent);
+		ensure!(
+			matches!(status, MigrationStatus::NotStarted | MigrationStatus::Failed),
+			Error::<T>::MigrationAlreadyConfirmed
+		);
+		let pallet_index = readiness_check.receiver_pallet_index().ok_or(Error::<T>::ChannelNotReady)?;
+
+		// * Update storage *
+		let call: <T as Config>::RuntimeCall =
+			Call::<T>::confirm_pallet_migrations { query_id: Default::default(), response: Default::default() }.into();
+		let timeout = now.saturating_add(QUERY_RESPONSE_TIME_WINDOW_BLOCKS.into());
+		let query_id = pallet_xcm::Pallet::<T>::new_notify_query(project_location.clone(), call, timeout, Here);
+		ActiveMigrationQueue::<T>::insert(query_id, (project_id, participant.clone(), timeout));
+		Self::change_migration_status(project_id, participant.clone(), MigrationStatus::Sent)?;
+
+		// * Send the migrations *
+		let xcm = Self::construct_migration_xcm_message(
+			Migrations::from(migrations.into_inner()),
+			resend_nonce,
+			query_id,
+			pallet_index,
+		);
+		pallet_xcm::Pallet::<T>::send_xcm(Here, project_location, xcm).map_err(|_| Error::<T>::XcmFailed)?;
+
+		// * Emit events *
+		Self::deposit_event(Event::PalletMigrationSent { project_id, account: participant, query_id });
+
+		Ok(())
+	}
+
+	/// Process the response of the receiver pallet to the migrations of a participant. Failed migrations can be sent
+	/// again.
+	#[transactional]
+	pub fn do_confirm_pallet_migrations(
+		location: xcm::v5::Location,
+		query_id: QueryId,
+		response: xcm::v5::Response,
+	) -> DispatchResult {
+		use xcm::v5::prelude::*;
+
+		// * Get variables *
+		let (project_id, participant, _) =
+			ActiveMigrationQueue::<T>::take(query_id).ok_or(Error::<T>::NoActiveMigrationsFound)?;
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let migration_info = PalletMigrations::<T>::get(project_id).ok_or(Error::<T>::NotAllowed)?;
+		let para_id = match location.unpack() {
+			(1, [Parachain(para_id)]) => ParaId::from(*para_id),
+			_ => return Err(Error::<T>::WrongParaId.into()),
+		};
+
+		// * Validity checks *
+		ensure!(project_details.status == ProjectStatus::CTMigrationStarted, Error::<T>::IncorrectRound);
+		ensure!(migration_info.parachain_id == para_id, Error::<T>::WrongParaId);
+
+		// * Update storage *
+		let status = match response {
+			Response::DispatchResult(MaybeErrorCode::Success) => MigrationStatus::Confirmed,
+			Response::DispatchResult(_) => MigrationStatus::Failed,
+			_ => return Err(Error::<T>::NotAllowed.into()),
+		};
+		Self::change_migration_status(project_id, participant.clone(), status.clone())?;
+
+		// * Emit events *
+		Self::deposit_event(Event::MigrationStatusUpdated { project_id, account: participant, status });
+
+		Ok(())
+	}
+
+	/// Mark the migrations of a participant as failed when the receiver pallet did not respond before the query
+	/// timed out. The receiver pallet skips them if they were executed and only the response was lost.
+	#[transactional]
+	pub fn do_fail_timed_out_pallet_migration(query_id: QueryId) -> DispatchResult {
+		// * Get variables *
+		let (project_id, participant, timeout) =
+			ActiveMigrationQueue::<T>::get(query_id).ok_or(Error::<T>::NoActiveMigrationsFound)?;
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let now = frame_system::Pallet::<T>::block_number();
+
+		// * Validity checks *
+		ensure!(project_details.status == ProjectStatus::CTMigrationStarted, Error::<T>::IncorrectRound);
+		ensure!(now > timeout, Error::<T>::MigrationQueryNotTimedOut);
+
+		// * Update storage *
+		ActiveMigrationQueue::<T>::remove(query_id);
+		Self::change_migration_status(project_id, participant.clone(), MigrationStatus::Failed)?;
+
+		// * Emit events *
+		Self::deposit_event(Event::MigrationStatusUpdated {
+			project_id,
+			account: participant,
+			status: MigrationStatus::Failed,
+		});
+
+		Ok(())
+	}
+
+	/// XCM sending the migrations of a participant to the receiver pallet, and reporting back the dispatch result.
+	pub fn construct_migration_xcm_message(
+		migrations: Migrations,
+		resend_nonce: u32,
+		query_id: QueryId,
+		pallet_index: PalletIndex,
+	) -> xcm::v5::Xcm<()> {
+		use xcm::v5::prelude::*;
+
+		let encoded_call = (pallet_index, MIGRATE_FOR_USER_CALL_INDEX, migrations, resend_nonce).encode();
+		Xcm(vec![
+			UnpaidExecution { weight_limit: Unlimited, check_origin: None },
+			Transact {
+				origin_kind: OriginKind::Xcm,
+				fallback_max_weight: Some(MIGRATION_MAX_WEIGHT),
+				call: encoded_call.into(),
+			},
+			ReportTransactStatus(QueryResponseInfo {
+				destination: Location::new(1, [Parachain(Self::polimec_para_id())]),
+				query_id,
+				max_weight: MAX_RESPONSE_WEIGHT,
+			}),
+		])
+	}
+
+	/// Parachain id of Polimec, taken from its universal location.
+	pub fn polimec_para_id() -> u32 {
+		use xcm::v5::prelude::*;
+
+		<T as pallet_xcm::Config>::UniversalLocation::get()
+			.iter()
+			.find_map(|junction| match junction {
+				Parachain(para_id) => Some(*para_id),
+				_ => None,
+			})
+			.unwrap_or_default()
+	}
+
+	/// Dispute the confirmation of a participant's CT migration, within the dispute window.
+	#[transactional]
+	pub fn do_dispute_migration(project_id: ProjectId, participant: AccountIdOf<T>) -> DispatchResult {
+		// * Get variables *
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let (status, migrations) =
+			UserMigrations::<T>::get((project_id, participant.clone())).ok_or(Error::<T>::NoMigrationsFound)?;
+		let now = <T as Config>::BlockNumberProvider::current_block_number();
+
+		// * Validity checks *
+		ensure!(project_details.status == ProjectStatus::CTMigrationStarted, Error::<T>::IncorrectRound);
+		ensure!(status == MigrationStatus::Confirmed, Error::<T>::MigrationNotConfirmed);
+		let confirmed_at = MigrationConfirmedAt::<T>::get(project_id, participant.clone())
+			.ok_or(Error::<T>::MigrationDisputeWindowClosed)?;
+		ensure!(
+			now <= confirmed_at.saturating_add(T::MigrationDisputeWindow::get()),
+			Error::<T>::MigrationDisputeWindowClosed
+		);
+
+		// * Update storage *
+		MigrationConfirmedAt::<T>::remove(project_id, participant.clone());
+		OpenMigrationDisputes::<T>::mutate(project_id, |disputes| *disputes = disputes.saturating_add(1));
+		UserMigrations::<T>::insert((project_id, participant.clone()), (MigrationStatus::Disputed, migrations));
+
+		// * Emit events *
+		Self::deposit_event(Event::MigrationStatusUpdated {
+			project_id,
+			account: participant,
+			status: MigrationStatus::Disputed,
+		});
+
+		Ok(())
+	}
+
+	/// Resolve a dispute over a CT migration. An upheld dispute means the tokens were not delivered, and the
+	/// migration has to be done again.
+	#[transactional]
+	pub fn do_resolve_migration_dispute(
+		project_id: ProjectId,
+		participant: AccountIdOf<T>,
+		dispute_upheld: bool,
+	) -> DispatchResult {
+		// * Get variables *
+		let (status, migrations) =
+			UserMigrations::<T>::get((project_id, participant.clone())).ok_or(Error::<T>::NoMigrationsFound)?;
+
+		// * Validity checks *
+		ensure!(status == MigrationStatus::Disputed, Error::<T>::MigrationNotDisputed);
+
+		// * Update storage *
+		let new_status = if dispute_upheld {
+			UnmigratedCounter::<T>::mutate(project_id, |counter| *counter = counter.saturating_add(1));
+			MigrationResendNonces::<T>::mutate(project_id, participant.clone(), |nonce| {
+				*nonce = nonce.saturating_add(1)
+			});
+			MigrationStatus::NotStarted
+		} else {
+			MigrationStatus::Confirmed
+		};
+		OpenMigrationDisputes::<T>::mutate(project_id, |disputes| *disputes = disputes.saturating_sub(1));
+		UserMigrations::<T>::insert((project_id, participant.clone()), (new_status.clone(), migrations));
+
+		// * Emit events *
+		Self::deposit_event(Event::MigrationStatusUpdated { project_id, account: participant, status: new_status });
+
+		Ok(())
+	}
+
+	#[transactional]
+	pub fn do_mark_project_ct_migration_as_finished(project_id: ProjectId) -> DispatchResult {
+		// * Get variables *
+		let mut project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let now = <T as Config>::BlockNumberProvider::current_block_number();
+
+		// * Validity checks *
+		ensure!(project_details.status == ProjectStatus::CTMigrationStarted, Error::<T>::IncorrectRound);
+
+		let unmigrated_participants = UnmigratedCounter::<T>::get(project_id);
+		ensure!(unmigrated_participants == 0, Error::<T>::MigrationsStillPending);
+		ensure!(OpenMigrationDisputes::<T>::get(project_id) == 0, Error::<T>::MigrationDisputesOpen);
+		ensure!(
+			!MigrationDisputeDeadlines::<T>::get(project_id).is_some_and(|deadline| now <= deadline),
+			Error::<T>::MigrationDisputeWindowOpen
+		);
+
+		// * Update storage *
+		project_details.status = ProjectStatus::CTMigrationFinished;
+		ProjectsDetails::<T>::insert(project_id, project_details);
+
+		// * Emit events *
+		Self::deposit_event(Event::CTMigrationFinished { project_id });
+
+		Ok(())
+	}
+}
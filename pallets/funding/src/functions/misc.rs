@@ -1,14 +1,18 @@
 #[allow(clippy::wildcard_imports)]
 use super::*;
 use alloc::string::{String, ToString};
-use polimec_common::{assets::AcceptedFundingAsset, ProvideAssetPrice};
+use polimec_common::{
+	assets::{AcceptedFundingAsset, FundingAssetInfo, ProvideFundingAssets, MAX_FUNDING_ASSETS},
+	ProvideAssetPrice,
+};
 use sp_core::{
 	ecdsa::{Public as EcdsaPublic, Signature as EcdsaSignature},
+	ed25519::{Public as EdPublic, Signature as EdSignature},
 	keccak_256,
 	sr25519::{Public as SrPublic, Signature as SrSignature},
 	ByteArray,
 };
-use sp_runtime::traits::Verify;
+use sp_runtime::{traits::Verify, SaturatedConversion};
 
 // Helper functions
 // ATTENTION: if this is called directly, it will not be transactional
@@ -30,12 +34,32 @@ impl<T: Config> Pallet<T> {
 			PriceOf::<T>::checked_from_rational(1, 10).ok_or(Error::<T>::BadMath)?;
 		let bucket_delta_price: <T as Config>::Price = metadata.minimum_price.saturating_mul(ten_percent_in_price);
 
-		let bucket: BucketOf<T> =
+		let mut bucket: BucketOf<T> =
 			Bucket::new(auction_allocation_size, metadata.minimum_price, bucket_delta_price, bucket_delta_amount);
 
+		// Descending auctions start at the ceiling, and use the minimum price as the floor.
+		if let SaleMode::DutchAuction { ceiling_price, price_step, .. } = metadata.sale_mode {
+			bucket.current_price = ceiling_price;
+			bucket.delta_price = price_step;
+		}
+
 		Ok(bucket)
 	}
 
+	/// Bring the price of the bucket up to date at block `at` of the auction round. Descending auctions move the price
+	/// with time instead of with the tokens sold.
+	pub fn refresh_bucket_price(
+		project_metadata: &ProjectMetadataOf<T>,
+		project_details: &ProjectDetailsOf<T>,
+		bucket: &mut BucketOf<T>,
+		at: BlockNumberFor<T>,
+	) {
+		if let Some(auction_start) = project_details.round_duration.start() {
+			let blocks_elapsed: u32 = at.saturating_sub(auction_start).saturated_into();
+			project_metadata.sale_mode.price_strategy().refresh(bucket, blocks_elapsed);
+		}
+	}
+
 	pub fn calculate_plmc_bond(ticket_size: Balance, multiplier: MultiplierOf<T>) -> Result<Balance, DispatchError> {
 		let plmc_usd_price = <PriceProviderOf<T>>::get_decimals_aware_price(&Location::here(), PLMC_DECIMALS)
 			.ok_or(Error::<T>::PriceNotFound)?;
@@ -51,9 +75,7 @@ impl<T: Config> Pallet<T> {
 		ticket_size: Balance,
 		asset: AcceptedFundingAsset,
 	) -> Result<Balance, DispatchError> {
-		let asset_id = asset.id();
-		let asset_usd_price = <PriceProviderOf<T>>::get_decimals_aware_price(&asset_id, asset.decimals())
-			.ok_or(Error::<T>::PriceNotFound)?;
+		let asset_usd_price = Self::get_decimals_aware_funding_asset_price(&asset).ok_or(Error::<T>::PriceNotFound)?;
 		asset_usd_price
 			.reciprocal()
 			.and_then(|recip| recip.checked_mul_int(ticket_size))
@@ -91,7 +113,7 @@ impl<T: Config> Pallet<T> {
 				project_id,
 				who.clone(),
 				amount,
-				asset.id(),
+				Self::funding_asset_location(asset)?,
 				HoldReason::Participation.into(),
 			),
 		}
@@ -140,34 +162,31 @@ impl<T: Config> Pallet<T> {
 	}
 
 	// Calculate the total fee allocation for a project, based on the funding reached.
-	fn calculate_fee_allocation(project_id: ProjectId) -> Result<Balance, DispatchError> {
+	fn calculate_fee_allocation(project_id: ProjectId, fee_config: &FeeConfig) -> Result<Balance, DispatchError> {
 		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
-		let bucket = Buckets::<T>::get(project_id).ok_or(Error::<T>::BucketNotFound)?;
 		// Fetching the necessary data for a specific project.
 		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
 
 		// Determine how much funding has been achieved.
 		let funding_amount_reached = project_details.funding_amount_reached_usd;
-		let fee_usd = Self::compute_total_fee_from_brackets(funding_amount_reached);
+		let fee_usd = Self::compute_total_fee_from_brackets(funding_amount_reached, &fee_config.fee_brackets);
 		let fee_percentage = Perquintill::from_rational(fee_usd, funding_amount_reached);
 
-		let token_sold = if bucket.current_price == bucket.initial_price {
-			project_metadata.total_allocation_size.saturating_sub(bucket.amount_left)
-		} else {
-			project_metadata.total_allocation_size
-		};
+		// Tokens sold in both the auction and the community round
+		let token_sold =
+			project_metadata.total_allocation_size.saturating_sub(project_details.remaining_contribution_tokens);
 		let total_fee_allocation = fee_percentage * token_sold;
 
 		Ok(total_fee_allocation)
 	}
 
 	/// Computes the total fee from all defined fee brackets.
-	fn compute_total_fee_from_brackets(funding_reached: Balance) -> Balance {
+	fn compute_total_fee_from_brackets(funding_reached: Balance, fee_brackets: &[(Percent, Balance)]) -> Balance {
 		let mut remaining_for_fee = funding_reached;
 
-		T::FeeBrackets::get()
-			.into_iter()
-			.map(|(fee, limit)| Self::compute_fee_for_bracket(&mut remaining_for_fee, fee, limit))
+		fee_brackets
+			.iter()
+			.map(|&(fee, limit)| Self::compute_fee_for_bracket(&mut remaining_for_fee, fee, limit))
 			.fold(Balance::zero(), |acc, fee| acc.saturating_add(fee))
 	}
 
@@ -183,6 +202,24 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Fee configuration of a project. Projects that started their evaluation before the fee configuration was
+	/// stored on-chain use the default one, which has the fees they were created with.
+	pub fn project_fee_config(project_id: ProjectId) -> FeeConfig {
+		ProjectFeeConfigs::<T>::get(project_id).unwrap_or_default()
+	}
+
+	/// Share of the funding target the evaluators of a project have to bond. Falls back to the
+	/// `EvaluationSuccessThreshold` of the pallet if the issuer did not declare one.
+	pub fn evaluation_success_threshold(project_metadata: &ProjectMetadataOf<T>) -> Percent {
+		project_metadata.evaluation_success_threshold.unwrap_or_else(T::EvaluationSuccessThreshold::get)
+	}
+
+	/// Share of the allocation a project has to sell for its funding to succeed. Falls back to the
+	/// `FundingSuccessThreshold` of the pallet if the issuer did not declare one.
+	pub fn funding_success_threshold(project_metadata: &ProjectMetadataOf<T>) -> Perquintill {
+		project_metadata.funding_success_threshold.unwrap_or_else(T::FundingSuccessThreshold::get)
+	}
+
 	/// Generate and return evaluator rewards based on a project's funding status.
 	///
 	/// The function calculates rewards based on several metrics: funding achieved,
@@ -193,19 +230,21 @@ impl<T: Config> Pallet<T> {
 	/// reusable, not just for evaluator rewards.
 	pub fn generate_evaluator_rewards_info(project_id: ProjectId) -> Result<RewardInfo, DispatchError> {
 		// Fetching the necessary data for a specific project.
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
 		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
-		let total_fee_allocation = Self::calculate_fee_allocation(project_id)?;
+		let fee_config = Self::project_fee_config(project_id);
+		let total_fee_allocation = Self::calculate_fee_allocation(project_id, &fee_config)?;
 
 		// Calculate rewards.
-		let evaluator_rewards = Perquintill::from_percent(30) * total_fee_allocation;
+		let evaluator_rewards = fee_config.evaluator_rewards * total_fee_allocation;
 
 		// Distribute rewards between early and normal evaluators.
-		let early_evaluator_reward_pot = Perquintill::from_percent(20) * evaluator_rewards;
-		let normal_evaluator_reward_pot = Perquintill::from_percent(80) * evaluator_rewards;
+		let early_evaluator_reward_pot = fee_config.early_evaluator_rewards * evaluator_rewards;
+		let normal_evaluator_reward_pot = fee_config.normal_evaluator_rewards * evaluator_rewards;
 
 		let normal_evaluator_total_bonded_usd = project_details.evaluation_round_info.total_bonded_usd;
 		let early_evaluation_reward_threshold_usd =
-			T::EvaluationSuccessThreshold::get() * project_details.fundraising_target_usd;
+			Self::evaluation_success_threshold(&project_metadata) * project_details.fundraising_target_usd;
 		let early_evaluator_total_bonded_usd =
 			normal_evaluator_total_bonded_usd.min(early_evaluation_reward_threshold_usd);
 
@@ -223,17 +262,17 @@ impl<T: Config> Pallet<T> {
 	pub fn generate_liquidity_pools_and_long_term_holder_rewards(
 		project_id: ProjectId,
 	) -> Result<(Balance, Balance), DispatchError> {
-		let total_fee_allocation = Self::calculate_fee_allocation(project_id)?;
+		let fee_config = Self::project_fee_config(project_id);
+		let total_fee_allocation = Self::calculate_fee_allocation(project_id, &fee_config)?;
 
-		let liquidity_pools_percentage = Perquintill::from_percent(50);
-		let liquidity_pools_reward_pot = liquidity_pools_percentage * total_fee_allocation;
-
-		let long_term_holder_percentage = Perquintill::from_percent(20);
-		let long_term_holder_reward_pot = long_term_holder_percentage * total_fee_allocation;
+		let liquidity_pools_reward_pot = fee_config.liquidity_pools * total_fee_allocation;
+		let long_term_holder_reward_pot = fee_config.long_term_holders * total_fee_allocation;
 
 		Ok((liquidity_pools_reward_pot, long_term_holder_reward_pot))
 	}
 
+	/// Move the CT migration of a user forward. Confirmed and disputed migrations can only change through the
+	/// resolution of a dispute. Confirming a migration opens its dispute window.
 	pub fn change_migration_status(
 		project_id: ProjectId,
 		user: T::AccountId,
@@ -243,15 +282,211 @@ impl<T: Config> Pallet<T> {
 		let (current_status, migrations) =
 			UserMigrations::<T>::get((project_id, user.clone())).ok_or(Error::<T>::NoMigrationsFound)?;
 
-		ensure!(current_status == MigrationStatus::NotStarted, Error::<T>::MigrationAlreadyConfirmed);
+		ensure!(
+			!matches!(current_status, MigrationStatus::Confirmed | MigrationStatus::Disputed),
+			Error::<T>::MigrationAlreadyConfirmed
+		);
 
-		UnmigratedCounter::<T>::mutate(project_id, |counter| *counter = counter.saturating_sub(1));
+		if status == MigrationStatus::Confirmed {
+			let now = <T as Config>::BlockNumberProvider::current_block_number();
+			UnmigratedCounter::<T>::mutate(project_id, |counter| *counter = counter.saturating_sub(1));
+			MigrationConfirmedAt::<T>::insert(project_id, user.clone(), now);
+			MigrationDisputeDeadlines::<T>::insert(project_id, now.saturating_add(T::MigrationDisputeWindow::get()));
+		}
 		UserMigrations::<T>::insert((project_id, user), (status, migrations));
 		ProjectsDetails::<T>::insert(project_id, project_details);
 
 		Ok(())
 	}
 
+	/// Frees the slot of a project in the active projects of its issuer's did.
+	pub fn remove_active_project(did: Did, project_id: ProjectId) {
+		DidWithActiveProjects::<T>::mutate_exists(did, |maybe_projects| {
+			if let Some(projects) = maybe_projects {
+				projects.retain(|id| *id != project_id);
+				if projects.is_empty() {
+					*maybe_projects = None;
+				}
+			}
+		});
+	}
+
+	/// Whether the ownership of a project still matters. That is until the project failed, or until its CT migration
+	/// finished and every funding milestone was decided.
+	fn is_project_transferable(project_id: ProjectId, project_details: &ProjectDetailsOf<T>) -> bool {
+		match project_details.status {
+			ProjectStatus::FundingFailed |
+			ProjectStatus::SettlementStarted(FundingOutcome::Failure) |
+			ProjectStatus::SettlementFinished(FundingOutcome::Failure) => false,
+			ProjectStatus::CTMigrationFinished => ProjectsMetadata::<T>::get(project_id).is_some_and(|metadata| {
+				let milestones = metadata.funding_milestones.len() as u32;
+				milestones > 0 && !MilestoneOutcomes::<T>::contains_key(project_id, milestones - 1)
+			}),
+			_ => true,
+		}
+	}
+
+	/// Whether a DID evaluated, bid or contributed on a project. Community round contributions are accounted in
+	/// `AuctionBoughtUSD` together with the bids.
+	fn did_participated_in_project(project_id: ProjectId, did: &Did) -> bool {
+		!EvaluationBondedUSD::<T>::get((project_id, did.clone())).is_zero() ||
+			!AuctionBoughtUSD::<T>::get((project_id, did.clone())).is_zero()
+	}
+
+	/// Propose a new owner for a project. Ownership only changes once the new owner accepts it.
+	#[transactional]
+	pub fn do_propose_project_transfer(
+		issuer: AccountIdOf<T>,
+		project_id: ProjectId,
+		new_issuer: AccountIdOf<T>,
+	) -> DispatchResult {
+		// * Get variables *
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+
+		// * Validity checks *
+		ensure!(project_details.issuer_account == issuer, Error::<T>::NotIssuer);
+		ensure!(Self::is_project_transferable(project_id, &project_details), Error::<T>::IncorrectRound);
+
+		// * Update storage *
+		PendingProjectTransfers::<T>::insert(project_id, new_issuer.clone());
+
+		// * Emit events *
+		Self::deposit_event(Event::ProjectTransferProposed { project_id, issuer, new_issuer });
+
+		Ok(())
+	}
+
+	/// Hand the ownership of a project over to the proposed account, and the DID it used to accept it. The funds raised
+	/// and released by the milestones go to the new funding destination account from then on. If the project is still
+	/// active, it counts towards the active projects of the new DID.
+	#[transactional]
+	pub fn do_accept_project_transfer(
+		new_issuer: AccountIdOf<T>,
+		project_id: ProjectId,
+		new_did: Did,
+		funding_destination_account: AccountIdOf<T>,
+	) -> DispatchResult {
+		// * Get variables *
+		let mut project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let mut project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
+		let proposed_issuer =
+			PendingProjectTransfers::<T>::get(project_id).ok_or(Error::<T>::NoPendingProjectTransfer)?;
+		let old_did = project_details.issuer_did.clone();
+		let is_active = DidWithActiveProjects::<T>::get(old_did.clone()).contains(&project_id);
+
+		// * Validity checks *
+		ensure!(proposed_issuer == new_issuer, Error::<T>::NotProposedIssuer);
+		ensure!(Self::is_project_transferable(project_id, &project_details), Error::<T>::IncorrectRound);
+		ensure!(!Self::did_participated_in_project(project_id, &new_did), Error::<T>::NewIssuerIsParticipant);
+
+		// * Update storage *
+		if is_active && old_did != new_did {
+			Self::remove_active_project(old_did, project_id);
+			DidWithActiveProjects::<T>::try_mutate(new_did.clone(), |projects| projects.try_push(project_id))
+				.map_err(|_| Error::<T>::TooManyActiveProjects)?;
+		}
+		project_details.issuer_account = new_issuer.clone();
+		project_details.issuer_did = new_did.clone();
+		ProjectsDetails::<T>::insert(project_id, project_details);
+		project_metadata.funding_destination_account = funding_destination_account.clone();
+		ProjectsMetadata::<T>::insert(project_id, project_metadata);
+		PendingProjectTransfers::<T>::remove(project_id);
+
+		// * Emit events *
+		Self::deposit_event(Event::ProjectTransferred {
+			project_id,
+			issuer: new_issuer,
+			issuer_did: new_did,
+			funding_destination_account,
+		});
+
+		Ok(())
+	}
+
+	/// Stop new evaluations, bids and contributions on a project until governance resumes it or forces it to fail.
+	#[transactional]
+	pub fn do_halt_project(project_id: ProjectId, reason: BoundedVec<u8, StringLimitOf<T>>) -> DispatchResult {
+		// * Get variables *
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+
+		// * Validity checks *
+		ensure!(
+			matches!(
+				project_details.status,
+				ProjectStatus::Application |
+					ProjectStatus::EvaluationRound |
+					ProjectStatus::AuctionRound |
+					ProjectStatus::CommunityRound
+			),
+			Error::<T>::IncorrectRound
+		);
+
+		// * Update storage *
+		HaltedProjects::<T>::insert(project_id, reason.clone());
+
+		// * Emit events *
+		Self::deposit_event(Event::ProjectHalted { project_id, reason });
+
+		Ok(())
+	}
+
+	#[transactional]
+	pub fn do_resume_project(project_id: ProjectId) -> DispatchResult {
+		// * Validity checks *
+		ensure!(HaltedProjects::<T>::contains_key(project_id), Error::<T>::ProjectNotHalted);
+
+		// * Update storage *
+		HaltedProjects::<T>::remove(project_id);
+
+		// * Emit events *
+		Self::deposit_event(Event::ProjectResumed { project_id });
+
+		Ok(())
+	}
+
+	/// Move a project that did not start its settlement to `FundingFailed`. The evaluators outcome is cleared, so
+	/// evaluations are settled without a slash.
+	#[transactional]
+	pub fn do_force_project_failure(project_id: ProjectId, reason: BoundedVec<u8, StringLimitOf<T>>) -> DispatchResult {
+		// * Get variables *
+		let mut project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let current_status = project_details.status.clone();
+
+		// * Validity checks *
+		ensure!(
+			matches!(
+				current_status,
+				ProjectStatus::Application |
+					ProjectStatus::EvaluationRound |
+					ProjectStatus::AuctionRound |
+					ProjectStatus::CommunityRound |
+					ProjectStatus::AwaitingIssuerDecision |
+					ProjectStatus::FundingSuccessful
+			),
+			Error::<T>::IncorrectRound
+		);
+
+		// * Update storage *
+		ProjectsInAuctionRound::<T>::remove(project_id);
+		HaltedProjects::<T>::remove(project_id);
+		Self::remove_active_project(project_details.issuer_did.clone(), project_id);
+		project_details.is_frozen = true;
+		project_details.evaluation_round_info.evaluators_outcome = None;
+		Self::transition_project(
+			project_id,
+			project_details,
+			current_status,
+			ProjectStatus::FundingFailed,
+			None,
+			true,
+		)?;
+
+		// * Emit events *
+		Self::deposit_event(Event::ProjectForcedToFail { project_id, reason });
+
+		Ok(())
+	}
+
 	pub(crate) fn transition_project(
 		project_id: ProjectId,
 		mut project_details: ProjectDetailsOf<T>,
@@ -265,13 +500,27 @@ impl<T: Config> Pallet<T> {
 		ensure!(project_details.status == current_round, Error::<T>::IncorrectRound);
 		ensure!(project_details.round_duration.ended(now) || skip_end_check, Error::<T>::TooEarlyForRound);
 
+		// The transition scheduled for the current round may not have been processed yet
+		if let Some(current_round_end) = project_details.round_duration.end() {
+			ProjectTransitionQueue::<T>::remove(current_round_end.saturating_add(One::one()), project_id);
+		}
+
 		let round_end =
 			maybe_round_duration.map(|round_duration| now.saturating_add(round_duration).saturating_sub(One::one()));
 		project_details.round_duration = BlockNumberPair::new(Some(now), round_end);
 		project_details.status = next_round.clone();
 
+		// Rounds with an end are closed on the block after it, and finished fundraises move to settlement right away
+		let transition_block = match next_round {
+			ProjectStatus::FundingSuccessful | ProjectStatus::FundingFailed => Some(now.saturating_add(One::one())),
+			_ => round_end.map(|end| end.saturating_add(One::one())),
+		};
+
 		// * Update storage *
 		ProjectsDetails::<T>::insert(project_id, project_details);
+		if let Some(block) = transition_block {
+			ProjectTransitionQueue::<T>::insert(block, project_id, ());
+		}
 
 		// * Emit events *
 		Self::deposit_event(Event::ProjectPhaseTransition { project_id, phase: next_round });
@@ -279,18 +528,186 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
-	pub fn get_substrate_message_to_sign(polimec_account: AccountIdOf<T>, project_id: ProjectId) -> Option<String> {
+	/// Transition the projects scheduled in `ProjectTransitionQueue` since the last processed block, while there is
+	/// weight left and up to `MaxTransitionsPerBlock` of them. The rest, and the blocks not visited yet, are carried
+	/// over to the next blocks.
+	pub(crate) fn process_scheduled_transitions(available_weight: Weight) -> Weight {
+		let db_weight = <T as frame_system::Config>::DbWeight::get();
+		let now = <T as Config>::BlockNumberProvider::current_block_number();
+		let transition_weight = Self::max_transition_weight().saturating_add(db_weight.reads_writes(1, 1));
+		let mut weight_consumed = db_weight.reads_writes(1, 1);
+		let mut transitions = 0u32;
+
+		let mut block = LastTransitionBlockProcessed::<T>::get().map_or(now, |last| last.saturating_add(One::one()));
+		while block <= now {
+			// Reading the queue of a block costs weight even if nothing was scheduled on it
+			if weight_consumed.saturating_add(db_weight.reads(1)).any_gt(available_weight) {
+				LastTransitionBlockProcessed::<T>::put(block.saturating_sub(One::one()));
+				return weight_consumed;
+			}
+			weight_consumed.saturating_accrue(db_weight.reads(1));
+
+			while let Some(project_id) = ProjectTransitionQueue::<T>::iter_key_prefix(block).next() {
+				if transitions >= T::MaxTransitionsPerBlock::get() ||
+					weight_consumed.saturating_add(transition_weight).any_gt(available_weight)
+				{
+					LastTransitionBlockProcessed::<T>::put(block.saturating_sub(One::one()));
+					return weight_consumed;
+				}
+				transitions.saturating_accrue(1);
+				weight_consumed.saturating_accrue(db_weight.reads_writes(1, 1));
+				ProjectTransitionQueue::<T>::remove(block, project_id);
+				weight_consumed.saturating_accrue(Self::transition_scheduled_project(project_id, now));
+			}
+			block = block.saturating_add(One::one());
+		}
+		LastTransitionBlockProcessed::<T>::put(now);
+
+		weight_consumed
+	}
+
+	/// Place the bids scheduled in `ScheduledBidQueue` for the current block, and then the ones left from the blocks
+	/// that were skipped by the block number provider, up to `MaxScheduledBidsWeightPerBlock`.
+	/// `do_schedule_bid` never queues more bids than fit in that weight, so the bids of the current block are always
+	/// placed, and the ones scheduled before the end of the auction are placed while it is still open.
+	pub(crate) fn process_scheduled_bids() -> Weight {
+		let db_weight = <T as frame_system::Config>::DbWeight::get();
+		let now = <T as Config>::BlockNumberProvider::current_block_number();
+		let mut weight_consumed = db_weight.reads_writes(1, 1);
+
+		let first_block =
+			LastScheduledBidBlockProcessed::<T>::get().map_or(now, |last| last.saturating_add(One::one()));
+		if !Self::process_scheduled_bid_queue(now, &mut weight_consumed) {
+			LastScheduledBidBlockProcessed::<T>::put(first_block.saturating_sub(One::one()));
+			return weight_consumed;
+		}
+
+		let mut block = first_block;
+		while block < now {
+			if !Self::process_scheduled_bid_queue(block, &mut weight_consumed) {
+				LastScheduledBidBlockProcessed::<T>::put(block.saturating_sub(One::one()));
+				return weight_consumed;
+			}
+			block = block.saturating_add(One::one());
+		}
+		LastScheduledBidBlockProcessed::<T>::put(now);
+
+		weight_consumed
+	}
+
+	/// Place the bids queued for `block` while they fit in `MaxScheduledBidsWeightPerBlock`. Returns whether the queue
+	/// was emptied. The bids that did not fit stay in the queue.
+	fn process_scheduled_bid_queue(block: BlockNumberFor<T>, weight_consumed: &mut Weight) -> bool {
+		let db_weight = <T as frame_system::Config>::DbWeight::get();
+		let max_weight = T::MaxScheduledBidsWeightPerBlock::get();
+		let bid_weight = Self::scheduled_bid_weight();
+
+		weight_consumed.saturating_accrue(db_weight.reads_writes(1, 1));
+		let mut scheduled_bid_ids = ScheduledBidQueue::<T>::take(block);
+		while let Some(&scheduled_bid_id) = scheduled_bid_ids.first() {
+			if weight_consumed.saturating_add(bid_weight).any_gt(max_weight) {
+				ScheduledBidQueue::<T>::insert(block, scheduled_bid_ids);
+				return false;
+			}
+			scheduled_bid_ids.remove(0);
+			Self::execute_scheduled_bid(scheduled_bid_id);
+			weight_consumed.saturating_accrue(bid_weight);
+		}
+
+		true
+	}
+
+	/// Amount of bids that can be scheduled for the same block, so they are all placed in it by
+	/// `process_scheduled_bids`.
+	pub fn scheduled_bids_per_block() -> u32 {
+		let db_weight = <T as frame_system::Config>::DbWeight::get();
+		let available_weight = T::MaxScheduledBidsWeightPerBlock::get().saturating_sub(db_weight.reads_writes(2, 2));
+		available_weight
+			.checked_div_per_component(&Self::scheduled_bid_weight())
+			.unwrap_or(u64::MAX)
+			.min(MAX_SCHEDULED_BIDS_PER_BLOCK.into()) as u32
+	}
+
+	/// Weight budgeted for placing a scheduled bid, regardless of the outcome.
+	pub(crate) fn scheduled_bid_weight() -> Weight {
+		<T as Config>::WeightInfo::cancel_scheduled_bid().saturating_add(<T as Config>::WeightInfo::bid(10))
+	}
+
+	/// Worst case weight of moving a scheduled project to its next round.
+	fn max_transition_weight() -> Weight {
+		<T as Config>::WeightInfo::end_evaluation_failure()
+			.max(<T as Config>::WeightInfo::end_funding_project_successful())
+			.max(<T as Config>::WeightInfo::start_settlement())
+			.max(<T as Config>::WeightInfo::decide_project_outcome())
+	}
+
+	/// Move a scheduled project to its next round, based on its current status.
+	fn transition_scheduled_project(project_id: ProjectId, now: BlockNumberFor<T>) -> Weight {
+		let db_weight = <T as frame_system::Config>::DbWeight::get();
+		let Some(project_details) = ProjectsDetails::<T>::get(project_id) else { return db_weight.reads(1) };
+
+		let (result, weight) = match project_details.status {
+			ProjectStatus::EvaluationRound =>
+				(Self::do_end_evaluation(project_id), <T as Config>::WeightInfo::end_evaluation_failure()),
+			ProjectStatus::AuctionRound | ProjectStatus::CommunityRound =>
+				(Self::do_end_funding(project_id), <T as Config>::WeightInfo::end_funding_project_successful()),
+			ProjectStatus::FundingSuccessful | ProjectStatus::FundingFailed =>
+				(Self::do_start_settlement(project_id), <T as Config>::WeightInfo::start_settlement()),
+			ProjectStatus::AwaitingIssuerDecision => (
+				Self::do_apply_default_project_outcome(project_id),
+				<T as Config>::WeightInfo::decide_project_outcome(),
+			),
+			// The project was already transitioned manually
+			_ => return db_weight.reads(1),
+		};
+
+		match result {
+			Ok(()) => weight,
+			// The outbid bids are still being processed in `on_idle`, so we try again on the next block
+			Err(error) if error == Error::<T>::OversubscribedBidsRemaining.into() => {
+				ProjectTransitionQueue::<T>::insert(now.saturating_add(One::one()), project_id, ());
+				weight.saturating_add(db_weight.writes(1))
+			},
+			// The project was transitioned manually into a round that has its own scheduled transition
+			Err(error) if error == Error::<T>::TooEarlyForRound.into() => weight,
+			// Other failures are also tried again on the next block, so the project is never left without a transition
+			Err(error) => {
+				ProjectTransitionQueue::<T>::insert(now.saturating_add(One::one()), project_id, ());
+				Self::deposit_event(Event::ProjectTransitionFailed { project_id, error });
+				weight.saturating_add(db_weight.writes(1))
+			},
+		}
+	}
+
+	/// Domain of the messages signed by the receiving accounts, i.e. the genesis hash of the chain. Signatures made
+	/// for another chain running this pallet are not valid here.
+	pub fn receiving_account_signature_domain() -> [u8; 32] {
+		let genesis_hash = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+		let genesis_hash = genesis_hash.as_ref();
+		let length = genesis_hash.len().min(32);
+
+		let mut domain = [0u8; 32];
+		domain[..length].copy_from_slice(&genesis_hash[..length]);
+		domain
+	}
+
+	pub fn get_substrate_message_to_sign(
+		polimec_account: AccountIdOf<T>,
+		project_id: ProjectId,
+		expiry: BlockNumberFor<T>,
+	) -> Option<String> {
 		let mut message = String::new();
 
-		let polimec_account_ss58_string = T::SS58Conversion::convert(polimec_account.clone());
+		let polimec_account_ss58_string = T::SS58Conversion::convert(polimec_account);
 		let project_id_string = project_id.to_string();
-		let nonce_string = frame_system::Pallet::<T>::account_nonce(polimec_account).to_string();
+		let expiry_string = expiry.saturated_into::<u32>().to_string();
+		let domain_string = hex::encode(Self::receiving_account_signature_domain());
 
 		use alloc::fmt::Write;
 		write!(
 			&mut message,
-			"Polimec account: {} - project id: {} - nonce: {}",
-			polimec_account_ss58_string, project_id_string, nonce_string
+			"Polimec account: {} - project id: {} - expiry: {} - domain: 0x{}",
+			polimec_account_ss58_string, project_id_string, expiry_string, domain_string
 		)
 		.ok()?;
 		Some(message)
@@ -301,6 +718,7 @@ impl<T: Config> Pallet<T> {
 		expected_ethereum_account: [u8; 20],
 		polimec_account: AccountIdOf<T>,
 		project_id: ProjectId,
+		expiry: BlockNumberFor<T>,
 	) -> bool {
 		match signature_bytes[64] {
 			27 => signature_bytes[64] = 0x00,
@@ -309,9 +727,10 @@ impl<T: Config> Pallet<T> {
 		}
 
 		let hashed_message = typed_data_v4::get_eip_712_message(
-			&T::SS58Conversion::convert(polimec_account.clone()),
+			&T::SS58Conversion::convert(polimec_account),
 			project_id,
-			frame_system::Pallet::<T>::account_nonce(polimec_account),
+			expiry.saturated_into(),
+			Self::receiving_account_signature_domain(),
 		);
 
 		let ecdsa_signature = EcdsaSignature::from_slice(&signature_bytes).unwrap();
@@ -329,52 +748,253 @@ impl<T: Config> Pallet<T> {
 		expected_substrate_account: [u8; 32],
 		polimec_account: AccountIdOf<T>,
 		project_id: ProjectId,
+		expiry: BlockNumberFor<T>,
 	) -> bool {
-		let message_to_sign = Self::get_substrate_message_to_sign(polimec_account, project_id).unwrap();
+		let message_to_sign = Self::get_substrate_message_to_sign(polimec_account, project_id, expiry).unwrap();
 		let message_bytes = message_to_sign.into_bytes();
 		let signature = SrSignature::from_slice(&signature_bytes[..64]).unwrap();
 		let public = SrPublic::from_slice(&expected_substrate_account).unwrap();
 		signature.verify(message_bytes.as_slice(), &public)
 	}
 
-	pub fn verify_receiving_account_signature(
+	pub fn verify_solana_account(
+		signature_bytes: [u8; 65],
+		expected_solana_account: [u8; 32],
+		polimec_account: AccountIdOf<T>,
+		project_id: ProjectId,
+		expiry: BlockNumberFor<T>,
+	) -> bool {
+		let Some(message_to_sign) = Self::get_substrate_message_to_sign(polimec_account, project_id, expiry) else {
+			return false
+		};
+		let Some(offchain_message) = solana_offchain_message::get_offchain_message(message_to_sign.as_bytes()) else {
+			return false
+		};
+		let signature = EdSignature::from_slice(&signature_bytes[..64]).unwrap();
+		let public = EdPublic::from_slice(&expected_solana_account).unwrap();
+		signature.verify(offchain_message.as_slice(), &public)
+	}
+
+	pub fn verify_cosmos_account(
+		signature_bytes: [u8; 65],
+		expected_cosmos_account: [u8; 20],
+		bech32_prefix: &[u8],
+		polimec_account: AccountIdOf<T>,
+		project_id: ProjectId,
+		expiry: BlockNumberFor<T>,
+	) -> bool {
+		let Some(message_to_sign) = Self::get_substrate_message_to_sign(polimec_account, project_id, expiry) else {
+			return false
+		};
+		let signer = cosmos_adr_036::bech32_encode(bech32_prefix, &expected_cosmos_account);
+		let hashed_message = cosmos_adr_036::get_sign_doc_hash(&signer, message_to_sign.as_bytes());
+
+		// Cosmos wallets only return the 64 bytes of the signature, so we try both recovery ids.
+		(0u8..=1).any(|recovery_id| {
+			let mut recoverable_signature = [0u8; 65];
+			recoverable_signature[..64].copy_from_slice(&signature_bytes[..64]);
+			recoverable_signature[64] = recovery_id;
+			EcdsaSignature::from_raw(recoverable_signature)
+				.recover_prehashed(&hashed_message)
+				.is_some_and(|public| cosmos_adr_036::get_account_from_public(&public) == expected_cosmos_account)
+		})
+	}
+
+	/// Check that the owner of the receiving account signed the message for the Polimec account, project and expiry.
+	/// The account type of the project decides the signature scheme.
+	pub fn verify_account_type_signature(
+		account_type: &ParticipantsAccountType,
 		polimec_account: &AccountIdOf<T>,
 		project_id: ProjectId,
 		receiver_account: &Junction,
+		expiry: BlockNumberFor<T>,
 		signature_bytes: [u8; 65],
 	) -> DispatchResult {
-		match receiver_account {
-			Junction::AccountId32 { id: substrate_account, .. } => {
-				ensure!(
-					Self::verify_substrate_account(
-						signature_bytes,
-						*substrate_account,
-						polimec_account.clone(),
-						project_id
-					),
-					Error::<T>::BadReceiverAccountSignature
-				);
-			},
-
-			Junction::AccountKey20 { key: expected_ethereum_account, .. } => {
-				ensure!(
-					Self::verify_ethereum_account(
-						signature_bytes,
-						*expected_ethereum_account,
-						polimec_account.clone(),
-						project_id,
-					),
-					Error::<T>::BadReceiverAccountSignature
-				);
-			},
+		let polimec_account = polimec_account.clone();
+		let is_valid_signature = match (account_type, receiver_account) {
+			(ParticipantsAccountType::Polkadot, Junction::AccountId32 { id: substrate_account, .. }) =>
+				Self::verify_substrate_account(signature_bytes, *substrate_account, polimec_account, project_id, expiry),
+			(ParticipantsAccountType::Ethereum, Junction::AccountKey20 { key: expected_ethereum_account, .. }) =>
+				Self::verify_ethereum_account(
+					signature_bytes,
+					*expected_ethereum_account,
+					polimec_account,
+					project_id,
+					expiry,
+				),
+			(ParticipantsAccountType::Solana, Junction::AccountId32 { id: solana_account, .. }) =>
+				Self::verify_solana_account(signature_bytes, *solana_account, polimec_account, project_id, expiry),
+			(
+				ParticipantsAccountType::Cosmos { bech32_prefix },
+				Junction::AccountKey20 { key: expected_cosmos_account, .. },
+			) => Self::verify_cosmos_account(
+				signature_bytes,
+				*expected_cosmos_account,
+				bech32_prefix,
+				polimec_account,
+				project_id,
+				expiry,
+			),
 			_ => return Err(Error::<T>::UnsupportedReceiverAccountJunction.into()),
 		};
+		ensure!(is_valid_signature, Error::<T>::BadReceiverAccountSignature);
+
+		Ok(())
+	}
+
+	/// Bind the account that receives the contribution tokens of the participations of `account` on a project. The
+	/// signature has to be submitted before the expiry block, and it stays valid regardless of the other
+	/// transactions of the account.
+	pub fn do_bind_receiving_account(
+		account: &AccountIdOf<T>,
+		project_id: ProjectId,
+		receiving_account: Junction,
+		expiry: BlockNumberFor<T>,
+		signature_bytes: [u8; 65],
+	) -> DispatchResult {
+		// * Get variables *
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
+		let now = <T as Config>::BlockNumberProvider::current_block_number();
+
+		// * Validity checks *
+		ensure!(now <= expiry, Error::<T>::ReceivingAccountSignatureExpired);
+		ensure!(
+			project_metadata.participants_account_type.junction_is_supported(&receiving_account),
+			Error::<T>::UnsupportedReceiverAccountJunction
+		);
+		Self::verify_account_type_signature(
+			&project_metadata.participants_account_type,
+			account,
+			project_id,
+			&receiving_account,
+			expiry,
+			signature_bytes,
+		)?;
+
+		// * Update storage *
+		ReceivingAccounts::<T>::insert(project_id, account, receiving_account);
+
+		// * Emit events *
+		Self::deposit_event(Event::ReceivingAccountBound { project_id, account: account.clone(), receiving_account });
+
+		Ok(())
+	}
+
+	/// Account that receives the contribution tokens of the participations of `account` on a project. Falls back to
+	/// the Polimec account itself if no other account was bound.
+	pub fn receiving_account_of(project_id: ProjectId, account: &AccountIdOf<T>) -> Junction {
+		ReceivingAccounts::<T>::get(project_id, account).unwrap_or_else(|| Junction::AccountId32 {
+			network: Some(NetworkId::Polkadot),
+			id: T::AccountId32Conversion::convert(account.clone()),
+		})
+	}
+
+	/// Leaf of a project allowlist for a DID and its USD cap.
+	pub fn allowlist_leaf(did: &Did, usd_cap: Balance) -> [u8; 32] {
+		keccak_256(&(did, usd_cap).encode())
+	}
+
+	/// Root of a merkle tree computed from a leaf and the sibling hashes up to the root. Pairs of nodes are sorted
+	/// before being hashed together, so proofs don't need to carry the position of each sibling.
+	pub fn merkle_root(leaf: [u8; 32], proof: &[[u8; 32]]) -> [u8; 32] {
+		proof.iter().fold(leaf, |node, sibling| {
+			if node <= *sibling {
+				keccak_256(&[node, *sibling].concat())
+			} else {
+				keccak_256(&[*sibling, node].concat())
+			}
+		})
+	}
+
+	/// Check the proof against the allowlist of the project, and remember the USD cap of the DID for its next
+	/// participations. Projects without an allowlist accept any proof.
+	pub fn do_prove_allowlist(project_id: ProjectId, did: &Did, allowlist_proof: AllowlistProof) -> DispatchResult {
+		let Some(allowlist_root) = ProjectAllowlists::<T>::get(project_id) else { return Ok(()) };
+
+		let leaf = Self::allowlist_leaf(did, allowlist_proof.usd_cap);
+		let computed_root = Self::merkle_root(leaf, &allowlist_proof.proof);
+		ensure!(computed_root == allowlist_root, Error::<T>::InvalidAllowlistProof);
+
+		AllowlistCaps::<T>::insert(project_id, did, allowlist_proof.usd_cap);
+		Ok(())
+	}
+
+	/// If the project has an allowlist, check that the DID proved to be part of it, and that its bids and
+	/// contributions stay within the cap. `usd_bid_total` is `None` for participations that don't count towards the cap.
+	pub fn ensure_allowlisted(project_id: ProjectId, did: &Did, usd_bid_total: Option<Balance>) -> DispatchResult {
+		if !ProjectAllowlists::<T>::contains_key(project_id) {
+			return Ok(());
+		}
+		let usd_cap = AllowlistCaps::<T>::get(project_id, did).ok_or(Error::<T>::NotAllowlisted)?;
+		if let Some(usd_bid_total) = usd_bid_total {
+			ensure!(usd_bid_total <= usd_cap, Error::<T>::AllowlistCapExceeded);
+		}
 		Ok(())
 	}
 
 	pub fn get_decimals_aware_funding_asset_price(funding_asset: &AcceptedFundingAsset) -> Option<PriceOf<T>> {
-		let funding_asset_id = funding_asset.id();
-		<PriceProviderOf<T>>::get_decimals_aware_price(&funding_asset_id, funding_asset.decimals())
+		let info = FundingAssets::<T>::get(funding_asset)?;
+		<PriceProviderOf<T>>::get_decimals_aware_price(&info.oracle_key, info.decimals)
+	}
+
+	/// Location of a registered funding asset, enabled or not.
+	pub fn funding_asset_location(asset: AcceptedFundingAsset) -> Result<Location, DispatchError> {
+		Ok(FundingAssets::<T>::get(asset).ok_or(Error::<T>::FundingAssetNotAccepted)?.location)
+	}
+
+	/// Whether the asset is registered and can be used for new participations.
+	pub fn is_funding_asset_enabled(asset: AcceptedFundingAsset) -> bool {
+		FundingAssets::<T>::get(asset).is_some_and(|info| info.enabled)
+	}
+
+	pub fn do_set_funding_asset(asset: AcceptedFundingAsset, info: FundingAssetInfo) -> DispatchResult {
+		// * Validity checks *
+		if let Some(registered_info) = FundingAssets::<T>::get(asset) {
+			ensure!(
+				registered_info.location == info.location && registered_info.decimals == info.decimals,
+				Error::<T>::FundingAssetImmutable
+			);
+		} else {
+			ensure!(
+				FundingAssets::<T>::iter_keys().count() < MAX_FUNDING_ASSETS as usize,
+				Error::<T>::TooManyFundingAssets
+			);
+			ensure!(
+				FundingAssets::<T>::iter_values().all(|registered_info| registered_info.location != info.location),
+				Error::<T>::FundingAssetLocationTaken
+			);
+		}
+
+		// * Update storage *
+		FundingAssets::<T>::insert(asset, info.clone());
+
+		// * Emit events *
+		Self::deposit_event(Event::FundingAssetSet { asset, info });
+
+		Ok(())
+	}
+
+	pub fn do_set_fee_config(fee_config: FeeConfig) -> DispatchResult {
+		// * Validity checks *
+		ensure!(fee_config.is_valid(), Error::<T>::FeeConfigError);
+
+		// * Update storage *
+		FeeConfiguration::<T>::put(fee_config.clone());
+
+		// * Emit events *
+		Self::deposit_event(Event::FeeConfigSet { fee_config });
+
+		Ok(())
+	}
+}
+
+impl<T: Config> ProvideFundingAssets for Pallet<T> {
+	fn funding_asset(asset: AcceptedFundingAsset) -> Option<FundingAssetInfo> {
+		FundingAssets::<T>::get(asset)
+	}
+
+	fn funding_assets() -> Vec<(AcceptedFundingAsset, FundingAssetInfo)> {
+		FundingAssets::<T>::iter().collect()
 	}
 }
 
@@ -383,13 +1003,19 @@ pub mod typed_data_v4 {
 	use hex_literal::hex;
 
 	/// Returns the first part needed for a typed data v4 message. It specifies the entity that requires the signature.
-	pub fn get_domain_separator(name: &str, version: &str, chain_id: u32, verifying_contract: [u8; 20]) -> [u8; 32] {
+	pub fn get_domain_separator(
+		name: &str,
+		version: &str,
+		chain_id: u32,
+		verifying_contract: [u8; 20],
+		salt: [u8; 32],
+	) -> [u8; 32] {
 		/// EIP-712 domain separator calculation
 		/// RFC: https://eips.ethereum.org/EIPS/eip-712
-		// keccak_256(b"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)");
-		const DOMAIN_TYPE_HASH: [u8; 32] = hex!("8b73c3c69bb8fe3d512ecc4cf759cc79239f7b179b0ffacaa9a75d522b39400f");
+		// keccak_256(b"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract,bytes32 salt)");
+		const DOMAIN_TYPE_HASH: [u8; 32] = hex!("d87cd6ef79d4e2b95e15ce8abf732db51ec771f1ca2edccf22a46c729ac56472");
 
-		let mut data = [0u8; 32 * 5];
+		let mut data = [0u8; 32 * 6];
 
 		// Copy pre-computed domain type hash
 		data[..32].copy_from_slice(&DOMAIN_TYPE_HASH);
@@ -409,14 +1035,17 @@ pub mod typed_data_v4 {
 		// Copy contract address with proper padding
 		data[140..160].copy_from_slice(&verifying_contract);
 
+		// Copy salt, which is the signature domain of the chain
+		data[160..192].copy_from_slice(&salt);
+
 		// Calculate final hash
 		keccak_256(&data)
 	}
 
 	/// Returns the second part needed for a typed data v4 message. It specifies the message details with type information.
-	pub fn get_message(polimec_account: &str, project_id: u32, nonce: u32) -> [u8; 32] {
-		// keccak_256(b"ParticipationAuthorization(string polimecAccount,uint32 projectId,uint32 nonce)");
-		const DOMAIN_TYPE_HASH: [u8; 32] = hex!("fd56e61c83fe04559072170cf0f970f35093edf39291b74939109a1c9ded28a3");
+	pub fn get_message(polimec_account: &str, project_id: u32, expiry: u32) -> [u8; 32] {
+		// keccak_256(b"ParticipationAuthorization(string polimecAccount,uint32 projectId,uint32 expiry)");
+		const DOMAIN_TYPE_HASH: [u8; 32] = hex!("14d85281e8fcac98c752674f94119b7cadcce1a70e53c1bc53fcae56545568a2");
 
 		let mut data = [0u8; 32 * 4];
 
@@ -428,17 +1057,17 @@ pub mod typed_data_v4 {
 		let project_id_bytes: [u8; 4] = project_id.to_be_bytes();
 		data[92..96].copy_from_slice(&project_id_bytes);
 
-		let nonce_bytes: [u8; 4] = nonce.to_be_bytes();
-		data[124..128].copy_from_slice(&nonce_bytes);
+		let expiry_bytes: [u8; 4] = expiry.to_be_bytes();
+		data[124..128].copy_from_slice(&expiry_bytes);
 
 		keccak_256(&data)
 	}
 
 	/// Returns the final message hash that will be signed by the user.
-	pub fn get_eip_712_message(polimec_account: &str, project_id: u32, nonce: u32) -> [u8; 32] {
+	pub fn get_eip_712_message(polimec_account: &str, project_id: u32, expiry: u32, domain: [u8; 32]) -> [u8; 32] {
 		let domain_separator =
-			get_domain_separator("Polimec", "1", 1, hex!("0000000000000000000000000000000000003344"));
-		let message = get_message(polimec_account, project_id, nonce);
+			get_domain_separator("Polimec", "2", 1, hex!("0000000000000000000000000000000000003344"), domain);
+		let message = get_message(polimec_account, project_id, expiry);
 
 		let mut data = [0u8; 32 * 2 + 2];
 		data[0..2].copy_from_slice(b"\x19\x01");
@@ -448,3 +1077,132 @@ pub mod typed_data_v4 {
 		keccak_256(&data)
 	}
 }
+
+pub mod solana_offchain_message {
+	use super::*;
+
+	/// Prefix of every Solana off-chain message, so it can never be a valid transaction.
+	const SIGNING_DOMAIN: &[u8; 16] = b"\xffsolana offchain";
+	/// Version 0 of the off-chain message header.
+	const HEADER_VERSION: u8 = 0;
+	/// Messages made only of printable ASCII characters.
+	const FORMAT_RESTRICTED_ASCII: u8 = 0;
+	/// Maximum length of a message that Ledger devices can sign.
+	const MAX_LEN_LEDGER: usize = 1212;
+
+	/// Returns the bytes signed by Solana wallets for an off-chain message. `None` if the message is not printable
+	/// ASCII, or too long to be signed by a hardware wallet.
+	/// RFC: https://github.com/solana-labs/solana/blob/master/docs/src/proposals/off-chain-message-signing.md
+	pub fn get_offchain_message(message: &[u8]) -> Option<Vec<u8>> {
+		if message.is_empty() || message.len() > MAX_LEN_LEDGER || !message.iter().all(|c| (0x20..=0x7e).contains(c)) {
+			return None;
+		}
+		let message_length: u16 = message.len().try_into().ok()?;
+
+		let mut data = Vec::with_capacity(SIGNING_DOMAIN.len() + 4 + message.len());
+		data.extend_from_slice(SIGNING_DOMAIN);
+		data.push(HEADER_VERSION);
+		data.push(FORMAT_RESTRICTED_ASCII);
+		data.extend_from_slice(&message_length.to_le_bytes());
+		data.extend_from_slice(message);
+		Some(data)
+	}
+}
+
+pub mod cosmos_adr_036 {
+	use super::*;
+	use ripemd::{Digest, Ripemd160};
+	use sp_io::hashing::sha2_256;
+
+	const BECH32_CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+	const BASE64_CHARSET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+	/// Returns the 20 byte account of a compressed secp256k1 public key.
+	pub fn get_account_from_public(public: &EcdsaPublic) -> [u8; 20] {
+		let mut account = [0u8; 20];
+		account.copy_from_slice(&Ripemd160::digest(sha2_256(public.as_ref())));
+		account
+	}
+
+	/// Returns the hash signed by Cosmos wallets for arbitrary data, i.e. the amino JSON of an ADR-036 sign doc.
+	/// RFC: https://github.com/cosmos/cosmos-sdk/blob/main/docs/architecture/adr-036-arbitrary-signature.md
+	pub fn get_sign_doc_hash(signer: &str, data: &[u8]) -> [u8; 32] {
+		let mut sign_doc = String::new();
+
+		use alloc::fmt::Write;
+		// Keys are sorted alphabetically and the JSON has no whitespace, as in the amino encoding.
+		let _ = write!(
+			&mut sign_doc,
+			concat!(
+				r#"{{"account_number":"0","chain_id":"","fee":{{"amount":[],"gas":"0"}},"memo":"","#,
+				r#""msgs":[{{"type":"sign/MsgSignData","value":{{"data":"{}","signer":"{}"}}}}],"sequence":"0"}}"#,
+			),
+			base64_encode(data),
+			signer
+		);
+
+		sha2_256(sign_doc.as_bytes())
+	}
+
+	/// Encodes the bytes of an account into a bech32 address with the given human readable part.
+	/// RFC: https://github.com/bitcoin/bips/blob/master/bip-0173.mediawiki
+	pub fn bech32_encode(hrp: &[u8], account: &[u8]) -> String {
+		// Regroup the 8 bit bytes into 5 bit words, padding the last one with zeros.
+		let mut words = Vec::new();
+		let (mut accumulator, mut bits) = (0u32, 0u32);
+		for byte in account {
+			accumulator = (accumulator << 8) | u32::from(*byte);
+			bits += 8;
+			while bits >= 5 {
+				bits -= 5;
+				words.push(((accumulator >> bits) & 31) as u8);
+			}
+		}
+		if bits > 0 {
+			words.push(((accumulator << (5 - bits)) & 31) as u8);
+		}
+
+		let mut checksum_input: Vec<u8> = hrp.iter().map(|c| c >> 5).collect();
+		checksum_input.push(0);
+		checksum_input.extend(hrp.iter().map(|c| c & 31));
+		checksum_input.extend_from_slice(&words);
+		checksum_input.extend_from_slice(&[0u8; 6]);
+		let polymod = bech32_polymod(&checksum_input) ^ 1;
+		words.extend((0..6).map(|i| ((polymod >> (5 * (5 - i))) & 31) as u8));
+
+		let mut address = String::with_capacity(hrp.len() + 1 + words.len());
+		address.extend(hrp.iter().map(|c| char::from(*c)));
+		address.push('1');
+		address.extend(words.iter().map(|word| char::from(BECH32_CHARSET[usize::from(*word)])));
+		address
+	}
+
+	fn bech32_polymod(values: &[u8]) -> u32 {
+		const GENERATOR: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+		values.iter().fold(1u32, |checksum, value| {
+			let top = checksum >> 25;
+			let checksum = ((checksum & 0x1ffffff) << 5) ^ u32::from(*value);
+			GENERATOR
+				.iter()
+				.enumerate()
+				.filter(|(i, _)| (top >> i) & 1 == 1)
+				.fold(checksum, |checksum, (_, generator)| checksum ^ generator)
+		})
+	}
+
+	fn base64_encode(data: &[u8]) -> String {
+		let mut encoded = String::with_capacity(data.len().div_ceil(3) * 4);
+		for chunk in data.chunks(3) {
+			let bytes = [chunk[0], chunk.get(1).copied().unwrap_or(0), chunk.get(2).copied().unwrap_or(0)];
+			let triple = (u32::from(bytes[0]) << 16) | (u32::from(bytes[1]) << 8) | u32::from(bytes[2]);
+			for i in 0..4 {
+				if i <= chunk.len() {
+					encoded.push(char::from(BASE64_CHARSET[((triple >> (18 - 6 * i)) & 63) as usize]));
+				} else {
+					encoded.push('=');
+				}
+			}
+		}
+		encoded
+	}
+}
@@ -1,11 +1,12 @@
 #[allow(clippy::wildcard_imports)]
 use super::{traits::*, *};
-use alloc::vec;
+use alloc::{vec, vec::Vec};
 use core::ops::Not;
 use frame_support::{
 	dispatch::{DispatchResult, DispatchResultWithPostInfo, PostDispatchInfo},
 	ensure,
 	pallet_prelude::*,
+	storage::{with_transaction, TransactionOutcome},
 	traits::{
 		fungible::{Mutate, MutateHold as FungibleMutateHold},
 		fungibles::{metadata::Mutate as MetadataMutate, Create, Mutate as FungiblesMutate},
@@ -22,14 +23,16 @@ use sp_runtime::traits::Convert;
 mod application;
 #[path = "3_auction.rs"]
 mod auction;
-#[path = "6_ct_migration.rs"]
+#[path = "4_community_round.rs"]
+mod community_round;
+#[path = "7_ct_migration.rs"]
 mod ct_migration;
 #[path = "2_evaluation.rs"]
 mod evaluation;
-#[path = "4_funding_end.rs"]
+#[path = "5_funding_end.rs"]
 mod funding_end;
 pub mod misc;
-#[path = "5_settlement.rs"]
+#[path = "6_settlement.rs"]
 mod settlement;
 
 pub mod runtime_api;
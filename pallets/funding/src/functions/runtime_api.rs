@@ -3,9 +3,14 @@ use crate::{traits::BondingRequirementCalculation, *};
 use alloc::{collections::BTreeMap, string::String, vec::Vec};
 use frame_support::traits::fungibles::{Inspect, InspectEnumerable};
 use itertools::Itertools;
-use polimec_common::{assets::AcceptedFundingAsset, credentials::InvestorType, ProvideAssetPrice};
+use polimec_common::{
+	assets::AcceptedFundingAsset,
+	credentials::InvestorType,
+	migration_types::{MigrationInfo, Migrations},
+	ProvideAssetPrice,
+};
 use sp_core::Get;
-use sp_runtime::traits::Zero;
+use sp_runtime::{traits::Zero, PerThing, Percent, Perquintill};
 
 sp_api::decl_runtime_apis! {
 	#[api_version(2)]
@@ -35,21 +40,46 @@ sp_api::decl_runtime_apis! {
 		fn participations_of(account: AccountIdOf<T>, project_id: Option<ProjectId>) -> Vec<BidInfoOf<T>>;
 	}
 
-	#[api_version(1)]
+	#[api_version(4)]
 	pub trait ProjectInformation<T: Config> {
 		/// Get the percentage of the target reached for a project
 		fn usd_target_percent_reached(project_id: ProjectId) -> FixedU128;
 
-		/// Get all the projects created by a single DID.
+		/// Get all the projects created by a single DID, active or not, ordered by project id.
 		fn projects_by_did(did: Did) -> Vec<ProjectId>;
+
+		/// Get the projects of a DID that did not reach the end of their funding round yet. These count towards the
+		/// maximum of active projects per DID.
+		#[api_version(5)]
+		fn active_projects_by_did(did: Did) -> Vec<ProjectId>;
+
+		/// Get the evaluation and funding success thresholds of a project. These are the ones declared by the issuer,
+		/// or the defaults of the pallet otherwise.
+		#[api_version(5)]
+		fn project_success_thresholds(project_id: ProjectId) -> Option<(Percent, Perquintill)>;
+
+		/// Get the CT amount a bid on a pro-rata sale gets, based on everything bid so far. Final once the funding ended.
+		fn pro_rata_ct_amount_of_bid(project_id: ProjectId, bid_id: u32) -> Option<Balance>;
+
+		/// Get the price at which the auction currently sells tokens. Once the auction is over, only descending
+		/// auctions return a price, which is the one every bidder pays.
+		fn current_auction_price(project_id: ProjectId) -> Option<PriceOf<T>>;
+
+		/// Get the participants of a project whose migration is not confirmed yet, with the `MigrationInfo` of the
+		/// merkle leaf that confirms it. Used by the issuer to build the tree of a batch of migration confirmations.
+		fn migration_confirmation_leaves(project_id: ProjectId) -> Vec<(AccountIdOf<T>, MigrationInfo)>;
 	}
 
-	#[api_version(4)]
+	#[api_version(6)]
 	pub trait ExtrinsicHelpers<T: Config> {
-		/// Get the current price of a contribution token (either current bucket in the auction, or WAP in contribution phase),
+		/// Get the current price of a contribution token (either current bucket in the auction, or WAP in the community round),
 		/// and calculate the amount of tokens that can be bought with the given amount USDT/USDC/DOT.
 		fn funding_asset_to_ct_amount_classic(project_id: ProjectId, funding_asset: AcceptedFundingAsset, funding_asset_amount: Balance) -> Balance;
 
+		/// Same as `funding_asset_to_ct_amount_classic`, but in the auction `max_price` previews a bid with that price
+		/// cap, and only counts the tokens sold up to it.
+		fn funding_asset_to_ct_amount_classic_with_max_price(project_id: ProjectId, funding_asset: AcceptedFundingAsset, funding_asset_amount: Balance, max_price: Option<PriceOf<T>>) -> Balance;
+
 		/// Calculate how many CTs and what the OTM fee is for a given project and funding asset amount.
 		fn funding_asset_to_ct_amount_otm(project_id: ProjectId, funding_asset: AcceptedFundingAsset, funding_asset_amount: Balance) -> (Balance, Balance);
 
@@ -63,9 +93,11 @@ sp_api::decl_runtime_apis! {
 		/// Gets the minimum and maximum amount of FundingAsset a user can input in the UI.
 		fn get_funding_asset_min_max_amounts(project_id: ProjectId, did: Did, funding_asset: AcceptedFundingAsset, investor_type: InvestorType) -> Option<(Balance, Balance)>;
 
-		/// Gets the hex encoded bytes of the message needed to be signed by the receiving account to participate in the project.
+		/// Gets the message needed to be signed by the receiving account to bind it to the Polimec account for the project.
 		/// The message will first be prefixed with a blockchain-dependent string, then hashed, and then signed.
-		fn get_message_to_sign_by_receiving_account(project_id: ProjectId, polimec_account: AccountIdOf<T>) -> Option<String>;
+		/// Solana accounts sign it as an off-chain message, and Cosmos accounts as the data of an ADR-036 sign doc.
+		/// The signature can be submitted with `bind_receiving_account` until the `expiry` block.
+		fn get_message_to_sign_by_receiving_account(project_id: ProjectId, polimec_account: AccountIdOf<T>, expiry: BlockNumberFor<T>) -> Option<String>;
 	}
 }
 
@@ -161,30 +193,21 @@ impl<T: Config> Pallet<T> {
 		project_id: ProjectId,
 		asset: AcceptedFundingAsset,
 		asset_amount: Balance,
+	) -> Balance {
+		Self::funding_asset_to_ct_amount_classic_with_max_price(project_id, asset, asset_amount, None)
+	}
+
+	pub fn funding_asset_to_ct_amount_classic_with_max_price(
+		project_id: ProjectId,
+		asset: AcceptedFundingAsset,
+		asset_amount: Balance,
+		max_price: Option<PriceOf<T>>,
 	) -> Balance {
 		let funding_asset_usd_price =
 			Pallet::<T>::get_decimals_aware_funding_asset_price(&asset).expect("Price not found");
 		let usd_ticket_size = funding_asset_usd_price.saturating_mul_int(asset_amount);
 
-		let mut ct_amount = Balance::zero();
-
-		let mut usd_to_spend = usd_ticket_size;
-		let mut current_bucket = Buckets::<T>::get(project_id).expect("Bucket not found");
-		while usd_to_spend > Zero::zero() {
-			let bucket_price = current_bucket.current_price;
-
-			let ct_to_buy = bucket_price.reciprocal().expect("Bad math").saturating_mul_int(usd_to_spend);
-			let ct_to_buy = ct_to_buy.min(current_bucket.amount_left);
-
-			ct_amount = ct_amount.saturating_add(ct_to_buy);
-			// if usd spent is 0, we will have an infinite loop
-			let usd_spent = bucket_price.saturating_mul_int(ct_to_buy).max(One::one());
-			usd_to_spend = usd_to_spend.saturating_sub(usd_spent);
-
-			current_bucket.update(ct_to_buy);
-		}
-
-		ct_amount
+		Self::usd_to_ct_amount(project_id, usd_ticket_size, max_price)
 	}
 
 	pub fn funding_asset_to_ct_amount_otm(
@@ -206,12 +229,63 @@ impl<T: Config> Pallet<T> {
 		let participating_usd_ticket_size =
 			funding_asset_usd_price.saturating_mul_int(participating_funding_asset_amount);
 
-		let mut ct_amount = Balance::zero();
+		let ct_amount = Self::usd_to_ct_amount(project_id, participating_usd_ticket_size, None);
 
-		let mut usd_to_spend = participating_usd_ticket_size;
+		(ct_amount, fee_funding_asset_amount)
+	}
+
+	/// Calculate the amount of CTs that can be bought with a USD amount. In the auction, the amount is spread over the
+	/// buckets starting from the current one. In the community round, the WAP of the auction is used.
+	/// Pro-rata sales always use the minimum price, and the amount bid in the auction might still be reduced at the end.
+	/// A `max_price` stops the auction calculation at the first price above it, like it does for a bid.
+	fn usd_to_ct_amount(project_id: ProjectId, usd_ticket_size: Balance, max_price: Option<PriceOf<T>>) -> Balance {
+		let project_details = ProjectsDetails::<T>::get(project_id).expect("Project not found");
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).expect("Project not found");
 		let mut current_bucket = Buckets::<T>::get(project_id).expect("Bucket not found");
+		let above_max_price = |price: PriceOf<T>| {
+			project_details.status != ProjectStatus::CommunityRound &&
+				max_price.is_some_and(|max_price| price > max_price)
+		};
+
+		if project_metadata.sale_mode == SaleMode::ProRata {
+			if above_max_price(project_metadata.minimum_price) {
+				return Zero::zero();
+			}
+			let ct_amount =
+				project_metadata.minimum_price.reciprocal().expect("Bad math").saturating_mul_int(usd_ticket_size);
+			return if project_details.status == ProjectStatus::CommunityRound {
+				ct_amount.min(project_details.remaining_contribution_tokens)
+			} else {
+				ct_amount.min(project_metadata.total_allocation_size)
+			};
+		}
+
+		if project_details.status == ProjectStatus::CommunityRound {
+			let wap = current_bucket.calculate_wap(project_metadata.total_allocation_size);
+			let ct_amount = wap.reciprocal().expect("Bad math").saturating_mul_int(usd_ticket_size);
+			return ct_amount.min(project_details.remaining_contribution_tokens);
+		}
+
+		// Descending auctions sell every token at the current price, which only moves with time.
+		if let SaleMode::DutchAuction { .. } = project_metadata.sale_mode {
+			let now = <T as Config>::BlockNumberProvider::current_block_number();
+			Self::refresh_bucket_price(&project_metadata, &project_details, &mut current_bucket, now);
+			if above_max_price(current_bucket.current_price) {
+				return Zero::zero();
+			}
+			let ct_amount =
+				current_bucket.current_price.reciprocal().expect("Bad math").saturating_mul_int(usd_ticket_size);
+			return ct_amount.min(current_bucket.amount_left);
+		}
+
+		let mut ct_amount = Balance::zero();
+
+		let mut usd_to_spend = usd_ticket_size;
 		while usd_to_spend > Zero::zero() {
 			let bucket_price = current_bucket.current_price;
+			if above_max_price(bucket_price) {
+				break;
+			}
 
 			let ct_to_buy = bucket_price.reciprocal().expect("Bad math").saturating_mul_int(usd_to_spend);
 			let ct_to_buy = ct_to_buy.min(current_bucket.amount_left);
@@ -224,7 +298,7 @@ impl<T: Config> Pallet<T> {
 			current_bucket.update(ct_to_buy);
 		}
 
-		(ct_amount, fee_funding_asset_amount)
+		ct_amount
 	}
 
 	pub fn get_next_vesting_schedule_merge_candidates(
@@ -270,7 +344,11 @@ impl<T: Config> Pallet<T> {
 		let otm_multiplier: MultiplierOf<T> = ParticipationMode::OTM.multiplier().try_into().ok()?;
 		let required_usd_bond = otm_multiplier.calculate_usd_bonding_requirement::<T>(usd_amount)?;
 		let plmc_bond = plmc_price.reciprocal()?.saturating_mul_int(required_usd_bond);
-		pallet_proxy_bonding::Pallet::<T>::calculate_fee(plmc_bond, funding_asset.id()).ok()
+		pallet_proxy_bonding::Pallet::<T>::calculate_fee(
+			plmc_bond,
+			Pallet::<T>::funding_asset_location(funding_asset).ok()?,
+		)
+		.ok()
 	}
 
 	pub fn get_funding_asset_min_max_amounts(
@@ -280,6 +358,7 @@ impl<T: Config> Pallet<T> {
 		investor_type: InvestorType,
 	) -> Option<(Balance, Balance)> {
 		let project_metadata = ProjectsMetadata::<T>::get(project_id)?;
+		let project_details = ProjectsDetails::<T>::get(project_id)?;
 		let funding_asset_price = Pallet::<T>::get_decimals_aware_funding_asset_price(&funding_asset)?;
 
 		let (min_usd_ticket, maybe_max_usd_ticket, already_spent_usd, total_cts_usd_amount) = {
@@ -294,6 +373,18 @@ impl<T: Config> Pallet<T> {
 			let mut total_cts_usd_amount = 0;
 
 			let mut current_bucket = Buckets::<T>::get(project_id)?;
+			if project_details.status == ProjectStatus::CommunityRound {
+				// Only the tokens left after the auction can be bought, at the fixed WAP
+				let wap = current_bucket.calculate_wap(project_metadata.total_allocation_size);
+				total_cts_usd_amount = wap.saturating_mul_int(project_details.remaining_contribution_tokens);
+				max_contribution_tokens = Zero::zero();
+			} else if let SaleMode::DutchAuction { .. } = project_metadata.sale_mode {
+				// Only the tokens left can be bought, at the current price
+				let now = <T as Config>::BlockNumberProvider::current_block_number();
+				Pallet::<T>::refresh_bucket_price(&project_metadata, &project_details, &mut current_bucket, now);
+				total_cts_usd_amount = current_bucket.current_price.saturating_mul_int(current_bucket.amount_left);
+				max_contribution_tokens = Zero::zero();
+			}
 			while max_contribution_tokens > 0u128 {
 				let bucket_price = current_bucket.current_price;
 				let ct_to_buy = max_contribution_tokens.min(current_bucket.amount_left);
@@ -327,8 +418,9 @@ impl<T: Config> Pallet<T> {
 	pub fn get_message_to_sign_by_receiving_account(
 		project_id: ProjectId,
 		polimec_account: AccountIdOf<T>,
+		expiry: BlockNumberFor<T>,
 	) -> Option<String> {
-		Pallet::<T>::get_substrate_message_to_sign(polimec_account, project_id)
+		Pallet::<T>::get_substrate_message_to_sign(polimec_account, project_id, expiry)
 	}
 
 	pub fn usd_target_percent_reached(project_id: ProjectId) -> FixedU128 {
@@ -338,10 +430,65 @@ impl<T: Config> Pallet<T> {
 		FixedU128::from_rational(funding_reached, funding_target)
 	}
 
+	pub fn pro_rata_ct_amount_of_bid(project_id: ProjectId, bid_id: u32) -> Option<Balance> {
+		let project_metadata = ProjectsMetadata::<T>::get(project_id)?;
+		if project_metadata.sale_mode != SaleMode::ProRata {
+			return None;
+		}
+		let bid = Bids::<T>::get(project_id, bid_id)?;
+
+		let ct_amount_bid = ProRataBidCTAmount::<T>::get(project_id);
+		let accepted_ratio = ProRataAcceptedRatio::<T>::get(project_id).unwrap_or_else(|| {
+			if ct_amount_bid > project_metadata.total_allocation_size {
+				Perquintill::from_rational(project_metadata.total_allocation_size, ct_amount_bid)
+			} else {
+				Perquintill::one()
+			}
+		});
+
+		Some(accepted_ratio.mul_floor(bid.original_ct_amount))
+	}
+
+	pub fn current_auction_price(project_id: ProjectId) -> Option<PriceOf<T>> {
+		let project_details = ProjectsDetails::<T>::get(project_id)?;
+		if project_details.status != ProjectStatus::AuctionRound {
+			return DutchAuctionClearingPrice::<T>::get(project_id);
+		}
+		let project_metadata = ProjectsMetadata::<T>::get(project_id)?;
+		let mut bucket = Buckets::<T>::get(project_id)?;
+
+		let now = <T as Config>::BlockNumberProvider::current_block_number();
+		Self::refresh_bucket_price(&project_metadata, &project_details, &mut bucket, now);
+		Some(bucket.current_price)
+	}
+
+	pub fn migration_confirmation_leaves(project_id: ProjectId) -> Vec<(AccountIdOf<T>, MigrationInfo)> {
+		UserMigrations::<T>::iter_prefix((project_id,))
+			.filter(|(_account, (status, _migrations))| *status == MigrationStatus::NotStarted)
+			.map(|(account, (_status, migrations))| {
+				let migration_info = Self::migration_confirmation_info(&Migrations::from(migrations.into_inner()));
+				(account, migration_info)
+			})
+			.collect()
+	}
+
 	pub fn projects_by_did(did: Did) -> Vec<ProjectId> {
 		ProjectsDetails::<T>::iter()
 			.filter(|(_project_id, project_details)| project_details.issuer_did == did)
 			.map(|(project_id, _)| project_id)
+			.sorted()
 			.collect()
 	}
+
+	pub fn active_projects_by_did(did: Did) -> Vec<ProjectId> {
+		DidWithActiveProjects::<T>::get(did).into_inner()
+	}
+
+	pub fn project_success_thresholds(project_id: ProjectId) -> Option<(Percent, Perquintill)> {
+		let project_metadata = ProjectsMetadata::<T>::get(project_id)?;
+		Some((
+			Self::evaluation_success_threshold(&project_metadata),
+			Self::funding_success_threshold(&project_metadata),
+		))
+	}
 }
@@ -5,10 +5,10 @@ use itertools::{izip, GroupBy};
 #[allow(clippy::wildcard_imports)]
 use polimec_common::assets::AcceptedFundingAsset;
 use polimec_common::{
-	assets::AcceptedFundingAsset::{DOT, ETH, USDC, USDT},
+	assets::{DOT, ETH, USDC, USDT},
 	ProvideAssetPrice,
 };
-use sp_core::{blake2_256, ecdsa, hexdisplay::AsBytesRef, keccak_256, sr25519, Pair};
+use sp_core::{blake2_256, ecdsa, ed25519, hexdisplay::AsBytesRef, keccak_256, sr25519, Pair};
 use sp_runtime::traits::TrailingZeroInput;
 use InvestorType::{self, *};
 
@@ -172,7 +172,7 @@ impl<
 			if bid.mode == ParticipationMode::OTM {
 				self.add_otm_fee_to(&mut funding_asset_spent, usd_ticket_size, bid.asset);
 			}
-			output.push(UserToFundingAsset::new(bid.bidder.clone(), funding_asset_spent, bid.asset.id()));
+			output.push(UserToFundingAsset::new(bid.bidder.clone(), funding_asset_spent, bid.asset.id().unwrap()));
 		}
 		output
 	}
@@ -194,7 +194,7 @@ impl<
 				self.add_otm_fee_to(&mut funding_asset_spent, usd_ticket_size, bid.asset);
 			}
 
-			output.push(UserToFundingAsset::<T>::new(bid.bidder.clone(), funding_asset_spent, bid.asset.id()));
+			output.push(UserToFundingAsset::<T>::new(bid.bidder.clone(), funding_asset_spent, bid.asset.id().unwrap()));
 		}
 
 		output.merge_accounts(MergeOperation::Add)
@@ -227,7 +227,7 @@ impl<
 				}
 
 				if remaining_cts <= Zero::zero() {
-					output.push(UserToFundingAsset::new(bid.bidder, charged_funding_asset, bid.asset.id()));
+					output.push(UserToFundingAsset::new(bid.bidder, charged_funding_asset, bid.asset.id().unwrap()));
 					continue
 				}
 
@@ -243,7 +243,7 @@ impl<
 
 				let returned_foreign_asset = charged_funding_asset - actual_funding_asset_spent;
 
-				output.push(UserToFundingAsset::<T>::new(bid.bidder, returned_foreign_asset, bid.asset.id()));
+				output.push(UserToFundingAsset::<T>::new(bid.bidder, returned_foreign_asset, bid.asset.id().unwrap()));
 			}
 		}
 
@@ -261,8 +261,12 @@ impl<
 			self.execute(|| <PriceProviderOf<T>>::get_decimals_aware_price(&Location::here(), PLMC_DECIMALS).unwrap());
 		let usd_bond = multiplier.calculate_usd_bonding_requirement::<T>(usd_ticket_size).unwrap();
 		let plmc_bond = plmc_usd_price.reciprocal().unwrap().saturating_mul_int(usd_bond);
-		let otm_fee =
-			self.execute(|| <pallet_proxy_bonding::Pallet<T>>::calculate_fee(plmc_bond, funding_asset.id())).unwrap();
+		let otm_fee = self
+			.execute(|| {
+				let funding_asset_location = Pallet::<T>::funding_asset_location(funding_asset)?;
+				<pallet_proxy_bonding::Pallet<T>>::calculate_fee(plmc_bond, funding_asset_location)
+			})
+			.unwrap();
 		*balance += otm_fee;
 	}
 
@@ -401,7 +405,7 @@ impl<
 	) -> Vec<EvaluationParams<T>> {
 		let funding_target = project_metadata.minimum_price.saturating_mul_int(project_metadata.total_allocation_size);
 		// if we use just the threshold, then for big usd targets we lose the evaluation due to PLMC conversion errors in `evaluation_end`
-		let evaluation_fail_percent = <T as Config>::EvaluationSuccessThreshold::get().deconstruct() / 2;
+		let evaluation_fail_percent = Pallet::<T>::evaluation_success_threshold(&project_metadata).deconstruct() / 2;
 
 		let usd_threshold = Percent::from_percent(evaluation_fail_percent) * funding_target;
 
@@ -584,11 +588,16 @@ impl<
 		seed_string: &str,
 		project_id: ProjectId,
 		polimec_account: AccountIdOf<T>,
+		expiry: BlockNumberFor<T>,
 	) -> (Junction, [u8; 65]) {
-		let polimec_account_ss58_string = T::SS58Conversion::convert(polimec_account.clone());
-		let nonce = self.execute(|| frame_system::Pallet::<T>::account_nonce(polimec_account));
-		let message_to_sign =
-			crate::functions::misc::typed_data_v4::get_eip_712_message(&polimec_account_ss58_string, project_id, nonce);
+		let polimec_account_ss58_string = T::SS58Conversion::convert(polimec_account);
+		let domain = self.execute(|| Pallet::<T>::receiving_account_signature_domain());
+		let message_to_sign = crate::functions::misc::typed_data_v4::get_eip_712_message(
+			&polimec_account_ss58_string,
+			project_id,
+			expiry.saturated_into(),
+			domain,
+		);
 		let ecdsa_pair = ecdsa::Pair::from_string(seed_string, None).unwrap();
 		let signature = ecdsa_pair.sign_prehashed(&message_to_sign);
 		let mut signature_bytes = [0u8; 65];
@@ -616,9 +625,10 @@ impl<
 		seed_string: &str,
 		project_id: ProjectId,
 		polimec_account: AccountIdOf<T>,
+		expiry: BlockNumberFor<T>,
 	) -> (Junction, [u8; 65]) {
 		let message_to_sign =
-			self.execute(|| Pallet::<T>::get_substrate_message_to_sign(polimec_account, project_id)).unwrap();
+			self.execute(|| Pallet::<T>::get_substrate_message_to_sign(polimec_account, project_id, expiry)).unwrap();
 		let message_to_sign = message_to_sign.into_bytes();
 
 		let sr_pair = sr25519::Pair::from_string(seed_string, None).unwrap();
@@ -629,6 +639,51 @@ impl<
 		(junction, signature_bytes)
 	}
 
+	pub fn sol_key_and_sig_from(
+		&mut self,
+		seed_string: &str,
+		project_id: ProjectId,
+		polimec_account: AccountIdOf<T>,
+		expiry: BlockNumberFor<T>,
+	) -> (Junction, [u8; 65]) {
+		let message_to_sign =
+			self.execute(|| Pallet::<T>::get_substrate_message_to_sign(polimec_account, project_id, expiry)).unwrap();
+		let offchain_message =
+			crate::functions::misc::solana_offchain_message::get_offchain_message(message_to_sign.as_bytes()).unwrap();
+
+		let ed_pair = ed25519::Pair::from_string(seed_string, None).unwrap();
+		let signature = ed_pair.sign(&offchain_message);
+		let mut signature_bytes = [0u8; 65];
+		signature_bytes[..64].copy_from_slice(signature.as_bytes_ref());
+		let junction = Junction::AccountId32 { network: None, id: ed_pair.public().to_raw() };
+		(junction, signature_bytes)
+	}
+
+	pub fn cosmos_key_and_sig_from(
+		&mut self,
+		seed_string: &str,
+		bech32_prefix: &[u8],
+		project_id: ProjectId,
+		polimec_account: AccountIdOf<T>,
+		expiry: BlockNumberFor<T>,
+	) -> (Junction, [u8; 65]) {
+		use crate::functions::misc::cosmos_adr_036;
+
+		let message_to_sign =
+			self.execute(|| Pallet::<T>::get_substrate_message_to_sign(polimec_account, project_id, expiry)).unwrap();
+		let ecdsa_pair = ecdsa::Pair::from_string(seed_string, None).unwrap();
+		let cosmos_account = cosmos_adr_036::get_account_from_public(&ecdsa_pair.public());
+		let signer = cosmos_adr_036::bech32_encode(bech32_prefix, &cosmos_account);
+		let hashed_message = cosmos_adr_036::get_sign_doc_hash(&signer, message_to_sign.as_bytes());
+
+		// Cosmos wallets don't return the recovery id, so we leave it out.
+		let signature = ecdsa_pair.sign_prehashed(&hashed_message);
+		let mut signature_bytes = [0u8; 65];
+		signature_bytes[..64].copy_from_slice(&signature.as_bytes_ref()[..64]);
+		let junction = Junction::AccountKey20 { network: None, key: cosmos_account };
+		(junction, signature_bytes)
+	}
+
 	pub fn account_from_u32(&self, x: u32, seed: &str) -> AccountIdOf<T> {
 		let entropy = (x, seed).using_encoded(blake2_256);
 		Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
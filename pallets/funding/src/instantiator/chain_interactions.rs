@@ -386,6 +386,11 @@ impl<
 		}
 		let project_details = self.get_project_details(project_id);
 
+		// Rounds with an end are transitioned automatically on the block after it
+		if project_details.status != original_state {
+			return project_details.status;
+		}
+
 		match project_details.status {
 			ProjectStatus::Application => {
 				self.execute(|| <Pallet<T>>::do_start_evaluation(issuer, project_id).unwrap());
@@ -397,9 +402,15 @@ impl<
 				self.process_oversubscribed_bids(project_id);
 				self.execute(|| <Pallet<T>>::do_end_funding(project_id).unwrap());
 			},
+			ProjectStatus::CommunityRound => {
+				self.execute(|| <Pallet<T>>::do_end_funding(project_id).unwrap());
+			},
 			ProjectStatus::FundingSuccessful | ProjectStatus::FundingFailed => {
 				self.execute(|| <Pallet<T>>::do_start_settlement(project_id).unwrap());
 			},
+			ProjectStatus::AwaitingIssuerDecision => {
+				self.execute(|| <Pallet<T>>::do_apply_default_project_outcome(project_id).unwrap());
+			},
 			_ => panic!("Unexpected project status"),
 		}
 		let new_details = self.get_project_details(project_id);
@@ -468,6 +479,7 @@ impl<
 					investor_type: bid.investor_type,
 					whitelisted_policy: project_policy.clone(),
 					receiving_account: bid.receiving_account,
+					max_price: None,
 				};
 				crate::Pallet::<T>::do_bid(params)
 			})?;
@@ -475,6 +487,52 @@ impl<
 		Ok(().into())
 	}
 
+	pub fn mint_necessary_tokens_for_contributions(&mut self, project_id: ProjectId, contributions: Vec<BidParams<T>>) {
+		let wap = self.get_community_round_price(project_id);
+
+		let necessary_plmc = self.calculate_auction_plmc_charged_with_given_price(&contributions, wap);
+		let necessary_funding_assets =
+			self.calculate_auction_funding_asset_charged_with_given_price(&contributions, wap);
+
+		self.mint_plmc_ed_if_required(necessary_plmc.accounts());
+		self.mint_funding_asset_ed_if_required(necessary_funding_assets.to_account_asset_map());
+		self.mint_plmc_to(necessary_plmc);
+		self.mint_funding_asset_to(necessary_funding_assets);
+	}
+
+	pub fn contribute_for_users(
+		&mut self,
+		project_id: ProjectId,
+		contributions: Vec<BidParams<T>>,
+	) -> DispatchResultWithPostInfo {
+		let project_policy = self.get_project_metadata(project_id).policy_ipfs_cid.unwrap();
+
+		for contribution in contributions {
+			self.execute(|| {
+				let did = generate_did_from_account(contribution.bidder.clone());
+				let params = DoContributeParams::<T> {
+					contributor: contribution.bidder.clone(),
+					project_id,
+					ct_amount: contribution.amount,
+					mode: contribution.mode,
+					funding_asset: contribution.asset,
+					did,
+					investor_type: contribution.investor_type,
+					whitelisted_policy: project_policy.clone(),
+					receiving_account: contribution.receiving_account,
+				};
+				crate::Pallet::<T>::do_contribute(params)
+			})?;
+		}
+		Ok(().into())
+	}
+
+	/// The fixed price of the community round, i.e. the weighted average price of the auction.
+	pub fn get_community_round_price(&mut self, project_id: ProjectId) -> PriceOf<T> {
+		let project_metadata = self.get_project_metadata(project_id);
+		self.execute(|| Buckets::<T>::get(project_id).unwrap().calculate_wap(project_metadata.total_allocation_size))
+	}
+
 	pub fn process_oversubscribed_bids(&mut self, project_id: ProjectId) {
 		self.execute(|| while Pallet::<T>::do_process_next_oversubscribed_bid(project_id).is_ok() {});
 	}
@@ -487,6 +545,10 @@ impl<
 			Bids::<T>::iter_prefix(project_id)
 				.for_each(|(_, bid)| Pallet::<T>::do_settle_bid(project_id, bid.id).unwrap());
 
+			Contributions::<T>::iter_prefix(project_id).for_each(|(_, contribution)| {
+				Pallet::<T>::do_settle_contribution(project_id, contribution.id).unwrap()
+			});
+
 			if mark_as_settled {
 				crate::Pallet::<T>::do_mark_project_as_settled(project_id).unwrap();
 			}
@@ -501,6 +563,10 @@ impl<
 		self.execute(|| Bids::<T>::iter_prefix_values(project_id).collect())
 	}
 
+	pub fn get_contributions(&mut self, project_id: ProjectId) -> Vec<ContributionInfoOf<T>> {
+		self.execute(|| Contributions::<T>::iter_prefix_values(project_id).collect())
+	}
+
 	// Used to check all the USDT/USDC/DOT was paid to the issuer funding account
 	pub fn assert_total_funding_paid_out(&mut self, project_id: ProjectId, bids: Vec<BidInfoOf<T>>) {
 		let project_metadata = self.get_project_metadata(project_id);
@@ -515,23 +581,24 @@ impl<
 				AcceptedFundingAsset::USDT => total_expected_usdt += bid.funding_asset_amount_locked,
 				AcceptedFundingAsset::USDC => total_expected_usdc += bid.funding_asset_amount_locked,
 				AcceptedFundingAsset::ETH => total_expected_eth += bid.funding_asset_amount_locked,
+				_ => panic!("Only the built-in funding assets are paid out in this check"),
 			}
 		}
 
 		let total_stored_dot = self.get_free_funding_asset_balance_for(
-			AcceptedFundingAsset::DOT.id(),
+			AcceptedFundingAsset::DOT.id().unwrap(),
 			project_metadata.funding_destination_account.clone(),
 		);
 		let total_stored_usdt = self.get_free_funding_asset_balance_for(
-			AcceptedFundingAsset::USDT.id(),
+			AcceptedFundingAsset::USDT.id().unwrap(),
 			project_metadata.funding_destination_account.clone(),
 		);
 		let total_stored_usdc = self.get_free_funding_asset_balance_for(
-			AcceptedFundingAsset::USDC.id(),
+			AcceptedFundingAsset::USDC.id().unwrap(),
 			project_metadata.funding_destination_account.clone(),
 		);
 		let total_stored_eth = self.get_free_funding_asset_balance_for(
-			AcceptedFundingAsset::ETH.id(),
+			AcceptedFundingAsset::ETH.id().unwrap(),
 			project_metadata.funding_destination_account,
 		);
 
@@ -783,14 +850,40 @@ impl<
 
 		assert_eq!(self.get_plmc_total_supply(), expected_plmc_supply);
 
-		let status = self.go_to_next_state(project_id);
+		let mut status = self.go_to_next_state(project_id);
+		if status == ProjectStatus::CommunityRound {
+			status = self.go_to_next_state(project_id);
+		}
 
-		if status == ProjectStatus::FundingSuccessful || status == ProjectStatus::FundingFailed {
+		if matches!(
+			status,
+			ProjectStatus::FundingSuccessful | ProjectStatus::FundingFailed | ProjectStatus::AwaitingIssuerDecision
+		) {
 			self.test_ct_not_created_for(project_id);
 		} else {
-			panic!("Project should be in FundingSuccessful or FundingFailed status");
+			panic!("Project should be in FundingSuccessful, FundingFailed or AwaitingIssuerDecision status");
+		}
+
+		project_id
+	}
+
+	pub fn create_community_contributing_project(
+		&mut self,
+		project_metadata: ProjectMetadataOf<T>,
+		issuer: AccountIdOf<T>,
+		maybe_did: Option<Did>,
+		evaluations: Vec<EvaluationParams<T>>,
+		bids: Vec<BidParams<T>>,
+	) -> ProjectId {
+		let project_id = self.create_auctioning_project(project_metadata, issuer, maybe_did, evaluations);
+
+		if !bids.is_empty() {
+			self.mint_necessary_tokens_for_bids(project_id, bids.clone());
+			self.bid_for_users(project_id, bids).unwrap();
 		}
 
+		assert_eq!(self.go_to_next_state(project_id), ProjectStatus::CommunityRound);
+
 		project_id
 	}
 
@@ -206,7 +206,7 @@ impl<T: Config> From<(AccountIdOf<T>, Balance, AssetIdOf<T>)> for UserToFundingA
 }
 impl<T: Config> From<(AccountIdOf<T>, Balance)> for UserToFundingAsset<T> {
 	fn from((account, asset_amount): (AccountIdOf<T>, Balance)) -> Self {
-		UserToFundingAsset::<T>::new(account, asset_amount, AcceptedFundingAsset::USDT.id())
+		UserToFundingAsset::<T>::new(account, asset_amount, AcceptedFundingAsset::USDT.id().unwrap())
 	}
 }
 impl<T: Config> Accounts for Vec<UserToFundingAsset<T>> {
@@ -427,7 +427,7 @@ impl<T: Config> From<(AccountIdOf<T>, InvestorType, Balance, ParticipationMode,
 }
 impl<T: Config> From<BidParams<T>> for (AccountIdOf<T>, AssetIdOf<T>) {
 	fn from(bid: BidParams<T>) -> (AccountIdOf<T>, AssetIdOf<T>) {
-		(bid.bidder, bid.asset.id())
+		(bid.bidder, bid.asset.id().unwrap())
 	}
 }
 
@@ -449,7 +449,7 @@ impl<T: Config> Conversions for Vec<BidParams<T>> {
 	fn to_account_asset_map(&self) -> Vec<(Self::AccountId, Self::AssetId)> {
 		let mut btree = BTreeSet::new();
 		for BidParams { bidder, asset, .. } in self.iter() {
-			btree.insert((bidder.clone(), asset.id()));
+			btree.insert((bidder.clone(), asset.id().unwrap()));
 		}
 		btree.into_iter().collect_vec()
 	}
@@ -32,6 +32,7 @@
 //!     target funding (in USD) is locked in PLMC, a project is given access to the funding round. Evaluators are either
 //!     rewarded in contribution tokens if the project gets funded, or have their PLMC slashed otherwise.
 //! - **Bidders**: They contribute financially to projects by locking PLMC and paying out USDT/USDC/DOT, and are rewarded in contribution tokens.
+//! - **Contributors**: They buy the tokens left unsold after the auction at a fixed price, and are rewarded in contribution tokens.
 //!
 //! Users need to go through a KYC/AML by a third party in order to use the protocol. This process classifies them
 //! into one of the following categories, based on their investment experience and financial status:
@@ -46,18 +47,36 @@
 //! 4) **Evaluation End**: Anyone can end the evaluation round with the [`end_evaluation`](Pallet::end_evaluation) extrinsic after the defined end block.
 //! 5) **Auction Start**: If the project receives at least 10% of its target funding (in USD) in PLMC bonded, the auction starts immediately after `end_evaluation` is called.
 //! 6) **Bid**: Investors can place bids on the project using the [`bid`](Pallet::bid) extrinsic. The price starts at the issuer-defined minimum, and increases by increments of 10% in price and bucket size.
-//! 7) **Funding End**: Anyone can end the project with the [`end_project`](Pallet::end_project) extrinsic after the defined end block.
+//! 7) **Community Round Start**: Anyone can end the auction with the [`end_funding`](Pallet::end_funding) extrinsic after the defined end block.
+//!     If the auction did not sell out, the remaining tokens are offered in the community round.
+//! 8) **Contribute**: Investors can buy the remaining tokens using the [`contribute`](Pallet::contribute) extrinsic, at the weighted average price of the auction.
+//! 9) **Funding End**: Anyone can end the project with the [`end_funding`](Pallet::end_funding) extrinsic after the defined end block.
 //!     The project will now be considered Failed if it reached <=33% of its target funding in USD, and Successful otherwise.
-//! 8) **Settlement Start**: Anyone can start the settlement process with the [`start_settlement`](Pallet::start_settlement) extrinsic after the defined end block.
-//! 9) **Settle Evaluation**: Anyone can now settle an evaluation with the [`settle_evaluation`](Pallet::settle_evaluation) extrinsic.
+//!     If it ended between the issuer decision threshold and the success threshold, the issuer accepts or rejects the
+//!     funding with the [`decide_project_outcome`](Pallet::decide_project_outcome) extrinsic. Otherwise it is accepted
+//!     automatically once the deadline passes.
+//! 10) **Settlement Start**: Anyone can start the settlement process with the [`start_settlement`](Pallet::start_settlement) extrinsic after the defined end block.
+//! 11) **Settle Evaluation**: Anyone can now settle an evaluation with the [`settle_evaluation`](Pallet::settle_evaluation) extrinsic.
 //!     This will unlock the PLMC bonded, and either apply a slash to the PLMC, or reward CTs to the evaluator.
-//! 10) **Settle Bid**: Anyone can now settle a bid with the [`settle_bid`](Pallet::settle_bid) extrinsic.
+//! 12) **Settle Bid**: Anyone can now settle a bid with the [`settle_bid`](Pallet::settle_bid) extrinsic.
 //!     This will set a vesting schedule on the PLMC bonded, and pay out the funding assets to the issuer. It will also issue refunds in case the bid failed,
 //!     or the price paid was higher than the weighted average price.
-//! 11) **Settlement End**: Anyone can now mark the project settlement as finished by calling the [`mark_project_as_settled`](Pallet::mark_project_as_settled) extrinsic.
-//! 12) **Migration Start**: Once the issuer has tokens to distribute on mainnet, he can start the migration process with the [`start_offchain`](Pallet::start_offchain_migration) extrinsic.
-//! 13) **Confirm Migration**: The issuer has to mark each participant's CTs as migrated with the [`confirm_offchain_migration`](Pallet::confirm_offchain_migration) extrinsic.
-//! 14) **Migration End**: Once all participants have migrated their CTs, anyone can mark the migration as finished with the [`mark_project_ct_migration_as_finished`](Pallet::mark_project_ct_migration_as_finished) extrinsic.
+//! 13) **Settle Contribution**: Anyone can now settle a contribution with the [`settle_contribution`](Pallet::settle_contribution) extrinsic.
+//!     This will set a vesting schedule on the PLMC bonded, and pay out the funding assets to the issuer, or refund them if the project failed.
+//!     If the project vests its contribution tokens, they are frozen in the accounts of bidders and contributors, who
+//!     unfreeze them as they vest with the [`claim_vested_contribution_tokens`](Pallet::claim_vested_contribution_tokens) extrinsic.
+//! 14) **Settlement End**: Anyone can now mark the project settlement as finished by calling the [`mark_project_as_settled`](Pallet::mark_project_as_settled) extrinsic.
+//! 15) **Migration Start**: Once the issuer has tokens to distribute on mainnet, he can start the migration process with the [`start_offchain`](Pallet::start_offchain_migration) extrinsic.
+//!     Projects with their own parachain can instead use the [`start_pallet_migration`](Pallet::start_pallet_migration) extrinsic.
+//!     The HRMP channels are then opened, and the [`start_pallet_migration_readiness_check`](Pallet::start_pallet_migration_readiness_check)
+//!     extrinsic checks that the parachain can receive the migrations.
+//! 16) **Confirm Migration**: The issuer has to mark each participant's CTs as migrated with the [`confirm_offchain_migration`](Pallet::confirm_offchain_migration) extrinsic.
+//!     Alternatively, the issuer submits the merkle root of a batch of migrated participants with the [`submit_migration_confirmation_root`](Pallet::submit_migration_confirmation_root) extrinsic,
+//!     and anyone confirms each participant of the batch with the [`prove_offchain_migration`](Pallet::prove_offchain_migration) extrinsic.
+//!     With the pallet migration, anyone sends them to the parachain with the [`send_pallet_migration_for`](Pallet::send_pallet_migration_for) extrinsic,
+//!     and they are confirmed when the receiver pallet reports back.
+//!     Participants who did not receive their mainnet tokens can [`dispute`](Pallet::dispute_migration) the confirmation for a while, and governance [`resolves`](Pallet::resolve_migration_dispute) the dispute.
+//! 17) **Migration End**: Once all participants have migrated their CTs and no disputes are open, anyone can mark the migration as finished with the [`mark_project_ct_migration_as_finished`](Pallet::mark_project_ct_migration_as_finished) extrinsic.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -72,6 +91,7 @@ extern crate alloc;
 
 pub use crate::weights::WeightInfo;
 use alloc::string::String;
+use core::ops::RangeInclusive;
 use frame_support::{
 	traits::{
 		tokens::{fungible, fungibles},
@@ -99,6 +119,7 @@ pub mod migrations;
 pub mod traits;
 pub mod types;
 pub mod weights;
+pub mod xcm_executor_impl;
 
 #[cfg(test)]
 pub mod mock;
@@ -130,6 +151,8 @@ pub type ProjectMetadataOf<T> = ProjectMetadata<BoundedVec<u8, StringLimitOf<T>>
 pub type ProjectDetailsOf<T> = ProjectDetails<AccountIdOf<T>, Did, BlockNumberFor<T>, EvaluationRoundInfo>;
 pub type EvaluationInfoOf<T> = EvaluationInfo<u32, Did, ProjectId, AccountIdOf<T>, BlockNumberFor<T>>;
 pub type BidInfoOf<T> = BidInfo<ProjectId, Did, PriceOf<T>, AccountIdOf<T>, BlockNumberFor<T>>;
+pub type ScheduledBidInfoOf<T> = ScheduledBidInfo<ProjectId, Did, PriceOf<T>, AccountIdOf<T>, BlockNumberFor<T>>;
+pub type ContributionInfoOf<T> = ContributionInfo<ProjectId, Did, PriceOf<T>, AccountIdOf<T>, BlockNumberFor<T>>;
 pub type BucketOf<T> = Bucket<PriceOf<T>>;
 pub type WeightInfoOf<T> = <T as Config>::WeightInfo;
 pub type VestingOf<T> = pallet_linear_release::Pallet<T>;
@@ -152,7 +175,10 @@ pub mod pallet {
 	};
 	use frame_system::pallet_prelude::*;
 	use on_slash_vesting::OnSlash;
-	use polimec_common::{assets::AcceptedFundingAsset, ProvideAssetPrice};
+	use polimec_common::{
+		assets::{AcceptedFundingAsset, FundingAssetInfo, MAX_FUNDING_ASSETS},
+		ProvideAssetPrice,
+	};
 	use sp_arithmetic::Percent;
 	use sp_runtime::{
 		traits::{ConstU8, Convert, ConvertBack, Get},
@@ -163,6 +189,12 @@ pub mod pallet {
 	pub enum HoldReason {
 		Evaluation,
 		Participation,
+		ScheduledBid,
+	}
+
+	#[pallet::composite_enum]
+	pub enum FreezeReason {
+		ContributionTokenVesting,
 	}
 
 	#[pallet::pallet]
@@ -182,7 +214,7 @@ pub mod pallet {
 			UsdDecimals = ConstU8<USD_DECIMALS>,
 			FeeToken = Self::FundingCurrency,
 			PriceProvider = PriceProviderOf<Self>,
-		>
+		> + pallet_xcm::Config
 	{
 		/// A way to convert from and to the account type used in CT migrations
 		type AccountId32Conversion: ConvertBack<Self::AccountId, [u8; 32]>;
@@ -214,6 +246,14 @@ pub mod pallet {
 			+ AccountTouch<ProjectId, AccountIdOf<Self>, Balance = Balance>
 			+ ContainsPair<ProjectId, AccountIdOf<Self>>;
 
+		/// Freezes the contribution tokens that did not vest yet in the participant accounts (i.e pallet-assets-freezer)
+		type ContributionTokenFreezer: fungibles::MutateFreeze<
+			AccountIdOf<Self>,
+			AssetId = ProjectId,
+			Balance = Balance,
+			Id = <Self as Config>::RuntimeFreezeReason,
+		>;
+
 		/// Convert 24 hours as FixedU128, to the corresponding amount of blocks in the same type as frame_system
 		type DaysToBlocks: Convert<FixedU128, BlockNumberFor<Self>>;
 
@@ -221,6 +261,14 @@ pub mod pallet {
 		#[pallet::constant]
 		type AuctionRoundDuration: Get<BlockNumberFor<Self>>;
 
+		/// The length (expressed in number of blocks) of the Community Round.
+		#[pallet::constant]
+		type CommunityRoundDuration: Get<BlockNumberFor<Self>>;
+
+		/// Origin that can halt the participations of a live project, and force it to fail, e.g. when it turns out
+		/// to be fraudulent.
+		type EmergencyOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
 		/// The length (expressed in number of blocks) of the evaluation period.
 		#[pallet::constant]
 		type EvaluationRoundDuration: Get<BlockNumberFor<Self>>;
@@ -229,13 +277,18 @@ pub mod pallet {
 		#[pallet::constant]
 		type EvaluationSuccessThreshold: Get<Percent>;
 
+		/// Range of evaluation success thresholds a project can declare instead of `EvaluationSuccessThreshold`.
+		type EvaluationSuccessThresholdBounds: Get<RangeInclusive<Percent>>;
+
 		/// How much an evaluation should be slashed if it the project doesn't reach a certain theshold of funding.
 		#[pallet::constant]
 		type EvaluatorSlash: Get<Percent>;
 
-		/// The fee brackets for the project's funding
-		#[pallet::constant]
-		type FeeBrackets: Get<Vec<(Percent, Balance)>>;
+		/// Origin that updates the fee brackets and the shares of the fees given as rewards.
+		type FeeConfigOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+		/// Origin that registers, updates and disables the funding assets.
+		type FundingAssetOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
 
 		/// The currency used for funding projects in bids and contributions
 		type FundingCurrency: fungibles::InspectEnumerable<AccountIdOf<Self>, Balance = Balance, AssetId = Location>
@@ -243,14 +296,67 @@ pub mod pallet {
 			+ fungibles::metadata::Mutate<AccountIdOf<Self>, AssetId = Location>
 			+ fungibles::Mutate<AccountIdOf<Self>, Balance = Balance>;
 
+		/// What percentage of the allocation has to be sold for the funding to be successful, unless the project
+		/// declares its own threshold.
 		type FundingSuccessThreshold: Get<Perquintill>;
 
+		/// Range of funding success thresholds a project can declare instead of `FundingSuccessThreshold`. Its lower
+		/// end must not be below `IssuerDecisionThreshold`, which the integrity test enforces.
+		type FundingSuccessThresholdBounds: Get<RangeInclusive<Perquintill>>;
+
 		/// Credentialized investor Origin, ensures users are of investing type Retail, or Professional, or Institutional.
 		type InvestorOrigin: EnsureOriginWithCredentials<
 			<Self as frame_system::Config>::RuntimeOrigin,
 			Success = (AccountIdOf<Self>, Did, InvestorType, Cid),
 		>;
 
+		/// How long the issuer has to accept or reject a raise that ended in `AwaitingIssuerDecision`. After it,
+		/// `DefaultFundingOutcomeDecision` is applied automatically.
+		#[pallet::constant]
+		type IssuerDecisionDuration: Get<BlockNumberFor<Self>>;
+
+		/// Decision applied to a raise in `AwaitingIssuerDecision` whose issuer did not decide before the
+		/// `IssuerDecisionDuration` deadline.
+		#[pallet::constant]
+		type DefaultFundingOutcomeDecision: Get<FundingOutcomeDecision>;
+
+		/// Raises that sold at least this percentage of the allocation, but less than `FundingSuccessThreshold`, let
+		/// the issuer decide if the funding is accepted or rejected.
+		#[pallet::constant]
+		type IssuerDecisionThreshold: Get<Perquintill>;
+
+		/// Maximum number of projects a single issuer DID can have active at the same time. A project stops being
+		/// active once its funding round ends, or it fails the evaluation.
+		#[pallet::constant]
+		type MaxActiveProjectsPerDid: Get<u32>;
+
+		/// Range of `max_capacity` values of an incoming HRMP channel request from a project's parachain that we accept.
+		type MaxCapacityThresholds: Get<RangeInclusive<u32>>;
+
+		/// Range of `max_message_size` values of an incoming HRMP channel request from a project's parachain that we
+		/// accept.
+		type MaxMessageSizeThresholds: Get<RangeInclusive<u32>>;
+
+		/// Maximum weight spent placing scheduled bids at the start of a block. It also limits how many bids can be
+		/// scheduled for the same block, so they are all placed in it.
+		#[pallet::constant]
+		type MaxScheduledBidsWeightPerBlock: Get<Weight>;
+
+		/// Maximum number of scheduled project transitions done in a block. The ones left are done on the next blocks.
+		#[pallet::constant]
+		type MaxTransitionsPerBlock: Get<u32>;
+
+		/// Origin that resolves the disputes of participants over the confirmation of their CT migration.
+		type MigrationDisputeOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+		/// Blocks after the confirmation of a CT migration during which the participant can dispute it.
+		#[pallet::constant]
+		type MigrationDisputeWindow: Get<BlockNumberFor<Self>>;
+
+		/// Origin that approves or rejects the funding milestones of projects, releasing the escrowed funds to the
+		/// issuer or letting the participants claim them back.
+		type MilestoneOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
 		#[pallet::constant]
 		type MinUsdPerEvaluation: Get<Balance>;
 
@@ -276,12 +382,26 @@ pub mod pallet {
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
 
+		/// Pallet that projects need in their runtime to receive the CT migrations through XCM.
+		type PolimecReceiverInfo: Get<xcm::v5::PalletInfo>;
+
 		/// Type that represents the value of something in USD
 		type Price: FixedPointNumber + Parameter + Copy + MaxEncodedLen + MaybeSerializeDeserialize;
 
 		/// Method to get the price of an asset like USDT or PLMC. Likely to come from an oracle
 		type PriceProvider: ProvideAssetPrice<AssetId = AssetIdOf<Self>, Price = Self::Price>;
 
+		/// `max_capacity` of the HRMP channel requested from Polimec to a project's parachain.
+		#[pallet::constant]
+		type RequiredMaxCapacity: Get<u32>;
+
+		/// `max_message_size` of the HRMP channel requested from Polimec to a project's parachain.
+		#[pallet::constant]
+		type RequiredMaxMessageSize: Get<u32>;
+
+		/// The call enum constructed by the construct_runtime macro. Used for the XCM query response callbacks.
+		type RuntimeCall: Parameter + IsType<<Self as pallet_xcm::Config>::RuntimeCall> + From<Call<Self>>;
+
 		/// The event enum constructed by the construct_runtime macro
 		type RuntimeEvent: From<Event<Self>>
 			+ TryInto<Event<Self>>
@@ -292,8 +412,12 @@ pub mod pallet {
 		/// The hold reason enum constructed by the construct_runtime macro
 		type RuntimeHoldReason: From<HoldReason> + Parameter + MaxEncodedLen + Copy;
 
+		/// The freeze reason enum constructed by the construct_runtime macro
+		type RuntimeFreezeReason: From<FreezeReason> + Parameter + MaxEncodedLen + Copy;
+
 		/// The origin enum constructed by the construct_runtime macro
-		type RuntimeOrigin: IsType<<Self as frame_system::Config>::RuntimeOrigin>;
+		type RuntimeOrigin: IsType<<Self as frame_system::Config>::RuntimeOrigin>
+			+ Into<Result<pallet_xcm::Origin, <Self as Config>::RuntimeOrigin>>;
 
 		/// test and benchmarking helper to set the prices of assets
 		#[cfg(feature = "runtime-benchmarks")]
@@ -337,6 +461,10 @@ pub mod pallet {
 	/// An increasing counter to assign a unique id to bids
 	pub type NextBidId<T: Config> = StorageValue<_, u32, ValueQuery>;
 
+	#[pallet::storage]
+	/// An increasing counter to assign a unique id to contributions
+	pub type NextContributionId<T: Config> = StorageValue<_, u32, ValueQuery>;
+
 	#[pallet::storage]
 	/// A StorageMap containing the primary project information of projects
 	pub type ProjectsMetadata<T: Config> = StorageMap<_, Blake2_128Concat, ProjectId, ProjectMetadataOf<T>>;
@@ -353,6 +481,21 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type ProjectsInAuctionRound<T: Config> = StorageMap<_, Blake2_128Concat, ProjectId, ()>;
 
+	/// Used to track which projects need the automatic settlement of their participations in on_idle
+	#[pallet::storage]
+	pub type ProjectsInSettlement<T: Config> = StorageMap<_, Blake2_128Concat, ProjectId, SettlementCursor>;
+
+	/// Projects that should be transitioned automatically at a given block, because their current round ended or
+	/// because they are waiting for the settlement to start.
+	#[pallet::storage]
+	pub type ProjectTransitionQueue<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Blake2_128Concat, ProjectId, ()>;
+
+	/// The last block for which the `ProjectTransitionQueue` was fully processed. Used to not miss any block when the
+	/// block number provider skips some numbers, or when the transitions of a block did not fit in it.
+	#[pallet::storage]
+	pub type LastTransitionBlockProcessed<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
 	#[pallet::storage]
 	/// Keep track of the PLMC bonds made to each project by each evaluator
 	pub type Evaluations<T: Config> = StorageNMap<
@@ -370,12 +513,23 @@ pub mod pallet {
 	pub type Bids<T: Config> =
 		StorageDoubleMap<_, Blake2_128Concat, ProjectId, Blake2_128Concat, u32, BidInfoOf<T>, OptionQuery>;
 
+	#[pallet::storage]
+	/// Contains the community round contributions for each project
+	pub type Contributions<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ProjectId, Blake2_128Concat, u32, ContributionInfoOf<T>, OptionQuery>;
+
 	/// StorageMap containing the first bid that should be settled at a certain price point, and the last bid available at that price point.
 	/// Bids should be settled from the higest price first, and then from the lowest index first. Both indexes are inclusive.
 	#[pallet::storage]
 	pub type BidsBucketBounds<T: Config> =
 		StorageDoubleMap<_, Blake2_128Concat, ProjectId, Blake2_128Concat, PriceOf<T>, BidBucketBounds, OptionQuery>;
 
+	/// The bids placed right before and after a bid at the same price. Bid ids are shared by all projects and bids
+	/// can be removed, so the bids of a price are walked through this list instead of through their indexes.
+	#[pallet::storage]
+	pub type BidsBucketNeighbours<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ProjectId, Blake2_128Concat, u32, BidBucketNeighbours, OptionQuery>;
+
 	/// This map allows bidders to release their bid early if they were outbid.
 	/// The map contains the bucket price and bid index of the last bid to be outbid.
 	/// Indexes higher than the one stored here in the same bucket can be released.
@@ -391,11 +545,83 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type CTAmountOversubscribed<T: Config> = StorageMap<_, Blake2_128Concat, ProjectId, Balance, ValueQuery>;
 
+	/// Total CT amount bid on a project using `SaleMode::ProRata`. Unlike the bucket auction, it can exceed the allocation.
+	#[pallet::storage]
+	pub type ProRataBidCTAmount<T: Config> = StorageMap<_, Blake2_128Concat, ProjectId, Balance, ValueQuery>;
+
+	/// Share of each bid that is accepted on an oversubscribed `SaleMode::ProRata` project. Set when the funding ends,
+	/// and missing if every bid was fully accepted.
+	#[pallet::storage]
+	pub type ProRataAcceptedRatio<T: Config> = StorageMap<_, Blake2_128Concat, ProjectId, Perquintill, OptionQuery>;
+
+	/// Price every bidder pays on a `SaleMode::DutchAuction` project. Set when the funding ends, to the price at which
+	/// the allocation sold out, or the last price reached if it never did.
+	#[pallet::storage]
+	pub type DutchAuctionClearingPrice<T: Config> = StorageMap<_, Blake2_128Concat, ProjectId, PriceOf<T>, OptionQuery>;
+
+	/// Merkle root of the DIDs allowed to participate in a project. Projects without one are open to everyone.
+	#[pallet::storage]
+	pub type ProjectAllowlists<T: Config> = StorageMap<_, Blake2_128Concat, ProjectId, [u8; 32], OptionQuery>;
+
+	/// USD cap on the bids of each DID that proved to be part of a project allowlist.
+	#[pallet::storage]
+	pub type AllowlistCaps<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ProjectId, Blake2_128Concat, Did, Balance, OptionQuery>;
+
+	/// Contribution tokens bought by each participant on projects with a `ct_vesting` schedule. The part that did not
+	/// vest yet is frozen in the participant account.
+	#[pallet::storage]
+	pub type ContributionTokenVestings<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ProjectId, Blake2_128Concat, AccountIdOf<T>, Balance, ValueQuery>;
+
+	/// Funding assets kept in the project account for the issuer, on projects with funding milestones.
+	#[pallet::storage]
+	pub type MilestoneEscrowTotals<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ProjectId, Blake2_128Concat, AcceptedFundingAsset, Balance, ValueQuery>;
+
+	/// Funding assets paid by each participant that are kept in the project account, on projects with funding
+	/// milestones. Used to refund the tranches of rejected milestones pro-rata.
+	#[pallet::storage]
+	pub type MilestoneEscrowContributions<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, ProjectId>,
+			NMapKey<Blake2_128Concat, AccountIdOf<T>>,
+			NMapKey<Blake2_128Concat, AcceptedFundingAsset>,
+		),
+		Balance,
+		ValueQuery,
+	>;
+
+	/// Decisions taken on the funding milestones of each project, by milestone index.
+	#[pallet::storage]
+	pub type MilestoneOutcomes<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ProjectId, Blake2_128Concat, u32, MilestoneOutcome, OptionQuery>;
+
+	/// Participants that already claimed their refund of a rejected milestone.
+	#[pallet::storage]
+	pub type MilestoneRefundsClaimed<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, ProjectId>,
+			NMapKey<Blake2_128Concat, u32>,
+			NMapKey<Blake2_128Concat, AccountIdOf<T>>,
+		),
+		(),
+		OptionQuery,
+	>;
+
 	/// Stores the total usd amount participated by a user on a project. Used to track the ticket sizes when its capped.
 	#[pallet::storage]
 	pub type AuctionBoughtUSD<T: Config> =
 		StorageNMap<_, (NMapKey<Blake2_128Concat, ProjectId>, NMapKey<Blake2_128Concat, Did>), Balance, ValueQuery>;
 
+	/// Stores the total usd amount bonded by a DID in the evaluations of a project. Used to know who evaluated a project,
+	/// since evaluations are stored by account.
+	#[pallet::storage]
+	pub type EvaluationBondedUSD<T: Config> =
+		StorageNMap<_, (NMapKey<Blake2_128Concat, ProjectId>, NMapKey<Blake2_128Concat, Did>), Balance, ValueQuery>;
+
 	/// Stores the CT amounts and vesting schedules for users with successful bids. Will be used by issuers to mint
 	/// their tokens on TGE
 	#[pallet::storage]
@@ -411,9 +637,147 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type UnmigratedCounter<T: Config> = StorageMap<_, Blake2_128Concat, ProjectId, u32, ValueQuery>;
 
-	/// A map to keep track of what issuer's did has an active project. It prevents one issuer having multiple active projects
+	/// Block at which the CT migration of a participant was confirmed, to check its dispute window. Removed once the
+	/// migration is disputed, since it can only be disputed once.
+	#[pallet::storage]
+	pub type MigrationConfirmedAt<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ProjectId,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		BlockNumberFor<T>,
+		OptionQuery,
+	>;
+
+	/// Number of times the CT migrations of a participant had to be delivered again after an upheld dispute. Sent with
+	/// the migrations, so the receiver pallet executes them again instead of skipping them as already executed.
+	#[pallet::storage]
+	pub type MigrationResendNonces<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ProjectId, Blake2_128Concat, AccountIdOf<T>, u32, ValueQuery>;
+
+	/// Last block at which the latest confirmed CT migration of a project can be disputed. The migration of the project
+	/// cannot be finished until it has passed.
+	#[pallet::storage]
+	pub type MigrationDisputeDeadlines<T: Config> =
+		StorageMap<_, Blake2_128Concat, ProjectId, BlockNumberFor<T>, OptionQuery>;
+
+	/// Number of disputed CT migrations of a project waiting for the governance resolution. The migration of the
+	/// project cannot be finished while there are open disputes.
+	#[pallet::storage]
+	pub type OpenMigrationDisputes<T: Config> = StorageMap<_, Blake2_128Concat, ProjectId, u32, ValueQuery>;
+
+	/// Merkle roots of batches of participants whose offchain migration was completed, with how many participants
+	/// of each batch are left to be confirmed.
+	#[pallet::storage]
+	pub type MigrationConfirmationRoots<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ProjectId, Identity, [u8; 32], u32, OptionQuery>;
+
+	/// Projects migrating their CTs to their own parachain through XCM, with the state of the HRMP channels and
+	/// the readiness checks.
+	#[pallet::storage]
+	pub type PalletMigrations<T: Config> = StorageMap<_, Blake2_128Concat, ProjectId, PalletMigrationInfo, OptionQuery>;
+
+	/// Project migrating its CTs to each parachain in `PalletMigrations`. Used to find the project of the HRMP
+	/// notifications sent by the relay, which only carry the parachain id.
+	#[pallet::storage]
+	pub type PalletMigrationProjectsByParaId<T: Config> =
+		StorageMap<_, Blake2_128Concat, ParaId, ProjectId, OptionQuery>;
+
+	/// Project of each query of the latest readiness check sent to a project's parachain.
+	#[pallet::storage]
+	pub type PalletMigrationProjectsByQueryId<T: Config> =
+		StorageMap<_, Blake2_128Concat, QueryId, ProjectId, OptionQuery>;
+
+	/// Migrations sent to a project's parachain that are waiting for the response of the receiver pallet, by query id.
+	/// Stored with the block at which the query times out, after which the migrations can be marked as failed.
+	#[pallet::storage]
+	pub type ActiveMigrationQueue<T: Config> =
+		StorageMap<_, Blake2_128Concat, QueryId, (ProjectId, AccountIdOf<T>, BlockNumberFor<T>), OptionQuery>;
+
+	/// A map to keep track of the active projects of each issuer's did. It limits how many projects an issuer can run
+	/// at the same time.
+	#[pallet::storage]
+	pub type DidWithActiveProjects<T: Config> =
+		StorageMap<_, Blake2_128Concat, Did, BoundedVec<ProjectId, T::MaxActiveProjectsPerDid>, ValueQuery>;
+
+	/// Registry of the assets that can be used to participate in a project, and to pay the One Token Model fees.
+	#[pallet::storage]
+	pub type FundingAssets<T: Config> =
+		StorageMap<_, Blake2_128Concat, AcceptedFundingAsset, FundingAssetInfo, OptionQuery>;
+
+	/// Account proposed by the issuer of a project to take over its ownership. The transfer completes once that
+	/// account accepts it.
+	#[pallet::storage]
+	pub type PendingProjectTransfers<T: Config> =
+		StorageMap<_, Blake2_128Concat, ProjectId, AccountIdOf<T>, OptionQuery>;
+
+	/// Projects whose participations were halted by the `EmergencyOrigin`, with the reason given for it.
+	#[pallet::storage]
+	pub type HaltedProjects<T: Config> =
+		StorageMap<_, Blake2_128Concat, ProjectId, BoundedVec<u8, StringLimitOf<T>>, OptionQuery>;
+
+	/// Account that receives the contribution tokens of the participations of an account on a project, bound with a
+	/// signature of the receiving account. Participations of accounts not in here deliver to the account itself.
+	#[pallet::storage]
+	pub type ReceivingAccounts<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ProjectId, Blake2_128Concat, AccountIdOf<T>, Junction, OptionQuery>;
+
+	/// Fee configuration given to the projects that start their evaluation from now on.
+	#[pallet::storage]
+	pub type FeeConfiguration<T: Config> = StorageValue<_, FeeConfig, ValueQuery>;
+
+	/// Fee configuration of a project, taken when its evaluation started. Updates of the `FeeConfiguration` don't
+	/// affect the projects that are already live.
+	#[pallet::storage]
+	pub type ProjectFeeConfigs<T: Config> = StorageMap<_, Blake2_128Concat, ProjectId, FeeConfig, OptionQuery>;
+
+	#[pallet::storage]
+	/// An increasing counter to assign a unique id to scheduled bids
+	pub type NextScheduledBidId<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Bids waiting for their execution block, with the funds that were held for them.
+	#[pallet::storage]
+	pub type ScheduledBids<T: Config> = StorageMap<_, Blake2_128Concat, u32, ScheduledBidInfoOf<T>, OptionQuery>;
+
+	/// Ids of the scheduled bids to place at a given block, in the order they were scheduled.
+	#[pallet::storage]
+	pub type ScheduledBidQueue<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<u32, ConstU32<MAX_SCHEDULED_BIDS_PER_BLOCK>>,
+		ValueQuery,
+	>;
+
+	/// The last block for which the `ScheduledBidQueue` was fully processed. Used to not miss any block when the block
+	/// number provider skips some numbers, or when the bids of a block did not fit in it.
 	#[pallet::storage]
-	pub type DidWithActiveProjects<T: Config> = StorageMap<_, Blake2_128Concat, Did, ProjectId, OptionQuery>;
+	pub type LastScheduledBidBlockProcessed<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		/// Funding assets registered at genesis.
+		pub funding_assets: Vec<(AcceptedFundingAsset, FundingAssetInfo)>,
+		#[serde(skip)]
+		pub _config: PhantomData<T>,
+	}
+
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { funding_assets: AcceptedFundingAsset::builtin_assets(), _config: PhantomData }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			assert!(self.funding_assets.len() <= MAX_FUNDING_ASSETS as usize, "Too many funding assets");
+			for (asset, info) in &self.funding_assets {
+				FundingAssets::<T>::insert(asset, info);
+			}
+		}
+	}
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub (super) fn deposit_event)]
@@ -424,10 +788,20 @@ pub mod pallet {
 		ProjectRemoved { project_id: ProjectId, issuer: T::AccountId },
 		/// The metadata of a project was modified.
 		MetadataEdited { project_id: ProjectId, metadata: ProjectMetadataOf<T> },
+		/// The allowlist of a project was set or removed.
+		AllowlistSet { project_id: ProjectId, allowlist_root: Option<[u8; 32]> },
 		/// Project transitioned to a new phase.
 		ProjectPhaseTransition { project_id: ProjectId, phase: ProjectStatus },
+		/// The issuer of a project, or the automatic default after the deadline, decided the outcome of the raise.
+		ProjectOutcomeDecided { project_id: ProjectId, decision: FundingOutcomeDecision },
+		/// A scheduled automatic transition of a project failed. It is tried again on the next block.
+		ProjectTransitionFailed { project_id: ProjectId, error: DispatchError },
 		/// A `bonder` bonded an `amount` of PLMC for `project_id`.
 		Evaluation { project_id: ProjectId, evaluator: AccountIdOf<T>, id: u32, plmc_amount: Balance },
+		/// More PLMC was bonded to an existing evaluation.
+		EvaluationIncreased { project_id: ProjectId, evaluator: AccountIdOf<T>, id: u32, plmc_amount: Balance },
+		/// An evaluation was withdrawn during the evaluation round, and its PLMC bond was released.
+		EvaluationWithdrawn { project_id: ProjectId, evaluator: AccountIdOf<T>, id: u32, plmc_amount: Balance },
 		/// A bid was made for a project
 		Bid {
 			project_id: ProjectId,
@@ -440,6 +814,44 @@ pub mod pallet {
 			plmc_bond: Balance,
 			mode: ParticipationMode,
 		},
+		/// A bid reached the maximum price set by the bidder before being fully placed. No funds were locked for the
+		/// unfilled amount.
+		BidCappedByMaxPrice {
+			project_id: ProjectId,
+			bidder: AccountIdOf<T>,
+			max_price: T::Price,
+			unfilled_ct_amount: Balance,
+		},
+		/// A bid on a descending auction asked for more tokens than were left. Only `filled_ct_amount` was bought, and
+		/// no funds were locked for the unfilled amount.
+		BidCappedByAmountLeft {
+			project_id: ProjectId,
+			bidder: AccountIdOf<T>,
+			filled_ct_amount: Balance,
+			unfilled_ct_amount: Balance,
+		},
+		/// A bid was reduced or fully cancelled during the auction round. The PLMC bond and funding assets of the
+		/// cancelled amount were returned to the bidder.
+		BidCancelled {
+			project_id: ProjectId,
+			bidder: AccountIdOf<T>,
+			id: u32,
+			ct_amount: Balance,
+			funding_amount: Balance,
+			plmc_bond: Balance,
+		},
+		/// A contribution was made for a project in the community round
+		Contribution {
+			project_id: ProjectId,
+			contributor: AccountIdOf<T>,
+			id: u32,
+			ct_amount: Balance,
+			ct_price: T::Price,
+			funding_asset: AcceptedFundingAsset,
+			funding_amount: Balance,
+			plmc_bond: Balance,
+			mode: ParticipationMode,
+		},
 		/// An oversubscribed bid has been marked as rejected, and can now be settled early and release the funds back to the user.
 		OversubscribedBidProcessed { project_id: ProjectId, bid_id: u32 },
 		/// An evaluation was settled. PLMC has been unbonded with either a CT reward or a PLMC slash depending on the project outcome.
@@ -460,6 +872,21 @@ pub mod pallet {
 			status: BidStatus,
 			final_ct_amount: Balance,
 		},
+		/// A contribution was settled. On Funding Success the PLMC has been unbonded/locked with a vesting schedule and the funding assets have been transferred to the issuer.
+		/// If Funding Failed, the PLMC has been unbonded and the funds have been returned to the contributor.
+		ContributionSettled { project_id: ProjectId, account: AccountIdOf<T>, id: u32, ct_amount: Balance },
+		/// The contribution tokens of a participant that vested since their last claim were unfrozen.
+		VestedContributionTokensClaimed { project_id: ProjectId, account: AccountIdOf<T>, amount: Balance },
+		/// A funding milestone was approved and its tranche paid out to the issuer, or rejected.
+		FundingMilestoneDecided { project_id: ProjectId, milestone_index: u32, outcome: MilestoneOutcome },
+		/// A participant got back their share of the tranche of a rejected milestone.
+		MilestoneRefundClaimed {
+			project_id: ProjectId,
+			milestone_index: u32,
+			account: AccountIdOf<T>,
+			funding_asset: AcceptedFundingAsset,
+			amount: Balance,
+		},
 		/// Issuer started the CT migration to mainnet tokens using the pallet migration method
 		PalletMigrationStarted { project_id: ProjectId, para_id: ParaId },
 		/// A channel was accepted from a parachain to Polimec belonging to a project. A request has been sent to the relay for a Polimec->project channel
@@ -469,13 +896,55 @@ pub mod pallet {
 		/// Started a migration readiness check
 		MigrationReadinessCheckStarted { project_id: ProjectId, caller: T::AccountId },
 		/// Migration readiness check was accepted
-		MigrationCheckResponseAccepted { project_id: ProjectId, query_id: QueryId, response: Response },
+		MigrationCheckResponseAccepted { project_id: ProjectId, query_id: QueryId, response: xcm::v5::Response },
 		/// Migration readiness check was rejected
-		MigrationCheckResponseRejected { project_id: ProjectId, query_id: QueryId, response: Response },
+		MigrationCheckResponseRejected { project_id: ProjectId, query_id: QueryId, response: xcm::v5::Response },
+		/// The CT migrations of a user were sent to the project's parachain. Awaiting the response of the receiver pallet.
+		PalletMigrationSent { project_id: ProjectId, account: AccountIdOf<T>, query_id: QueryId },
+		/// The issuer submitted the merkle root of a batch of `count` participants whose offchain migration was
+		/// completed.
+		MigrationConfirmationRootSubmitted { project_id: ProjectId, root: [u8; 32], count: u32 },
 		/// A user's CT migrations status was updated
 		MigrationStatusUpdated { project_id: ProjectId, account: AccountIdOf<T>, status: MigrationStatus },
 		/// The CT migration of a project has been completed. All CTs were converted to mainnet tokens.
 		CTMigrationFinished { project_id: ProjectId },
+		/// A funding asset was registered or updated.
+		FundingAssetSet { asset: AcceptedFundingAsset, info: FundingAssetInfo },
+		/// The issuer of a project proposed another account to take over its ownership.
+		ProjectTransferProposed { project_id: ProjectId, issuer: AccountIdOf<T>, new_issuer: AccountIdOf<T> },
+		/// The ownership of a project was transferred to a new issuer account and DID, along with the account its funds
+		/// are paid out to.
+		ProjectTransferred {
+			project_id: ProjectId,
+			issuer: AccountIdOf<T>,
+			issuer_did: Did,
+			funding_destination_account: AccountIdOf<T>,
+		},
+		/// The participations of a project were halted by governance.
+		ProjectHalted { project_id: ProjectId, reason: BoundedVec<u8, StringLimitOf<T>> },
+		/// The participations of a halted project were allowed again by governance.
+		ProjectResumed { project_id: ProjectId },
+		/// Governance forced a project to fail. Participants get refunded, and evaluators are not slashed.
+		ProjectForcedToFail { project_id: ProjectId, reason: BoundedVec<u8, StringLimitOf<T>> },
+		/// The fee configuration for new projects was updated.
+		FeeConfigSet { fee_config: FeeConfig },
+		/// An account bound the account that receives the contribution tokens of its participations on a project.
+		ReceivingAccountBound { project_id: ProjectId, account: AccountIdOf<T>, receiving_account: Junction },
+		/// A bid was scheduled to be placed at `execution_block`. The funds to pay for it at `max_price` were held.
+		BidScheduled {
+			project_id: ProjectId,
+			bidder: AccountIdOf<T>,
+			id: u32,
+			ct_amount: Balance,
+			max_price: T::Price,
+			execution_block: BlockNumberFor<T>,
+		},
+		/// A scheduled bid was cancelled before its execution, and its held funds were returned.
+		ScheduledBidCancelled { project_id: ProjectId, bidder: AccountIdOf<T>, id: u32 },
+		/// A scheduled bid was placed. The `Bid` events of the placed bids are emitted before this one.
+		ScheduledBidExecuted { project_id: ProjectId, bidder: AccountIdOf<T>, id: u32 },
+		/// A scheduled bid could not be placed at its execution block, and its held funds were returned.
+		ScheduledBidFailed { project_id: ProjectId, bidder: AccountIdOf<T>, id: u32, error: DispatchError },
 	}
 
 	#[pallet::error]
@@ -496,6 +965,8 @@ pub mod pallet {
 		BadReceiverAccountSignature,
 		/// Used a Junction variant unsupported to represent a receiving account.
 		UnsupportedReceiverAccountJunction,
+		/// The signature of the receiving account expired before it was submitted.
+		ReceivingAccountSignatureExpired,
 
 		// * Project Error. Project information not found, or project has an incorrect state. *
 		/// The project details were not found. Happens when the project with provided ID does
@@ -530,8 +1001,8 @@ pub mod pallet {
 		/// The action's caller is not the issuer of the project and is not allowed to execute
 		/// this action.
 		NotIssuer,
-		/// The issuer already has an active project. The issuer can only have one active project.
-		HasActiveProject,
+		/// The issuer already has the maximum number of active projects allowed per DID.
+		TooManyActiveProjects,
 		/// The issuer tries to participate to their own project.
 		ParticipationToOwnProject,
 		/// The issuer has not enough funds to cover the escrow account costs.
@@ -559,6 +1030,18 @@ pub mod pallet {
 		// The combination of decimals and price of this project is not representable within our 6 decimals USD system,
 		// and integer space of 128 bits.
 		BadTokenomics,
+		/// The parameters of the sale mode are not valid. A descending auction needs a ceiling price above the minimum
+		/// price, a non-zero price step and a non-zero amount of blocks per step.
+		SaleModeError,
+		/// The contribution token vesting is not valid. It needs to release the tokens over at least one block.
+		CtVestingError,
+		/// The funding milestones are not valid. Their tranches have to be non-zero and add up to 100%.
+		FundingMilestonesError,
+		/// The evaluation or funding success threshold of the project is outside the bounds set by governance.
+		SuccessThresholdError,
+		/// The account type of the participants is not valid. Cosmos projects need a lowercase alphanumeric bech32
+		/// prefix.
+		ParticipantsAccountTypeError,
 
 		// * Error related to an participation action. Evaluation, bid or contribution failed. *
 		/// The amount specified in the call is too low.
@@ -569,6 +1052,8 @@ pub mod pallet {
 		FundingAssetNotAccepted,
 		/// The user already has the maximum number of participations in this project.
 		TooManyUserParticipations,
+		/// Withdrawing the evaluation would read more evaluations of the project than the `max_evaluations_read` given.
+		TooManyEvaluationsToReassign,
 		/// The user is not allowed to use the selected multiplier.
 		ForbiddenMultiplier,
 		/// The user has a winning bid in the auction round and is not allowed to participate
@@ -586,6 +1071,27 @@ pub mod pallet {
 		OversubscribedBidsRemaining,
 		/// User has a partially accepted bid and needs to first process the rejected amount before settling the accepted amount.
 		RejectedAmountInPartialBidRemaining,
+		/// The bid cannot be cancelled. Only bids in the current bucket that were not outbid can be cancelled, and only if
+		/// the bids they outbid were not refunded yet.
+		BidNotCancellable,
+		/// Cancelling the bid would accept again more outbid bids than the `max_restored_bids` given.
+		TooManyBidsToRestore,
+		/// A batch of bids needs at least one entry.
+		EmptyBidBatch,
+		/// The current price of the auction is above the maximum price set by the bidder.
+		PriceAboveMaxPrice,
+		/// Scheduled bids must be executed after the current block, and before the end of the auction round.
+		InvalidExecutionBlock,
+		/// The execution block already has as many scheduled bids as can be placed in it.
+		TooManyScheduledBids,
+		/// The DID already has a scheduled bid at the execution block.
+		DidAlreadyScheduledAtBlock,
+		/// The project has an allowlist, and the participant did not prove to be part of it.
+		NotAllowlisted,
+		/// The merkle proof does not match the allowlist of the project.
+		InvalidAllowlistProof,
+		/// The bids of the participant would go over the USD cap of their allowlist entry.
+		AllowlistCapExceeded,
 
 		//  * An error related to the migration process. *
 		/// Tried to start a migration check but the bidirectional channel is not yet open
@@ -599,6 +1105,8 @@ pub mod pallet {
 		NoMigrationsFound,
 		/// User has no active migrations in the queue.
 		NoActiveMigrationsFound,
+		/// The query of the CT migrations sent to the project's parachain has not timed out yet.
+		MigrationQueryNotTimedOut,
 		/// Wrong para_id is provided.
 		WrongParaId,
 		/// Migration channel is not ready for migrations.
@@ -611,10 +1119,59 @@ pub mod pallet {
 		ParticipationsNotSettled,
 		/// Tried to mark project as fully settled but there are participations that are not settled.
 		SettlementNotComplete,
+		/// The participant has no vested contribution tokens left to unfreeze.
+		NoVestedContributionTokens,
+		/// The project has no funding milestone with this index.
+		MilestoneNotFound,
+		/// The funding milestone was already approved or rejected.
+		MilestoneAlreadyDecided,
+		/// Funding milestones have to be decided in order.
+		PreviousMilestoneNotDecided,
+		/// Refunds can only be claimed for rejected milestones.
+		MilestoneNotRejected,
+		/// The participant already claimed their refund of this milestone.
+		MilestoneRefundAlreadyClaimed,
+		/// The participant has no escrowed funds to be refunded.
+		NoMilestoneRefund,
 		/// Tried to mark a project's CT migration as finished but there are still migrations to be confirmed
 		MigrationsStillPending,
 		/// Tried to confirm an already confirmed user CT migration
 		MigrationAlreadyConfirmed,
+		/// The CT migrations of the user were already sent to the project's parachain, and are awaiting its response.
+		MigrationAlreadySent,
+		/// Only confirmed CT migrations can be disputed.
+		MigrationNotConfirmed,
+		/// The dispute window of the CT migration is over, or the migration was already disputed.
+		MigrationDisputeWindowClosed,
+		/// The CT migration of the user is not disputed.
+		MigrationNotDisputed,
+		/// Tried to mark a project's CT migration as finished but there are still disputes to be resolved.
+		MigrationDisputesOpen,
+		/// Tried to mark a project's CT migration as finished while a confirmed migration can still be disputed.
+		MigrationDisputeWindowOpen,
+		/// The count of a batch of migration confirmations is zero, or higher than the participants left to confirm.
+		InvalidMigrationConfirmationCount,
+		/// The merkle proof does not match any batch of migration confirmations of the project.
+		InvalidMigrationConfirmationProof,
+		/// The funding asset registry is full.
+		TooManyFundingAssets,
+		/// Only the oracle key and the enabled flag of a registered funding asset can be changed.
+		FundingAssetImmutable,
+		/// The location is already registered for another funding asset.
+		FundingAssetLocationTaken,
+		/// The project has no pending ownership transfer.
+		NoPendingProjectTransfer,
+		/// The caller is not the account proposed to take over the project.
+		NotProposedIssuer,
+		/// The DID accepting the project transfer already evaluated, bid or contributed to the project.
+		NewIssuerIsParticipant,
+		/// The participations of the project were halted by governance.
+		ProjectHalted,
+		/// The project is not halted.
+		ProjectNotHalted,
+		/// The fee configuration is not valid. The fees cannot increase from one bracket to the next, the last
+		/// bracket has to be unbounded, and the reward shares cannot add up to more than 100%.
+		FeeConfigError,
 	}
 
 	#[pallet::call]
@@ -676,7 +1233,8 @@ pub mod pallet {
 			Self::do_start_evaluation(account, project_id)
 		}
 
-		/// Bond PLMC for a project in the evaluation stage
+		/// Bond PLMC for a project in the evaluation stage. The reward goes to the receiving account bound by the
+		/// caller, or to the caller itself.
 		#[pallet::call_index(4)]
 		#[pallet::weight(WeightInfoOf::<T>::evaluate())]
 		pub fn evaluate(
@@ -688,28 +1246,7 @@ pub mod pallet {
 			let (account, did, _investor_type, whitelisted_policy) =
 				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
 
-			let receiving_account = Junction::AccountId32 {
-				network: Some(NetworkId::Polkadot),
-				id: T::AccountId32Conversion::convert(account.clone()),
-			};
-
-			Self::do_evaluate(&account, project_id, usd_amount, did, whitelisted_policy, receiving_account)
-		}
-
-		#[pallet::call_index(5)]
-		#[pallet::weight(WeightInfoOf::<T>::evaluate())]
-		pub fn evaluate_with_receiving_account(
-			origin: OriginFor<T>,
-			jwt: UntrustedToken,
-			project_id: ProjectId,
-			#[pallet::compact] usd_amount: Balance,
-			receiving_account: Junction,
-			signature_bytes: [u8; 65],
-		) -> DispatchResult {
-			let (account, did, _investor_type, whitelisted_policy) =
-				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
-
-			Self::verify_receiving_account_signature(&account, project_id, &receiving_account, signature_bytes)?;
+			let receiving_account = Self::receiving_account_of(project_id, &account);
 
 			Self::do_evaluate(&account, project_id, usd_amount, did, whitelisted_policy, receiving_account)
 		}
@@ -721,7 +1258,8 @@ pub mod pallet {
 			Self::do_end_evaluation(project_id)
 		}
 
-		/// Bid for a project in the Auction round
+		/// Bid for a project in the Auction round. The contribution tokens go to the receiving account bound by the
+		/// caller, or to the caller itself.
 		#[pallet::call_index(7)]
 		#[pallet::weight(WeightInfoOf::<T>::bid(10))]
 		pub fn bid(
@@ -735,42 +1273,7 @@ pub mod pallet {
 			let (bidder, did, investor_type, whitelisted_policy) =
 				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
 
-			let receiving_account = Junction::AccountId32 {
-				network: Some(NetworkId::Polkadot),
-				id: T::AccountId32Conversion::convert(bidder.clone()),
-			};
-
-			let params = DoBidParams::<T> {
-				bidder,
-				project_id,
-				ct_amount,
-				mode,
-				funding_asset,
-				did,
-				investor_type,
-				whitelisted_policy,
-				receiving_account,
-			};
-
-			Self::do_bid(params)
-		}
-
-		#[pallet::call_index(8)]
-		#[pallet::weight(WeightInfoOf::<T>::bid(10))]
-		pub fn bid_with_receiving_account(
-			origin: OriginFor<T>,
-			jwt: UntrustedToken,
-			project_id: ProjectId,
-			#[pallet::compact] ct_amount: Balance,
-			mode: ParticipationMode,
-			funding_asset: AcceptedFundingAsset,
-			receiving_account: Junction,
-			signature_bytes: [u8; 65],
-		) -> DispatchResultWithPostInfo {
-			let (bidder, did, investor_type, whitelisted_policy) =
-				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
-
-			Self::verify_receiving_account_signature(&bidder, project_id, &receiving_account, signature_bytes)?;
+			let receiving_account = Self::receiving_account_of(project_id, &bidder);
 
 			let params = DoBidParams::<T> {
 				bidder,
@@ -782,6 +1285,7 @@ pub mod pallet {
 				investor_type,
 				whitelisted_policy,
 				receiving_account,
+				max_price: None,
 			};
 
 			Self::do_bid(params)
@@ -869,45 +1373,629 @@ pub mod pallet {
 
 			Self::do_mark_project_ct_migration_as_finished(project_id)
 		}
-	}
 
-	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		fn on_idle(_n: BlockNumberFor<T>, available_weight: Weight) -> Weight {
-			// Early return if no projects in auction round
-			if ProjectsInAuctionRound::<T>::iter_keys().next().is_none() {
-				return <T as frame_system::Config>::DbWeight::get().reads(1);
-			}
+		/// Buy the contribution tokens left unsold after the auction, at the weighted average price of the auction.
+		#[pallet::call_index(18)]
+		#[pallet::weight(WeightInfoOf::<T>::contribute())]
+		pub fn contribute(
+			origin: OriginFor<T>,
+			jwt: UntrustedToken,
+			project_id: ProjectId,
+			#[pallet::compact] ct_amount: Balance,
+			mode: ParticipationMode,
+			funding_asset: AcceptedFundingAsset,
+		) -> DispatchResultWithPostInfo {
+			let (contributor, did, investor_type, whitelisted_policy) =
+				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
 
-			let mut weight_consumed = <T as frame_system::Config>::DbWeight::get().reads(1);
-			let read_weight = <T as frame_system::Config>::DbWeight::get().reads(1);
-			let process_weight = <T as Config>::WeightInfo::process_next_oversubscribed_bid();
-
-			// Iterate over all projects in auction round
-			for (project_id, _) in ProjectsInAuctionRound::<T>::iter() {
-				// Add weight for reading the storage item
-				weight_consumed.saturating_accrue(read_weight);
-
-				// Check if we have enough weight to continue
-				if weight_consumed.saturating_add(process_weight).all_gt(available_weight) {
-					return weight_consumed;
-				}
-
-				loop {
-					// Check weight before processing each bid
-					if weight_consumed.saturating_add(process_weight).all_gt(available_weight) {
-						return weight_consumed;
-					}
-
-					weight_consumed.saturating_accrue(process_weight);
-					match Self::do_process_next_oversubscribed_bid(project_id) {
-						// Returns Ok if a bid was processed successfully
-						Ok(_) => continue,
-						// Returns Err if there are no more bids to process, so we go to next project
-						Err(_) => break,
-					}
-				}
-			}
+			let receiving_account = Self::receiving_account_of(project_id, &contributor);
+
+			let params = DoContributeParams::<T> {
+				contributor,
+				project_id,
+				ct_amount,
+				mode,
+				funding_asset,
+				did,
+				investor_type,
+				whitelisted_policy,
+				receiving_account,
+			};
+
+			Self::do_contribute(params)
+		}
+
+		#[pallet::call_index(20)]
+		#[pallet::weight(WeightInfoOf::<T>::settle_contribution())]
+		pub fn settle_contribution(
+			origin: OriginFor<T>,
+			project_id: ProjectId,
+			contribution_id: u32,
+		) -> DispatchResult {
+			let _caller = ensure_signed(origin)?;
+			Self::do_settle_contribution(project_id, contribution_id)
+		}
+
+		/// Cancel a bid placed in the current bucket of the auction, and get the PLMC bond and funding assets back.
+		/// `max_restored_bids` is the maximum amount of outbid bids that can be accepted again by the cancellation.
+		#[pallet::call_index(21)]
+		#[pallet::weight(WeightInfoOf::<T>::cancel_bid(*max_restored_bids))]
+		pub fn cancel_bid(
+			origin: OriginFor<T>,
+			project_id: ProjectId,
+			bid_id: u32,
+			max_restored_bids: u32,
+		) -> DispatchResultWithPostInfo {
+			let bidder = ensure_signed(origin)?;
+			let bid = Bids::<T>::get(project_id, bid_id).ok_or(Error::<T>::ParticipationNotFound)?;
+			Self::do_decrease_bid(&bidder, project_id, bid_id, bid.original_ct_amount, max_restored_bids)
+		}
+
+		/// Reduce the CT amount of a bid placed in the current bucket of the auction. The PLMC bond and funding assets
+		/// of the removed amount are returned.
+		/// `max_restored_bids` is the maximum amount of outbid bids that can be accepted again by the decrease.
+		#[pallet::call_index(22)]
+		#[pallet::weight(WeightInfoOf::<T>::cancel_bid(*max_restored_bids))]
+		pub fn decrease_bid(
+			origin: OriginFor<T>,
+			project_id: ProjectId,
+			bid_id: u32,
+			#[pallet::compact] ct_amount: Balance,
+			max_restored_bids: u32,
+		) -> DispatchResultWithPostInfo {
+			let bidder = ensure_signed(origin)?;
+			Self::do_decrease_bid(&bidder, project_id, bid_id, ct_amount, max_restored_bids)
+		}
+
+		/// Bond more PLMC to one of your evaluations while the evaluation round is running.
+		#[pallet::call_index(23)]
+		#[pallet::weight(WeightInfoOf::<T>::evaluate())]
+		pub fn increase_evaluation(
+			origin: OriginFor<T>,
+			jwt: UntrustedToken,
+			project_id: ProjectId,
+			evaluation_id: u32,
+			#[pallet::compact] usd_amount: Balance,
+		) -> DispatchResult {
+			let (account, did, _investor_type, whitelisted_policy) =
+				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
+
+			Self::do_increase_evaluation(&account, project_id, evaluation_id, usd_amount, did, whitelisted_policy)
+		}
+
+		/// Withdraw one of your evaluations while the evaluation round is running, and get the PLMC bond back.
+		/// `max_evaluations_read` is the maximum amount of evaluations of the project that can be read to give its early
+		/// USD amount to the next evaluations.
+		#[pallet::call_index(24)]
+		#[pallet::weight(WeightInfoOf::<T>::withdraw_evaluation(*max_evaluations_read))]
+		pub fn withdraw_evaluation(
+			origin: OriginFor<T>,
+			project_id: ProjectId,
+			evaluation_id: u32,
+			max_evaluations_read: u32,
+		) -> DispatchResultWithPostInfo {
+			let evaluator = ensure_signed(origin)?;
+			Self::do_withdraw_evaluation(&evaluator, project_id, evaluation_id, max_evaluations_read)
+		}
+
+		/// Restrict the participation in a project to the DIDs of a merkle allowlist, or remove the restriction with
+		/// `None`. Can only be changed before the evaluation round starts.
+		#[pallet::call_index(25)]
+		#[pallet::weight(WeightInfoOf::<T>::set_allowlist())]
+		pub fn set_allowlist(
+			origin: OriginFor<T>,
+			jwt: UntrustedToken,
+			project_id: ProjectId,
+			allowlist_root: Option<[u8; 32]>,
+		) -> DispatchResult {
+			let (account, _did, investor_type, _cid) =
+				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
+			ensure!(investor_type == InvestorType::Institutional, Error::<T>::WrongInvestorType);
+			Self::do_set_allowlist(account, project_id, allowlist_root)
+		}
+
+		/// Same as `evaluate`, but first proves that the DID is part of the project allowlist.
+		#[pallet::call_index(26)]
+		#[pallet::weight(WeightInfoOf::<T>::evaluate())]
+		pub fn evaluate_with_allowlist_proof(
+			origin: OriginFor<T>,
+			jwt: UntrustedToken,
+			project_id: ProjectId,
+			#[pallet::compact] usd_amount: Balance,
+			allowlist_proof: AllowlistProof,
+		) -> DispatchResult {
+			let (account, did, _investor_type, whitelisted_policy) =
+				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
+
+			let receiving_account = Self::receiving_account_of(project_id, &account);
+
+			Self::do_prove_allowlist(project_id, &did, allowlist_proof)?;
+			Self::do_evaluate(&account, project_id, usd_amount, did, whitelisted_policy, receiving_account)
+		}
+
+		/// Same as `bid`, but first proves that the DID is part of the project allowlist.
+		#[pallet::call_index(27)]
+		#[pallet::weight(WeightInfoOf::<T>::bid(10))]
+		pub fn bid_with_allowlist_proof(
+			origin: OriginFor<T>,
+			jwt: UntrustedToken,
+			project_id: ProjectId,
+			#[pallet::compact] ct_amount: Balance,
+			mode: ParticipationMode,
+			funding_asset: AcceptedFundingAsset,
+			allowlist_proof: AllowlistProof,
+		) -> DispatchResultWithPostInfo {
+			let (bidder, did, investor_type, whitelisted_policy) =
+				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
+
+			let receiving_account = Self::receiving_account_of(project_id, &bidder);
+
+			Self::do_prove_allowlist(project_id, &did, allowlist_proof)?;
+
+			let params = DoBidParams::<T> {
+				bidder,
+				project_id,
+				ct_amount,
+				mode,
+				funding_asset,
+				did,
+				investor_type,
+				whitelisted_policy,
+				receiving_account,
+				max_price: None,
+			};
+
+			Self::do_bid(params)
+		}
+
+		/// Unfreeze the contribution tokens of the caller that vested since their last claim.
+		#[pallet::call_index(28)]
+		#[pallet::weight(WeightInfoOf::<T>::claim_vested_contribution_tokens())]
+		pub fn claim_vested_contribution_tokens(origin: OriginFor<T>, project_id: ProjectId) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			Self::do_claim_vested_contribution_tokens(&caller, project_id)
+		}
+
+		/// Approve or reject the next funding milestone of a settled project. Approving pays its tranche of the
+		/// escrowed funds out to the issuer, rejecting lets the participants claim it back.
+		#[pallet::call_index(29)]
+		#[pallet::weight(WeightInfoOf::<T>::decide_funding_milestone())]
+		pub fn decide_funding_milestone(
+			origin: OriginFor<T>,
+			project_id: ProjectId,
+			milestone_index: u32,
+			approve: bool,
+		) -> DispatchResult {
+			T::MilestoneOrigin::ensure_origin(origin)?;
+			Self::do_decide_funding_milestone(project_id, milestone_index, approve)
+		}
+
+		/// Claim back the caller's share of the tranche of a rejected funding milestone.
+		#[pallet::call_index(30)]
+		#[pallet::weight(WeightInfoOf::<T>::claim_milestone_refund())]
+		pub fn claim_milestone_refund(
+			origin: OriginFor<T>,
+			project_id: ProjectId,
+			milestone_index: u32,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			Self::do_claim_milestone_refund(&caller, project_id, milestone_index)
+		}
+
+		/// Accept or reject the funding of a project that ended in `AwaitingIssuerDecision`. If the issuer does not
+		/// decide before the deadline, `DefaultFundingOutcomeDecision` is applied automatically.
+		#[pallet::call_index(31)]
+		#[pallet::weight(WeightInfoOf::<T>::decide_project_outcome())]
+		pub fn decide_project_outcome(
+			origin: OriginFor<T>,
+			jwt: UntrustedToken,
+			project_id: ProjectId,
+			outcome: FundingOutcomeDecision,
+		) -> DispatchResult {
+			let (account, _did, investor_type, _cid) =
+				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
+			ensure!(investor_type == InvestorType::Institutional, Error::<T>::WrongInvestorType);
+			Self::do_decide_project_outcome(account, project_id, outcome)
+		}
+
+		/// Start the CT migration to the project's own parachain. Once the parachain opens an HRMP channel to
+		/// Polimec, the channel back to the project is requested automatically.
+		#[pallet::call_index(32)]
+		#[pallet::weight(WeightInfoOf::<T>::start_pallet_migration())]
+		pub fn start_pallet_migration(
+			origin: OriginFor<T>,
+			jwt: UntrustedToken,
+			project_id: ProjectId,
+			para_id: ParaId,
+		) -> DispatchResultWithPostInfo {
+			let (account, _did, investor_type, _cid) =
+				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
+			ensure!(investor_type == InvestorType::Institutional, Error::<T>::WrongInvestorType);
+
+			Self::do_start_pallet_migration(&account, project_id, para_id)
+		}
+
+		/// Query the project's parachain to check that Polimec holds enough mainnet tokens there, and that the
+		/// receiver pallet is part of its runtime.
+		#[pallet::call_index(33)]
+		#[pallet::weight(WeightInfoOf::<T>::start_pallet_migration_readiness_check())]
+		pub fn start_pallet_migration_readiness_check(origin: OriginFor<T>, project_id: ProjectId) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			Self::do_start_pallet_migration_readiness_check(&caller, project_id)
+		}
+
+		/// Called only by other chains through a query response xcm message
+		#[pallet::call_index(34)]
+		#[pallet::weight(WeightInfoOf::<T>::pallet_migration_readiness_response())]
+		pub fn pallet_migration_readiness_response(
+			origin: OriginFor<T>,
+			query_id: QueryId,
+			response: xcm::v5::Response,
+		) -> DispatchResult {
+			let location = pallet_xcm::ensure_response(<T as Config>::RuntimeOrigin::from(origin))?;
+			Self::do_pallet_migration_readiness_response(location, query_id, response)
+		}
+
+		/// Send the CT migrations of a participant to the receiver pallet of the project's parachain.
+		#[pallet::call_index(35)]
+		#[pallet::weight(WeightInfoOf::<T>::send_pallet_migration_for())]
+		pub fn send_pallet_migration_for(
+			origin: OriginFor<T>,
+			project_id: ProjectId,
+			participant: AccountIdOf<T>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_send_pallet_migration_for(project_id, participant)
+		}
+
+		/// Called only by other chains through a query response xcm message
+		#[pallet::call_index(36)]
+		#[pallet::weight(WeightInfoOf::<T>::confirm_pallet_migrations())]
+		pub fn confirm_pallet_migrations(
+			origin: OriginFor<T>,
+			query_id: QueryId,
+			response: xcm::v5::Response,
+		) -> DispatchResult {
+			let location = pallet_xcm::ensure_response(<T as Config>::RuntimeOrigin::from(origin))?;
+			Self::do_confirm_pallet_migrations(location, query_id, response)
+		}
+
+		/// Submit the merkle root of a batch of `count` participants whose offchain migration was completed. The
+		/// leaves are the keccak hash of the SCALE encoded `(account, MigrationInfo)`, as returned by the
+		/// `migration_confirmation_leaves` runtime API.
+		#[pallet::call_index(37)]
+		#[pallet::weight(WeightInfoOf::<T>::submit_migration_confirmation_root())]
+		pub fn submit_migration_confirmation_root(
+			origin: OriginFor<T>,
+			project_id: ProjectId,
+			root: [u8; 32],
+			count: u32,
+		) -> DispatchResult {
+			let caller = ensure_signed(origin)?;
+			Self::do_submit_migration_confirmation_root(project_id, caller, root, count)
+		}
+
+		/// Confirm the offchain migration of a participant, by proving it is part of a batch submitted by the issuer.
+		#[pallet::call_index(38)]
+		#[pallet::weight(WeightInfoOf::<T>::prove_offchain_migration())]
+		pub fn prove_offchain_migration(
+			origin: OriginFor<T>,
+			project_id: ProjectId,
+			participant: AccountIdOf<T>,
+			proof: BoundedVec<[u8; 32], ConstU32<MAX_MIGRATION_PROOF_LENGTH>>,
+		) -> DispatchResult {
+			let _caller = ensure_signed(origin)?;
+			Self::do_prove_offchain_migration(project_id, participant, proof)
+		}
+
+		/// Dispute the confirmation of the caller's CT migration, i.e. when the mainnet tokens were not received.
+		/// Only possible within the dispute window after the confirmation.
+		#[pallet::call_index(39)]
+		#[pallet::weight(WeightInfoOf::<T>::dispute_migration())]
+		pub fn dispute_migration(origin: OriginFor<T>, project_id: ProjectId) -> DispatchResult {
+			let participant = ensure_signed(origin)?;
+			Self::do_dispute_migration(project_id, participant)
+		}
+
+		/// Resolve the dispute of a participant over their CT migration. An upheld dispute sets the migration back to
+		/// `NotStarted`, so it has to be done again. A rejected one confirms the migration for good.
+		#[pallet::call_index(40)]
+		#[pallet::weight(WeightInfoOf::<T>::resolve_migration_dispute())]
+		pub fn resolve_migration_dispute(
+			origin: OriginFor<T>,
+			project_id: ProjectId,
+			participant: AccountIdOf<T>,
+			dispute_upheld: bool,
+		) -> DispatchResult {
+			T::MigrationDisputeOrigin::ensure_origin(origin)?;
+			Self::do_resolve_migration_dispute(project_id, participant, dispute_upheld)
+		}
+
+		/// Register a funding asset, or update its registry entry. Assets are never removed, since existing
+		/// participations are settled with them, but they can be disabled for new ones.
+		#[pallet::call_index(41)]
+		#[pallet::weight(WeightInfoOf::<T>::set_funding_asset())]
+		pub fn set_funding_asset(
+			origin: OriginFor<T>,
+			asset: AcceptedFundingAsset,
+			info: FundingAssetInfo,
+		) -> DispatchResult {
+			T::FundingAssetOrigin::ensure_origin(origin)?;
+			Self::do_set_funding_asset(asset, info)
+		}
+
+		/// Propose another account to take over the ownership of a project. A new proposal replaces the pending one.
+		#[pallet::call_index(42)]
+		#[pallet::weight(WeightInfoOf::<T>::propose_project_transfer())]
+		pub fn propose_project_transfer(
+			origin: OriginFor<T>,
+			jwt: UntrustedToken,
+			project_id: ProjectId,
+			new_issuer: AccountIdOf<T>,
+		) -> DispatchResult {
+			let (account, _did, investor_type, _cid) =
+				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
+			ensure!(investor_type == InvestorType::Institutional, Error::<T>::WrongInvestorType);
+			Self::do_propose_project_transfer(account, project_id, new_issuer)
+		}
+
+		/// Accept the ownership of a project. The DID of the credential becomes the issuer DID of the project, and
+		/// `funding_destination_account` replaces the account the funds of the project are paid out to.
+		#[pallet::call_index(43)]
+		#[pallet::weight(WeightInfoOf::<T>::accept_project_transfer())]
+		pub fn accept_project_transfer(
+			origin: OriginFor<T>,
+			jwt: UntrustedToken,
+			project_id: ProjectId,
+			funding_destination_account: AccountIdOf<T>,
+		) -> DispatchResult {
+			let (account, did, investor_type, _cid) =
+				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
+			ensure!(investor_type == InvestorType::Institutional, Error::<T>::WrongInvestorType);
+			Self::do_accept_project_transfer(account, project_id, did, funding_destination_account)
+		}
+
+		/// Halt the participations of a project that has not finished its fundraise yet.
+		#[pallet::call_index(44)]
+		#[pallet::weight(WeightInfoOf::<T>::halt_project())]
+		pub fn halt_project(
+			origin: OriginFor<T>,
+			project_id: ProjectId,
+			reason: BoundedVec<u8, StringLimitOf<T>>,
+		) -> DispatchResult {
+			T::EmergencyOrigin::ensure_origin(origin)?;
+			Self::do_halt_project(project_id, reason)
+		}
+
+		/// Allow the participations of a halted project again.
+		#[pallet::call_index(45)]
+		#[pallet::weight(WeightInfoOf::<T>::resume_project())]
+		pub fn resume_project(origin: OriginFor<T>, project_id: ProjectId) -> DispatchResult {
+			T::EmergencyOrigin::ensure_origin(origin)?;
+			Self::do_resume_project(project_id)
+		}
+
+		/// Force a project that has not started its settlement yet to fail. Bidders and contributors get refunded in
+		/// the settlement, and evaluators get their full bond back.
+		#[pallet::call_index(46)]
+		#[pallet::weight(WeightInfoOf::<T>::force_project_failure())]
+		pub fn force_project_failure(
+			origin: OriginFor<T>,
+			project_id: ProjectId,
+			reason: BoundedVec<u8, StringLimitOf<T>>,
+		) -> DispatchResult {
+			T::EmergencyOrigin::ensure_origin(origin)?;
+			Self::do_force_project_failure(project_id, reason)
+		}
+
+		/// Update the fee configuration. Only projects that start their evaluation afterwards use it.
+		#[pallet::call_index(47)]
+		#[pallet::weight(WeightInfoOf::<T>::set_fee_config())]
+		pub fn set_fee_config(origin: OriginFor<T>, fee_config: FeeConfig) -> DispatchResult {
+			T::FeeConfigOrigin::ensure_origin(origin)?;
+			Self::do_set_fee_config(fee_config)
+		}
+
+		/// Bind the account that receives the contribution tokens of the next participations of the caller on a
+		/// project. The receiving account signs the message given by `get_message_to_sign_by_receiving_account`, which
+		/// can be submitted until the `expiry` block.
+		#[pallet::call_index(48)]
+		#[pallet::weight(WeightInfoOf::<T>::bind_receiving_account())]
+		pub fn bind_receiving_account(
+			origin: OriginFor<T>,
+			project_id: ProjectId,
+			receiving_account: Junction,
+			expiry: BlockNumberFor<T>,
+			signature_bytes: [u8; 65],
+		) -> DispatchResult {
+			let account = ensure_signed(origin)?;
+			Self::do_bind_receiving_account(&account, project_id, receiving_account, expiry, signature_bytes)
+		}
+
+		/// Place several bids on a project in the auction round with a single credential check. The entries are
+		/// placed in order and atomically: if one of them fails, the whole batch is reverted.
+		#[pallet::call_index(49)]
+		#[pallet::weight(WeightInfoOf::<T>::bid(10).saturating_mul(entries.len() as u64))]
+		pub fn bid_batch(
+			origin: OriginFor<T>,
+			jwt: UntrustedToken,
+			project_id: ProjectId,
+			entries: BoundedVec<BidBatchEntry, ConstU32<MAX_BID_BATCH_SIZE>>,
+		) -> DispatchResultWithPostInfo {
+			let (bidder, did, investor_type, whitelisted_policy) =
+				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
+
+			let receiving_account = Self::receiving_account_of(project_id, &bidder);
+
+			let params = DoBidBatchParams::<T> {
+				bidder,
+				project_id,
+				entries,
+				did,
+				investor_type,
+				whitelisted_policy,
+				receiving_account,
+			};
+
+			Self::do_bid_batch(params)
+		}
+
+		/// Commit a bid that is placed automatically at `execution_block`, inside the auction round. The funds needed
+		/// to pay for the whole amount at `max_price` are held until then, and the bid is placed with that price cap.
+		/// If the bid fails at the execution block, the held funds are returned. A DID can only schedule one bid per
+		/// block.
+		#[pallet::call_index(50)]
+		#[pallet::weight(WeightInfoOf::<T>::schedule_bid())]
+		pub fn schedule_bid(
+			origin: OriginFor<T>,
+			jwt: UntrustedToken,
+			project_id: ProjectId,
+			#[pallet::compact] ct_amount: Balance,
+			mode: ParticipationMode,
+			funding_asset: AcceptedFundingAsset,
+			max_price: PriceOf<T>,
+			execution_block: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let (bidder, did, investor_type, whitelisted_policy) =
+				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
+
+			let params = DoScheduleBidParams::<T> {
+				bidder,
+				project_id,
+				ct_amount,
+				mode,
+				funding_asset,
+				did,
+				investor_type,
+				whitelisted_policy,
+				max_price,
+				execution_block,
+			};
+
+			Self::do_schedule_bid(params)
+		}
+
+		/// Cancel a scheduled bid of the caller before its execution block, and get back its held funds.
+		#[pallet::call_index(51)]
+		#[pallet::weight(WeightInfoOf::<T>::cancel_scheduled_bid())]
+		pub fn cancel_scheduled_bid(origin: OriginFor<T>, scheduled_bid_id: u32) -> DispatchResult {
+			let bidder = ensure_signed(origin)?;
+			Self::do_cancel_scheduled_bid(&bidder, scheduled_bid_id)
+		}
+
+		/// Same as `contribute`, but first proves that the DID is part of the project allowlist.
+		#[pallet::call_index(52)]
+		#[pallet::weight(WeightInfoOf::<T>::contribute())]
+		pub fn contribute_with_allowlist_proof(
+			origin: OriginFor<T>,
+			jwt: UntrustedToken,
+			project_id: ProjectId,
+			#[pallet::compact] ct_amount: Balance,
+			mode: ParticipationMode,
+			funding_asset: AcceptedFundingAsset,
+			allowlist_proof: AllowlistProof,
+		) -> DispatchResultWithPostInfo {
+			let (contributor, did, investor_type, whitelisted_policy) =
+				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
+
+			let receiving_account = Self::receiving_account_of(project_id, &contributor);
+
+			Self::do_prove_allowlist(project_id, &did, allowlist_proof)?;
+
+			let params = DoContributeParams::<T> {
+				contributor,
+				project_id,
+				ct_amount,
+				mode,
+				funding_asset,
+				did,
+				investor_type,
+				whitelisted_policy,
+				receiving_account,
+			};
+
+			Self::do_contribute(params)
+		}
+
+		/// Mark the CT migrations sent to a project's parachain as failed once their query timed out with no
+		/// response, so they can be sent again.
+		#[pallet::call_index(53)]
+		#[pallet::weight(WeightInfoOf::<T>::fail_timed_out_pallet_migration())]
+		pub fn fail_timed_out_pallet_migration(origin: OriginFor<T>, query_id: QueryId) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_fail_timed_out_pallet_migration(query_id)
+		}
+
+		/// Same as `bid`, but the bid fails if the current price is already above `max_price`, and stops short of any
+		/// bucket priced above it. The unfilled amount is not bid. The allowlist proof is only needed on projects with
+		/// an allowlist, by DIDs that did not prove to be part of it yet.
+		#[pallet::call_index(54)]
+		#[pallet::weight(WeightInfoOf::<T>::bid(10))]
+		pub fn bid_with_max_price(
+			origin: OriginFor<T>,
+			jwt: UntrustedToken,
+			project_id: ProjectId,
+			#[pallet::compact] ct_amount: Balance,
+			mode: ParticipationMode,
+			funding_asset: AcceptedFundingAsset,
+			max_price: PriceOf<T>,
+			allowlist_proof: Option<AllowlistProof>,
+		) -> DispatchResultWithPostInfo {
+			let (bidder, did, investor_type, whitelisted_policy) =
+				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
+
+			let receiving_account = Self::receiving_account_of(project_id, &bidder);
+
+			if let Some(allowlist_proof) = allowlist_proof {
+				Self::do_prove_allowlist(project_id, &did, allowlist_proof)?;
+			}
+
+			let params = DoBidParams::<T> {
+				bidder,
+				project_id,
+				ct_amount,
+				mode,
+				funding_asset,
+				did,
+				investor_type,
+				whitelisted_policy,
+				receiving_account,
+				max_price: Some(max_price),
+			};
+
+			Self::do_bid(params)
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn integrity_test() {
+			let issuer_decision_threshold = T::IssuerDecisionThreshold::get();
+			assert!(
+				*T::FundingSuccessThresholdBounds::get().start() >= issuer_decision_threshold,
+				"FundingSuccessThresholdBounds must not start below IssuerDecisionThreshold"
+			);
+			assert!(
+				T::FundingSuccessThreshold::get() >= issuer_decision_threshold,
+				"FundingSuccessThreshold must not be below IssuerDecisionThreshold"
+			);
+			assert!(
+				Self::scheduled_bids_per_block() > 0,
+				"MaxScheduledBidsWeightPerBlock must fit at least one scheduled bid"
+			);
+		}
+
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			Self::process_scheduled_bids()
+		}
+
+		fn on_idle(_n: BlockNumberFor<T>, available_weight: Weight) -> Weight {
+			let mut weight_consumed = Self::process_scheduled_transitions(available_weight);
+			weight_consumed.saturating_accrue(Self::process_oversubscribed_bids_on_idle(
+				available_weight.saturating_sub(weight_consumed),
+			));
+			weight_consumed
+				.saturating_accrue(Self::settle_projects_on_idle(available_weight.saturating_sub(weight_consumed)));
 
 			weight_consumed
 		}
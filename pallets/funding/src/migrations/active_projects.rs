@@ -0,0 +1,82 @@
+// active_projects.rs
+
+use crate::{Config, DidWithActiveProjects, ProjectId};
+use alloc::vec::Vec;
+use frame_support::{pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade, weights::Weight};
+
+pub mod v14 {
+	use super::*;
+
+	const LOG: &str = "funding::migration::v14";
+
+	/// Turns the single active project of each issuer DID into a bounded list of active projects.
+	pub struct UncheckedMigrationToV14<T: Config>(PhantomData<T>);
+	impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrationToV14<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, DispatchError> {
+			let did_count = DidWithActiveProjects::<T>::iter_keys().count() as u32;
+			log::info!(target: LOG, "Pre-upgrade: {} DidWithActiveProjects entries", did_count);
+
+			Ok(did_count.encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let mut items = 0u64;
+			log::info!(target: LOG, "Starting active projects migration to V14");
+
+			DidWithActiveProjects::<T>::translate(|_key, project_id: ProjectId| {
+				items = items.saturating_add(1);
+				BoundedVec::try_from(Vec::from([project_id])).ok()
+			});
+
+			log::info!(target: LOG, "Migrated {} active projects", items);
+			T::DbWeight::get().reads_writes(items, items)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(pre_state: Vec<u8>) -> Result<(), DispatchError> {
+			let pre_did_count: u32 = Decode::decode(&mut &pre_state[..]).expect("Failed to decode pre-migration state");
+			let post_did_count = DidWithActiveProjects::<T>::iter_values().count() as u32;
+
+			if pre_did_count != post_did_count {
+				return Err("DidWithActiveProjects count mismatch".into());
+			}
+
+			Ok(())
+		}
+	}
+
+	pub type MigrationToV14<T> = frame_support::migrations::VersionedMigration<
+		13,
+		14,
+		UncheckedMigrationToV14<T>,
+		crate::Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{v14::*, *};
+	use crate::mock::{new_test_ext, TestRuntime as Test};
+	use frame_support::storage::unhashed;
+	use polimec_common_test_utils::generate_did_from_account;
+
+	#[test]
+	fn migration_to_v14() {
+		new_test_ext().execute_with(|| {
+			for account in 0..3u64 {
+				let did = generate_did_from_account(account);
+				let key = DidWithActiveProjects::<Test>::hashed_key_for(did);
+				unhashed::put(&key, &(account as ProjectId));
+			}
+
+			UncheckedMigrationToV14::<Test>::on_runtime_upgrade();
+
+			for account in 0..3u64 {
+				let did = generate_did_from_account(account);
+				assert_eq!(DidWithActiveProjects::<Test>::get(did).into_inner(), vec![account as ProjectId]);
+			}
+		});
+	}
+}
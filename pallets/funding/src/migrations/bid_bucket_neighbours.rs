@@ -0,0 +1,191 @@
+// bid_bucket_neighbours.rs
+
+use crate::Config;
+use alloc::{collections::btree_map::BTreeMap, vec::Vec};
+use frame_support::{pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade, weights::Weight};
+
+pub mod v18 {
+	use super::*;
+	use crate::{
+		BidBucketBounds, BidBucketNeighbours, Bids, BidsBucketBounds, BidsBucketNeighbours, OutbidBidsCutoff,
+		OutbidBidsCutoffs, PriceOf, ProjectId,
+	};
+
+	const LOG: &str = "funding::migration::v18";
+
+	/// Link the stored bids of each price in `BidsBucketNeighbours`, ordered by id. The bucket bounds and outbid
+	/// cutoffs that pointed to bids settled early are moved to the closest stored bid, as the bids are now walked
+	/// through their neighbours instead of their indexes.
+	pub struct UncheckedMigrationToV18<T: Config>(PhantomData<T>);
+	impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrationToV18<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, DispatchError> {
+			let bids_count = Bids::<T>::iter_keys().count() as u32;
+			log::info!(target: LOG, "Pre-upgrade: {} Bids entries", bids_count);
+
+			Ok(bids_count.encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let mut reads = 0u64;
+			let mut writes = 0u64;
+			log::info!(target: LOG, "Starting bid bucket neighbours migration to V18");
+
+			let mut bids_by_price: BTreeMap<(ProjectId, PriceOf<T>), Vec<u32>> = BTreeMap::new();
+			for (project_id, bid_id, bid) in Bids::<T>::iter() {
+				reads = reads.saturating_add(1);
+				bids_by_price.entry((project_id, bid.original_ct_usd_price)).or_default().push(bid_id);
+			}
+
+			for bid_ids in bids_by_price.values_mut() {
+				bid_ids.sort();
+			}
+			for ((project_id, _), bid_ids) in bids_by_price.iter() {
+				for (position, bid_id) in bid_ids.iter().enumerate() {
+					let neighbours = BidBucketNeighbours {
+						previous_bid_index: position.checked_sub(1).map(|previous| bid_ids[previous]),
+						next_bid_index: bid_ids.get(position.saturating_add(1)).copied(),
+					};
+					BidsBucketNeighbours::<T>::insert(project_id, bid_id, neighbours);
+					writes = writes.saturating_add(1);
+				}
+			}
+
+			let bounds: Vec<_> = BidsBucketBounds::<T>::iter_keys().collect();
+			for (project_id, price) in bounds {
+				reads = reads.saturating_add(1);
+				match bids_by_price.get(&(project_id, price)) {
+					Some(bid_ids) => BidsBucketBounds::<T>::insert(
+						project_id,
+						price,
+						BidBucketBounds { first_bid_index: bid_ids[0], last_bid_index: bid_ids[bid_ids.len() - 1] },
+					),
+					None => BidsBucketBounds::<T>::remove(project_id, price),
+				}
+				writes = writes.saturating_add(1);
+			}
+
+			// A cutoff on a bid settled early moves to the bid outbid right before it: the next stored bid at the same
+			// price, or else the first stored bid of the closest lower price.
+			let cutoffs: Vec<_> = OutbidBidsCutoffs::<T>::iter().collect();
+			for (project_id, cutoff) in cutoffs {
+				reads = reads.saturating_add(2);
+				if Bids::<T>::contains_key(project_id, cutoff.bid_index) {
+					continue;
+				}
+				let same_price = bids_by_price
+					.get(&(project_id, cutoff.bid_price))
+					.and_then(|bid_ids| bid_ids.iter().find(|bid_id| **bid_id > cutoff.bid_index))
+					.map(|bid_id| OutbidBidsCutoff { bid_price: cutoff.bid_price, bid_index: *bid_id });
+				let previous_cutoff = same_price.or_else(|| {
+					bids_by_price
+						.iter()
+						.rev()
+						.find(|((bids_project_id, price), _)| {
+							*bids_project_id == project_id && *price < cutoff.bid_price
+						})
+						.map(|((_, price), bid_ids)| OutbidBidsCutoff { bid_price: *price, bid_index: bid_ids[0] })
+				});
+				OutbidBidsCutoffs::<T>::set(project_id, previous_cutoff);
+				writes = writes.saturating_add(1);
+			}
+
+			log::info!(target: LOG, "Linked the bids of {} prices", bids_by_price.len());
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(pre_state: Vec<u8>) -> Result<(), DispatchError> {
+			let bids_count: u32 = Decode::decode(&mut &pre_state[..]).expect("Failed to decode pre-migration state");
+			let linked_count = BidsBucketNeighbours::<T>::iter_keys().count() as u32;
+
+			if linked_count != bids_count {
+				return Err("BidsBucketNeighbours does not link every bid".into());
+			}
+
+			Ok(())
+		}
+	}
+
+	pub type MigrationToV18<T> = frame_support::migrations::VersionedMigration<
+		17,
+		18,
+		UncheckedMigrationToV18<T>,
+		crate::Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{v18::*, *};
+	use crate::{
+		mock::{new_test_ext, TestRuntime as Test},
+		BidBucketBounds, BidBucketNeighbours, BidInfoOf, BidStatus, Bids, BidsBucketBounds, BidsBucketNeighbours,
+		OutbidBidsCutoff, OutbidBidsCutoffs, ParticipationMode, PriceOf,
+	};
+	use polimec_common::assets::AcceptedFundingAsset;
+	use polimec_common_test_utils::generate_did_from_account;
+	use sp_runtime::FixedPointNumber;
+	use xcm::v4::Junction;
+
+	fn bid(project_id: u32, id: u32, price: PriceOf<Test>) -> BidInfoOf<Test> {
+		BidInfoOf::<Test> {
+			id,
+			project_id,
+			bidder: 1,
+			did: generate_did_from_account(1),
+			status: BidStatus::YetUnknown,
+			original_ct_amount: 100,
+			original_ct_usd_price: price,
+			funding_asset: AcceptedFundingAsset::USDT,
+			funding_asset_amount_locked: 100,
+			mode: ParticipationMode::Classic(1),
+			plmc_bond: 100,
+			when: 1,
+			receiving_account: Junction::AccountId32 { network: None, id: [0u8; 32] },
+		}
+	}
+
+	#[test]
+	fn migration_to_v18() {
+		new_test_ext().execute_with(|| {
+			let low_price = PriceOf::<Test>::from_rational(1, 1);
+			let high_price = PriceOf::<Test>::from_rational(11, 10);
+			// Bid 1 belongs to another project, and bids 3 and 6 were settled early.
+			for (id, price) in [(0, low_price), (2, low_price), (4, high_price), (5, high_price)] {
+				Bids::<Test>::insert(0, id, bid(0, id, price));
+			}
+			Bids::<Test>::insert(1, 1, bid(1, 1, low_price));
+			BidsBucketBounds::<Test>::insert(0, low_price, BidBucketBounds { first_bid_index: 0, last_bid_index: 3 });
+			BidsBucketBounds::<Test>::insert(0, high_price, BidBucketBounds { first_bid_index: 4, last_bid_index: 6 });
+			OutbidBidsCutoffs::<Test>::insert(0, OutbidBidsCutoff { bid_price: low_price, bid_index: 3 });
+
+			UncheckedMigrationToV18::<Test>::on_runtime_upgrade();
+
+			assert_eq!(
+				BidsBucketNeighbours::<Test>::get(0, 0),
+				Some(BidBucketNeighbours { previous_bid_index: None, next_bid_index: Some(2) })
+			);
+			assert_eq!(
+				BidsBucketNeighbours::<Test>::get(0, 2),
+				Some(BidBucketNeighbours { previous_bid_index: Some(0), next_bid_index: None })
+			);
+			assert_eq!(
+				BidsBucketNeighbours::<Test>::get(0, 5),
+				Some(BidBucketNeighbours { previous_bid_index: Some(4), next_bid_index: None })
+			);
+			assert_eq!(BidsBucketNeighbours::<Test>::get(1, 1), Some(BidBucketNeighbours::default()));
+			assert_eq!(
+				BidsBucketBounds::<Test>::get(0, low_price),
+				Some(BidBucketBounds { first_bid_index: 0, last_bid_index: 2 })
+			);
+			assert_eq!(
+				BidsBucketBounds::<Test>::get(0, high_price),
+				Some(BidBucketBounds { first_bid_index: 4, last_bid_index: 5 })
+			);
+			// No stored bid was outbid after bid 3, so no bid is outbid anymore.
+			assert_eq!(OutbidBidsCutoffs::<Test>::get(0), None);
+		});
+	}
+}
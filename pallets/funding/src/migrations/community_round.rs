@@ -0,0 +1,183 @@
+// community_round.rs
+
+use crate::{AccountIdOf, Config, ProjectDetailsOf};
+use alloc::vec::Vec;
+use frame_support::{pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade, weights::Weight};
+use frame_system::pallet_prelude::BlockNumberFor;
+use polimec_common::credentials::Did;
+
+pub mod v8 {
+	use super::*;
+	use crate::{Balance, BlockNumberPair, EvaluationRoundInfo, FundingOutcome, ProjectStatus};
+
+	const LOG: &str = "funding::migration::v8";
+
+	/// `ProjectStatus` before the community round was added after the auction.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	pub enum OldProjectStatus {
+		Application,
+		EvaluationRound,
+		AuctionRound,
+		FundingFailed,
+		FundingSuccessful,
+		SettlementStarted(FundingOutcome),
+		SettlementFinished(FundingOutcome),
+		CTMigrationStarted,
+		CTMigrationFinished,
+	}
+
+	impl From<OldProjectStatus> for ProjectStatus {
+		fn from(status: OldProjectStatus) -> Self {
+			match status {
+				OldProjectStatus::Application => ProjectStatus::Application,
+				OldProjectStatus::EvaluationRound => ProjectStatus::EvaluationRound,
+				OldProjectStatus::AuctionRound => ProjectStatus::AuctionRound,
+				OldProjectStatus::FundingFailed => ProjectStatus::FundingFailed,
+				OldProjectStatus::FundingSuccessful => ProjectStatus::FundingSuccessful,
+				OldProjectStatus::SettlementStarted(outcome) => ProjectStatus::SettlementStarted(outcome),
+				OldProjectStatus::SettlementFinished(outcome) => ProjectStatus::SettlementFinished(outcome),
+				OldProjectStatus::CTMigrationStarted => ProjectStatus::CTMigrationStarted,
+				OldProjectStatus::CTMigrationFinished => ProjectStatus::CTMigrationFinished,
+			}
+		}
+	}
+
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	pub struct OldProjectDetails<AccountId, Did, BlockNumber, EvaluationRoundInfo> {
+		pub issuer_account: AccountId,
+		pub issuer_did: Did,
+		pub is_frozen: bool,
+		pub status: OldProjectStatus,
+		pub round_duration: BlockNumberPair<BlockNumber>,
+		pub fundraising_target_usd: Balance,
+		pub remaining_contribution_tokens: Balance,
+		pub funding_amount_reached_usd: Balance,
+		pub evaluation_round_info: EvaluationRoundInfo,
+		pub usd_bid_on_oversubscription: Option<Balance>,
+		pub funding_end_block: Option<BlockNumber>,
+	}
+
+	pub type OldProjectDetailsOf<T> = OldProjectDetails<AccountIdOf<T>, Did, BlockNumberFor<T>, EvaluationRoundInfo>;
+
+	pub struct UncheckedMigrationToV8<T: Config>(PhantomData<T>);
+	impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrationToV8<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, DispatchError> {
+			let project_count = crate::ProjectsDetails::<T>::iter_keys().count() as u32;
+			log::info!(target: LOG, "Pre-upgrade: {} ProjectsDetails entries", project_count);
+
+			Ok(project_count.encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let mut items = 0u64;
+			log::info!(target: LOG, "Starting project status migration to V8");
+
+			let translate_project_details = |_key, item: OldProjectDetailsOf<T>| -> Option<ProjectDetailsOf<T>> {
+				items = items.saturating_add(1);
+				Some(ProjectDetailsOf::<T> {
+					issuer_account: item.issuer_account,
+					issuer_did: item.issuer_did,
+					is_frozen: item.is_frozen,
+					status: item.status.into(),
+					round_duration: item.round_duration,
+					fundraising_target_usd: item.fundraising_target_usd,
+					remaining_contribution_tokens: item.remaining_contribution_tokens,
+					funding_amount_reached_usd: item.funding_amount_reached_usd,
+					evaluation_round_info: item.evaluation_round_info,
+					usd_bid_on_oversubscription: item.usd_bid_on_oversubscription,
+					funding_end_block: item.funding_end_block,
+				})
+			};
+			crate::ProjectsDetails::<T>::translate(translate_project_details);
+
+			log::info!(target: LOG, "Migrated {} project details", items);
+			T::DbWeight::get().reads_writes(items, items)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(pre_state: Vec<u8>) -> Result<(), DispatchError> {
+			let pre_project_count: u32 =
+				Decode::decode(&mut &pre_state[..]).expect("Failed to decode pre-migration state");
+			let post_project_count = crate::ProjectsDetails::<T>::iter_values().count() as u32;
+
+			if pre_project_count != post_project_count {
+				return Err("ProjectsDetails count mismatch".into());
+			}
+
+			Ok(())
+		}
+	}
+
+	pub type MigrationToV8<T> = frame_support::migrations::VersionedMigration<
+		7,
+		8,
+		UncheckedMigrationToV8<T>,
+		crate::Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{v8::*, *};
+	use crate::{
+		mock::{new_test_ext, TestRuntime as Test},
+		EvaluationRoundInfo, FundingOutcome, ProjectStatus, ProjectsDetails,
+	};
+	use frame_support::{storage::unhashed, weights::RuntimeDbWeight};
+	use polimec_common_test_utils::generate_did_from_account;
+
+	fn old_project_details(status: OldProjectStatus) -> OldProjectDetailsOf<Test> {
+		OldProjectDetails {
+			issuer_account: 1,
+			issuer_did: generate_did_from_account(1),
+			is_frozen: true,
+			status,
+			round_duration: Default::default(),
+			fundraising_target_usd: 1_000_000,
+			remaining_contribution_tokens: 500_000,
+			funding_amount_reached_usd: 250_000,
+			evaluation_round_info: EvaluationRoundInfo {
+				total_bonded_usd: 100_000,
+				total_bonded_plmc: 10_000,
+				evaluators_outcome: None,
+			},
+			usd_bid_on_oversubscription: None,
+			funding_end_block: Some(42),
+		}
+	}
+
+	#[test]
+	fn migration_to_v8() {
+		let mut ext = new_test_ext();
+		ext.execute_with(|| {
+			let old_statuses = [
+				(OldProjectStatus::AuctionRound, ProjectStatus::AuctionRound),
+				(OldProjectStatus::FundingFailed, ProjectStatus::FundingFailed),
+				(
+					OldProjectStatus::SettlementFinished(FundingOutcome::Success),
+					ProjectStatus::SettlementFinished(FundingOutcome::Success),
+				),
+				(OldProjectStatus::CTMigrationFinished, ProjectStatus::CTMigrationFinished),
+			];
+			for (project_id, (old_status, _)) in old_statuses.iter().enumerate() {
+				let key = ProjectsDetails::<Test>::hashed_key_for(project_id as u32);
+				unhashed::put(&key, &old_project_details(old_status.clone()));
+			}
+
+			let weight = UncheckedMigrationToV8::<Test>::on_runtime_upgrade();
+
+			for (project_id, (old_status, new_status)) in old_statuses.into_iter().enumerate() {
+				let old_details = old_project_details(old_status);
+				let new_details = ProjectsDetails::<Test>::get(project_id as u32).unwrap();
+				assert_eq!(new_details.status, new_status);
+				assert_eq!(new_details.issuer_did, old_details.issuer_did);
+				assert_eq!(new_details.remaining_contribution_tokens, old_details.remaining_contribution_tokens);
+				assert_eq!(new_details.funding_end_block, old_details.funding_end_block);
+			}
+			let db_weight: RuntimeDbWeight = <Test as frame_system::Config>::DbWeight::get();
+			assert_eq!(weight, db_weight.reads_writes(4, 4));
+		});
+	}
+}
@@ -0,0 +1,143 @@
+// ct_vesting.rs
+
+use crate::{AccountIdOf, Config, PriceOf, ProjectMetadataOf, StringLimitOf};
+use alloc::vec::Vec;
+use frame_support::{pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade, weights::Weight};
+use polimec_common::{
+	assets::{AcceptedFundingAsset, MAX_FUNDING_ASSETS},
+	credentials::Cid,
+};
+
+pub mod v11 {
+	use super::*;
+	use crate::{Balance, BiddingTicketSizes, CurrencyMetadata, ParticipantsAccountType, SaleMode};
+
+	const LOG: &str = "funding::migration::v11";
+
+	/// `ProjectMetadata` before the contribution token vesting was added.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	pub struct OldProjectMetadata<BoundedString, Price, AccountId, Cid> {
+		pub token_information: CurrencyMetadata<BoundedString>,
+		pub mainnet_token_max_supply: Balance,
+		pub total_allocation_size: Balance,
+		pub minimum_price: Price,
+		pub bidding_ticket_sizes: BiddingTicketSizes<Price>,
+		pub participation_currencies: BoundedVec<AcceptedFundingAsset, ConstU32<MAX_FUNDING_ASSETS>>,
+		pub funding_destination_account: AccountId,
+		pub policy_ipfs_cid: Option<Cid>,
+		pub participants_account_type: ParticipantsAccountType,
+		pub sale_mode: SaleMode<Price>,
+	}
+
+	pub type OldProjectMetadataOf<T> =
+		OldProjectMetadata<BoundedVec<u8, StringLimitOf<T>>, PriceOf<T>, AccountIdOf<T>, Cid>;
+
+	pub struct UncheckedMigrationToV11<T: Config>(PhantomData<T>);
+	impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrationToV11<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, DispatchError> {
+			let project_count = crate::ProjectsMetadata::<T>::iter_keys().count() as u32;
+			log::info!(target: LOG, "Pre-upgrade: {} ProjectsMetadata entries", project_count);
+
+			Ok(project_count.encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let mut items = 0u64;
+			log::info!(target: LOG, "Starting project metadata migration to V11");
+
+			let translate_project_metadata = |_key, item: OldProjectMetadataOf<T>| -> Option<ProjectMetadataOf<T>> {
+				items = items.saturating_add(1);
+				Some(ProjectMetadataOf::<T> {
+					token_information: item.token_information,
+					mainnet_token_max_supply: item.mainnet_token_max_supply,
+					total_allocation_size: item.total_allocation_size,
+					minimum_price: item.minimum_price,
+					bidding_ticket_sizes: item.bidding_ticket_sizes,
+					participation_currencies: item.participation_currencies,
+					funding_destination_account: item.funding_destination_account,
+					policy_ipfs_cid: item.policy_ipfs_cid,
+					participants_account_type: item.participants_account_type,
+					sale_mode: item.sale_mode,
+					ct_vesting: None,
+					funding_milestones: BoundedVec::new(),
+					evaluation_success_threshold: None,
+					funding_success_threshold: None,
+				})
+			};
+			crate::ProjectsMetadata::<T>::translate(translate_project_metadata);
+
+			log::info!(target: LOG, "Migrated {} project metadata", items);
+			T::DbWeight::get().reads_writes(items, items)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(pre_state: Vec<u8>) -> Result<(), DispatchError> {
+			let pre_project_count: u32 =
+				Decode::decode(&mut &pre_state[..]).expect("Failed to decode pre-migration state");
+			let post_project_count = crate::ProjectsMetadata::<T>::iter_values().count() as u32;
+
+			if pre_project_count != post_project_count {
+				return Err("ProjectsMetadata count mismatch".into());
+			}
+
+			Ok(())
+		}
+	}
+
+	pub type MigrationToV11<T> = frame_support::migrations::VersionedMigration<
+		10,
+		11,
+		UncheckedMigrationToV11<T>,
+		crate::Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{v11::*, *};
+	use crate::{
+		mock::{new_test_ext, TestRuntime as Test},
+		tests::defaults::default_project_metadata,
+		ProjectsMetadata,
+	};
+	use frame_support::{storage::unhashed, weights::RuntimeDbWeight};
+
+	fn old_project_metadata(issuer: u64) -> OldProjectMetadataOf<Test> {
+		let metadata = default_project_metadata(issuer);
+		OldProjectMetadata {
+			token_information: metadata.token_information,
+			mainnet_token_max_supply: metadata.mainnet_token_max_supply,
+			total_allocation_size: metadata.total_allocation_size,
+			minimum_price: metadata.minimum_price,
+			bidding_ticket_sizes: metadata.bidding_ticket_sizes,
+			participation_currencies: metadata.participation_currencies,
+			funding_destination_account: metadata.funding_destination_account,
+			policy_ipfs_cid: metadata.policy_ipfs_cid,
+			participants_account_type: metadata.participants_account_type,
+			sale_mode: metadata.sale_mode,
+		}
+	}
+
+	#[test]
+	fn migration_to_v11() {
+		let mut ext = new_test_ext();
+		ext.execute_with(|| {
+			for project_id in 0..3u32 {
+				let key = ProjectsMetadata::<Test>::hashed_key_for(project_id);
+				unhashed::put(&key, &old_project_metadata(project_id as u64));
+			}
+
+			let weight = UncheckedMigrationToV11::<Test>::on_runtime_upgrade();
+
+			for project_id in 0..3u32 {
+				let new_metadata = ProjectsMetadata::<Test>::get(project_id).unwrap();
+				assert_eq!(new_metadata.ct_vesting, None);
+				assert_eq!(new_metadata, default_project_metadata(project_id as u64));
+			}
+			let db_weight: RuntimeDbWeight = <Test as frame_system::Config>::DbWeight::get();
+			assert_eq!(weight, db_weight.reads_writes(3, 3));
+		});
+	}
+}
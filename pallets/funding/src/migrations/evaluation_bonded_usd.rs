@@ -0,0 +1,109 @@
+// evaluation_bonded_usd.rs
+
+use crate::Config;
+use alloc::vec::Vec;
+use frame_support::{pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade, weights::Weight};
+
+pub mod v17 {
+	use super::*;
+	use crate::{EvaluationBondedUSD, Evaluations};
+
+	const LOG: &str = "funding::migration::v17";
+
+	/// Index the stored evaluations by DID in `EvaluationBondedUSD`. Evaluations of settled projects are no longer
+	/// stored, so they are not indexed.
+	pub struct UncheckedMigrationToV17<T: Config>(PhantomData<T>);
+	impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrationToV17<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, DispatchError> {
+			let evaluations_count = Evaluations::<T>::iter_keys().count() as u32;
+			log::info!(target: LOG, "Pre-upgrade: {} Evaluations entries", evaluations_count);
+
+			Ok(evaluations_count.encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let mut reads = 0u64;
+			let mut writes = 0u64;
+			log::info!(target: LOG, "Starting evaluation bonded USD migration to V17");
+
+			for evaluation in Evaluations::<T>::iter_values() {
+				reads = reads.saturating_add(2);
+				let usd_amount = evaluation.early_usd_amount.saturating_add(evaluation.late_usd_amount);
+				EvaluationBondedUSD::<T>::mutate((evaluation.project_id, evaluation.did), |amount| {
+					*amount = amount.saturating_add(usd_amount)
+				});
+				writes = writes.saturating_add(1);
+			}
+
+			log::info!(target: LOG, "Indexed {} evaluations", writes);
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(pre_state: Vec<u8>) -> Result<(), DispatchError> {
+			let evaluations_count: u32 =
+				Decode::decode(&mut &pre_state[..]).expect("Failed to decode pre-migration state");
+			let indexed_count = EvaluationBondedUSD::<T>::iter_keys().count() as u32;
+
+			if evaluations_count > 0 && indexed_count == 0 {
+				return Err("EvaluationBondedUSD was not populated".into());
+			}
+
+			Ok(())
+		}
+	}
+
+	pub type MigrationToV17<T> = frame_support::migrations::VersionedMigration<
+		16,
+		17,
+		UncheckedMigrationToV17<T>,
+		crate::Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{v17::*, *};
+	use crate::{mock::TestRuntime as Test, EvaluationBondedUSD, EvaluationInfoOf, Evaluations};
+	use polimec_common_test_utils::generate_did_from_account;
+	use xcm::v4::Junction;
+
+	fn evaluation(project_id: u32, evaluator: u64, id: u32, usd_amount: u128) -> EvaluationInfoOf<Test> {
+		EvaluationInfoOf::<Test> {
+			id,
+			did: generate_did_from_account(evaluator),
+			project_id,
+			evaluator,
+			original_plmc_bond: usd_amount,
+			current_plmc_bond: usd_amount,
+			early_usd_amount: usd_amount / 2,
+			late_usd_amount: usd_amount / 2,
+			when: 1,
+			receiving_account: Junction::AccountId32 { network: None, id: [0u8; 32] },
+		}
+	}
+
+	#[test]
+	fn migration_to_v17() {
+		crate::mock::new_test_ext().execute_with(|| {
+			let evaluations = [
+				evaluation(0, 1, 0, 1_000),
+				evaluation(0, 1, 1, 3_000),
+				evaluation(0, 2, 2, 500),
+				evaluation(1, 1, 3, 700),
+			];
+			for evaluation in evaluations {
+				Evaluations::<Test>::insert((evaluation.project_id, evaluation.evaluator, evaluation.id), evaluation);
+			}
+
+			UncheckedMigrationToV17::<Test>::on_runtime_upgrade();
+
+			assert_eq!(EvaluationBondedUSD::<Test>::get((0, generate_did_from_account(1))), 4_000);
+			assert_eq!(EvaluationBondedUSD::<Test>::get((0, generate_did_from_account(2))), 500);
+			assert_eq!(EvaluationBondedUSD::<Test>::get((1, generate_did_from_account(1))), 700);
+			assert_eq!(EvaluationBondedUSD::<Test>::get((1, generate_did_from_account(2))), 0);
+		});
+	}
+}
@@ -0,0 +1,79 @@
+// funding_assets.rs
+
+use crate::{Config, FundingAssets};
+use alloc::vec::Vec;
+use frame_support::{pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade, weights::Weight};
+use polimec_common::assets::AcceptedFundingAsset;
+
+pub mod v13 {
+	use super::*;
+
+	const LOG: &str = "funding::migration::v13";
+
+	/// Registers the funding assets that were hardcoded before the funding asset registry existed.
+	pub struct UncheckedMigrationToV13<T: Config>(PhantomData<T>);
+	impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrationToV13<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, DispatchError> {
+			let asset_count = FundingAssets::<T>::iter_keys().count() as u32;
+			log::info!(target: LOG, "Pre-upgrade: {} FundingAssets entries", asset_count);
+
+			Ok(asset_count.encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let mut items = 0u64;
+			log::info!(target: LOG, "Starting funding asset registry migration to V13");
+
+			for (asset, info) in AcceptedFundingAsset::builtin_assets() {
+				if !FundingAssets::<T>::contains_key(asset) {
+					FundingAssets::<T>::insert(asset, info);
+					items = items.saturating_add(1);
+				}
+			}
+
+			log::info!(target: LOG, "Registered {} funding assets", items);
+			let builtin_count = AcceptedFundingAsset::builtin_assets().len() as u64;
+			T::DbWeight::get().reads_writes(builtin_count, items)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_pre_state: Vec<u8>) -> Result<(), DispatchError> {
+			for (asset, _) in AcceptedFundingAsset::builtin_assets() {
+				if !FundingAssets::<T>::contains_key(asset) {
+					return Err("Built-in funding asset not registered".into());
+				}
+			}
+
+			Ok(())
+		}
+	}
+
+	pub type MigrationToV13<T> = frame_support::migrations::VersionedMigration<
+		12,
+		13,
+		UncheckedMigrationToV13<T>,
+		crate::Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{v13::*, *};
+	use crate::mock::{new_test_ext, TestRuntime as Test};
+
+	#[test]
+	fn registers_builtin_assets() {
+		new_test_ext().execute_with(|| {
+			let _ = FundingAssets::<Test>::clear(u32::MAX, None);
+			assert_eq!(FundingAssets::<Test>::iter_keys().count(), 0);
+
+			UncheckedMigrationToV13::<Test>::on_runtime_upgrade();
+
+			for (asset, info) in AcceptedFundingAsset::builtin_assets() {
+				assert_eq!(FundingAssets::<Test>::get(asset), Some(info));
+			}
+		});
+	}
+}
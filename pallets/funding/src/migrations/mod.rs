@@ -2,8 +2,19 @@
 
 use frame_support::traits::StorageVersion;
 
+pub mod active_projects;
+pub mod bid_bucket_neighbours;
+pub mod community_round;
+pub mod ct_vesting;
+pub mod evaluation_bonded_usd;
+pub mod funding_assets;
+pub mod funding_milestones;
+pub mod sale_mode;
+pub mod settlement_queue;
 pub mod storage_migrations;
+pub mod success_thresholds;
+pub mod transition_queue;
 pub mod vesting_info;
 
 /// Current storage version
-pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(7);
+pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(18);
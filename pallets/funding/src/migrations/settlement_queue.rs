@@ -0,0 +1,120 @@
+// settlement_queue.rs
+
+use crate::Config;
+use alloc::vec::Vec;
+use frame_support::{pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade, weights::Weight};
+
+pub mod v16 {
+	use super::*;
+	use crate::{ProjectStatus, ProjectsDetails, ProjectsInSettlement, SettlementCursor};
+
+	const LOG: &str = "funding::migration::v16";
+
+	/// Queue the projects that were already in the settlement round before `ProjectsInSettlement` existed, so their
+	/// participations are also settled automatically.
+	pub struct UncheckedMigrationToV16<T: Config>(PhantomData<T>);
+	impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrationToV16<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, DispatchError> {
+			let queued_count = ProjectsInSettlement::<T>::iter_keys().count() as u32;
+			log::info!(target: LOG, "Pre-upgrade: {} ProjectsInSettlement entries", queued_count);
+
+			Ok(queued_count.encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let mut reads = 0u64;
+			let mut writes = 0u64;
+			log::info!(target: LOG, "Starting settlement queue migration to V16");
+
+			for (project_id, project_details) in ProjectsDetails::<T>::iter() {
+				reads = reads.saturating_add(1);
+				if !matches!(project_details.status, ProjectStatus::SettlementStarted(_)) {
+					continue;
+				}
+				reads = reads.saturating_add(1);
+				if !ProjectsInSettlement::<T>::contains_key(project_id) {
+					ProjectsInSettlement::<T>::insert(project_id, SettlementCursor::default());
+					writes = writes.saturating_add(1);
+				}
+			}
+
+			log::info!(target: LOG, "Queued {} projects for settlement", writes);
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(pre_state: Vec<u8>) -> Result<(), DispatchError> {
+			let pre_queued_count: u32 =
+				Decode::decode(&mut &pre_state[..]).expect("Failed to decode pre-migration state");
+			let post_queued_count = ProjectsInSettlement::<T>::iter_keys().count() as u32;
+
+			if post_queued_count < pre_queued_count {
+				return Err("ProjectsInSettlement lost entries".into());
+			}
+
+			Ok(())
+		}
+	}
+
+	pub type MigrationToV16<T> = frame_support::migrations::VersionedMigration<
+		15,
+		16,
+		UncheckedMigrationToV16<T>,
+		crate::Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{v16::*, *};
+	use crate::{
+		mock::{new_test_ext, TestRuntime as Test},
+		BlockNumberPair, EvaluationRoundInfo, FundingOutcome, ProjectDetailsOf, ProjectStatus, ProjectsDetails,
+		ProjectsInSettlement, SettlementCursor,
+	};
+	use polimec_common_test_utils::generate_did_from_account;
+
+	fn project_details(status: ProjectStatus) -> ProjectDetailsOf<Test> {
+		ProjectDetailsOf::<Test> {
+			issuer_account: 1,
+			issuer_did: generate_did_from_account(1),
+			is_frozen: true,
+			status,
+			round_duration: BlockNumberPair::new(None, None),
+			fundraising_target_usd: 1_000_000,
+			remaining_contribution_tokens: 500_000,
+			funding_amount_reached_usd: 0,
+			evaluation_round_info: EvaluationRoundInfo {
+				total_bonded_usd: 0,
+				total_bonded_plmc: 0,
+				evaluators_outcome: None,
+			},
+			usd_bid_on_oversubscription: None,
+			funding_end_block: Some(1),
+		}
+	}
+
+	#[test]
+	fn migration_to_v16() {
+		new_test_ext().execute_with(|| {
+			let statuses = [
+				(ProjectStatus::SettlementStarted(FundingOutcome::Success), true),
+				(ProjectStatus::SettlementStarted(FundingOutcome::Failure), true),
+				(ProjectStatus::SettlementFinished(FundingOutcome::Success), false),
+				(ProjectStatus::FundingSuccessful, false),
+			];
+			for (project_id, (status, _)) in statuses.iter().enumerate() {
+				ProjectsDetails::<Test>::insert(project_id as u32, project_details(status.clone()));
+			}
+
+			UncheckedMigrationToV16::<Test>::on_runtime_upgrade();
+
+			for (project_id, (_, queued)) in statuses.into_iter().enumerate() {
+				assert_eq!(ProjectsInSettlement::<Test>::get(project_id as u32).is_some(), queued);
+			}
+			assert_eq!(ProjectsInSettlement::<Test>::get(0), Some(SettlementCursor::default()));
+		});
+	}
+}
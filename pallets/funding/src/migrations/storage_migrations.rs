@@ -1,11 +1,14 @@
 //! A module that is responsible for migration of storage.
 use crate::{
 	AccountIdOf, BiddingTicketSizes, Config, CurrencyMetadata, FixedPointNumber, ParticipantsAccountType, PriceOf,
-	ProjectMetadataOf, StringLimitOf,
+	ProjectMetadataOf, SaleMode, StringLimitOf,
 };
 use core::marker::PhantomData;
 use frame_support::traits::UncheckedOnRuntimeUpgrade;
-use polimec_common::{assets::AcceptedFundingAsset, credentials::Cid};
+use polimec_common::{
+	assets::{AcceptedFundingAsset, MAX_FUNDING_ASSETS},
+	credentials::Cid,
+};
 use scale_info::TypeInfo;
 use serde::{Deserialize, Serialize};
 use sp_core::{ConstU32, Decode, Encode, Get, MaxEncodedLen, RuntimeDebug};
@@ -146,8 +149,7 @@ pub mod v5_storage_items {
 		/// Participation currencies (e.g stablecoin, DOT, KSM)
 		/// e.g. https://github.com/paritytech/substrate/blob/427fd09bcb193c1e79dec85b1e207c718b686c35/frame/uniques/src/types.rs#L110
 		/// For now is easier to handle the case where only just one Currency is accepted
-		pub participation_currencies:
-			BoundedVec<AcceptedFundingAsset, ConstU32<{ AcceptedFundingAsset::VARIANT_COUNT as u32 }>>,
+		pub participation_currencies: BoundedVec<AcceptedFundingAsset, ConstU32<MAX_FUNDING_ASSETS>>,
 		pub funding_destination_account: AccountId,
 		/// Additional metadata
 		pub policy_ipfs_cid: Option<Cid>,
@@ -250,6 +252,11 @@ pub mod v6 {
 					funding_destination_account: item.funding_destination_account,
 					policy_ipfs_cid: item.policy_ipfs_cid,
 					participants_account_type: ParticipantsAccountType::Polkadot,
+					sale_mode: SaleMode::Auction,
+					ct_vesting: None,
+					funding_milestones: BoundedVec::new(),
+					evaluation_success_threshold: None,
+					funding_success_threshold: None,
 				})
 			};
 			crate::ProjectsMetadata::<T>::translate(translate_project_metadata);
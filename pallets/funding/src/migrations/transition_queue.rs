@@ -0,0 +1,126 @@
+// transition_queue.rs
+
+use crate::Config;
+use alloc::vec::Vec;
+use frame_support::{pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade, weights::Weight};
+use sp_runtime::traits::{BlockNumberProvider, One, Saturating};
+
+pub mod v9 {
+	use super::*;
+	use crate::{ProjectStatus, ProjectTransitionQueue, ProjectsDetails};
+
+	const LOG: &str = "funding::migration::v9";
+
+	/// Schedule the automatic transition of the projects that were already running before the transition queue existed.
+	pub struct UncheckedMigrationToV9<T: Config>(PhantomData<T>);
+	impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrationToV9<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, DispatchError> {
+			let queued_count = ProjectTransitionQueue::<T>::iter_keys().count() as u32;
+			log::info!(target: LOG, "Pre-upgrade: {} ProjectTransitionQueue entries", queued_count);
+
+			Ok(queued_count.encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let mut reads = 0u64;
+			let mut writes = 0u64;
+			log::info!(target: LOG, "Starting transition queue migration to V9");
+
+			let now = <T as Config>::BlockNumberProvider::current_block_number();
+			for (project_id, project_details) in ProjectsDetails::<T>::iter() {
+				reads = reads.saturating_add(1);
+				let transition_block = match project_details.status {
+					ProjectStatus::EvaluationRound | ProjectStatus::AuctionRound | ProjectStatus::CommunityRound =>
+						project_details.round_duration.end().map(|end| end.saturating_add(One::one()).max(now)),
+					ProjectStatus::FundingSuccessful | ProjectStatus::FundingFailed => Some(now),
+					_ => None,
+				};
+				if let Some(block) = transition_block {
+					ProjectTransitionQueue::<T>::insert(block, project_id, ());
+					writes = writes.saturating_add(1);
+				}
+			}
+
+			log::info!(target: LOG, "Scheduled {} project transitions", writes);
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(pre_state: Vec<u8>) -> Result<(), DispatchError> {
+			let pre_queued_count: u32 =
+				Decode::decode(&mut &pre_state[..]).expect("Failed to decode pre-migration state");
+			let post_queued_count = ProjectTransitionQueue::<T>::iter_keys().count() as u32;
+
+			if post_queued_count < pre_queued_count {
+				return Err("ProjectTransitionQueue lost entries".into());
+			}
+
+			Ok(())
+		}
+	}
+
+	pub type MigrationToV9<T> = frame_support::migrations::VersionedMigration<
+		8,
+		9,
+		UncheckedMigrationToV9<T>,
+		crate::Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{v9::*, *};
+	use crate::{
+		mock::{new_test_ext, System, TestRuntime as Test},
+		BlockNumberPair, EvaluationRoundInfo, ProjectDetailsOf, ProjectStatus, ProjectTransitionQueue, ProjectsDetails,
+	};
+	use polimec_common_test_utils::generate_did_from_account;
+
+	fn project_details(status: ProjectStatus, round_end: Option<u64>) -> ProjectDetailsOf<Test> {
+		ProjectDetailsOf::<Test> {
+			issuer_account: 1,
+			issuer_did: generate_did_from_account(1),
+			is_frozen: true,
+			status,
+			round_duration: BlockNumberPair::new(Some(1), round_end),
+			fundraising_target_usd: 1_000_000,
+			remaining_contribution_tokens: 500_000,
+			funding_amount_reached_usd: 0,
+			evaluation_round_info: EvaluationRoundInfo {
+				total_bonded_usd: 0,
+				total_bonded_plmc: 0,
+				evaluators_outcome: None,
+			},
+			usd_bid_on_oversubscription: None,
+			funding_end_block: None,
+		}
+	}
+
+	#[test]
+	fn migration_to_v9() {
+		let mut ext = new_test_ext();
+		ext.execute_with(|| {
+			System::set_block_number(10);
+			let statuses = [
+				(ProjectStatus::EvaluationRound, Some(20), Some(21)),
+				(ProjectStatus::AuctionRound, Some(5), Some(10)),
+				(ProjectStatus::FundingSuccessful, None, Some(10)),
+				(ProjectStatus::CTMigrationFinished, None, None),
+			];
+			for (project_id, (status, end, _)) in statuses.iter().enumerate() {
+				ProjectsDetails::<Test>::insert(project_id as u32, project_details(status.clone(), *end));
+			}
+
+			UncheckedMigrationToV9::<Test>::on_runtime_upgrade();
+
+			for (project_id, (_, _, expected_block)) in statuses.into_iter().enumerate() {
+				let scheduled_block = ProjectTransitionQueue::<Test>::iter_keys()
+					.find(|(_, id)| *id == project_id as u32)
+					.map(|(block, _)| block);
+				assert_eq!(scheduled_block, expected_block);
+			}
+		});
+	}
+}
@@ -26,13 +26,19 @@ use frame_support::{
 	construct_runtime, derive_impl,
 	pallet_prelude::Weight,
 	parameter_types,
-	traits::{AsEnsureOriginWithArg, ConstU16, ConstU32, ConstU64, EnqueueWithOrigin, OriginTrait, WithdrawReasons},
+	traits::{
+		AsEnsureOriginWithArg, ConstU16, ConstU32, ConstU64, Disabled, EnqueueWithOrigin, Everything, OriginTrait,
+		WithdrawReasons,
+	},
 	PalletId,
 };
 use frame_system as system;
 use frame_system::{EnsureRoot, RawOrigin as SystemRawOrigin};
 use functions::runtime_api::{ExtrinsicHelpers, Leaderboards, ProjectInformation, UserInformation};
-use polimec_common::{assets::AcceptedFundingAsset, credentials::EnsureInvestor, ProvideAssetPrice, USD_UNIT};
+use polimec_common::{
+	assets::AcceptedFundingAsset, credentials::EnsureInvestor, migration_types::MigrationInfo, ProvideAssetPrice,
+	USD_UNIT,
+};
 use polkadot_parachain_primitives::primitives::Sibling;
 use sp_arithmetic::{Perbill, Percent};
 use sp_core::{
@@ -47,8 +53,8 @@ use sp_runtime::{
 };
 use std::{cell::RefCell, marker::PhantomData};
 use system::EnsureSigned;
-use xcm_builder::{ParentIsPreset, SiblingParachainConvertsVia};
-use xcm_executor::traits::XcmAssetTransfers;
+use xcm_builder::{EnsureXcmOrigin, FixedWeightBounds, ParentIsPreset, SiblingParachainConvertsVia};
+use xcm_executor::traits::{FeeManager, FeeReason, XcmAssetTransfers};
 
 pub const PLMC: Balance = 10u128.pow(PLMC_DECIMALS as u32);
 pub const MILLI_PLMC: Balance = PLMC / 10u128.pow(3);
@@ -73,16 +79,16 @@ pub const fn free_deposit() -> Balance {
 
 pub struct SignedToAccountIndex<RuntimeOrigin, AccountId, Network>(PhantomData<(RuntimeOrigin, AccountId, Network)>);
 
-impl<RuntimeOrigin: OriginTrait + Clone, AccountId: Into<u64>, Network: Get<Option<NetworkId>>>
-	TryConvert<RuntimeOrigin, Location> for SignedToAccountIndex<RuntimeOrigin, AccountId, Network>
+impl<RuntimeOrigin: OriginTrait + Clone, AccountId: Into<u64>, Network: Get<Option<xcm::v5::NetworkId>>>
+	TryConvert<RuntimeOrigin, xcm::v5::Location> for SignedToAccountIndex<RuntimeOrigin, AccountId, Network>
 where
 	RuntimeOrigin::PalletsOrigin:
 		From<SystemRawOrigin<AccountId>> + TryInto<SystemRawOrigin<AccountId>, Error = RuntimeOrigin::PalletsOrigin>,
 {
-	fn try_convert(o: RuntimeOrigin) -> Result<Location, RuntimeOrigin> {
+	fn try_convert(o: RuntimeOrigin) -> Result<xcm::v5::Location, RuntimeOrigin> {
 		o.try_with_caller(|caller| match caller.try_into() {
 			Ok(SystemRawOrigin::Signed(who)) =>
-				Ok(Junction::AccountIndex64 { network: Network::get(), index: Into::<u64>::into(who) }.into()),
+				Ok(xcm::v5::Junction::AccountIndex64 { network: Network::get(), index: Into::<u64>::into(who) }.into()),
 			Ok(other) => Err(other.into()),
 			Err(other) => Err(other),
 		})
@@ -97,11 +103,11 @@ pub type LocationToAccountId = (
 );
 
 parameter_types! {
-	pub UniversalLocation: InteriorLocation = (
-		GlobalConsensus(Polkadot),
-		 Parachain(3344u32),
+	pub UniversalLocation: xcm::v5::InteriorLocation = (
+		xcm::v5::Junction::GlobalConsensus(xcm::v5::NetworkId::Polkadot),
+		xcm::v5::Junction::Parachain(3344u32),
 	).into();
-	pub const RelayNetwork: Option<NetworkId> = None;
+	pub const RelayNetwork: Option<xcm::v5::NetworkId> = None;
 	pub UnitWeightCost: Weight = Weight::from_parts(1_000_000_000, 64 * 1024);
 	pub const MaxInstructions: u32 = 100;
 
@@ -109,7 +115,7 @@ parameter_types! {
 }
 
 pub struct MockPrepared;
-impl PreparedMessage for MockPrepared {
+impl xcm::v5::PreparedMessage for MockPrepared {
 	fn weight_of(&self) -> Weight {
 		Weight::zero()
 	}
@@ -122,27 +128,92 @@ impl XcmAssetTransfers for MockXcmExecutor {
 	type IsTeleporter = ();
 }
 
-impl ExecuteXcm<RuntimeCall> for MockXcmExecutor {
+impl FeeManager for MockXcmExecutor {
+	fn is_waived(_origin: Option<&xcm::v5::Location>, _reason: FeeReason) -> bool {
+		true
+	}
+
+	fn handle_fee(_fee: xcm::v5::Assets, _context: Option<&xcm::v5::XcmContext>, _reason: FeeReason) {}
+}
+
+impl xcm::v5::ExecuteXcm<RuntimeCall> for MockXcmExecutor {
 	type Prepared = MockPrepared;
 
-	fn prepare(_message: Xcm<RuntimeCall>) -> core::result::Result<Self::Prepared, Xcm<RuntimeCall>> {
+	fn prepare(_message: xcm::v5::Xcm<RuntimeCall>) -> core::result::Result<Self::Prepared, xcm::v5::Xcm<RuntimeCall>> {
 		Ok(MockPrepared)
 	}
 
 	fn execute(
-		_origin: impl Into<Location>,
+		_origin: impl Into<xcm::v5::Location>,
 		_pre: Self::Prepared,
-		_id: &mut XcmHash,
+		_id: &mut xcm::v5::XcmHash,
 		_weight_credit: Weight,
-	) -> Outcome {
-		Outcome::Complete { used: Weight::zero() }
+	) -> xcm::v5::Outcome {
+		xcm::v5::Outcome::Complete { used: Weight::zero() }
 	}
 
-	fn charge_fees(_location: impl Into<Location>, _fees: Assets) -> XcmResult {
+	fn charge_fees(_location: impl Into<xcm::v5::Location>, _fees: xcm::v5::Assets) -> xcm::v5::Result {
 		Ok(())
 	}
 }
 
+thread_local! {
+	pub static SENT_XCM: RefCell<Vec<(xcm::v5::Location, xcm::v5::Xcm<()>)>> = RefCell::new(Vec::new());
+}
+
+/// Router that stores the messages sent, so tests can check them instead of delivering them.
+pub struct MockXcmRouter;
+impl xcm::v5::SendXcm for MockXcmRouter {
+	type Ticket = (xcm::v5::Location, xcm::v5::Xcm<()>);
+
+	fn validate(
+		destination: &mut Option<xcm::v5::Location>,
+		message: &mut Option<xcm::v5::Xcm<()>>,
+	) -> xcm::v5::SendResult<Self::Ticket> {
+		let destination = destination.take().ok_or(xcm::v5::SendError::MissingArgument)?;
+		let message = message.take().ok_or(xcm::v5::SendError::MissingArgument)?;
+		Ok(((destination, message), xcm::v5::Assets::new()))
+	}
+
+	fn deliver(ticket: Self::Ticket) -> Result<xcm::v5::XcmHash, xcm::v5::SendError> {
+		SENT_XCM.with(|sent| sent.borrow_mut().push(ticket));
+		Ok([0u8; 32])
+	}
+}
+
+/// Messages sent through the `MockXcmRouter` since the last call.
+pub fn take_sent_xcm() -> Vec<(xcm::v5::Location, xcm::v5::Xcm<()>)> {
+	SENT_XCM.with(|sent| sent.take())
+}
+
+impl pallet_xcm::Config for TestRuntime {
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type AdvertisedXcmVersion = pallet_xcm::CurrentXcmVersion;
+	type AuthorizedAliasConsideration = Disabled;
+	type Currency = Balances;
+	type CurrencyMatcher = ();
+	type ExecuteXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
+	type MaxLockers = ConstU32<8>;
+	type MaxRemoteLockConsumers = ConstU32<0>;
+	type RemoteLockConsumerIdentifier = ();
+	type RuntimeCall = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type SendXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
+	type SovereignAccountOf = LocationToAccountId;
+	type TrustedLockers = ();
+	type UniversalLocation = UniversalLocation;
+	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+	type WeightInfo = pallet_xcm::TestWeightInfo;
+	type XcmExecuteFilter = Everything;
+	type XcmExecutor = MockXcmExecutor;
+	type XcmReserveTransferFilter = Everything;
+	type XcmRouter = MockXcmRouter;
+	type XcmTeleportFilter = Everything;
+
+	const VERSION_DISCOVERY_QUEUE_SIZE: u32 = 100;
+}
+
 parameter_types! {
 	pub const AssetDeposit: Balance = PLMC; // 1 UNIT deposit to create asset
 	pub const AssetAccountDeposit: Balance = deposit(1, 16);
@@ -168,7 +239,7 @@ impl pallet_assets::Config<ContributionTokensInstance> for TestRuntime {
 	type Currency = Balances;
 	type Extra = ();
 	type ForceOrigin = EnsureRoot<AccountId>;
-	type Freezer = ();
+	type Freezer = ContributionTokensFreezer;
 	type Holder = ();
 	type MetadataDepositBase = MetadataDepositBase;
 	type MetadataDepositPerByte = MetadataDepositPerByte;
@@ -178,6 +249,11 @@ impl pallet_assets::Config<ContributionTokensInstance> for TestRuntime {
 	type WeightInfo = ();
 }
 
+impl pallet_assets_freezer::Config<ContributionTokensInstance> for TestRuntime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 pub struct PalletAssetsBenchmarkHelper;
 
@@ -286,19 +362,27 @@ pub const HOURS: BlockNumber = 300u64;
 parameter_types! {
 	pub const EvaluationRoundDuration: BlockNumber = 10u64;
 	pub const AuctionRoundDuration: BlockNumber = 15u64;
+	pub const CommunityRoundDuration: BlockNumber = 12u64;
+	pub const IssuerDecisionDuration: BlockNumber = 8u64;
+	pub static DefaultFundingOutcomeDecision: FundingOutcomeDecision = FundingOutcomeDecision::AcceptFunding;
+	pub const MigrationDisputeWindow: BlockNumber = 20u64;
 
 	pub const FundingPalletId: PalletId = PalletId(*b"plmc-fun");
-	pub FeeBrackets: Vec<(Percent, Balance)> = vec![
-		(Percent::from_percent(10), 1_000_000 * USD_UNIT),
-		(Percent::from_percent(8), 4_000_000 * USD_UNIT),
-		(Percent::from_percent(6), u128::MAX), // Making it max signifies the last bracket
-	];
 	pub EarlyEvaluationThreshold: Percent = Percent::from_percent(10);
+	pub EvaluationSuccessThresholdBounds: RangeInclusive<Percent> =
+		Percent::from_percent(5)..=Percent::from_percent(50);
 	pub EvaluatorSlash: Percent = Percent::from_percent(20);
 	pub BlockchainOperationTreasuryAccount: AccountId = AccountId::from(696969u32);
 	pub ProxyBondingTreasuryAccount: AccountId = AccountId::from(555u32);
 	pub ContributionTreasury: AccountId = AccountId::from(4204204206u32);
 	pub FundingSuccessThreshold: Perquintill = Perquintill::from_percent(33);
+	pub FundingSuccessThresholdBounds: RangeInclusive<Perquintill> =
+		Perquintill::from_percent(25)..=Perquintill::from_percent(90);
+	pub IssuerDecisionThreshold: Perquintill = Perquintill::from_percent(25);
+	pub const MaxActiveProjectsPerDid: u32 = 2;
+	pub const MaxTransitionsPerBlock: u32 = 5;
+	// Enough to place two scheduled bids per block
+	pub MaxScheduledBidsWeightPerBlock: Weight = PolimecFunding::scheduled_bid_weight().saturating_mul(2);
 }
 
 parameter_types! {
@@ -332,6 +416,9 @@ parameter_types! {
 	pub MaxCapacityThresholds: RangeInclusive<u32> = 8..=1000;
 	pub RequiredMaxCapacity: u32 = 8;
 	pub RequiredMaxMessageSize: u32 = 102_400;
+	pub PolimecReceiverInfo: xcm::v5::PalletInfo = xcm::v5::PalletInfo::new(
+		51, "PolimecReceiver".as_bytes().to_vec(), "polimec_receiver".as_bytes().to_vec(), 1, 1, 0
+	).unwrap();
 	pub VerifierPublicKey: [u8; 32] = [
 		32, 118, 30, 171, 58, 212, 197, 27, 146, 122, 255, 243, 34, 245, 90, 244, 221, 37, 253,
 		195, 18, 202, 111, 55, 39, 48, 123, 17, 101, 78, 215, 94,
@@ -355,10 +442,10 @@ impl ConvertBack<AccountId, [u8; 32]> for DummyConverter {
 }
 thread_local! {
 	pub static PRICE_MAP: RefCell<BTreeMap<Location, FixedU128>> = RefCell::new(BTreeMap::from_iter(vec![
-		(AcceptedFundingAsset::DOT.id(), FixedU128::from_float(69f64)), // DOT
-		(AcceptedFundingAsset::USDC.id(), FixedU128::from_float(0.97f64)), // USDC
-		(AcceptedFundingAsset::USDT.id(), FixedU128::from_float(1.0f64)), // USDT
-		(AcceptedFundingAsset::ETH.id(), FixedU128::from_float(3619.451f64)), // ETH
+		(AcceptedFundingAsset::DOT.id().unwrap(), FixedU128::from_float(69f64)), // DOT
+		(AcceptedFundingAsset::USDC.id().unwrap(), FixedU128::from_float(0.97f64)), // USDC
+		(AcceptedFundingAsset::USDT.id().unwrap(), FixedU128::from_float(1.0f64)), // USDT
+		(AcceptedFundingAsset::ETH.id().unwrap(), FixedU128::from_float(3619.451f64)), // ETH
 		(Location::here(), FixedU128::from_float(8.4f64)), // PLMC
 	]));
 }
@@ -395,24 +482,46 @@ impl Config for TestRuntime {
 	type BlockNumber = BlockNumber;
 	type BlockNumberProvider = System;
 	type BlockchainOperationTreasury = BlockchainOperationTreasuryAccount;
+	type CommunityRoundDuration = CommunityRoundDuration;
 	type ContributionTokenCurrency = ContributionTokens;
+	type ContributionTokenFreezer = ContributionTokensFreezer;
 	type ContributionTreasury = ContributionTreasury;
 	type DaysToBlocks = DaysToBlocks;
+	type DefaultFundingOutcomeDecision = DefaultFundingOutcomeDecision;
+	type EmergencyOrigin = EnsureRoot<AccountId>;
 	type EvaluationRoundDuration = EvaluationRoundDuration;
 	type EvaluationSuccessThreshold = EarlyEvaluationThreshold;
+	type EvaluationSuccessThresholdBounds = EvaluationSuccessThresholdBounds;
 	type EvaluatorSlash = EvaluatorSlash;
-	type FeeBrackets = FeeBrackets;
+	type FeeConfigOrigin = EnsureRoot<AccountId>;
+	type FundingAssetOrigin = EnsureRoot<AccountId>;
 	type FundingCurrency = ForeignAssets;
 	type FundingSuccessThreshold = FundingSuccessThreshold;
+	type FundingSuccessThresholdBounds = FundingSuccessThresholdBounds;
 	type InvestorOrigin = EnsureInvestor<TestRuntime>;
+	type IssuerDecisionDuration = IssuerDecisionDuration;
+	type IssuerDecisionThreshold = IssuerDecisionThreshold;
+	type MaxActiveProjectsPerDid = MaxActiveProjectsPerDid;
+	type MaxCapacityThresholds = MaxCapacityThresholds;
+	type MaxMessageSizeThresholds = MaxMessageSizeThresholds;
+	type MaxScheduledBidsWeightPerBlock = MaxScheduledBidsWeightPerBlock;
+	type MaxTransitionsPerBlock = MaxTransitionsPerBlock;
+	type MigrationDisputeOrigin = EnsureRoot<AccountId>;
+	type MigrationDisputeWindow = MigrationDisputeWindow;
+	type MilestoneOrigin = EnsureRoot<AccountId>;
 	type MinUsdPerEvaluation = MinUsdPerEvaluation;
 	type Multiplier = Multiplier;
 	type NativeCurrency = Balances;
 	type OnSlash = ();
 	type PalletId = FundingPalletId;
+	type PolimecReceiverInfo = PolimecReceiverInfo;
 	type Price = FixedU128;
 	type PriceProvider = ConstPriceProvider;
+	type RequiredMaxCapacity = RequiredMaxCapacity;
+	type RequiredMaxMessageSize = RequiredMaxMessageSize;
+	type RuntimeCall = RuntimeCall;
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type RuntimeOrigin = RuntimeOrigin;
 	type SS58Conversion = SS58Converter;
@@ -437,6 +546,7 @@ impl pallet_proxy_bonding::Config for TestRuntime {
 	type FeePercentage = FeePercentage;
 	type FeeRecipient = FeeRecipient;
 	type FeeToken = ForeignAssets;
+	type FundingAssets = PolimecFunding;
 	type Id = PalletId;
 	type PriceProvider = ConstPriceProvider;
 	type RootId = RootId;
@@ -455,9 +565,11 @@ construct_runtime!(
 		Balances: pallet_balances,
 		LinearRelease: pallet_linear_release,
 		ContributionTokens: pallet_assets::<Instance1>::{Pallet, Call, Storage, Event<T>},
+		ContributionTokensFreezer: pallet_assets_freezer::<Instance1>::{Pallet, Storage, Event<T>},
 		ForeignAssets: pallet_assets::<Instance2>::{Pallet, Call, Storage, Event<T>, Config<T>},
-		PolimecFunding: pallet_funding::{Pallet, Call, Storage, Event<T>, HoldReason}  = 52,
+		PolimecFunding: pallet_funding::{Pallet, Call, Storage, Event<T>, Config<T>, HoldReason, FreezeReason}  = 52,
 		ProxyBonding: pallet_proxy_bonding,
+		PolkadotXcm: pallet_xcm,
 
 		// NOTE: this pallet is only necessary to satisfy type bound of [`crate::instantiator::Instantiator`]
 		ParachainSystem: cumulus_pallet_parachain_system,
@@ -483,26 +595,26 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 		foreign_assets: ForeignAssetsConfig {
 			assets: vec![
 				(
-					AcceptedFundingAsset::USDT.id(),
+					AcceptedFundingAsset::USDT.id().unwrap(),
 					<TestRuntime as Config>::PalletId::get().into_account_truncating(),
 					// asset is sufficient, i.e. participants can hold only this asset to participate with OTM
 					true,
 					70_000,
 				),
 				(
-					AcceptedFundingAsset::USDC.id(),
+					AcceptedFundingAsset::USDC.id().unwrap(),
 					<TestRuntime as Config>::PalletId::get().into_account_truncating(),
 					true,
 					70_000,
 				),
 				(
-					AcceptedFundingAsset::DOT.id(),
+					AcceptedFundingAsset::DOT.id().unwrap(),
 					<TestRuntime as Config>::PalletId::get().into_account_truncating(),
 					true,
 					100_000_000,
 				),
 				(
-					AcceptedFundingAsset::ETH.id(),
+					AcceptedFundingAsset::ETH.id().unwrap(),
 					<TestRuntime as Config>::PalletId::get().into_account_truncating(),
 					true,
 					// 0.07 USD = .000041ETH at this moment
@@ -510,10 +622,10 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 				),
 			],
 			metadata: vec![
-				(AcceptedFundingAsset::USDT.id(), "USDT".as_bytes().to_vec(), "USDT".as_bytes().to_vec(), 6),
-				(AcceptedFundingAsset::USDC.id(), "USDC".as_bytes().to_vec(), "USDC".as_bytes().to_vec(), 6),
-				(AcceptedFundingAsset::DOT.id(), "DOT".as_bytes().to_vec(), "DOT".as_bytes().to_vec(), 10),
-				(AcceptedFundingAsset::ETH.id(), "ETH".as_bytes().to_vec(), "ETH".as_bytes().to_vec(), 18),
+				(AcceptedFundingAsset::USDT.id().unwrap(), "USDT".as_bytes().to_vec(), "USDT".as_bytes().to_vec(), 6),
+				(AcceptedFundingAsset::USDC.id().unwrap(), "USDC".as_bytes().to_vec(), "USDC".as_bytes().to_vec(), 6),
+				(AcceptedFundingAsset::DOT.id().unwrap(), "DOT".as_bytes().to_vec(), "DOT".as_bytes().to_vec(), 10),
+				(AcceptedFundingAsset::ETH.id().unwrap(), "ETH".as_bytes().to_vec(), "ETH".as_bytes().to_vec(), 18),
 			],
 			accounts: vec![],
 			next_asset_id: None,
@@ -556,6 +668,7 @@ sp_api::mock_impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(5)]
 	impl ProjectInformation<Block, TestRuntime> for TestRuntime {
 		fn usd_target_percent_reached(project_id: ProjectId) -> FixedU128 {
 			PolimecFunding::usd_target_percent_reached(project_id)
@@ -564,6 +677,26 @@ sp_api::mock_impl_runtime_apis! {
 		fn projects_by_did(did: Did) -> Vec<ProjectId> {
 			PolimecFunding::projects_by_did(did)
 		}
+
+		fn active_projects_by_did(did: Did) -> Vec<ProjectId> {
+			PolimecFunding::active_projects_by_did(did)
+		}
+
+		fn project_success_thresholds(project_id: ProjectId) -> Option<(Percent, Perquintill)> {
+			PolimecFunding::project_success_thresholds(project_id)
+		}
+
+		fn pro_rata_ct_amount_of_bid(project_id: ProjectId, bid_id: u32) -> Option<Balance> {
+			PolimecFunding::pro_rata_ct_amount_of_bid(project_id, bid_id)
+		}
+
+		fn current_auction_price(project_id: ProjectId) -> Option<Price> {
+			PolimecFunding::current_auction_price(project_id)
+		}
+
+		fn migration_confirmation_leaves(project_id: ProjectId) -> Vec<(AccountId, MigrationInfo)> {
+			PolimecFunding::migration_confirmation_leaves(project_id)
+		}
 	}
 
 	impl ExtrinsicHelpers<Block, TestRuntime> for TestRuntime {
@@ -571,6 +704,10 @@ sp_api::mock_impl_runtime_apis! {
 			PolimecFunding::funding_asset_to_ct_amount_classic(project_id, funding_asset, funding_asset_amount)
 		}
 
+		fn funding_asset_to_ct_amount_classic_with_max_price(project_id: ProjectId, funding_asset: AcceptedFundingAsset, funding_asset_amount: Balance, max_price: Option<Price>) -> Balance {
+			PolimecFunding::funding_asset_to_ct_amount_classic_with_max_price(project_id, funding_asset, funding_asset_amount, max_price)
+		}
+
 		fn funding_asset_to_ct_amount_otm(project_id: ProjectId, funding_asset: AcceptedFundingAsset, funding_asset_amount: Balance) -> (Balance, Balance) {
 			PolimecFunding::funding_asset_to_ct_amount_otm(project_id, funding_asset, funding_asset_amount)
 		}
@@ -587,8 +724,8 @@ sp_api::mock_impl_runtime_apis! {
 			PolimecFunding::get_funding_asset_min_max_amounts(project_id, did, funding_asset, investor_type)
 		}
 
-		fn get_message_to_sign_by_receiving_account(project_id: ProjectId, polimec_account: AccountId) -> Option<String> {
-			PolimecFunding::get_message_to_sign_by_receiving_account(project_id, polimec_account)
+		fn get_message_to_sign_by_receiving_account(project_id: ProjectId, polimec_account: AccountId, expiry: BlockNumber) -> Option<String> {
+			PolimecFunding::get_message_to_sign_by_receiving_account(project_id, polimec_account, expiry)
 		}
 	}
 }
@@ -143,7 +143,7 @@ mod create_project_extrinsic {
 					.map(|acc| UserToFundingAsset {
 						account: *acc,
 						asset_amount: 1_000_000 * USD_UNIT,
-						asset_id: USDT.id(),
+						asset_id: USDT.id().unwrap(),
 					})
 					.collect(),
 			);
@@ -153,7 +153,7 @@ mod create_project_extrinsic {
 					.map(|acc| UserToFundingAsset {
 						account: *acc,
 						asset_amount: 1_000_000 * USD_UNIT,
-						asset_id: USDC.id(),
+						asset_id: USDC.id().unwrap(),
 					})
 					.collect(),
 			);
@@ -163,7 +163,7 @@ mod create_project_extrinsic {
 					.map(|acc| UserToFundingAsset {
 						account: *acc,
 						asset_amount: 1_000_000__000_000_000_0,
-						asset_id: DOT.id(),
+						asset_id: DOT.id().unwrap(),
 					})
 					.collect(),
 			);
@@ -173,18 +173,23 @@ mod create_project_extrinsic {
 					.map(|acc| UserToFundingAsset {
 						account: *acc,
 						asset_amount: 1_000_000__000_000_000_000_000_000,
-						asset_id: ETH.id(),
+						asset_id: ETH.id().unwrap(),
 					})
 					.collect(),
 			);
 
-			// Cannot create 2 projects consecutively
+			// Cannot create more than `MaxActiveProjectsPerDid` projects consecutively
 			inst.execute(|| {
 				assert_ok!(Pallet::<TestRuntime>::create_project(
 					RuntimeOrigin::signed(ISSUER_1),
 					jwt.clone(),
 					project_metadata.clone()
 				));
+				assert_ok!(Pallet::<TestRuntime>::create_project(
+					RuntimeOrigin::signed(ISSUER_1),
+					jwt.clone(),
+					project_metadata.clone()
+				));
 			});
 			inst.execute(|| {
 				assert_noop!(
@@ -193,7 +198,7 @@ mod create_project_extrinsic {
 						jwt.clone(),
 						project_metadata.clone()
 					),
-					Error::<TestRuntime>::HasActiveProject
+					Error::<TestRuntime>::TooManyActiveProjects
 				);
 			});
 
@@ -207,7 +212,7 @@ mod create_project_extrinsic {
 						jwt.clone(),
 						project_metadata.clone()
 					),
-					Error::<TestRuntime>::HasActiveProject
+					Error::<TestRuntime>::TooManyActiveProjects
 				);
 			});
 			assert_eq!(inst.go_to_next_state(0), ProjectStatus::FundingFailed);
@@ -229,6 +234,7 @@ mod create_project_extrinsic {
 
 			inst.bid_for_users(1, failing_bids).unwrap();
 
+			assert_eq!(inst.go_to_next_state(1), ProjectStatus::CommunityRound);
 			assert_eq!(inst.go_to_next_state(1), ProjectStatus::FundingFailed);
 			inst.execute(|| {
 				assert_ok!(Pallet::<TestRuntime>::create_project(
@@ -246,6 +252,7 @@ mod create_project_extrinsic {
 
 			inst.bid_for_users(2, successful_bids).unwrap();
 
+			assert_eq!(inst.go_to_next_state(2), ProjectStatus::CommunityRound);
 			assert_eq!(inst.go_to_next_state(2), ProjectStatus::FundingSuccessful);
 
 			assert_ok!(inst.execute(|| crate::Pallet::<TestRuntime>::create_project(
@@ -336,12 +343,12 @@ mod create_project_extrinsic {
 		}
 
 		#[test]
-		fn did_cannot_have_2_active_projects() {
+		fn did_cannot_exceed_max_active_projects() {
 			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
 			let project_metadata = default_project_metadata(ISSUER_1);
 			let ed = inst.get_ed();
-			let issuer_mint: UserToPLMCBalance<TestRuntime> = (ISSUER_1, ed * 2).into();
-			// Create a first project
+			let issuer_mint: UserToPLMCBalance<TestRuntime> = (ISSUER_1, ed * 3).into();
+			// Create the maximum amount of active projects
 			let jwt = get_mock_jwt_with_cid(
 				ISSUER_1,
 				InvestorType::Institutional,
@@ -350,11 +357,26 @@ mod create_project_extrinsic {
 			);
 			inst.mint_plmc_to(vec![issuer_mint.clone()]);
 			inst.execute(|| {
-				assert_ok!(Pallet::<TestRuntime>::create_project(
-					RuntimeOrigin::signed(ISSUER_1),
-					jwt.clone(),
-					project_metadata.clone()
-				));
+				for _ in 0..<TestRuntime as Config>::MaxActiveProjectsPerDid::get() {
+					assert_ok!(Pallet::<TestRuntime>::create_project(
+						RuntimeOrigin::signed(ISSUER_1),
+						jwt.clone(),
+						project_metadata.clone()
+					));
+				}
+				assert_eq!(PolimecFunding::active_projects_by_did(generate_did_from_account(ISSUER_1)), vec![0, 1]);
+			});
+
+			// same account, same did
+			inst.execute(|| {
+				assert_noop!(
+					Pallet::<TestRuntime>::create_project(
+						RuntimeOrigin::signed(ISSUER_1),
+						jwt.clone(),
+						project_metadata.clone()
+					),
+					Error::<TestRuntime>::TooManyActiveProjects
+				);
 			});
 
 			// different account, same did
@@ -371,7 +393,7 @@ mod create_project_extrinsic {
 						jwt.clone(),
 						project_metadata.clone()
 					),
-					Error::<TestRuntime>::HasActiveProject
+					Error::<TestRuntime>::TooManyActiveProjects
 				);
 			});
 		}
@@ -818,6 +840,189 @@ mod create_project_extrinsic {
 				);
 			});
 		}
+
+		#[test]
+		fn invalid_dutch_auction_parameters() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let minimum_price = project_metadata.minimum_price;
+			let price_step = minimum_price.saturating_mul(PriceOf::<TestRuntime>::from_rational(1, 10));
+			let invalid_sale_modes = vec![
+				// Ceiling at the minimum price
+				SaleMode::DutchAuction { ceiling_price: minimum_price, price_step, blocks_per_step: 5 },
+				// Price never drops
+				SaleMode::DutchAuction {
+					ceiling_price: minimum_price.saturating_add(price_step),
+					price_step: Zero::zero(),
+					blocks_per_step: 5,
+				},
+				// Price drops without time passing
+				SaleMode::DutchAuction {
+					ceiling_price: minimum_price.saturating_add(price_step),
+					price_step,
+					blocks_per_step: 0,
+				},
+			];
+
+			inst.mint_plmc_to(default_plmc_balances());
+			for sale_mode in invalid_sale_modes {
+				let project_metadata = ProjectMetadataOf::<TestRuntime> { sale_mode, ..project_metadata.clone() };
+				let jwt = get_mock_jwt_with_cid(
+					ISSUER_1,
+					InvestorType::Institutional,
+					generate_did_from_account(ISSUER_1),
+					project_metadata.clone().policy_ipfs_cid.unwrap(),
+				);
+				inst.execute(|| {
+					assert_noop!(
+						Pallet::<TestRuntime>::create_project(RuntimeOrigin::signed(ISSUER_1), jwt, project_metadata),
+						Error::<TestRuntime>::SaleModeError
+					);
+				});
+			}
+		}
+
+		#[test]
+		fn ct_vesting_without_duration() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = ProjectMetadataOf::<TestRuntime> {
+				ct_vesting: Some(ContributionTokenVesting { cliff: 100, duration: 0 }),
+				..default_project_metadata(ISSUER_1)
+			};
+			inst.mint_plmc_to(default_plmc_balances());
+			let jwt = get_mock_jwt_with_cid(
+				ISSUER_1,
+				InvestorType::Institutional,
+				generate_did_from_account(ISSUER_1),
+				project_metadata.clone().policy_ipfs_cid.unwrap(),
+			);
+			inst.execute(|| {
+				assert_noop!(
+					Pallet::<TestRuntime>::create_project(RuntimeOrigin::signed(ISSUER_1), jwt, project_metadata),
+					Error::<TestRuntime>::CtVestingError
+				);
+			});
+		}
+
+		#[test]
+		fn invalid_funding_milestones() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let invalid_funding_milestones: Vec<BoundedVec<Percent, sp_core::ConstU32<MAX_FUNDING_MILESTONES>>> = vec![
+				// Part of the funds are never released
+				bounded_vec![Percent::from_percent(40), Percent::from_percent(50)],
+				// Empty tranche
+				bounded_vec![Percent::from_percent(100), Percent::zero()],
+			];
+
+			inst.mint_plmc_to(default_plmc_balances());
+			for funding_milestones in invalid_funding_milestones {
+				let project_metadata =
+					ProjectMetadataOf::<TestRuntime> { funding_milestones, ..default_project_metadata(ISSUER_1) };
+				let jwt = get_mock_jwt_with_cid(
+					ISSUER_1,
+					InvestorType::Institutional,
+					generate_did_from_account(ISSUER_1),
+					project_metadata.clone().policy_ipfs_cid.unwrap(),
+				);
+				inst.execute(|| {
+					assert_noop!(
+						Pallet::<TestRuntime>::create_project(RuntimeOrigin::signed(ISSUER_1), jwt, project_metadata),
+						Error::<TestRuntime>::FundingMilestonesError
+					);
+				});
+			}
+		}
+
+		#[test]
+		fn success_thresholds_out_of_bounds() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let invalid_thresholds = vec![
+				// Below the evaluation threshold bounds
+				(Some(Percent::from_percent(1)), None),
+				// Above the evaluation threshold bounds
+				(Some(Percent::from_percent(60)), None),
+				// Below the funding threshold bounds, where the issuer would decide the outcome
+				(None, Some(Perquintill::from_percent(20))),
+				// Above the funding threshold bounds
+				(None, Some(Perquintill::from_percent(95))),
+			];
+
+			inst.mint_plmc_to(default_plmc_balances());
+			for (evaluation_success_threshold, funding_success_threshold) in invalid_thresholds {
+				let project_metadata = ProjectMetadataOf::<TestRuntime> {
+					evaluation_success_threshold,
+					funding_success_threshold,
+					..default_project_metadata(ISSUER_1)
+				};
+				let jwt = get_mock_jwt_with_cid(
+					ISSUER_1,
+					InvestorType::Institutional,
+					generate_did_from_account(ISSUER_1),
+					project_metadata.clone().policy_ipfs_cid.unwrap(),
+				);
+				inst.execute(|| {
+					assert_noop!(
+						Pallet::<TestRuntime>::create_project(RuntimeOrigin::signed(ISSUER_1), jwt, project_metadata),
+						Error::<TestRuntime>::SuccessThresholdError
+					);
+				});
+			}
+		}
+
+		#[test]
+		fn invalid_bech32_prefix() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let invalid_bech32_prefixes: Vec<BoundedVec<u8, sp_core::ConstU32<MAX_BECH32_PREFIX_LENGTH>>> = vec![
+				// Empty prefix
+				bounded_vec![],
+				// Uppercase characters
+				bounded_vec![b'O', b'S', b'M', b'O'],
+				// Character outside of the alphanumeric range
+				bounded_vec![b'o', b's', b'm', b'o', b'-'],
+			];
+
+			inst.mint_plmc_to(default_plmc_balances());
+			for bech32_prefix in invalid_bech32_prefixes {
+				let project_metadata = ProjectMetadataOf::<TestRuntime> {
+					participants_account_type: ParticipantsAccountType::Cosmos { bech32_prefix },
+					..default_project_metadata(ISSUER_1)
+				};
+				let jwt = get_mock_jwt_with_cid(
+					ISSUER_1,
+					InvestorType::Institutional,
+					generate_did_from_account(ISSUER_1),
+					project_metadata.clone().policy_ipfs_cid.unwrap(),
+				);
+				inst.execute(|| {
+					assert_noop!(
+						Pallet::<TestRuntime>::create_project(RuntimeOrigin::signed(ISSUER_1), jwt, project_metadata),
+						Error::<TestRuntime>::ParticipantsAccountTypeError
+					);
+				});
+			}
+		}
+
+		#[test]
+		fn disabled_participation_currency() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			inst.mint_plmc_to(default_plmc_balances());
+			let jwt = get_mock_jwt_with_cid(
+				ISSUER_1,
+				InvestorType::Institutional,
+				generate_did_from_account(ISSUER_1),
+				project_metadata.clone().policy_ipfs_cid.unwrap(),
+			);
+			inst.execute(|| {
+				let disabled_usdc =
+					polimec_common::assets::FundingAssetInfo { enabled: false, ..USDC.builtin_info().unwrap() };
+				assert_ok!(PolimecFunding::set_funding_asset(RuntimeOrigin::root(), USDC, disabled_usdc));
+				assert_noop!(
+					Pallet::<TestRuntime>::create_project(RuntimeOrigin::signed(ISSUER_1), jwt, project_metadata),
+					Error::<TestRuntime>::FundingAssetNotAccepted
+				);
+			});
+		}
 	}
 }
 
@@ -909,6 +1114,11 @@ mod edit_project_extrinsic {
 				funding_destination_account: ISSUER_2,
 				policy_ipfs_cid: Some(new_policy_hash),
 				participants_account_type: ParticipantsAccountType::Polkadot,
+				sale_mode: SaleMode::Auction,
+				ct_vesting: None,
+				funding_milestones: BoundedVec::new(),
+				evaluation_success_threshold: None,
+				funding_success_threshold: None,
 			};
 
 			// No fields changed
@@ -1165,7 +1375,7 @@ mod remove_project_extrinsic {
 				assert!(ProjectsDetails::<TestRuntime>::get(project_id).is_none());
 				assert!(ProjectsMetadata::<TestRuntime>::get(project_id).is_none());
 				assert!(Buckets::<TestRuntime>::get(project_id).is_none());
-				assert!(DidWithActiveProjects::<TestRuntime>::get(generate_did_from_account(ISSUER_1)).is_none());
+				assert!(DidWithActiveProjects::<TestRuntime>::get(generate_did_from_account(ISSUER_1)).is_empty());
 			});
 		}
 
@@ -1194,13 +1404,18 @@ mod remove_project_extrinsic {
 			// Same account same did
 			inst.mint_plmc_to(vec![issuer_mint.clone()]);
 			inst.execute(|| {
+				assert_ok!(Pallet::<TestRuntime>::create_project(
+					RuntimeOrigin::signed(ISSUER_1),
+					jwt.clone(),
+					project_metadata.clone()
+				));
 				assert_noop!(
 					Pallet::<TestRuntime>::create_project(
 						RuntimeOrigin::signed(ISSUER_1),
 						jwt.clone(),
 						project_metadata.clone()
 					),
-					Error::<TestRuntime>::HasActiveProject
+					Error::<TestRuntime>::TooManyActiveProjects
 				);
 			});
 
@@ -1209,7 +1424,7 @@ mod remove_project_extrinsic {
 				assert_ok!(Pallet::<TestRuntime>::remove_project(RuntimeOrigin::signed(ISSUER_1), jwt.clone(), 0));
 			});
 
-			// Create a second project
+			// Create a third project
 			inst.execute(|| {
 				assert_ok!(Pallet::<TestRuntime>::create_project(
 					RuntimeOrigin::signed(ISSUER_1),
@@ -1300,3 +1515,377 @@ mod remove_project_extrinsic {
 		}
 	}
 }
+
+#[cfg(test)]
+mod set_allowlist_extrinsic {
+	use super::*;
+
+	#[cfg(test)]
+	mod success {
+		use super::*;
+
+		#[test]
+		fn allowlist_can_be_set_and_removed() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			inst.mint_plmc_to(default_plmc_balances());
+			let jwt = get_mock_jwt_with_cid(
+				ISSUER_1,
+				InvestorType::Institutional,
+				generate_did_from_account(ISSUER_1),
+				project_metadata.clone().policy_ipfs_cid.unwrap(),
+			);
+			let project_id = inst.create_new_project(project_metadata.clone(), ISSUER_1, None);
+			let (allowlist_root, _) =
+				two_member_allowlist([(EVALUATOR_1, 1000 * USD_UNIT), (BIDDER_1, 5000 * USD_UNIT)]);
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::set_allowlist(
+					RuntimeOrigin::signed(ISSUER_1),
+					jwt.clone(),
+					project_id,
+					Some(allowlist_root)
+				));
+				assert_eq!(ProjectAllowlists::<TestRuntime>::get(project_id), Some(allowlist_root));
+				System::assert_last_event(
+					Event::<TestRuntime>::AllowlistSet { project_id, allowlist_root: Some(allowlist_root) }.into(),
+				);
+
+				assert_ok!(PolimecFunding::set_allowlist(RuntimeOrigin::signed(ISSUER_1), jwt, project_id, None));
+				assert_eq!(ProjectAllowlists::<TestRuntime>::get(project_id), None);
+			});
+		}
+	}
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn called_by_different_issuer() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			inst.mint_plmc_to(default_plmc_balances());
+			let project_id = inst.create_new_project(project_metadata.clone(), ISSUER_1, None);
+			let jwt = get_mock_jwt_with_cid(
+				ISSUER_2,
+				InvestorType::Institutional,
+				generate_did_from_account(ISSUER_2),
+				project_metadata.clone().policy_ipfs_cid.unwrap(),
+			);
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::set_allowlist(RuntimeOrigin::signed(ISSUER_2), jwt, project_id, Some([1u8; 32])),
+					Error::<TestRuntime>::NotIssuer
+				);
+			});
+		}
+
+		#[test]
+		fn evaluation_already_started() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let jwt = get_mock_jwt_with_cid(
+				ISSUER_1,
+				InvestorType::Institutional,
+				generate_did_from_account(ISSUER_1),
+				project_metadata.clone().policy_ipfs_cid.unwrap(),
+			);
+			let project_id = inst.create_evaluating_project(project_metadata, ISSUER_1, None);
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::set_allowlist(RuntimeOrigin::signed(ISSUER_1), jwt, project_id, Some([1u8; 32])),
+					Error::<TestRuntime>::ProjectIsFrozen
+				);
+			});
+		}
+	}
+}
+
+#[cfg(test)]
+mod project_transfer_extrinsics {
+	use super::*;
+
+	fn institutional_jwt(account: AccountId, did: Did) -> UntrustedToken {
+		let policy_ipfs_cid = default_project_metadata(ISSUER_1).policy_ipfs_cid.unwrap();
+		get_mock_jwt_with_cid(account, InvestorType::Institutional, did, policy_ipfs_cid)
+	}
+
+	#[cfg(test)]
+	mod success {
+		use super::*;
+
+		#[test]
+		fn new_issuer_takes_over_the_project() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id = inst.create_new_project(project_metadata.clone(), ISSUER_1, None);
+			let old_did = generate_did_from_account(ISSUER_1);
+			let new_did = generate_did_from_account(ISSUER_2);
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::propose_project_transfer(
+					RuntimeOrigin::signed(ISSUER_1),
+					institutional_jwt(ISSUER_1, old_did.clone()),
+					project_id,
+					ISSUER_2
+				));
+				System::assert_last_event(
+					Event::<TestRuntime>::ProjectTransferProposed {
+						project_id,
+						issuer: ISSUER_1,
+						new_issuer: ISSUER_2,
+					}
+					.into(),
+				);
+
+				assert_ok!(PolimecFunding::accept_project_transfer(
+					RuntimeOrigin::signed(ISSUER_2),
+					institutional_jwt(ISSUER_2, new_did.clone()),
+					project_id,
+					ISSUER_3
+				));
+				System::assert_last_event(
+					Event::<TestRuntime>::ProjectTransferred {
+						project_id,
+						issuer: ISSUER_2,
+						issuer_did: new_did.clone(),
+						funding_destination_account: ISSUER_3,
+					}
+					.into(),
+				);
+
+				let project_details = ProjectsDetails::<TestRuntime>::get(project_id).unwrap();
+				assert_eq!(project_details.issuer_account, ISSUER_2);
+				assert_eq!(project_details.issuer_did, new_did);
+				let new_project_metadata = ProjectsMetadata::<TestRuntime>::get(project_id).unwrap();
+				assert_eq!(new_project_metadata.funding_destination_account, ISSUER_3);
+				assert!(PendingProjectTransfers::<TestRuntime>::get(project_id).is_none());
+				assert!(DidWithActiveProjects::<TestRuntime>::get(old_did.clone()).is_empty());
+				assert_eq!(DidWithActiveProjects::<TestRuntime>::get(new_did.clone()).into_inner(), vec![project_id]);
+
+				// Only the new issuer can act on the project
+				assert_noop!(
+					PolimecFunding::edit_project(
+						RuntimeOrigin::signed(ISSUER_1),
+						institutional_jwt(ISSUER_1, old_did),
+						project_id,
+						project_metadata.clone()
+					),
+					Error::<TestRuntime>::NotIssuer
+				);
+				assert_ok!(PolimecFunding::start_evaluation(
+					RuntimeOrigin::signed(ISSUER_2),
+					institutional_jwt(ISSUER_2, new_did),
+					project_id
+				));
+			});
+		}
+
+		#[test]
+		fn inactive_project_does_not_count_towards_new_did() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+			let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 80, 10);
+			let project_id = inst.create_finished_project(project_metadata, ISSUER_1, None, evaluations, bids);
+			let new_did = generate_did_from_account(ISSUER_2);
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::propose_project_transfer(
+					RuntimeOrigin::signed(ISSUER_1),
+					institutional_jwt(ISSUER_1, generate_did_from_account(ISSUER_1)),
+					project_id,
+					ISSUER_2
+				));
+				assert_ok!(PolimecFunding::accept_project_transfer(
+					RuntimeOrigin::signed(ISSUER_2),
+					institutional_jwt(ISSUER_2, new_did.clone()),
+					project_id,
+					ISSUER_2
+				));
+				assert!(DidWithActiveProjects::<TestRuntime>::get(new_did).is_empty());
+			});
+		}
+	}
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn proposed_by_non_issuer() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = inst.create_new_project(default_project_metadata(ISSUER_1), ISSUER_1, None);
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::propose_project_transfer(
+						RuntimeOrigin::signed(ISSUER_2),
+						institutional_jwt(ISSUER_2, generate_did_from_account(ISSUER_2)),
+						project_id,
+						ISSUER_2
+					),
+					Error::<TestRuntime>::NotIssuer
+				);
+			});
+		}
+
+		#[test]
+		fn accepted_without_proposal_or_by_other_account() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = inst.create_new_project(default_project_metadata(ISSUER_1), ISSUER_1, None);
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::accept_project_transfer(
+						RuntimeOrigin::signed(ISSUER_2),
+						institutional_jwt(ISSUER_2, generate_did_from_account(ISSUER_2)),
+						project_id,
+						ISSUER_2
+					),
+					Error::<TestRuntime>::NoPendingProjectTransfer
+				);
+
+				assert_ok!(PolimecFunding::propose_project_transfer(
+					RuntimeOrigin::signed(ISSUER_1),
+					institutional_jwt(ISSUER_1, generate_did_from_account(ISSUER_1)),
+					project_id,
+					ISSUER_2
+				));
+				assert_noop!(
+					PolimecFunding::accept_project_transfer(
+						RuntimeOrigin::signed(ISSUER_3),
+						institutional_jwt(ISSUER_3, generate_did_from_account(ISSUER_3)),
+						project_id,
+						ISSUER_3
+					),
+					Error::<TestRuntime>::NotProposedIssuer
+				);
+				assert_noop!(
+					PolimecFunding::accept_project_transfer(
+						RuntimeOrigin::signed(ISSUER_2),
+						get_mock_jwt_with_cid(
+							ISSUER_2,
+							InvestorType::Professional,
+							generate_did_from_account(ISSUER_2),
+							default_project_metadata(ISSUER_1).policy_ipfs_cid.unwrap()
+						),
+						project_id,
+						ISSUER_2
+					),
+					Error::<TestRuntime>::WrongInvestorType
+				);
+			});
+		}
+
+		#[test]
+		fn new_did_participated_in_the_project() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = inst.create_evaluating_project(default_project_metadata(ISSUER_1), ISSUER_1, None);
+			let evaluation = EvaluationParams::from((EVALUATOR_1, 500 * USD_UNIT));
+			let necessary_plmc = inst.calculate_evaluation_plmc_spent(vec![evaluation.clone()]);
+			inst.mint_plmc_ed_if_required(vec![EVALUATOR_1]);
+			inst.mint_plmc_to(necessary_plmc);
+			inst.evaluate_for_users(project_id, vec![evaluation]).unwrap();
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::propose_project_transfer(
+					RuntimeOrigin::signed(ISSUER_1),
+					institutional_jwt(ISSUER_1, generate_did_from_account(ISSUER_1)),
+					project_id,
+					EVALUATOR_1
+				));
+				assert_noop!(
+					PolimecFunding::accept_project_transfer(
+						RuntimeOrigin::signed(EVALUATOR_1),
+						institutional_jwt(EVALUATOR_1, generate_did_from_account(EVALUATOR_1)),
+						project_id,
+						EVALUATOR_1
+					),
+					Error::<TestRuntime>::NewIssuerIsParticipant
+				);
+			});
+		}
+
+		#[test]
+		fn new_did_contributed_to_the_project() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+			let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 20, 5);
+			let project_id =
+				inst.create_community_contributing_project(project_metadata, ISSUER_1, None, evaluations, bids);
+			let contribution =
+				BidParams::from((BIDDER_1, Institutional, 10_000 * CT_UNIT, ParticipationMode::Classic(1u8), USDT));
+			inst.mint_necessary_tokens_for_contributions(project_id, vec![contribution.clone()]);
+			inst.contribute_for_users(project_id, vec![contribution]).unwrap();
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::propose_project_transfer(
+					RuntimeOrigin::signed(ISSUER_1),
+					institutional_jwt(ISSUER_1, generate_did_from_account(ISSUER_1)),
+					project_id,
+					BIDDER_1
+				));
+				assert_noop!(
+					PolimecFunding::accept_project_transfer(
+						RuntimeOrigin::signed(BIDDER_1),
+						institutional_jwt(BIDDER_1, generate_did_from_account(BIDDER_1)),
+						project_id,
+						BIDDER_1
+					),
+					Error::<TestRuntime>::NewIssuerIsParticipant
+				);
+			});
+		}
+
+		#[test]
+		fn project_failed() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = inst.create_evaluating_project(default_project_metadata(ISSUER_1), ISSUER_1, None);
+			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::FundingFailed);
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::propose_project_transfer(
+						RuntimeOrigin::signed(ISSUER_1),
+						institutional_jwt(ISSUER_1, generate_did_from_account(ISSUER_1)),
+						project_id,
+						ISSUER_2
+					),
+					Error::<TestRuntime>::IncorrectRound
+				);
+			});
+		}
+
+		#[test]
+		fn new_did_has_too_many_active_projects() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = inst.create_new_project(default_project_metadata(ISSUER_1), ISSUER_1, None);
+			for _ in 0..<TestRuntime as Config>::MaxActiveProjectsPerDid::get() {
+				inst.create_new_project(default_project_metadata(ISSUER_2), ISSUER_2, None);
+			}
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::propose_project_transfer(
+					RuntimeOrigin::signed(ISSUER_1),
+					institutional_jwt(ISSUER_1, generate_did_from_account(ISSUER_1)),
+					project_id,
+					ISSUER_2
+				));
+				assert_noop!(
+					PolimecFunding::accept_project_transfer(
+						RuntimeOrigin::signed(ISSUER_2),
+						institutional_jwt(ISSUER_2, generate_did_from_account(ISSUER_2)),
+						project_id,
+						ISSUER_2
+					),
+					Error::<TestRuntime>::TooManyActiveProjects
+				);
+			});
+		}
+	}
+}
@@ -67,6 +67,40 @@ mod round_flow {
 		assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::SettlementStarted(FundingOutcome::Failure));
 	}
 
+	#[test]
+	fn project_evaluation_threshold_is_used() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let project_metadata = ProjectMetadataOf::<TestRuntime> {
+			evaluation_success_threshold: Some(Percent::from_percent(30)),
+			..default_project_metadata(ISSUER_1)
+		};
+		let target_funding = project_metadata.minimum_price.saturating_mul_int(project_metadata.total_allocation_size);
+
+		// Enough for the default threshold of the pallet, but not for the one of the project.
+		let evaluations = vec![(EVALUATOR_1, Percent::from_percent(20) * target_funding).into()];
+		let evaluation_plmc = inst.calculate_evaluation_plmc_spent(evaluations.clone());
+		inst.mint_plmc_ed_if_required(evaluations.accounts());
+		inst.mint_plmc_to(evaluation_plmc);
+
+		let project_id = inst.create_evaluating_project(project_metadata.clone(), ISSUER_1, None);
+		inst.evaluate_for_users(project_id, evaluations).unwrap();
+		assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::FundingFailed);
+
+		// The whole evaluation is below the threshold of the project, so all of it is early.
+		let threshold_usd = Percent::from_percent(30) * target_funding;
+		let evaluations = vec![(EVALUATOR_1, threshold_usd).into()];
+		let evaluation_plmc = inst.calculate_evaluation_plmc_spent(evaluations.clone());
+		inst.mint_plmc_to(evaluation_plmc);
+
+		let project_id = inst.create_evaluating_project(project_metadata, ISSUER_2, None);
+		inst.evaluate_for_users(project_id, evaluations).unwrap();
+		let stored_evaluation =
+			inst.execute(|| Evaluations::<TestRuntime>::iter_prefix_values((project_id,)).next().unwrap());
+		assert_eq!(stored_evaluation.early_usd_amount, threshold_usd);
+		assert_eq!(stored_evaluation.late_usd_amount, 0);
+		assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::AuctionRound);
+	}
+
 	#[test]
 	fn different_decimals_ct_works_as_expected() {
 		// Setup some base values to compare different decimals
@@ -610,6 +644,7 @@ mod evaluate_extrinsic {
 			inst.evaluate_for_users(project_id, new_evaluations).unwrap();
 
 			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::AuctionRound);
+			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::CommunityRound);
 			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::FundingFailed);
 
 			let free_balance = inst.get_free_plmc_balance_for(EVALUATOR_4);
@@ -673,23 +708,161 @@ mod evaluate_extrinsic {
 				project_metadata.clone().policy_ipfs_cid.unwrap(),
 			);
 
-			let (eth_acc, eth_sig) = inst.eth_key_and_sig_from("//EVALUATOR1", project_id, EVALUATOR_1);
+			let expiry = inst.current_block() + 10;
+			let (eth_acc, eth_sig) = inst.eth_key_and_sig_from("//EVALUATOR1", project_id, EVALUATOR_1, expiry);
 
 			let plmc =
 				inst.calculate_evaluation_plmc_spent(vec![EvaluationParams::from((EVALUATOR_1, 500 * USD_UNIT))]);
 			inst.mint_plmc_ed_if_required(plmc.accounts());
 			inst.mint_plmc_to(plmc.clone());
 
-			assert_ok!(inst.execute(|| {
-				PolimecFunding::evaluate_with_receiving_account(
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::bind_receiving_account(
 					RuntimeOrigin::signed(EVALUATOR_1),
-					jwt,
 					project_id,
-					500 * USD_UNIT,
 					eth_acc,
+					expiry,
 					eth_sig,
-				)
-			}));
+				));
+				assert_ok!(PolimecFunding::evaluate(
+					RuntimeOrigin::signed(EVALUATOR_1),
+					jwt,
+					project_id,
+					500 * USD_UNIT
+				));
+				assert_eq!(
+					Evaluations::<TestRuntime>::iter_prefix_values((project_id, EVALUATOR_1))
+						.next()
+						.unwrap()
+						.receiving_account,
+					eth_acc
+				);
+			});
+		}
+
+		#[test]
+		fn evaluate_on_solana_project() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+
+			let mut project_metadata = default_project_metadata(ISSUER_1);
+			project_metadata.participants_account_type = ParticipantsAccountType::Solana;
+
+			let project_id = inst.create_evaluating_project(project_metadata.clone(), ISSUER_1, None);
+			let jwt = get_mock_jwt_with_cid(
+				EVALUATOR_1,
+				InvestorType::Retail,
+				generate_did_from_account(EVALUATOR_1),
+				project_metadata.clone().policy_ipfs_cid.unwrap(),
+			);
+
+			let expiry = inst.current_block() + 10;
+			let (sol_acc, sol_sig) = inst.sol_key_and_sig_from("//EVALUATOR1", project_id, EVALUATOR_1, expiry);
+			// A Polkadot signature for the same 32 byte account is not accepted
+			let (dot_acc, dot_sig) = inst.dot_key_and_sig_from("//EVALUATOR1", project_id, EVALUATOR_1, expiry);
+
+			let plmc =
+				inst.calculate_evaluation_plmc_spent(vec![EvaluationParams::from((EVALUATOR_1, 500 * USD_UNIT))]);
+			inst.mint_plmc_ed_if_required(plmc.accounts());
+			inst.mint_plmc_to(plmc.clone());
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::bind_receiving_account(
+						RuntimeOrigin::signed(EVALUATOR_1),
+						project_id,
+						dot_acc,
+						expiry,
+						dot_sig,
+					),
+					Error::<TestRuntime>::BadReceiverAccountSignature
+				);
+				assert_ok!(PolimecFunding::bind_receiving_account(
+					RuntimeOrigin::signed(EVALUATOR_1),
+					project_id,
+					sol_acc,
+					expiry,
+					sol_sig,
+				));
+				assert_ok!(PolimecFunding::evaluate(
+					RuntimeOrigin::signed(EVALUATOR_1),
+					jwt,
+					project_id,
+					500 * USD_UNIT
+				));
+				assert_eq!(
+					Evaluations::<TestRuntime>::iter_prefix_values((project_id, EVALUATOR_1))
+						.next()
+						.unwrap()
+						.receiving_account,
+					sol_acc
+				);
+			});
+		}
+
+		#[test]
+		fn evaluate_on_cosmos_project() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+
+			let mut project_metadata = default_project_metadata(ISSUER_1);
+			project_metadata.participants_account_type =
+				ParticipantsAccountType::Cosmos { bech32_prefix: bounded_vec![b'o', b's', b'm', b'o'] };
+
+			let project_id = inst.create_evaluating_project(project_metadata.clone(), ISSUER_1, None);
+			let jwt = get_mock_jwt_with_cid(
+				EVALUATOR_1,
+				InvestorType::Retail,
+				generate_did_from_account(EVALUATOR_1),
+				project_metadata.clone().policy_ipfs_cid.unwrap(),
+			);
+
+			let expiry = inst.current_block() + 10;
+			let (cosmos_acc, cosmos_sig) =
+				inst.cosmos_key_and_sig_from("//EVALUATOR1", b"osmo", project_id, EVALUATOR_1, expiry);
+			// The signer of the ADR-036 document includes the prefix, so signing for another chain is not accepted
+			let (_, other_chain_sig) =
+				inst.cosmos_key_and_sig_from("//EVALUATOR1", b"cosmos", project_id, EVALUATOR_1, expiry);
+			// An Ethereum signature for the same 20 byte account is not accepted
+			let (_, eth_sig) = inst.eth_key_and_sig_from("//EVALUATOR1", project_id, EVALUATOR_1, expiry);
+
+			let plmc =
+				inst.calculate_evaluation_plmc_spent(vec![EvaluationParams::from((EVALUATOR_1, 500 * USD_UNIT))]);
+			inst.mint_plmc_ed_if_required(plmc.accounts());
+			inst.mint_plmc_to(plmc.clone());
+
+			inst.execute(|| {
+				for bad_sig in [other_chain_sig, eth_sig] {
+					assert_noop!(
+						PolimecFunding::bind_receiving_account(
+							RuntimeOrigin::signed(EVALUATOR_1),
+							project_id,
+							cosmos_acc,
+							expiry,
+							bad_sig,
+						),
+						Error::<TestRuntime>::BadReceiverAccountSignature
+					);
+				}
+				assert_ok!(PolimecFunding::bind_receiving_account(
+					RuntimeOrigin::signed(EVALUATOR_1),
+					project_id,
+					cosmos_acc,
+					expiry,
+					cosmos_sig,
+				));
+				assert_ok!(PolimecFunding::evaluate(
+					RuntimeOrigin::signed(EVALUATOR_1),
+					jwt,
+					project_id,
+					500 * USD_UNIT
+				));
+				assert_eq!(
+					Evaluations::<TestRuntime>::iter_prefix_values((project_id, EVALUATOR_1))
+						.next()
+						.unwrap()
+						.receiving_account,
+					cosmos_acc
+				);
+			});
 		}
 
 		#[test]
@@ -706,22 +879,85 @@ mod evaluate_extrinsic {
 				project_metadata.clone().policy_ipfs_cid.unwrap(),
 			);
 
-			let (dot_acc, dot_sig) = inst.dot_key_and_sig_from("//EVALUATOR1", project_id, EVALUATOR_1);
+			let expiry = inst.current_block() + 10;
+			let (dot_acc, dot_sig) = inst.dot_key_and_sig_from("//EVALUATOR1", project_id, EVALUATOR_1, expiry);
 			let plmc =
 				inst.calculate_evaluation_plmc_spent(vec![EvaluationParams::from((EVALUATOR_1, 500 * USD_UNIT))]);
 			inst.mint_plmc_ed_if_required(plmc.accounts());
 			inst.mint_plmc_to(plmc.clone());
 
-			assert_ok!(inst.execute(|| {
-				PolimecFunding::evaluate_with_receiving_account(
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::bind_receiving_account(
 					RuntimeOrigin::signed(EVALUATOR_1),
-					jwt,
 					project_id,
-					500 * USD_UNIT,
 					dot_acc,
+					expiry,
 					dot_sig,
-				)
-			}));
+				));
+				System::assert_last_event(
+					Event::<TestRuntime>::ReceivingAccountBound {
+						project_id,
+						account: EVALUATOR_1,
+						receiving_account: dot_acc,
+					}
+					.into(),
+				);
+			});
+
+			// Other transactions of the account don't invalidate the binding
+			inst.execute(|| frame_system::Pallet::<TestRuntime>::inc_account_nonce(EVALUATOR_1));
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::evaluate(
+					RuntimeOrigin::signed(EVALUATOR_1),
+					jwt,
+					project_id,
+					500 * USD_UNIT
+				));
+				assert_eq!(
+					Evaluations::<TestRuntime>::iter_prefix_values((project_id, EVALUATOR_1))
+						.next()
+						.unwrap()
+						.receiving_account,
+					dot_acc
+				);
+			});
+		}
+
+		#[test]
+		fn expired_receiving_account_signature() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id = inst.create_evaluating_project(project_metadata.clone(), ISSUER_1, None);
+
+			let expiry = inst.current_block() + 2;
+			let (dot_acc, dot_sig) = inst.dot_key_and_sig_from("//EVALUATOR1", project_id, EVALUATOR_1, expiry);
+			inst.advance_time(3);
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::bind_receiving_account(
+						RuntimeOrigin::signed(EVALUATOR_1),
+						project_id,
+						dot_acc,
+						expiry,
+						dot_sig,
+					),
+					Error::<TestRuntime>::ReceivingAccountSignatureExpired
+				);
+				// The expiry is part of the signed message, so it cannot be extended
+				assert_noop!(
+					PolimecFunding::bind_receiving_account(
+						RuntimeOrigin::signed(EVALUATOR_1),
+						project_id,
+						dot_acc,
+						expiry + 10,
+						dot_sig,
+					),
+					Error::<TestRuntime>::BadReceiverAccountSignature
+				);
+			});
 		}
 	}
 
@@ -998,3 +1234,459 @@ mod evaluate_extrinsic {
 		}
 	}
 }
+
+#[cfg(test)]
+mod increase_evaluation_extrinsic {
+	use super::*;
+
+	fn jwt_for(account: AccountId, project_metadata: &ProjectMetadataOf<TestRuntime>) -> UntrustedToken {
+		get_mock_jwt_with_cid(
+			account,
+			InvestorType::Retail,
+			generate_did_from_account(account),
+			project_metadata.clone().policy_ipfs_cid.unwrap(),
+		)
+	}
+
+	#[cfg(test)]
+	mod success {
+		use super::*;
+
+		#[test]
+		fn added_amount_is_split_between_early_and_late() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id = inst.create_evaluating_project(project_metadata.clone(), ISSUER_1, None);
+			let threshold_usd = <TestRuntime as Config>::EvaluationSuccessThreshold::get() *
+				inst.get_project_details(project_id).fundraising_target_usd;
+
+			let evaluation = EvaluationParams::from((EVALUATOR_1, threshold_usd - 1_000 * USD_UNIT));
+			let increase = EvaluationParams::from((EVALUATOR_1, 3_000 * USD_UNIT));
+			let initial_plmc = inst.calculate_evaluation_plmc_spent(vec![evaluation.clone()]);
+			let increase_plmc = inst.calculate_evaluation_plmc_spent(vec![increase.clone()]);
+			inst.mint_plmc_to(initial_plmc.accounts().existential_deposits());
+			inst.mint_plmc_to(initial_plmc.clone());
+			inst.mint_plmc_to(increase_plmc.clone());
+			inst.evaluate_for_users(project_id, vec![evaluation.clone()]).unwrap();
+
+			assert_ok!(inst.execute(|| PolimecFunding::increase_evaluation(
+				RuntimeOrigin::signed(EVALUATOR_1),
+				jwt_for(EVALUATOR_1, &project_metadata),
+				project_id,
+				0,
+				increase.usd_amount,
+			)));
+
+			let total_plmc = initial_plmc[0].plmc_amount + increase_plmc[0].plmc_amount;
+			let stored_evaluation =
+				inst.execute(|| Evaluations::<TestRuntime>::get((project_id, EVALUATOR_1, 0)).unwrap());
+			assert_eq!(stored_evaluation.original_plmc_bond, total_plmc);
+			assert_eq!(stored_evaluation.current_plmc_bond, total_plmc);
+			assert_eq!(stored_evaluation.early_usd_amount, threshold_usd);
+			assert_eq!(stored_evaluation.late_usd_amount, 2_000 * USD_UNIT);
+
+			let evaluation_round_info = inst.get_project_details(project_id).evaluation_round_info;
+			assert_eq!(evaluation_round_info.total_bonded_usd, threshold_usd + 2_000 * USD_UNIT);
+			assert_eq!(evaluation_round_info.total_bonded_plmc, total_plmc);
+			assert_eq!(inst.get_reserved_plmc_balance_for(EVALUATOR_1, HoldReason::Evaluation.into()), total_plmc);
+
+			inst.execute(|| {
+				System::assert_last_event(
+					Event::EvaluationIncreased {
+						project_id,
+						evaluator: EVALUATOR_1,
+						id: 0,
+						plmc_amount: increase_plmc[0].plmc_amount,
+					}
+					.into(),
+				)
+			});
+		}
+	}
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn other_did_cannot_increase_evaluation() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id = inst.create_evaluating_project(project_metadata.clone(), ISSUER_1, None);
+			let evaluation = EvaluationParams::from((EVALUATOR_1, 500 * USD_UNIT));
+			let necessary_plmc = inst.calculate_evaluation_plmc_spent(vec![evaluation.clone()]);
+			inst.mint_plmc_to(necessary_plmc.accounts().existential_deposits());
+			inst.mint_plmc_to(necessary_plmc);
+			inst.evaluate_for_users(project_id, vec![evaluation]).unwrap();
+
+			// The evaluation is stored under EVALUATOR_1, but the credential belongs to someone else
+			let jwt = get_mock_jwt_with_cid(
+				EVALUATOR_1,
+				InvestorType::Retail,
+				generate_did_from_account(EVALUATOR_2),
+				project_metadata.clone().policy_ipfs_cid.unwrap(),
+			);
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::increase_evaluation(
+						RuntimeOrigin::signed(EVALUATOR_1),
+						jwt,
+						project_id,
+						0,
+						500 * USD_UNIT
+					),
+					Error::<TestRuntime>::NotAllowed
+				);
+			});
+		}
+
+		#[test]
+		fn increase_below_minimum_evaluation() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id = inst.create_evaluating_project(project_metadata.clone(), ISSUER_1, None);
+			let evaluation = EvaluationParams::from((EVALUATOR_1, 500 * USD_UNIT));
+			let necessary_plmc = inst.calculate_evaluation_plmc_spent(vec![evaluation.clone()]);
+			inst.mint_plmc_to(necessary_plmc.accounts().existential_deposits());
+			inst.mint_plmc_to(necessary_plmc);
+			inst.evaluate_for_users(project_id, vec![evaluation]).unwrap();
+
+			let min_evaluation_amount_usd = <TestRuntime as Config>::MinUsdPerEvaluation::get();
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::increase_evaluation(
+						RuntimeOrigin::signed(EVALUATOR_1),
+						jwt_for(EVALUATOR_1, &project_metadata),
+						project_id,
+						0,
+						min_evaluation_amount_usd - 1
+					),
+					Error::<TestRuntime>::TooLow
+				);
+			});
+		}
+
+		#[test]
+		fn project_is_halted() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id = inst.create_evaluating_project(project_metadata.clone(), ISSUER_1, None);
+			let evaluation = EvaluationParams::from((EVALUATOR_1, 500 * USD_UNIT));
+			let necessary_plmc = inst.calculate_evaluation_plmc_spent(vec![evaluation.clone(), evaluation.clone()]);
+			inst.mint_plmc_to(necessary_plmc.accounts().existential_deposits());
+			inst.mint_plmc_to(necessary_plmc);
+			inst.evaluate_for_users(project_id, vec![evaluation]).unwrap();
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::halt_project(
+					RuntimeOrigin::root(),
+					project_id,
+					b"fraudulent issuer".to_vec().try_into().unwrap()
+				));
+				assert_noop!(
+					PolimecFunding::increase_evaluation(
+						RuntimeOrigin::signed(EVALUATOR_1),
+						jwt_for(EVALUATOR_1, &project_metadata),
+						project_id,
+						0,
+						500 * USD_UNIT
+					),
+					Error::<TestRuntime>::ProjectHalted
+				);
+			});
+		}
+
+		#[test]
+		fn evaluation_not_found() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id = inst.create_evaluating_project(project_metadata.clone(), ISSUER_1, None);
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::increase_evaluation(
+						RuntimeOrigin::signed(EVALUATOR_1),
+						jwt_for(EVALUATOR_1, &project_metadata),
+						project_id,
+						0,
+						500 * USD_UNIT
+					),
+					Error::<TestRuntime>::ParticipationNotFound
+				);
+			});
+		}
+	}
+}
+
+#[cfg(test)]
+mod withdraw_evaluation_extrinsic {
+	use super::*;
+
+	#[cfg(test)]
+	mod success {
+		use super::*;
+
+		#[test]
+		fn plmc_is_released_and_totals_updated() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id = inst.create_evaluating_project(project_metadata.clone(), ISSUER_1, None);
+			let evaluations = vec![
+				EvaluationParams::from((EVALUATOR_1, 500 * USD_UNIT)),
+				EvaluationParams::from((EVALUATOR_2, 1_000 * USD_UNIT)),
+			];
+			let necessary_plmc = inst.calculate_evaluation_plmc_spent(evaluations.clone());
+			inst.mint_plmc_to(necessary_plmc.accounts().existential_deposits());
+			inst.mint_plmc_to(necessary_plmc.clone());
+			inst.evaluate_for_users(project_id, evaluations).unwrap();
+			let free_plmc_before = inst.get_free_plmc_balance_for(EVALUATOR_1);
+
+			assert_ok!(inst.execute(|| PolimecFunding::withdraw_evaluation(
+				RuntimeOrigin::signed(EVALUATOR_1),
+				project_id,
+				0,
+				10
+			)));
+
+			assert_eq!(inst.get_free_plmc_balance_for(EVALUATOR_1), free_plmc_before + necessary_plmc[0].plmc_amount);
+			assert_eq!(inst.get_reserved_plmc_balance_for(EVALUATOR_1, HoldReason::Evaluation.into()), 0);
+			inst.execute(|| assert!(Evaluations::<TestRuntime>::get((project_id, EVALUATOR_1, 0)).is_none()));
+
+			let evaluation_round_info = inst.get_project_details(project_id).evaluation_round_info;
+			assert_eq!(evaluation_round_info.total_bonded_usd, 1_000 * USD_UNIT);
+			assert_eq!(evaluation_round_info.total_bonded_plmc, necessary_plmc[1].plmc_amount);
+
+			inst.execute(|| {
+				System::assert_last_event(
+					Event::EvaluationWithdrawn {
+						project_id,
+						evaluator: EVALUATOR_1,
+						id: 0,
+						plmc_amount: necessary_plmc[0].plmc_amount,
+					}
+					.into(),
+				)
+			});
+		}
+
+		#[test]
+		fn freed_early_amount_goes_to_next_evaluations() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id = inst.create_evaluating_project(project_metadata.clone(), ISSUER_1, None);
+			let threshold_usd = <TestRuntime as Config>::EvaluationSuccessThreshold::get() *
+				inst.get_project_details(project_id).fundraising_target_usd;
+
+			let evaluations = vec![
+				EvaluationParams::from((EVALUATOR_1, threshold_usd - 1_000 * USD_UNIT)),
+				EvaluationParams::from((EVALUATOR_2, 3_000 * USD_UNIT)),
+				EvaluationParams::from((EVALUATOR_3, 4_000 * USD_UNIT)),
+			];
+			let necessary_plmc = inst.calculate_evaluation_plmc_spent(evaluations.clone());
+			inst.mint_plmc_to(necessary_plmc.accounts().existential_deposits());
+			inst.mint_plmc_to(necessary_plmc);
+			inst.evaluate_for_users(project_id, evaluations).unwrap();
+
+			let evaluation_2 = inst.execute(|| Evaluations::<TestRuntime>::get((project_id, EVALUATOR_2, 1)).unwrap());
+			assert_eq!(evaluation_2.early_usd_amount, 1_000 * USD_UNIT);
+			assert_eq!(evaluation_2.late_usd_amount, 2_000 * USD_UNIT);
+
+			assert_ok!(inst.execute(|| PolimecFunding::withdraw_evaluation(
+				RuntimeOrigin::signed(EVALUATOR_1),
+				project_id,
+				0,
+				10
+			)));
+
+			// Only 7_000 USD are left bonded, which is all below the threshold
+			let evaluation_2 = inst.execute(|| Evaluations::<TestRuntime>::get((project_id, EVALUATOR_2, 1)).unwrap());
+			let evaluation_3 = inst.execute(|| Evaluations::<TestRuntime>::get((project_id, EVALUATOR_3, 2)).unwrap());
+			assert_eq!((evaluation_2.early_usd_amount, evaluation_2.late_usd_amount), (3_000 * USD_UNIT, 0));
+			assert_eq!((evaluation_3.early_usd_amount, evaluation_3.late_usd_amount), (4_000 * USD_UNIT, 0));
+		}
+	}
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn cannot_withdraw_after_evaluation_round() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id = inst.create_auctioning_project(
+				project_metadata.clone(),
+				ISSUER_1,
+				None,
+				inst.generate_successful_evaluations(project_metadata.clone(), 5),
+			);
+			let evaluation =
+				inst.execute(|| Evaluations::<TestRuntime>::iter_prefix_values((project_id,)).next().unwrap());
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::withdraw_evaluation(
+						RuntimeOrigin::signed(evaluation.evaluator),
+						project_id,
+						evaluation.id,
+						10
+					),
+					Error::<TestRuntime>::IncorrectRound
+				);
+			});
+		}
+
+		#[test]
+		fn cannot_read_more_evaluations_than_the_given_maximum() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id = inst.create_evaluating_project(project_metadata.clone(), ISSUER_1, None);
+			let evaluations = vec![
+				EvaluationParams::from((EVALUATOR_1, 500 * USD_UNIT)),
+				EvaluationParams::from((EVALUATOR_2, 1_000 * USD_UNIT)),
+				EvaluationParams::from((EVALUATOR_3, 1_000 * USD_UNIT)),
+			];
+			let necessary_plmc = inst.calculate_evaluation_plmc_spent(evaluations.clone());
+			inst.mint_plmc_to(necessary_plmc.accounts().existential_deposits());
+			inst.mint_plmc_to(necessary_plmc);
+			inst.evaluate_for_users(project_id, evaluations).unwrap();
+
+			// The two evaluations left are read to give them the freed early USD amount
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::withdraw_evaluation(RuntimeOrigin::signed(EVALUATOR_1), project_id, 0, 1),
+					Error::<TestRuntime>::TooManyEvaluationsToReassign
+				);
+				assert_ok!(PolimecFunding::withdraw_evaluation(RuntimeOrigin::signed(EVALUATOR_1), project_id, 0, 2));
+			});
+		}
+
+		#[test]
+		fn cannot_withdraw_someone_elses_evaluation() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id = inst.create_evaluating_project(project_metadata.clone(), ISSUER_1, None);
+			let evaluation = EvaluationParams::from((EVALUATOR_1, 500 * USD_UNIT));
+			let necessary_plmc = inst.calculate_evaluation_plmc_spent(vec![evaluation.clone()]);
+			inst.mint_plmc_to(necessary_plmc.accounts().existential_deposits());
+			inst.mint_plmc_to(necessary_plmc);
+			inst.evaluate_for_users(project_id, vec![evaluation]).unwrap();
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::withdraw_evaluation(RuntimeOrigin::signed(EVALUATOR_2), project_id, 0, 10),
+					Error::<TestRuntime>::ParticipationNotFound
+				);
+			});
+		}
+	}
+}
+
+#[cfg(test)]
+mod evaluate_with_allowlist_proof_extrinsic {
+	use super::*;
+
+	fn create_allowlisted_evaluating_project(inst: &mut MockInstantiator) -> (ProjectId, [AllowlistProof; 2]) {
+		let project_metadata = default_project_metadata(ISSUER_1);
+		let project_id = inst.create_evaluating_project(project_metadata, ISSUER_1, None);
+		let (allowlist_root, proofs) =
+			two_member_allowlist([(EVALUATOR_1, 1000 * USD_UNIT), (EVALUATOR_2, 2000 * USD_UNIT)]);
+		inst.execute(|| ProjectAllowlists::<TestRuntime>::insert(project_id, allowlist_root));
+		inst.mint_necessary_tokens_for_evaluations(vec![
+			(EVALUATOR_1, 1000 * USD_UNIT).into(),
+			(EVALUATOR_2, 1000 * USD_UNIT).into(),
+			(EVALUATOR_3, 1000 * USD_UNIT).into(),
+		]);
+
+		(project_id, proofs)
+	}
+
+	#[cfg(test)]
+	mod success {
+		use super::*;
+
+		#[test]
+		fn proof_is_only_needed_once() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, [proof_1, _]) = create_allowlisted_evaluating_project(&mut inst);
+			let project_metadata = inst.get_project_metadata(project_id);
+			let jwt = get_mock_jwt_with_cid(
+				EVALUATOR_1,
+				InvestorType::Retail,
+				generate_did_from_account(EVALUATOR_1),
+				project_metadata.policy_ipfs_cid.unwrap(),
+			);
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::evaluate_with_allowlist_proof(
+					RuntimeOrigin::signed(EVALUATOR_1),
+					jwt.clone(),
+					project_id,
+					500 * USD_UNIT,
+					proof_1
+				));
+				assert_eq!(
+					AllowlistCaps::<TestRuntime>::get(project_id, generate_did_from_account(EVALUATOR_1)),
+					Some(1000 * USD_UNIT)
+				);
+				assert_ok!(PolimecFunding::evaluate(
+					RuntimeOrigin::signed(EVALUATOR_1),
+					jwt,
+					project_id,
+					500 * USD_UNIT
+				));
+			});
+		}
+	}
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn evaluation_without_proof() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, _) = create_allowlisted_evaluating_project(&mut inst);
+			let project_metadata = inst.get_project_metadata(project_id);
+			let jwt = get_mock_jwt_with_cid(
+				EVALUATOR_1,
+				InvestorType::Retail,
+				generate_did_from_account(EVALUATOR_1),
+				project_metadata.policy_ipfs_cid.unwrap(),
+			);
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::evaluate(RuntimeOrigin::signed(EVALUATOR_1), jwt, project_id, 500 * USD_UNIT),
+					Error::<TestRuntime>::NotAllowlisted
+				);
+			});
+		}
+
+		#[test]
+		fn proof_of_another_member() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, [_, proof_2]) = create_allowlisted_evaluating_project(&mut inst);
+			let project_metadata = inst.get_project_metadata(project_id);
+			let jwt = get_mock_jwt_with_cid(
+				EVALUATOR_3,
+				InvestorType::Retail,
+				generate_did_from_account(EVALUATOR_3),
+				project_metadata.policy_ipfs_cid.unwrap(),
+			);
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::evaluate_with_allowlist_proof(
+						RuntimeOrigin::signed(EVALUATOR_3),
+						jwt,
+						project_id,
+						500 * USD_UNIT,
+						proof_2
+					),
+					Error::<TestRuntime>::InvalidAllowlistProof
+				);
+			});
+		}
+	}
+}
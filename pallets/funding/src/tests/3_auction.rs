@@ -37,6 +37,7 @@ mod round_flow {
 		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
 		let project_id = inst.create_auctioning_project(project_metadata.clone(), issuer, None, evaluations);
 
+		assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::CommunityRound);
 		assert!(matches!(inst.go_to_next_state(project_id), ProjectStatus::FundingFailed));
 	}
 
@@ -77,7 +78,7 @@ mod round_flow {
 				AcceptedFundingAsset::USDT => usdt_price,
 				AcceptedFundingAsset::USDC => usdc_price,
 				AcceptedFundingAsset::DOT => dot_price,
-				AcceptedFundingAsset::ETH => todo!(),
+				_ => todo!(),
 			};
 
 			let mut project_metadata = default_project_metadata.clone();
@@ -118,12 +119,12 @@ mod round_flow {
 
 			// A minimum bid goes through. This is a fixed USD value, but the extrinsic amount depends on CT decimals.
 			inst.mint_plmc_ed_if_required(vec![BIDDER_1]);
-			inst.mint_funding_asset_ed_if_required(vec![(BIDDER_1, funding_asset.id())]);
+			inst.mint_funding_asset_ed_if_required(vec![(BIDDER_1, funding_asset.id().unwrap())]);
 			inst.mint_plmc_to(vec![UserToPLMCBalance::new(BIDDER_1, min_professional_bid_plmc + ed)]);
 			inst.mint_funding_asset_to(vec![UserToFundingAsset::new(
 				BIDDER_1,
 				min_professional_bid_funding_asset,
-				funding_asset.id(),
+				funding_asset.id().unwrap(),
 			)]);
 
 			assert_ok!(inst.execute(|| PolimecFunding::bid(
@@ -306,6 +307,66 @@ mod round_flow {
 
 		assert!(inst.execute(|| ProjectsInAuctionRound::<TestRuntime>::iter_keys().next().is_none()))
 	}
+
+	#[test]
+	fn oversubscribed_bids_skip_bids_of_other_projects_and_settled_bids() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let project_metadata_1 = default_project_metadata(ISSUER_1);
+		let project_metadata_2 = default_project_metadata(ISSUER_2);
+		let evaluations_1 = inst.generate_successful_evaluations(project_metadata_1.clone(), 5);
+		let evaluations_2 = inst.generate_successful_evaluations(project_metadata_2.clone(), 5);
+		let bids_1 = inst.generate_bids_from_total_ct_percent(project_metadata_1.clone(), 100, 5);
+		let bids_2 = inst.generate_bids_from_total_ct_percent(project_metadata_2.clone(), 50, 5);
+		let project_id_1 = inst.create_auctioning_project(project_metadata_1.clone(), ISSUER_1, None, evaluations_1);
+		let project_id_2 = inst.create_auctioning_project(project_metadata_2.clone(), ISSUER_2, None, evaluations_2);
+		inst.mint_necessary_tokens_for_bids(project_id_1, bids_1.clone());
+		inst.mint_necessary_tokens_for_bids(project_id_2, bids_2.clone());
+
+		// The bids of both projects take turns, so the bid ids of each project are not consecutive.
+		for (bid_1, bid_2) in bids_1.iter().zip(bids_2.iter()) {
+			inst.bid_for_users(project_id_1, vec![bid_1.clone()]).unwrap();
+			inst.bid_for_users(project_id_2, vec![bid_2.clone()]).unwrap();
+		}
+		let mut stored_bids_1 = inst.get_bids(project_id_1);
+		stored_bids_1.sort_by_key(|bid| bid.id);
+
+		let oversubscribing_bid =
+			BidParams::<TestRuntime>::from((BIDDER_1, Retail, bids_1[4].amount + bids_1[3].amount, Classic(1u8), USDT));
+		inst.mint_necessary_tokens_for_bids(project_id_1, vec![oversubscribing_bid.clone()]);
+		inst.bid_for_users(project_id_1, vec![oversubscribing_bid]).unwrap();
+		inst.process_oversubscribed_bids(project_id_1);
+
+		let status_of = |inst: &mut MockInstantiator, bid_id: u32| {
+			inst.execute(|| Bids::<TestRuntime>::get(project_id_1, bid_id)).map(|bid| bid.status)
+		};
+		assert_eq!(status_of(&mut inst, stored_bids_1[4].id), Some(BidStatus::Rejected));
+		assert_eq!(status_of(&mut inst, stored_bids_1[3].id), Some(BidStatus::Rejected));
+		assert_eq!(status_of(&mut inst, stored_bids_1[2].id), Some(BidStatus::YetUnknown));
+		assert_eq!(
+			inst.execute(|| OutbidBidsCutoffs::<TestRuntime>::get(project_id_1)),
+			Some(OutbidBidsCutoff { bid_price: project_metadata_1.minimum_price, bid_index: stored_bids_1[3].id })
+		);
+
+		// Settling the outbid bids early removes them from their bucket, and the cutoff goes back to the start.
+		inst.execute(|| {
+			assert_ok!(PolimecFunding::do_settle_bid(project_id_1, stored_bids_1[3].id));
+			assert_ok!(PolimecFunding::do_settle_bid(project_id_1, stored_bids_1[4].id));
+			assert_eq!(OutbidBidsCutoffs::<TestRuntime>::get(project_id_1), None);
+			assert_eq!(
+				BidsBucketBounds::<TestRuntime>::get(project_id_1, project_metadata_1.minimum_price),
+				Some(BidBucketBounds { first_bid_index: stored_bids_1[0].id, last_bid_index: stored_bids_1[2].id })
+			);
+		});
+
+		// The next outbid bid is the last stored one of the first bucket.
+		let oversubscribing_bid =
+			BidParams::<TestRuntime>::from((BIDDER_2, Retail, bids_1[2].amount, Classic(1u8), USDT));
+		inst.mint_necessary_tokens_for_bids(project_id_1, vec![oversubscribing_bid.clone()]);
+		inst.bid_for_users(project_id_1, vec![oversubscribing_bid]).unwrap();
+		inst.process_oversubscribed_bids(project_id_1);
+		assert_eq!(status_of(&mut inst, stored_bids_1[2].id), Some(BidStatus::Rejected));
+		assert_eq!(status_of(&mut inst, stored_bids_1[1].id), Some(BidStatus::YetUnknown));
+	}
 }
 
 #[cfg(test)]
@@ -356,7 +417,7 @@ mod bid_extrinsic {
 			);
 
 			inst.mint_plmc_ed_if_required(vec![evaluator_bidder]);
-			inst.mint_funding_asset_ed_if_required(vec![(evaluator_bidder, USDT.id())]);
+			inst.mint_funding_asset_ed_if_required(vec![(evaluator_bidder, USDT.id().unwrap())]);
 			inst.mint_plmc_to(vec![UserToPLMCBalance::new(
 				evaluator_bidder,
 				necessary_plmc_for_bid - usable_evaluation_plmc,
@@ -649,6 +710,101 @@ mod bid_extrinsic {
 			assert_eq!(normalize_price(current_bucket.delta_price), PriceOf::<TestRuntime>::from_float(0.1));
 		}
 
+		#[test]
+		fn bid_with_max_price_stops_at_the_cap() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+
+			let mut project_metadata = default_project_metadata(ISSUER_1);
+			project_metadata.minimum_price = PriceProviderOf::<TestRuntime>::calculate_decimals_aware_price(
+				PriceOf::<TestRuntime>::from_float(1.0),
+				USD_DECIMALS,
+				project_metadata.clone().token_information.decimals,
+			)
+			.unwrap();
+
+			let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+			let project_id = inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, evaluations);
+
+			// bid that fills 90% of the first bucket
+			let bid_90_percent = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 90u8, 1);
+			// Without a cap, this bid would go over the first bucket and 3 more ones.
+			let bid_33_percent = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 33u8, 1);
+			let all_bids = vec![bid_90_percent[0].clone(), bid_33_percent[0].clone()];
+
+			inst.mint_plmc_ed_if_required(all_bids.accounts());
+			inst.mint_funding_asset_ed_if_required(all_bids.to_account_asset_map());
+			let necessary_plmc = inst.calculate_auction_plmc_charged_from_all_bids_made_or_with_bucket(
+				&all_bids,
+				project_metadata.clone(),
+				None,
+			);
+			let necessary_usdt = inst.calculate_auction_funding_asset_charged_from_all_bids_made_or_with_bucket(
+				&all_bids,
+				project_metadata.clone(),
+				None,
+			);
+			inst.mint_plmc_to(necessary_plmc.clone());
+			inst.mint_funding_asset_to(necessary_usdt.clone());
+
+			inst.bid_for_users(project_id, bid_90_percent.clone()).unwrap();
+
+			// The cap is the price of the second bucket
+			let current_bucket = inst.execute(|| Buckets::<TestRuntime>::get(project_id)).unwrap();
+			let max_price = current_bucket.current_price + current_bucket.delta_price;
+
+			let capped_bid = bid_33_percent[0].clone();
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::do_bid(DoBidParams::<TestRuntime> {
+					bidder: capped_bid.bidder,
+					project_id,
+					ct_amount: capped_bid.amount,
+					mode: capped_bid.mode,
+					funding_asset: capped_bid.asset,
+					did: generate_did_from_account(capped_bid.bidder),
+					investor_type: capped_bid.investor_type,
+					whitelisted_policy: project_metadata.clone().policy_ipfs_cid.unwrap(),
+					receiving_account: polkadot_junction!(capped_bid.bidder),
+					max_price: Some(max_price),
+				}));
+			});
+
+			// 10% at the price of the first bucket and 10% at the cap. The remaining 13% was not bid.
+			let stored_bids = inst.execute(|| {
+				Bids::<TestRuntime>::iter_prefix_values(project_id)
+					.filter(|bid| bid.bidder == capped_bid.bidder)
+					.sorted_by_key(|bid| bid.id)
+					.collect_vec()
+			});
+			assert_eq!(stored_bids.len(), 2);
+			assert_eq!(stored_bids[0].original_ct_usd_price, current_bucket.current_price);
+			assert_eq!(stored_bids[1].original_ct_usd_price, max_price);
+			for bid in &stored_bids {
+				assert_eq!(bid.original_ct_amount, Percent::from_percent(10) * project_metadata.total_allocation_size);
+			}
+
+			let unfilled_amount = Percent::from_percent(13) * project_metadata.total_allocation_size;
+			inst.execute(|| {
+				assert!(find_event!(
+					TestRuntime,
+					Event::<TestRuntime>::BidCappedByMaxPrice {
+						project_id: event_project_id,
+						bidder,
+						unfilled_ct_amount,
+						..
+					},
+					event_project_id == project_id,
+					bidder == capped_bid.bidder,
+					unfilled_ct_amount == unfilled_amount
+				)
+				.is_some());
+			});
+
+			// The next bucket, above the cap, was left untouched
+			let bucket_after = inst.execute(|| Buckets::<TestRuntime>::get(project_id)).unwrap();
+			assert!(bucket_after.current_price > max_price);
+			assert_eq!(bucket_after.amount_left, bucket_after.delta_amount);
+		}
+
 		#[test]
 		fn can_bid_with_frozen_tokens_funding_failed() {
 			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
@@ -709,6 +865,7 @@ mod bid_extrinsic {
 				));
 			});
 
+			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::CommunityRound);
 			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::FundingFailed);
 
 			let free_balance = inst.get_free_plmc_balance_for(BIDDER_4);
@@ -803,6 +960,7 @@ mod bid_extrinsic {
 				));
 			});
 
+			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::CommunityRound);
 			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::FundingSuccessful);
 			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::SettlementStarted(FundingOutcome::Success));
 
@@ -868,12 +1026,12 @@ mod bid_extrinsic {
 				ParticipationMode::OTM.multiplier().try_into().ok().unwrap();
 			let otm_duration = otm_multiplier.calculate_vesting_duration::<TestRuntime>();
 
-			let usdt_id = AcceptedFundingAsset::USDT.id();
+			let usdt_id = AcceptedFundingAsset::USDT.id().unwrap();
 			const USDT_PARTICIPATION: u128 = 5000 * USDT_UNIT;
 
 			let otm_usdt_fee: u128 = (FeePercentage::get() / ParticipationMode::OTM.multiplier()) * USDT_PARTICIPATION;
 
-			let usdt_ed = inst.get_funding_asset_ed(AcceptedFundingAsset::USDT.id());
+			let usdt_ed = inst.get_funding_asset_ed(AcceptedFundingAsset::USDT.id().unwrap());
 			let required_usdt =
 				UserToFundingAsset::new(BIDDER_1, USDT_PARTICIPATION + otm_usdt_fee + usdt_ed, usdt_id.clone());
 			inst.mint_funding_asset_to(vec![required_usdt.clone()]);
@@ -988,6 +1146,7 @@ mod bid_extrinsic {
 				Perquintill::from_float(0.999)
 			);
 
+			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::CommunityRound);
 			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::FundingSuccessful);
 			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::SettlementStarted(FundingOutcome::Success));
 			inst.settle_project(project_id, true);
@@ -1056,11 +1215,11 @@ mod bid_extrinsic {
 			let otm_multiplier: MultiplierOf<TestRuntime> =
 				ParticipationMode::OTM.multiplier().try_into().ok().unwrap();
 
-			let usdt_id = AcceptedFundingAsset::USDT.id();
+			let usdt_id = AcceptedFundingAsset::USDT.id().unwrap();
 			const USDT_PARTICIPATION: u128 = 5000 * USDT_UNIT;
 
 			let otm_usdt_fee: u128 = (FeePercentage::get() / ParticipationMode::OTM.multiplier()) * USDT_PARTICIPATION;
-			let usdt_ed = inst.get_funding_asset_ed(AcceptedFundingAsset::USDT.id());
+			let usdt_ed = inst.get_funding_asset_ed(AcceptedFundingAsset::USDT.id().unwrap());
 			let required_usdt =
 				UserToFundingAsset::new(BIDDER_1, USDT_PARTICIPATION + otm_usdt_fee + usdt_ed, usdt_id.clone());
 			inst.mint_funding_asset_to(vec![required_usdt.clone()]);
@@ -1142,6 +1301,7 @@ mod bid_extrinsic {
 				Perquintill::from_float(0.999)
 			);
 
+			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::CommunityRound);
 			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::FundingFailed);
 			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::SettlementStarted(FundingOutcome::Failure));
 			inst.settle_project(project_id, true);
@@ -1204,7 +1364,8 @@ mod bid_extrinsic {
 				project_metadata.clone().policy_ipfs_cid.unwrap(),
 			);
 
-			let (eth_acc, eth_sig) = inst.eth_key_and_sig_from("//BIDDER1", project_id, BIDDER_1);
+			let expiry = inst.current_block() + 10;
+			let (eth_acc, eth_sig) = inst.eth_key_and_sig_from("//BIDDER1", project_id, BIDDER_1, expiry);
 			let bid =
 				BidParams::from((BIDDER_1, Retail, 500 * CT_UNIT, ParticipationMode::OTM, AcceptedFundingAsset::USDT));
 			let mint_amount = inst.calculate_auction_funding_asset_charged_from_all_bids_made_or_with_bucket(
@@ -1215,18 +1376,30 @@ mod bid_extrinsic {
 			inst.mint_funding_asset_ed_if_required(mint_amount.to_account_asset_map());
 			inst.mint_funding_asset_to(mint_amount.clone());
 
-			assert_ok!(inst.execute(|| {
-				PolimecFunding::bid_with_receiving_account(
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::bind_receiving_account(
+					RuntimeOrigin::signed(BIDDER_1),
+					project_id,
+					eth_acc,
+					expiry,
+					eth_sig,
+				));
+				assert_ok!(PolimecFunding::bid(
 					RuntimeOrigin::signed(BIDDER_1),
 					jwt,
 					project_id,
 					500 * CT_UNIT,
 					ParticipationMode::OTM,
 					AcceptedFundingAsset::USDT,
-					eth_acc,
-					eth_sig,
-				)
-			}));
+				));
+				assert_eq!(
+					Bids::<TestRuntime>::iter_prefix_values(project_id)
+						.find(|bid| bid.bidder == BIDDER_1)
+						.unwrap()
+						.receiving_account,
+					eth_acc
+				);
+			});
 		}
 
 		#[test]
@@ -1244,7 +1417,8 @@ mod bid_extrinsic {
 				project_metadata.clone().policy_ipfs_cid.unwrap(),
 			);
 
-			let (dot_acc, dot_sig) = inst.dot_key_and_sig_from("//BIDDER1", project_id, BIDDER_1);
+			let expiry = inst.current_block() + 10;
+			let (dot_acc, dot_sig) = inst.dot_key_and_sig_from("//BIDDER1", project_id, BIDDER_1, expiry);
 			let bid =
 				BidParams::from((BIDDER_1, Retail, 500 * CT_UNIT, ParticipationMode::OTM, AcceptedFundingAsset::USDT));
 			let mint_amount = inst.calculate_auction_funding_asset_charged_from_all_bids_made_or_with_bucket(
@@ -1255,18 +1429,30 @@ mod bid_extrinsic {
 			inst.mint_funding_asset_ed_if_required(mint_amount.to_account_asset_map());
 			inst.mint_funding_asset_to(mint_amount.clone());
 
-			assert_ok!(inst.execute(|| {
-				PolimecFunding::bid_with_receiving_account(
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::bind_receiving_account(
+					RuntimeOrigin::signed(BIDDER_1),
+					project_id,
+					dot_acc,
+					expiry,
+					dot_sig,
+				));
+				assert_ok!(PolimecFunding::bid(
 					RuntimeOrigin::signed(BIDDER_1),
 					jwt,
 					project_id,
 					500 * CT_UNIT,
 					ParticipationMode::OTM,
 					AcceptedFundingAsset::USDT,
-					dot_acc,
-					dot_sig,
-				)
-			}));
+				));
+				assert_eq!(
+					Bids::<TestRuntime>::iter_prefix_values(project_id)
+						.find(|bid| bid.bidder == BIDDER_1)
+						.unwrap()
+						.receiving_account,
+					dot_acc
+				);
+			});
 		}
 	}
 
@@ -1391,6 +1577,7 @@ mod bid_extrinsic {
 						investor_type,
 						whitelisted_policy: project_metadata.clone().policy_ipfs_cid.unwrap(),
 						receiving_account: polkadot_junction!(BIDDER_2)
+						max_price: None,
 					}),
 					Error::<TestRuntime>::IncorrectRound
 				);
@@ -1434,6 +1621,7 @@ mod bid_extrinsic {
 						investor_type: InvestorType::Professional,
 						whitelisted_policy: project_metadata.clone().policy_ipfs_cid.unwrap(),
 						receiving_account: polkadot_junction!(BIDDER_1)
+						max_price: None,
 					}),
 					Error::<TestRuntime>::TooLow
 				);
@@ -1451,6 +1639,7 @@ mod bid_extrinsic {
 						investor_type: InvestorType::Institutional,
 						whitelisted_policy: project_metadata.clone().policy_ipfs_cid.unwrap(),
 						receiving_account: polkadot_junction!(BIDDER_2)
+						max_price: None,
 					}),
 					Error::<TestRuntime>::TooLow
 				);
@@ -1522,6 +1711,7 @@ mod bid_extrinsic {
 					investor_type: InvestorType::Professional,
 					whitelisted_policy: project_metadata.clone().policy_ipfs_cid.unwrap(),
 					receiving_account: polkadot_junction!(BIDDER_2)
+					max_price: None,
 				}));
 			});
 
@@ -1543,6 +1733,7 @@ mod bid_extrinsic {
 					investor_type: InvestorType::Institutional,
 					whitelisted_policy: project_metadata.clone().policy_ipfs_cid.unwrap(),
 					receiving_account: polkadot_junction!(BIDDER_3)
+					max_price: None,
 				}));
 			});
 		}
@@ -1690,6 +1881,7 @@ mod bid_extrinsic {
 					investor_type: InvestorType::Professional,
 					whitelisted_policy: project_metadata.clone().policy_ipfs_cid.unwrap(),
 					receiving_account: polkadot_junction!(ISSUER_1)
+					max_price: None,
 				})),
 				Error::<TestRuntime>::ParticipationToOwnProject
 			);
@@ -1725,11 +1917,84 @@ mod bid_extrinsic {
 					investor_type,
 					whitelisted_policy: project_metadata.clone().policy_ipfs_cid.unwrap(),
 					receiving_account: polkadot_junction!(bids[0].bidder),
+					max_price: None,
 				})
 			});
 			frame_support::assert_err!(outcome, Error::<TestRuntime>::FundingAssetNotAccepted);
 		}
 
+		#[test]
+		fn bid_with_max_price_below_current_price() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+			let project_id = inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, evaluations);
+
+			let bid = BidParams::<TestRuntime>::from((
+				BIDDER_1,
+				Retail,
+				5_000 * CT_UNIT,
+				ParticipationMode::Classic(1u8),
+				AcceptedFundingAsset::USDT,
+			));
+			inst.mint_necessary_tokens_for_bids(project_id, vec![bid.clone()]);
+
+			let current_bucket = inst.execute(|| Buckets::<TestRuntime>::get(project_id)).unwrap();
+			let max_price = current_bucket.current_price - current_bucket.delta_price;
+			let jwt = get_mock_jwt_with_cid(
+				bid.bidder,
+				bid.investor_type,
+				generate_did_from_account(bid.bidder),
+				project_metadata.clone().policy_ipfs_cid.unwrap(),
+			);
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::bid_with_max_price(
+						RuntimeOrigin::signed(bid.bidder),
+						jwt,
+						project_id,
+						bid.amount,
+						bid.mode,
+						bid.asset,
+						max_price,
+						None
+					),
+					Error::<TestRuntime>::PriceAboveMaxPrice
+				);
+			});
+		}
+
+		#[test]
+		fn bid_with_disabled_asset() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+			let project_id = inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, evaluations);
+
+			inst.execute(|| {
+				let disabled_usdt =
+					polimec_common::assets::FundingAssetInfo { enabled: false, ..USDT.builtin_info().unwrap() };
+				assert_ok!(PolimecFunding::set_funding_asset(RuntimeOrigin::root(), USDT, disabled_usdt));
+				assert_noop!(
+					PolimecFunding::bid(
+						RuntimeOrigin::signed(BIDDER_1),
+						get_mock_jwt_with_cid(
+							BIDDER_1,
+							InvestorType::Professional,
+							generate_did_from_account(BIDDER_1),
+							project_metadata.clone().policy_ipfs_cid.unwrap()
+						),
+						project_id,
+						5000 * CT_UNIT,
+						ParticipationMode::Classic(1u8),
+						AcceptedFundingAsset::USDT
+					),
+					Error::<TestRuntime>::FundingAssetNotAccepted
+				);
+			});
+		}
+
 		#[test]
 		fn wrong_policy_on_jwt() {
 			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
@@ -1789,88 +2054,592 @@ mod bid_extrinsic {
 }
 
 #[cfg(test)]
-mod end_auction_extrinsic {
+mod bid_batch_extrinsic {
 	use super::*;
 
-	// Partial acceptance at price <= wap (refund due to less CT bought)
-	// Rejection due to no more tokens left (full refund)
-	#[test]
-	fn bids_get_rejected_and_refunded() {
-		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
-		let issuer = ISSUER_1;
-		let mut project_metadata = default_project_metadata(issuer);
-		project_metadata.total_allocation_size = 50_000 * CT_UNIT;
-		project_metadata.mainnet_token_max_supply = project_metadata.total_allocation_size;
-		project_metadata.minimum_price = ConstPriceProvider::calculate_decimals_aware_price(
-			FixedU128::from_float(10.0f64),
-			USD_DECIMALS,
-			CT_DECIMALS,
-		)
-		.unwrap();
-		project_metadata.participation_currencies =
-			bounded_vec![AcceptedFundingAsset::USDT, AcceptedFundingAsset::USDC, AcceptedFundingAsset::DOT];
+	fn batch_entry(ct_amount: Balance, funding_asset: AcceptedFundingAsset) -> BidBatchEntry {
+		BidBatchEntry { ct_amount, mode: ParticipationMode::Classic(1u8), funding_asset }
+	}
 
-		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+	fn mint_for_batch(
+		inst: &mut MockInstantiator,
+		project_metadata: &ProjectMetadataOf<TestRuntime>,
+		entries: &[BidBatchEntry],
+	) {
+		let bids = entries
+			.iter()
+			.map(|entry| BidParams::from((BIDDER_1, Retail, entry.ct_amount, entry.mode, entry.funding_asset)))
+			.collect_vec();
+		let plmc_fundings = inst.calculate_auction_plmc_charged_with_given_price(&bids, project_metadata.minimum_price);
+		let funding_asset_fundings =
+			inst.calculate_auction_funding_asset_charged_with_given_price(&bids, project_metadata.minimum_price);
+		inst.mint_plmc_ed_if_required(plmc_fundings.accounts());
+		inst.mint_plmc_to(plmc_fundings);
+		inst.mint_funding_asset_ed_if_required(funding_asset_fundings.to_account_asset_map());
+		inst.mint_funding_asset_to(funding_asset_fundings);
+	}
 
-		// We use multiplier > 1 so after settlement, only the refunds defined above are done. The rest will be done
-		// through the linear release pallet
-		let bid_1 = BidParams::from((
-			BIDDER_1,
-			Retail,
-			5000 * CT_UNIT,
-			ParticipationMode::Classic(5u8),
-			AcceptedFundingAsset::USDT,
-		));
-		let bid_2 = BidParams::from((
-			BIDDER_2,
-			Institutional,
-			40_000 * CT_UNIT,
-			ParticipationMode::Classic(5u8),
-			AcceptedFundingAsset::USDC,
-		));
-		let bid_3 = BidParams::from((
-			BIDDER_5,
-			Professional,
-			10_000 * CT_UNIT,
-			ParticipationMode::Classic(5u8),
-			AcceptedFundingAsset::DOT,
-		));
-		let bid_4 = BidParams::from((
-			BIDDER_3,
-			Retail,
-			6000 * CT_UNIT,
-			ParticipationMode::Classic(5u8),
-			AcceptedFundingAsset::USDT,
-		));
-		let bid_5 = BidParams::from((
-			BIDDER_4,
-			Retail,
-			2000 * CT_UNIT,
-			ParticipationMode::Classic(5u8),
-			AcceptedFundingAsset::DOT,
-		));
-		// post bucketing, the bids look like this:
-		// (BIDDER_1, 5k) - (BIDDER_2, 40k) - (BIDDER_5, 5k)   - (BIDDER_5, 5k) - (BIDDER_3 - 5k) - (BIDDER_3 - 1k) - (BIDDER_4 - 2k)
-		// | -------------------- 10 USD ----------------------|---- 11 USD ---|---- 12 USD ----|----------- 13 USD -------------|
-		// (Accepted, 5k) - (Partially, 32k) - (Rejected, 5k) - (Accepted, 5k) - (Accepted - 5k) - (Accepted - 1k) - (Accepted - 2k)
+	#[cfg(test)]
+	mod success {
+		use super::*;
 
-		let bids = vec![bid_1, bid_2, bid_3, bid_4, bid_5];
+		#[test]
+		fn all_entries_are_placed() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let mut project_metadata = default_project_metadata(ISSUER_1);
+			project_metadata.participation_currencies =
+				bounded_vec![AcceptedFundingAsset::USDT, AcceptedFundingAsset::USDC];
+			let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+			let project_id = inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, evaluations);
 
-		let project_id = inst.create_auctioning_project(project_metadata.clone(), issuer, None, evaluations);
+			let entries = vec![
+				batch_entry(5_000 * CT_UNIT, AcceptedFundingAsset::USDT),
+				batch_entry(7_000 * CT_UNIT, AcceptedFundingAsset::USDC),
+			];
+			mint_for_batch(&mut inst, &project_metadata, &entries);
 
-		let plmc_amounts = inst.calculate_auction_plmc_charged_from_all_bids_made_or_with_bucket(
-			&bids,
-			project_metadata.clone(),
-			None,
-		);
-		let funding_asset_amounts = inst.calculate_auction_funding_asset_charged_from_all_bids_made_or_with_bucket(
-			&bids,
-			project_metadata.clone(),
-			None,
-		);
+			let jwt = get_mock_jwt_with_cid(
+				BIDDER_1,
+				InvestorType::Retail,
+				generate_did_from_account(BIDDER_1),
+				project_metadata.clone().policy_ipfs_cid.unwrap(),
+			);
+			let post_info = inst.execute(|| {
+				PolimecFunding::bid_batch(
+					RuntimeOrigin::signed(BIDDER_1),
+					jwt,
+					project_id,
+					entries.clone().try_into().unwrap(),
+				)
+				.unwrap()
+			});
+			assert_eq!(post_info.actual_weight, Some(<TestRuntime as Config>::WeightInfo::bid(1).saturating_mul(2)));
+
+			let bids = inst.execute(|| Bids::<TestRuntime>::iter_prefix_values(project_id).collect_vec());
+			let bidder_bids = bids.iter().filter(|bid| bid.bidder == BIDDER_1).collect_vec();
+			assert_eq!(bidder_bids.len(), 2);
+			for entry in entries {
+				assert!(bidder_bids.iter().any(|bid| bid.original_ct_amount == entry.ct_amount &&
+					bid.funding_asset == entry.funding_asset &&
+					bid.mode == entry.mode));
+				inst.execute(|| {
+					assert!(find_event!(
+						TestRuntime,
+						Event::<TestRuntime>::Bid {
+							project_id: event_project_id,
+							bidder,
+							ct_amount,
+							funding_asset,
+							..
+						},
+						event_project_id == project_id,
+						bidder == BIDDER_1,
+						ct_amount == entry.ct_amount,
+						funding_asset == entry.funding_asset
+					)
+					.is_some());
+				});
+			}
+		}
+	}
 
-		inst.mint_plmc_ed_if_required(bids.accounts());
-		inst.mint_funding_asset_ed_if_required(bids.to_account_asset_map());
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn empty_batch() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+			let project_id = inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, evaluations);
+
+			let jwt = get_mock_jwt_with_cid(
+				BIDDER_1,
+				InvestorType::Retail,
+				generate_did_from_account(BIDDER_1),
+				project_metadata.clone().policy_ipfs_cid.unwrap(),
+			);
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::bid_batch(RuntimeOrigin::signed(BIDDER_1), jwt, project_id, bounded_vec![]),
+					Error::<TestRuntime>::EmptyBidBatch
+				);
+			});
+		}
+
+		#[test]
+		fn failing_entry_reverts_the_whole_batch() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let mut project_metadata = default_project_metadata(ISSUER_1);
+			project_metadata.participation_currencies = bounded_vec![AcceptedFundingAsset::USDT];
+			let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+			let project_id = inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, evaluations);
+
+			let entries = vec![
+				batch_entry(5_000 * CT_UNIT, AcceptedFundingAsset::USDT),
+				batch_entry(5_000 * CT_UNIT, AcceptedFundingAsset::USDC),
+			];
+			mint_for_batch(&mut inst, &project_metadata, &entries);
+
+			let jwt = get_mock_jwt_with_cid(
+				BIDDER_1,
+				InvestorType::Retail,
+				generate_did_from_account(BIDDER_1),
+				project_metadata.clone().policy_ipfs_cid.unwrap(),
+			);
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::bid_batch(
+						RuntimeOrigin::signed(BIDDER_1),
+						jwt,
+						project_id,
+						entries.try_into().unwrap(),
+					),
+					Error::<TestRuntime>::FundingAssetNotAccepted
+				);
+			});
+		}
+
+		#[test]
+		fn credential_is_checked() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+			let project_id = inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, evaluations);
+
+			let entries = vec![batch_entry(5_000 * CT_UNIT, AcceptedFundingAsset::USDT)];
+			mint_for_batch(&mut inst, &project_metadata, &entries);
+
+			let jwt = get_mock_jwt_with_cid(
+				BIDDER_1,
+				InvestorType::Retail,
+				generate_did_from_account(BIDDER_1),
+				"wrong_cid".as_bytes().to_vec().try_into().unwrap(),
+			);
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::bid_batch(
+						RuntimeOrigin::signed(BIDDER_1),
+						jwt,
+						project_id,
+						entries.try_into().unwrap(),
+					),
+					Error::<TestRuntime>::PolicyMismatch
+				);
+			});
+		}
+	}
+}
+
+#[cfg(test)]
+mod cancel_bid_extrinsic {
+	use super::*;
+
+	// 20 bids of 25k CT each fill up the first bucket, and a 25k CT bid in the second bucket outbids the last one.
+	fn create_project_with_outbid_bid(
+		inst: &mut MockInstantiator,
+	) -> (ProjectId, ProjectMetadataOf<TestRuntime>, BidInfoOf<TestRuntime>, BidInfoOf<TestRuntime>) {
+		let project_metadata = default_project_metadata(ISSUER_1);
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+		let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 100, 20);
+		let project_id = inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, evaluations);
+		inst.mint_necessary_tokens_for_bids(project_id, bids.clone());
+		inst.bid_for_users(project_id, bids).unwrap();
+
+		let oversubscribing_bid =
+			BidParams::from((BIDDER_1, Retail, 25_000 * CT_UNIT, ParticipationMode::Classic(1u8), USDT));
+		inst.mint_necessary_tokens_for_bids(project_id, vec![oversubscribing_bid.clone()]);
+		inst.bid_for_users(project_id, vec![oversubscribing_bid]).unwrap();
+		inst.process_oversubscribed_bids(project_id);
+
+		let stored_bids = inst.get_bids(project_id);
+		let oversubscribing_bid = stored_bids.iter().find(|bid| bid.bidder == BIDDER_1).unwrap().clone();
+		let outbid_bid = stored_bids.iter().find(|bid| bid.status == BidStatus::Rejected).unwrap().clone();
+		(project_id, project_metadata, oversubscribing_bid, outbid_bid)
+	}
+
+	#[cfg(test)]
+	mod success {
+		use super::*;
+
+		#[test]
+		fn bid_can_be_cancelled_in_the_first_bucket() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+			let project_id = inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, evaluations);
+
+			let bid = BidParams::from((BIDDER_1, Retail, 1_000 * CT_UNIT, ParticipationMode::Classic(2u8), USDT));
+			inst.mint_necessary_tokens_for_bids(project_id, vec![bid.clone()]);
+			inst.bid_for_users(project_id, vec![bid]).unwrap();
+
+			let stored_bid = inst.get_bids(project_id)[0].clone();
+			let prev_plmc = inst.get_free_plmc_balance_for(BIDDER_1);
+			let prev_usdt = inst.get_free_funding_asset_balance_for(USDT.id().unwrap(), BIDDER_1);
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::cancel_bid(RuntimeOrigin::signed(BIDDER_1), project_id, stored_bid.id, 1));
+			});
+
+			assert!(inst.get_bids(project_id).is_empty());
+			assert_eq!(inst.get_free_plmc_balance_for(BIDDER_1), prev_plmc + stored_bid.plmc_bond);
+			assert_eq!(
+				inst.get_free_funding_asset_balance_for(USDT.id().unwrap(), BIDDER_1),
+				prev_usdt + stored_bid.funding_asset_amount_locked
+			);
+			assert_eq!(inst.get_reserved_plmc_balance_for(BIDDER_1, HoldReason::Participation.into()), 0);
+			inst.execute(|| {
+				let bucket = Buckets::<TestRuntime>::get(project_id).unwrap();
+				assert_eq!(bucket.amount_left, project_metadata.total_allocation_size);
+				assert_eq!(bucket.current_price, project_metadata.minimum_price);
+				assert_eq!(AuctionBoughtUSD::<TestRuntime>::get((project_id, generate_did_from_account(BIDDER_1))), 0);
+				assert!(BidsBucketBounds::<TestRuntime>::get(project_id, project_metadata.minimum_price).is_none());
+				System::assert_last_event(
+					Event::BidCancelled {
+						project_id,
+						bidder: BIDDER_1,
+						id: stored_bid.id,
+						ct_amount: stored_bid.original_ct_amount,
+						funding_amount: stored_bid.funding_asset_amount_locked,
+						plmc_bond: stored_bid.plmc_bond,
+					}
+					.into(),
+				);
+			});
+		}
+
+		#[test]
+		fn bid_can_be_decreased() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+			let project_id = inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, evaluations);
+
+			let bid = BidParams::from((BIDDER_1, Retail, 1_000 * CT_UNIT, ParticipationMode::Classic(2u8), USDT));
+			inst.mint_necessary_tokens_for_bids(project_id, vec![bid.clone()]);
+			inst.bid_for_users(project_id, vec![bid]).unwrap();
+
+			let stored_bid = inst.get_bids(project_id)[0].clone();
+			let prev_plmc = inst.get_free_plmc_balance_for(BIDDER_1);
+			let prev_usdt = inst.get_free_funding_asset_balance_for(USDT.id().unwrap(), BIDDER_1);
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::decrease_bid(
+					RuntimeOrigin::signed(BIDDER_1),
+					project_id,
+					stored_bid.id,
+					400 * CT_UNIT,
+					1
+				));
+			});
+
+			let decreased_bid = inst.get_bids(project_id)[0].clone();
+			let refunded_plmc = Perquintill::from_percent(40) * stored_bid.plmc_bond;
+			let refunded_usdt = Perquintill::from_percent(40) * stored_bid.funding_asset_amount_locked;
+			assert_eq!(decreased_bid.original_ct_amount, 600 * CT_UNIT);
+			assert_eq!(decreased_bid.plmc_bond, stored_bid.plmc_bond - refunded_plmc);
+			assert_eq!(
+				decreased_bid.funding_asset_amount_locked,
+				stored_bid.funding_asset_amount_locked - refunded_usdt
+			);
+			assert_eq!(inst.get_free_plmc_balance_for(BIDDER_1), prev_plmc + refunded_plmc);
+			assert_eq!(
+				inst.get_free_funding_asset_balance_for(USDT.id().unwrap(), BIDDER_1),
+				prev_usdt + refunded_usdt
+			);
+			inst.execute(|| {
+				let bucket = Buckets::<TestRuntime>::get(project_id).unwrap();
+				assert_eq!(bucket.amount_left, project_metadata.total_allocation_size - 600 * CT_UNIT);
+				assert_eq!(
+					AuctionBoughtUSD::<TestRuntime>::get((project_id, generate_did_from_account(BIDDER_1))),
+					project_metadata.minimum_price.saturating_mul_int(600 * CT_UNIT)
+				);
+			});
+		}
+
+		#[test]
+		fn cancelling_an_oversubscribing_bid_accepts_outbid_bids_again() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, project_metadata, oversubscribing_bid, outbid_bid) =
+				create_project_with_outbid_bid(&mut inst);
+			let bucket_before = inst.execute(|| Buckets::<TestRuntime>::get(project_id).unwrap());
+			assert!(inst.execute(|| OutbidBidsCutoffs::<TestRuntime>::get(project_id)).is_some());
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::cancel_bid(
+					RuntimeOrigin::signed(BIDDER_1),
+					project_id,
+					oversubscribing_bid.id,
+					1
+				));
+			});
+
+			let restored_bid = inst.execute(|| Bids::<TestRuntime>::get(project_id, outbid_bid.id)).unwrap();
+			assert_eq!(restored_bid.status, BidStatus::YetUnknown);
+			assert!(inst.get_bids(project_id).iter().all(|bid| bid.status == BidStatus::YetUnknown));
+			inst.execute(|| {
+				assert!(OutbidBidsCutoffs::<TestRuntime>::get(project_id).is_none());
+				assert_eq!(CTAmountOversubscribed::<TestRuntime>::get(project_id), 0);
+				let bucket = Buckets::<TestRuntime>::get(project_id).unwrap();
+				assert_eq!(bucket.current_price, bucket_before.current_price);
+				assert_eq!(bucket.amount_left, bucket.delta_amount);
+				assert!(BidsBucketBounds::<TestRuntime>::get(project_id, bucket.current_price).is_none());
+			});
+
+			// The auction ends as if the oversubscribing bid was never made
+			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::FundingSuccessful);
+			assert_eq!(inst.get_project_details(project_id).remaining_contribution_tokens, 0);
+			assert_eq!(
+				inst.get_project_details(project_id).funding_amount_reached_usd,
+				project_metadata.minimum_price.saturating_mul_int(project_metadata.total_allocation_size)
+			);
+		}
+
+		#[test]
+		fn decreasing_an_oversubscribing_bid_partially_accepts_outbid_bid() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, _project_metadata, oversubscribing_bid, outbid_bid) =
+				create_project_with_outbid_bid(&mut inst);
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::decrease_bid(
+					RuntimeOrigin::signed(BIDDER_1),
+					project_id,
+					oversubscribing_bid.id,
+					10_000 * CT_UNIT,
+					1
+				));
+			});
+
+			let restored_bid = inst.execute(|| Bids::<TestRuntime>::get(project_id, outbid_bid.id)).unwrap();
+			assert_eq!(restored_bid.status, BidStatus::PartiallyAccepted(10_000 * CT_UNIT));
+			inst.execute(|| {
+				let cutoff = OutbidBidsCutoffs::<TestRuntime>::get(project_id).unwrap();
+				assert_eq!(cutoff.bid_index, outbid_bid.id);
+			});
+
+			// The rest of the outbid bid can't be settled early anymore
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::do_settle_bid(project_id, outbid_bid.id),
+					Error::<TestRuntime>::SettlementNotStarted
+				);
+			});
+		}
+	}
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn cannot_cancel_someone_elses_bid() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+			let project_id = inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, evaluations);
+
+			let bid = BidParams::from((BIDDER_1, Retail, 1_000 * CT_UNIT, ParticipationMode::Classic(1u8), USDT));
+			inst.mint_necessary_tokens_for_bids(project_id, vec![bid.clone()]);
+			inst.bid_for_users(project_id, vec![bid]).unwrap();
+			let stored_bid = inst.get_bids(project_id)[0].clone();
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::cancel_bid(RuntimeOrigin::signed(BIDDER_2), project_id, stored_bid.id, 1),
+					Error::<TestRuntime>::NotAllowed
+				);
+			});
+		}
+
+		#[test]
+		fn cannot_decrease_by_more_than_the_bid_amount() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+			let project_id = inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, evaluations);
+
+			let bid = BidParams::from((BIDDER_1, Retail, 1_000 * CT_UNIT, ParticipationMode::Classic(1u8), USDT));
+			inst.mint_necessary_tokens_for_bids(project_id, vec![bid.clone()]);
+			inst.bid_for_users(project_id, vec![bid]).unwrap();
+			let stored_bid = inst.get_bids(project_id)[0].clone();
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::decrease_bid(
+						RuntimeOrigin::signed(BIDDER_1),
+						project_id,
+						stored_bid.id,
+						1_000 * CT_UNIT + 1,
+						1
+					),
+					Error::<TestRuntime>::TooHigh
+				);
+				assert_noop!(
+					PolimecFunding::decrease_bid(RuntimeOrigin::signed(BIDDER_1), project_id, stored_bid.id, 0, 1),
+					Error::<TestRuntime>::TooLow
+				);
+			});
+		}
+
+		#[test]
+		fn cannot_cancel_bid_from_a_lower_bucket() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, _project_metadata, _oversubscribing_bid, _outbid_bid) =
+				create_project_with_outbid_bid(&mut inst);
+			let first_bucket_bid = inst.get_bids(project_id).into_iter().find(|bid| bid.id == 0).unwrap();
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::cancel_bid(
+						RuntimeOrigin::signed(first_bucket_bid.bidder),
+						project_id,
+						first_bucket_bid.id,
+						1
+					),
+					Error::<TestRuntime>::BidNotCancellable
+				);
+			});
+		}
+
+		#[test]
+		fn cannot_cancel_if_outbid_bids_were_refunded() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, _project_metadata, oversubscribing_bid, outbid_bid) =
+				create_project_with_outbid_bid(&mut inst);
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::do_settle_bid(project_id, outbid_bid.id));
+				assert_noop!(
+					PolimecFunding::cancel_bid(RuntimeOrigin::signed(BIDDER_1), project_id, oversubscribing_bid.id, 1),
+					Error::<TestRuntime>::BidNotCancellable
+				);
+			});
+		}
+
+		#[test]
+		fn cannot_restore_more_bids_than_the_given_maximum() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, _project_metadata, oversubscribing_bid, _outbid_bid) =
+				create_project_with_outbid_bid(&mut inst);
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::cancel_bid(RuntimeOrigin::signed(BIDDER_1), project_id, oversubscribing_bid.id, 0),
+					Error::<TestRuntime>::TooManyBidsToRestore
+				);
+			});
+		}
+
+		#[test]
+		fn cannot_cancel_bid_after_auction_end() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+			let project_id = inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, evaluations);
+
+			let bid = BidParams::from((BIDDER_1, Retail, 1_000 * CT_UNIT, ParticipationMode::Classic(1u8), USDT));
+			inst.mint_necessary_tokens_for_bids(project_id, vec![bid.clone()]);
+			inst.bid_for_users(project_id, vec![bid]).unwrap();
+			let stored_bid = inst.get_bids(project_id)[0].clone();
+
+			let end_block = inst.get_project_details(project_id).round_duration.end().unwrap();
+			inst.jump_to_block(end_block + 1);
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::cancel_bid(RuntimeOrigin::signed(BIDDER_1), project_id, stored_bid.id, 1),
+					Error::<TestRuntime>::IncorrectRound
+				);
+			});
+		}
+	}
+}
+
+#[cfg(test)]
+mod end_auction_extrinsic {
+	use super::*;
+
+	// Partial acceptance at price <= wap (refund due to less CT bought)
+	// Rejection due to no more tokens left (full refund)
+	#[test]
+	fn bids_get_rejected_and_refunded() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let issuer = ISSUER_1;
+		let mut project_metadata = default_project_metadata(issuer);
+		project_metadata.total_allocation_size = 50_000 * CT_UNIT;
+		project_metadata.mainnet_token_max_supply = project_metadata.total_allocation_size;
+		project_metadata.minimum_price = ConstPriceProvider::calculate_decimals_aware_price(
+			FixedU128::from_float(10.0f64),
+			USD_DECIMALS,
+			CT_DECIMALS,
+		)
+		.unwrap();
+		project_metadata.participation_currencies =
+			bounded_vec![AcceptedFundingAsset::USDT, AcceptedFundingAsset::USDC, AcceptedFundingAsset::DOT];
+
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+
+		// We use multiplier > 1 so after settlement, only the refunds defined above are done. The rest will be done
+		// through the linear release pallet
+		let bid_1 = BidParams::from((
+			BIDDER_1,
+			Retail,
+			5000 * CT_UNIT,
+			ParticipationMode::Classic(5u8),
+			AcceptedFundingAsset::USDT,
+		));
+		let bid_2 = BidParams::from((
+			BIDDER_2,
+			Institutional,
+			40_000 * CT_UNIT,
+			ParticipationMode::Classic(5u8),
+			AcceptedFundingAsset::USDC,
+		));
+		let bid_3 = BidParams::from((
+			BIDDER_5,
+			Professional,
+			10_000 * CT_UNIT,
+			ParticipationMode::Classic(5u8),
+			AcceptedFundingAsset::DOT,
+		));
+		let bid_4 = BidParams::from((
+			BIDDER_3,
+			Retail,
+			6000 * CT_UNIT,
+			ParticipationMode::Classic(5u8),
+			AcceptedFundingAsset::USDT,
+		));
+		let bid_5 = BidParams::from((
+			BIDDER_4,
+			Retail,
+			2000 * CT_UNIT,
+			ParticipationMode::Classic(5u8),
+			AcceptedFundingAsset::DOT,
+		));
+		// post bucketing, the bids look like this:
+		// (BIDDER_1, 5k) - (BIDDER_2, 40k) - (BIDDER_5, 5k)   - (BIDDER_5, 5k) - (BIDDER_3 - 5k) - (BIDDER_3 - 1k) - (BIDDER_4 - 2k)
+		// | -------------------- 10 USD ----------------------|---- 11 USD ---|---- 12 USD ----|----------- 13 USD -------------|
+		// (Accepted, 5k) - (Partially, 32k) - (Rejected, 5k) - (Accepted, 5k) - (Accepted - 5k) - (Accepted - 1k) - (Accepted - 2k)
+
+		let bids = vec![bid_1, bid_2, bid_3, bid_4, bid_5];
+
+		let project_id = inst.create_auctioning_project(project_metadata.clone(), issuer, None, evaluations);
+
+		let plmc_amounts = inst.calculate_auction_plmc_charged_from_all_bids_made_or_with_bucket(
+			&bids,
+			project_metadata.clone(),
+			None,
+		);
+		let funding_asset_amounts = inst.calculate_auction_funding_asset_charged_from_all_bids_made_or_with_bucket(
+			&bids,
+			project_metadata.clone(),
+			None,
+		);
+
+		inst.mint_plmc_ed_if_required(bids.accounts());
+		inst.mint_funding_asset_ed_if_required(bids.to_account_asset_map());
 
 		let prev_plmc_balances = inst.get_free_plmc_balances_for(bids.accounts());
 		let prev_funding_asset_balances = inst.get_free_funding_asset_balances_for(bids.to_account_asset_map());
@@ -1891,8 +2660,10 @@ mod end_auction_extrinsic {
 		let bidder_5_rejected_bid = inst.execute(|| Bids::<TestRuntime>::get(project_id, 2)).unwrap();
 		let _bidder_5_accepted_bid = inst.execute(|| Bids::<TestRuntime>::get(project_id, 3)).unwrap();
 		let bidder_5_plmc_pre_balance = inst.get_free_plmc_balance_for(bidder_5_rejected_bid.bidder);
-		let bidder_5_funding_asset_pre_balance = inst
-			.get_free_funding_asset_balance_for(bidder_5_rejected_bid.funding_asset.id(), bidder_5_rejected_bid.bidder);
+		let bidder_5_funding_asset_pre_balance = inst.get_free_funding_asset_balance_for(
+			bidder_5_rejected_bid.funding_asset.id().unwrap(),
+			bidder_5_rejected_bid.bidder,
+		);
 
 		assert!(matches!(inst.go_to_next_state(project_id), ProjectStatus::SettlementStarted(FundingOutcome::Success)));
 		inst.settle_project(project_id, true);
@@ -1918,130 +2689,893 @@ mod end_auction_extrinsic {
 
 		// Assertions about rejected bid
 		let bidder_5_plmc_post_balance = inst.get_free_plmc_balance_for(bidder_5_rejected_bid.bidder);
-		let bidder_5_funding_asset_post_balance = inst
-			.get_free_funding_asset_balance_for(bidder_5_rejected_bid.funding_asset.id(), bidder_5_rejected_bid.bidder);
+		let bidder_5_funding_asset_post_balance = inst.get_free_funding_asset_balance_for(
+			bidder_5_rejected_bid.funding_asset.id().unwrap(),
+			bidder_5_rejected_bid.bidder,
+		);
+
+		// Bidder 5's accepted bid should have some refunds due to paying the wap in the end instead of the bucket price.
+		// Bidder 5's rejected bid should have a full refund
+		let bidder_5_returned_plmc = returned_auction_plmc.iter().find(|x| x.account == BIDDER_5).unwrap().plmc_amount;
+		let bidder_5_returned_funding_asset =
+			returned_funding_assets.iter().find(|x| x.account == BIDDER_5).unwrap().asset_amount;
+
+		assert!(inst.execute(|| Bids::<TestRuntime>::get(project_id, 2)).is_none());
+		assert_eq!(bidder_5_plmc_post_balance, bidder_5_plmc_pre_balance + bidder_5_returned_plmc);
+		assert_eq!(
+			bidder_5_funding_asset_post_balance,
+			bidder_5_funding_asset_pre_balance + bidder_5_returned_funding_asset
+		);
+
+		inst.do_free_plmc_assertions(expected_free_plmc);
+		inst.do_reserved_plmc_assertions(expected_reserved_plmc, HoldReason::Participation.into());
+		inst.do_free_funding_asset_assertions(expected_free_funding_assets);
+
+		for (asset, expected_amount) in expected_issuer_funding {
+			let real_amount = inst.get_free_funding_asset_balance_for(asset, ISSUER_1);
+			assert_eq!(real_amount, expected_amount);
+		}
+	}
+
+	#[test]
+	fn oversubscribed_bid_can_get_refund_and_bid_again_in_auction_round() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let project_metadata = default_project_metadata(ISSUER_1);
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+		let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 100, 10);
+
+		let project_id = inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, evaluations);
+
+		inst.mint_necessary_tokens_for_bids(project_id, bids.clone());
+		inst.bid_for_users(project_id, bids.clone()).unwrap();
+
+		// First bid is OTM
+		let first_bid_to_refund = bids[9].clone();
+		// Second is classic
+		let second_bid_to_refund = bids[8].clone();
+
+		let oversubscribing_bids = vec![
+			(
+				BIDDER_1,
+				Retail,
+				first_bid_to_refund.amount + 200 * CT_UNIT,
+				ParticipationMode::Classic(1),
+				AcceptedFundingAsset::USDT,
+			)
+				.into(),
+			(BIDDER_2, Retail, second_bid_to_refund.amount, ParticipationMode::Classic(1), AcceptedFundingAsset::USDT)
+				.into(),
+		];
+
+		inst.mint_necessary_tokens_for_bids(project_id, oversubscribing_bids.clone());
+		inst.bid_for_users(project_id, vec![oversubscribing_bids[0].clone()]).unwrap();
+
+		inst.process_oversubscribed_bids(project_id);
+
+		let pre_first_refund_bidder_plmc_balance = inst.get_free_plmc_balance_for(first_bid_to_refund.bidder);
+		let pre_first_refund_bidder_funding_asset_balance = inst
+			.get_free_funding_asset_balance_for(first_bid_to_refund.asset.id().unwrap(), first_bid_to_refund.bidder);
+
+		let first_bid = inst.execute(|| Bids::<TestRuntime>::get(project_id, 9)).unwrap();
+		assert!(matches!(first_bid.status, BidStatus::Rejected));
+		let second_bid = inst.execute(|| Bids::<TestRuntime>::get(project_id, 8)).unwrap();
+		assert!(matches!(second_bid.status, BidStatus::PartiallyAccepted(_)));
+
+		inst.execute(|| {
+			assert_ok!(Pallet::<TestRuntime>::do_settle_bid(project_id, 9));
+			assert_noop!(
+				Pallet::<TestRuntime>::do_settle_bid(project_id, 8),
+				Error::<TestRuntime>::SettlementNotStarted
+			);
+		});
+
+		let post_first_refund_bidder_plmc_balance = inst.get_free_plmc_balance_for(first_bid_to_refund.bidder);
+		let post_first_refund_bidder_funding_asset_balance = inst
+			.get_free_funding_asset_balance_for(first_bid_to_refund.asset.id().unwrap(), first_bid_to_refund.bidder);
+
+		// OTM bid doesnt give PLMC refund to bidder
+		assert_eq!(post_first_refund_bidder_plmc_balance, pre_first_refund_bidder_plmc_balance);
+		let mut funding_asset_refund = first_bid.funding_asset_amount_locked;
+		let usd_ticket = first_bid.original_ct_usd_price.saturating_mul_int(first_bid.original_ct_amount);
+		inst.add_otm_fee_to(&mut funding_asset_refund, usd_ticket, first_bid_to_refund.asset);
+		assert_eq!(
+			post_first_refund_bidder_funding_asset_balance,
+			pre_first_refund_bidder_funding_asset_balance + funding_asset_refund
+		);
+
+		inst.bid_for_users(project_id, vec![oversubscribing_bids[1].clone()]).unwrap();
+
+		inst.process_oversubscribed_bids(project_id);
+		let pre_second_refund_bidder_plmc_balance = inst.get_free_plmc_balance_for(second_bid_to_refund.bidder);
+		let pre_second_refund_bidder_funding_asset_balance = inst
+			.get_free_funding_asset_balance_for(second_bid_to_refund.asset.id().unwrap(), second_bid_to_refund.bidder);
+
+		let second_bid = inst.execute(|| Bids::<TestRuntime>::get(project_id, 8)).unwrap();
+		assert!(matches!(second_bid.status, BidStatus::Rejected));
+		let third_bid = inst.execute(|| Bids::<TestRuntime>::get(project_id, 7)).unwrap();
+		assert!(matches!(third_bid.status, BidStatus::PartiallyAccepted(_)));
+
+		inst.execute(|| {
+			assert_ok!(Pallet::<TestRuntime>::do_settle_bid(project_id, 8));
+			assert_noop!(
+				Pallet::<TestRuntime>::do_settle_bid(project_id, 7),
+				Error::<TestRuntime>::SettlementNotStarted
+			);
+		});
+
+		let post_second_refund_bidder_plmc_balance = inst.get_free_plmc_balance_for(second_bid_to_refund.bidder);
+		let post_second_refund_bidder_funding_asset_balance = inst
+			.get_free_funding_asset_balance_for(second_bid_to_refund.asset.id().unwrap(), second_bid_to_refund.bidder);
+
+		// Classic bid
+		assert_eq!(
+			post_second_refund_bidder_plmc_balance,
+			pre_second_refund_bidder_plmc_balance + second_bid.plmc_bond
+		);
+		assert_eq!(
+			post_second_refund_bidder_funding_asset_balance,
+			pre_second_refund_bidder_funding_asset_balance + second_bid.funding_asset_amount_locked
+		);
+	}
+}
+
+mod pro_rata_sale {
+	use super::*;
+
+	fn create_oversubscribed_pro_rata_project(
+		inst: &mut MockInstantiator,
+	) -> (ProjectId, ProjectMetadataOf<TestRuntime>, Vec<BidParams<TestRuntime>>) {
+		let project_metadata =
+			ProjectMetadataOf::<TestRuntime> { sale_mode: SaleMode::ProRata, ..default_project_metadata(ISSUER_1) };
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+		let project_id = inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, evaluations);
+
+		// 750k CT are bid for an allocation of 500k CT, so every bid gets two thirds of its amount.
+		let bids: Vec<BidParams<TestRuntime>> = vec![
+			(BIDDER_1, Retail, 250_000 * CT_UNIT, ParticipationMode::Classic(1u8), USDT).into(),
+			(BIDDER_2, Professional, 250_000 * CT_UNIT, ParticipationMode::Classic(2u8), USDT).into(),
+			(BIDDER_3, Institutional, 250_000 * CT_UNIT, ParticipationMode::Classic(1u8), USDC).into(),
+		];
+		inst.mint_necessary_tokens_for_bids(project_id, bids.clone());
+		inst.bid_for_users(project_id, bids.clone()).unwrap();
+
+		(project_id, project_metadata, bids)
+	}
+
+	#[test]
+	fn bids_above_the_allocation_do_not_move_the_price() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let (project_id, project_metadata, _bids) = create_oversubscribed_pro_rata_project(&mut inst);
+
+		let stored_bids = inst.get_bids(project_id);
+		assert_eq!(stored_bids.len(), 3);
+		assert!(stored_bids
+			.iter()
+			.all(|bid| bid.original_ct_usd_price == project_metadata.minimum_price &&
+				bid.status == BidStatus::YetUnknown));
+		inst.execute(|| {
+			let bucket = Buckets::<TestRuntime>::get(project_id).unwrap();
+			assert_eq!(bucket.current_price, project_metadata.minimum_price);
+			assert_eq!(bucket.amount_left, project_metadata.total_allocation_size);
+			assert_eq!(ProRataBidCTAmount::<TestRuntime>::get(project_id), 750_000 * CT_UNIT);
+			assert_eq!(CTAmountOversubscribed::<TestRuntime>::get(project_id), 0);
+
+			let block_hash = System::block_hash(System::block_number());
+			let expected_ct_amount = Perquintill::from_rational(2u64, 3u64).mul_floor(250_000 * CT_UNIT);
+			for bid in stored_bids {
+				assert_eq!(
+					TestRuntime::pro_rata_ct_amount_of_bid(&TestRuntime, block_hash, project_id, bid.id).unwrap(),
+					Some(expected_ct_amount)
+				);
+			}
+		});
+	}
+
+	#[test]
+	fn decreasing_a_bid_reduces_the_pro_rata_amount() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let (project_id, _project_metadata, _bids) = create_oversubscribed_pro_rata_project(&mut inst);
+		let bid = inst.get_bids(project_id).into_iter().find(|bid| bid.bidder == BIDDER_1).unwrap();
+
+		inst.execute(|| {
+			assert_ok!(PolimecFunding::cancel_bid(RuntimeOrigin::signed(BIDDER_1), project_id, bid.id, 1));
+			assert_eq!(ProRataBidCTAmount::<TestRuntime>::get(project_id), 500_000 * CT_UNIT);
+		});
+
+		assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::FundingSuccessful);
+		inst.execute(|| assert!(ProRataAcceptedRatio::<TestRuntime>::get(project_id).is_none()));
+		assert_eq!(inst.get_project_details(project_id).remaining_contribution_tokens, 0);
+	}
+
+	#[test]
+	fn undersubscribed_sale_leaves_tokens_for_the_community_round() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let project_metadata =
+			ProjectMetadataOf::<TestRuntime> { sale_mode: SaleMode::ProRata, ..default_project_metadata(ISSUER_1) };
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+		let project_id = inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, evaluations);
+
+		let bid: BidParams<TestRuntime> =
+			(BIDDER_1, Retail, 300_000 * CT_UNIT, ParticipationMode::Classic(1u8), USDT).into();
+		inst.mint_necessary_tokens_for_bids(project_id, vec![bid.clone()]);
+		inst.bid_for_users(project_id, vec![bid]).unwrap();
+
+		assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::CommunityRound);
+		let project_details = inst.get_project_details(project_id);
+		assert_eq!(project_details.remaining_contribution_tokens, 200_000 * CT_UNIT);
+		assert_eq!(
+			project_details.funding_amount_reached_usd,
+			project_metadata.minimum_price.saturating_mul_int(300_000 * CT_UNIT)
+		);
+		inst.execute(|| assert!(ProRataAcceptedRatio::<TestRuntime>::get(project_id).is_none()));
+	}
+
+	#[test]
+	fn oversubscribed_bids_are_partially_refunded_on_settlement() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let (project_id, project_metadata, _bids) = create_oversubscribed_pro_rata_project(&mut inst);
+
+		assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::FundingSuccessful);
+		let accepted_ratio = Perquintill::from_rational(2u64, 3u64);
+		inst.execute(|| {
+			assert_eq!(ProRataAcceptedRatio::<TestRuntime>::get(project_id), Some(accepted_ratio));
+		});
+		let project_details = inst.get_project_details(project_id);
+		assert_eq!(project_details.remaining_contribution_tokens, 0);
+		assert_eq!(
+			project_details.funding_amount_reached_usd,
+			project_metadata.minimum_price.saturating_mul_int(project_metadata.total_allocation_size)
+		);
+
+		assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::SettlementStarted(FundingOutcome::Success));
+		for bid in inst.get_bids(project_id) {
+			let prev_funding_asset =
+				inst.get_free_funding_asset_balance_for(bid.funding_asset.id().unwrap(), bid.bidder);
+			inst.execute(|| assert_ok!(PolimecFunding::do_settle_bid(project_id, bid.id)));
+
+			let final_ct_amount = accepted_ratio.mul_floor(bid.original_ct_amount);
+			assert_eq!(inst.get_ct_asset_balance_for(project_id, bid.bidder), final_ct_amount);
+
+			let accepted_usd = bid.original_ct_usd_price.saturating_mul_int(final_ct_amount);
+			let accepted_funding_asset = inst
+				.execute(|| PolimecFunding::calculate_funding_asset_amount(accepted_usd, bid.funding_asset))
+				.unwrap();
+			assert_eq!(
+				inst.get_free_funding_asset_balance_for(bid.funding_asset.id().unwrap(), bid.bidder),
+				prev_funding_asset + bid.funding_asset_amount_locked - accepted_funding_asset
+			);
+		}
+	}
+}
+
+mod dutch_auction {
+	use super::*;
+
+	// The price starts at twice the minimum price, and drops by 10% of the minimum price every 2 blocks.
+	fn dutch_auction_metadata() -> ProjectMetadataOf<TestRuntime> {
+		let project_metadata = default_project_metadata(ISSUER_1);
+		let minimum_price = project_metadata.minimum_price;
+		let sale_mode = SaleMode::DutchAuction {
+			ceiling_price: minimum_price.saturating_mul(PriceOf::<TestRuntime>::saturating_from_integer(2u32)),
+			price_step: minimum_price.saturating_mul(PriceOf::<TestRuntime>::from_rational(1, 10)),
+			blocks_per_step: 2,
+		};
+		ProjectMetadataOf::<TestRuntime> { sale_mode, ..project_metadata }
+	}
+
+	fn price_after_steps(project_metadata: &ProjectMetadataOf<TestRuntime>, steps: u32) -> PriceOf<TestRuntime> {
+		let SaleMode::DutchAuction { ceiling_price, price_step, .. } = project_metadata.sale_mode else {
+			panic!("Not a dutch auction")
+		};
+		ceiling_price
+			.saturating_sub(price_step.saturating_mul(PriceOf::<TestRuntime>::saturating_from_integer(steps)))
+			.max(project_metadata.minimum_price)
+	}
+
+	fn current_auction_price(inst: &mut MockInstantiator, project_id: ProjectId) -> Option<PriceOf<TestRuntime>> {
+		inst.execute(|| {
+			let block_hash = System::block_hash(System::block_number());
+			TestRuntime::current_auction_price(&TestRuntime, block_hash, project_id).unwrap()
+		})
+	}
+
+	// BIDDER_1 buys 300k CT after 2 price steps, and BIDDER_2 buys the remaining 200k CT after 4 price steps.
+	fn create_sold_out_dutch_auction(inst: &mut MockInstantiator) -> (ProjectId, ProjectMetadataOf<TestRuntime>) {
+		let project_metadata = dutch_auction_metadata();
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+		let project_id = inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, evaluations);
+		let auction_start = inst.get_project_details(project_id).round_duration.start().unwrap();
+
+		let bids: Vec<BidParams<TestRuntime>> = vec![
+			(BIDDER_1, Retail, 300_000 * CT_UNIT, ParticipationMode::Classic(1u8), USDT).into(),
+			(BIDDER_2, Professional, 300_000 * CT_UNIT, ParticipationMode::Classic(2u8), USDC).into(),
+		];
+		inst.mint_necessary_tokens_for_bids(project_id, bids.clone());
+
+		inst.jump_to_block(auction_start + 4);
+		inst.bid_for_users(project_id, vec![bids[0].clone()]).unwrap();
+		inst.jump_to_block(auction_start + 8);
+		inst.bid_for_users(project_id, vec![bids[1].clone()]).unwrap();
+
+		(project_id, project_metadata)
+	}
+
+	#[test]
+	fn price_drops_with_time() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let project_metadata = dutch_auction_metadata();
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+		let project_id = inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, evaluations);
+		let auction_start = inst.get_project_details(project_id).round_duration.start().unwrap();
+
+		assert_eq!(current_auction_price(&mut inst, project_id), Some(price_after_steps(&project_metadata, 0)));
+		inst.jump_to_block(auction_start + 1);
+		assert_eq!(current_auction_price(&mut inst, project_id), Some(price_after_steps(&project_metadata, 0)));
+		inst.jump_to_block(auction_start + 5);
+		assert_eq!(current_auction_price(&mut inst, project_id), Some(price_after_steps(&project_metadata, 2)));
+
+		let bid: BidParams<TestRuntime> =
+			(BIDDER_1, Retail, 1_000 * CT_UNIT, ParticipationMode::Classic(1u8), USDT).into();
+		inst.mint_necessary_tokens_for_bids(project_id, vec![bid.clone()]);
+		inst.bid_for_users(project_id, vec![bid]).unwrap();
+
+		// Buying tokens does not move the price
+		let stored_bid = inst.get_bids(project_id)[0].clone();
+		assert_eq!(stored_bid.original_ct_usd_price, price_after_steps(&project_metadata, 2));
+		assert_eq!(current_auction_price(&mut inst, project_id), Some(price_after_steps(&project_metadata, 2)));
+		inst.execute(|| {
+			let bucket = Buckets::<TestRuntime>::get(project_id).unwrap();
+			assert_eq!(bucket.amount_left, project_metadata.total_allocation_size - 1_000 * CT_UNIT);
+		});
+	}
+
+	#[test]
+	fn bids_can_be_decreased_and_cancelled_within_the_same_price_step() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let project_metadata = dutch_auction_metadata();
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+		let project_id = inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, evaluations);
+
+		let bids: Vec<BidParams<TestRuntime>> = vec![
+			(BIDDER_1, Retail, 10_000 * CT_UNIT, ParticipationMode::Classic(1u8), USDT).into(),
+			(BIDDER_2, Retail, 10_000 * CT_UNIT, ParticipationMode::Classic(1u8), USDT).into(),
+		];
+		inst.mint_necessary_tokens_for_bids(project_id, bids.clone());
+		inst.bid_for_users(project_id, bids).unwrap();
+		let stored_bids = inst.get_bids(project_id);
+		let bid_1 = stored_bids.iter().find(|bid| bid.bidder == BIDDER_1).unwrap().clone();
+		let bid_2 = stored_bids.iter().find(|bid| bid.bidder == BIDDER_2).unwrap().clone();
+		assert_eq!(bid_1.original_ct_usd_price, price_after_steps(&project_metadata, 0));
+
+		inst.execute(|| {
+			assert_ok!(PolimecFunding::decrease_bid(
+				RuntimeOrigin::signed(BIDDER_1),
+				project_id,
+				bid_1.id,
+				4_000 * CT_UNIT,
+				0
+			));
+			assert_ok!(PolimecFunding::cancel_bid(RuntimeOrigin::signed(BIDDER_2), project_id, bid_2.id, 0));
+		});
+
+		let stored_bids = inst.get_bids(project_id);
+		assert_eq!(stored_bids.len(), 1);
+		assert_eq!(stored_bids[0].original_ct_amount, 6_000 * CT_UNIT);
+		assert_eq!(inst.get_reserved_plmc_balance_for(BIDDER_2, HoldReason::Participation.into()), 0);
+		inst.execute(|| {
+			let bucket = Buckets::<TestRuntime>::get(project_id).unwrap();
+			assert_eq!(bucket.amount_left, project_metadata.total_allocation_size - 6_000 * CT_UNIT);
+		});
+	}
+
+	#[test]
+	fn round_ends_once_the_allocation_is_sold_out() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let (project_id, project_metadata) = create_sold_out_dutch_auction(&mut inst);
+		let now = inst.current_block();
 
-		// Bidder 5's accepted bid should have some refunds due to paying the wap in the end instead of the bucket price.
-		// Bidder 5's rejected bid should have a full refund
-		let bidder_5_returned_plmc = returned_auction_plmc.iter().find(|x| x.account == BIDDER_5).unwrap().plmc_amount;
-		let bidder_5_returned_funding_asset =
-			returned_funding_assets.iter().find(|x| x.account == BIDDER_5).unwrap().asset_amount;
+		// The last bid only gets the tokens that were left
+		let last_bid = inst.get_bids(project_id).into_iter().find(|bid| bid.bidder == BIDDER_2).unwrap();
+		assert_eq!(last_bid.original_ct_amount, 200_000 * CT_UNIT);
+		assert_eq!(inst.get_project_details(project_id).round_duration.end(), Some(now));
+		inst.execute(|| {
+			System::assert_has_event(
+				Event::BidCappedByAmountLeft {
+					project_id,
+					bidder: BIDDER_2,
+					filled_ct_amount: 200_000 * CT_UNIT,
+					unfilled_ct_amount: 100_000 * CT_UNIT,
+				}
+				.into(),
+			);
+		});
 
-		assert!(inst.execute(|| Bids::<TestRuntime>::get(project_id, 2)).is_none());
-		assert_eq!(bidder_5_plmc_post_balance, bidder_5_plmc_pre_balance + bidder_5_returned_plmc);
+		let late_bid: BidParams<TestRuntime> =
+			(BIDDER_3, Retail, 1_000 * CT_UNIT, ParticipationMode::Classic(1u8), USDT).into();
+		inst.mint_necessary_tokens_for_bids(project_id, vec![late_bid.clone()]);
+		assert_err!(inst.bid_for_users(project_id, vec![late_bid]), Error::<TestRuntime>::IncorrectRound);
+
+		// The funding end is scheduled for the next block, and done once that block is idle
+		inst.advance_time(2);
+		let project_details = inst.get_project_details(project_id);
+		assert_eq!(project_details.status, ProjectStatus::FundingSuccessful);
+		assert_eq!(project_details.remaining_contribution_tokens, 0);
+		let clearing_price = price_after_steps(&project_metadata, 4);
 		assert_eq!(
-			bidder_5_funding_asset_post_balance,
-			bidder_5_funding_asset_pre_balance + bidder_5_returned_funding_asset
+			project_details.funding_amount_reached_usd,
+			clearing_price.saturating_mul_int(project_metadata.total_allocation_size)
 		);
+		inst.execute(|| {
+			assert_eq!(DutchAuctionClearingPrice::<TestRuntime>::get(project_id), Some(clearing_price));
+		});
+		assert_eq!(current_auction_price(&mut inst, project_id), Some(clearing_price));
+	}
 
-		inst.do_free_plmc_assertions(expected_free_plmc);
-		inst.do_reserved_plmc_assertions(expected_reserved_plmc, HoldReason::Participation.into());
-		inst.do_free_funding_asset_assertions(expected_free_funding_assets);
+	#[test]
+	fn bids_are_checked_on_the_tokens_left() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let project_metadata = dutch_auction_metadata();
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+		let project_id = inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, evaluations);
 
-		for (asset, expected_amount) in expected_issuer_funding {
-			let real_amount = inst.get_free_funding_asset_balance_for(asset, ISSUER_1);
-			assert_eq!(real_amount, expected_amount);
+		// Only 1 CT is left, which is below the minimum ticket size at the current price
+		let bids: Vec<BidParams<TestRuntime>> = vec![
+			(BIDDER_1, Retail, 300_000 * CT_UNIT, ParticipationMode::Classic(1u8), USDT).into(),
+			(BIDDER_2, Professional, 199_999 * CT_UNIT, ParticipationMode::Classic(1u8), USDT).into(),
+		];
+		inst.mint_necessary_tokens_for_bids(project_id, bids.clone());
+		inst.bid_for_users(project_id, bids).unwrap();
+
+		let late_bid: BidParams<TestRuntime> =
+			(BIDDER_3, Retail, 1_000 * CT_UNIT, ParticipationMode::Classic(1u8), USDT).into();
+		inst.mint_necessary_tokens_for_bids(project_id, vec![late_bid.clone()]);
+		assert_err!(inst.bid_for_users(project_id, vec![late_bid]), Error::<TestRuntime>::TooLow);
+		inst.execute(|| {
+			assert_eq!(Buckets::<TestRuntime>::get(project_id).unwrap().amount_left, CT_UNIT);
+		});
+	}
+
+	#[test]
+	fn bids_above_the_clearing_price_are_refunded_on_settlement() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let (project_id, project_metadata) = create_sold_out_dutch_auction(&mut inst);
+		let clearing_price = price_after_steps(&project_metadata, 4);
+
+		assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::FundingSuccessful);
+		assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::SettlementStarted(FundingOutcome::Success));
+		for bid in inst.get_bids(project_id) {
+			let prev_funding_asset =
+				inst.get_free_funding_asset_balance_for(bid.funding_asset.id().unwrap(), bid.bidder);
+			inst.execute(|| assert_ok!(PolimecFunding::do_settle_bid(project_id, bid.id)));
+
+			assert_eq!(inst.get_ct_asset_balance_for(project_id, bid.bidder), bid.original_ct_amount);
+			let paid_usd = clearing_price.saturating_mul_int(bid.original_ct_amount);
+			let paid_funding_asset =
+				inst.execute(|| PolimecFunding::calculate_funding_asset_amount(paid_usd, bid.funding_asset)).unwrap();
+			assert_eq!(
+				inst.get_free_funding_asset_balance_for(bid.funding_asset.id().unwrap(), bid.bidder),
+				prev_funding_asset + bid.funding_asset_amount_locked - paid_funding_asset
+			);
 		}
 	}
 
 	#[test]
-	fn oversubscribed_bid_can_get_refund_and_bid_again_in_auction_round() {
+	fn unsold_tokens_go_to_the_community_round_at_the_clearing_price() {
 		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
-		let project_metadata = default_project_metadata(ISSUER_1);
+		let project_metadata = dutch_auction_metadata();
 		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
-		let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 100, 10);
-
 		let project_id = inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, evaluations);
 
-		inst.mint_necessary_tokens_for_bids(project_id, bids.clone());
-		inst.bid_for_users(project_id, bids.clone()).unwrap();
+		let bid: BidParams<TestRuntime> =
+			(BIDDER_1, Retail, 100_000 * CT_UNIT, ParticipationMode::Classic(1u8), USDT).into();
+		inst.mint_necessary_tokens_for_bids(project_id, vec![bid.clone()]);
+		inst.bid_for_users(project_id, vec![bid]).unwrap();
 
-		// First bid is OTM
-		let first_bid_to_refund = bids[9].clone();
-		// Second is classic
-		let second_bid_to_refund = bids[8].clone();
+		let round_duration = inst.get_project_details(project_id).round_duration;
+		let steps = (round_duration.end().unwrap() - round_duration.start().unwrap()) as u32 / 2;
+		let clearing_price = price_after_steps(&project_metadata, steps);
 
-		let oversubscribing_bids = vec![
-			(
-				BIDDER_1,
-				Retail,
-				first_bid_to_refund.amount + 200 * CT_UNIT,
-				ParticipationMode::Classic(1),
-				AcceptedFundingAsset::USDT,
-			)
-				.into(),
-			(BIDDER_2, Retail, second_bid_to_refund.amount, ParticipationMode::Classic(1), AcceptedFundingAsset::USDT)
-				.into(),
-		];
+		assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::CommunityRound);
+		let project_details = inst.get_project_details(project_id);
+		assert_eq!(project_details.remaining_contribution_tokens, 400_000 * CT_UNIT);
+		assert_eq!(project_details.funding_amount_reached_usd, clearing_price.saturating_mul_int(100_000 * CT_UNIT));
+		inst.execute(|| {
+			assert_eq!(DutchAuctionClearingPrice::<TestRuntime>::get(project_id), Some(clearing_price));
+			let bucket = Buckets::<TestRuntime>::get(project_id).unwrap();
+			assert_eq!(bucket.calculate_wap(project_metadata.total_allocation_size), clearing_price);
+		});
+	}
+}
 
-		inst.mint_necessary_tokens_for_bids(project_id, oversubscribing_bids.clone());
-		inst.bid_for_users(project_id, vec![oversubscribing_bids[0].clone()]).unwrap();
+#[cfg(test)]
+mod bid_with_allowlist_proof_extrinsic {
+	use super::*;
 
-		inst.process_oversubscribed_bids(project_id);
+	#[test]
+	fn bids_are_limited_by_the_allowlist_cap() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let project_metadata = default_project_metadata(ISSUER_1);
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+		let project_id = inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, evaluations);
 
-		let pre_first_refund_bidder_plmc_balance = inst.get_free_plmc_balance_for(first_bid_to_refund.bidder);
-		let pre_first_refund_bidder_funding_asset_balance =
-			inst.get_free_funding_asset_balance_for(first_bid_to_refund.asset.id(), first_bid_to_refund.bidder);
+		let (allowlist_root, [proof_1, _]) =
+			two_member_allowlist([(BIDDER_1, 5000 * USD_UNIT), (BIDDER_2, 5000 * USD_UNIT)]);
+		inst.execute(|| ProjectAllowlists::<TestRuntime>::insert(project_id, allowlist_root));
 
-		let first_bid = inst.execute(|| Bids::<TestRuntime>::get(project_id, 9)).unwrap();
-		assert!(matches!(first_bid.status, BidStatus::Rejected));
-		let second_bid = inst.execute(|| Bids::<TestRuntime>::get(project_id, 8)).unwrap();
-		assert!(matches!(second_bid.status, BidStatus::PartiallyAccepted(_)));
+		let ct_amount = project_metadata.minimum_price.reciprocal().unwrap().saturating_mul_int(3000 * USD_UNIT);
+		let bid: BidParams<TestRuntime> = (BIDDER_1, Retail, ct_amount).into();
+		inst.mint_necessary_tokens_for_bids(project_id, vec![bid.clone(), bid.clone()]);
+		let jwt = get_mock_jwt_with_cid(
+			BIDDER_1,
+			InvestorType::Retail,
+			generate_did_from_account(BIDDER_1),
+			project_metadata.policy_ipfs_cid.unwrap(),
+		);
 
 		inst.execute(|| {
-			assert_ok!(Pallet::<TestRuntime>::do_settle_bid(project_id, 9));
 			assert_noop!(
-				Pallet::<TestRuntime>::do_settle_bid(project_id, 8),
-				Error::<TestRuntime>::SettlementNotStarted
+				PolimecFunding::bid(
+					RuntimeOrigin::signed(BIDDER_1),
+					jwt.clone(),
+					project_id,
+					bid.amount,
+					bid.mode,
+					bid.asset
+				),
+				Error::<TestRuntime>::NotAllowlisted
+			);
+			assert_ok!(PolimecFunding::bid_with_allowlist_proof(
+				RuntimeOrigin::signed(BIDDER_1),
+				jwt.clone(),
+				project_id,
+				bid.amount,
+				bid.mode,
+				bid.asset,
+				proof_1
+			));
+			assert_noop!(
+				PolimecFunding::bid(RuntimeOrigin::signed(BIDDER_1), jwt, project_id, bid.amount, bid.mode, bid.asset),
+				Error::<TestRuntime>::AllowlistCapExceeded
 			);
 		});
+	}
+}
 
-		let post_first_refund_bidder_plmc_balance = inst.get_free_plmc_balance_for(first_bid_to_refund.bidder);
-		let post_first_refund_bidder_funding_asset_balance =
-			inst.get_free_funding_asset_balance_for(first_bid_to_refund.asset.id(), first_bid_to_refund.bidder);
+#[cfg(test)]
+mod schedule_bid_extrinsic {
+	use super::*;
 
-		// OTM bid doesnt give PLMC refund to bidder
-		assert_eq!(post_first_refund_bidder_plmc_balance, pre_first_refund_bidder_plmc_balance);
-		let mut funding_asset_refund = first_bid.funding_asset_amount_locked;
-		let usd_ticket = first_bid.original_ct_usd_price.saturating_mul_int(first_bid.original_ct_amount);
-		inst.add_otm_fee_to(&mut funding_asset_refund, usd_ticket, first_bid_to_refund.asset);
-		assert_eq!(
-			post_first_refund_bidder_funding_asset_balance,
-			pre_first_refund_bidder_funding_asset_balance + funding_asset_refund
+	const SCHEDULED_CT_AMOUNT: Balance = 5_000 * CT_UNIT;
+
+	// Create a project in the auction round, and give BIDDER_1 the PLMC and USDT needed to schedule a bid of
+	// `SCHEDULED_CT_AMOUNT` capped at the minimum price. Returns the project and the funds that get held.
+	fn create_project_and_fund_bidder(
+		inst: &mut MockInstantiator,
+	) -> (ProjectId, ProjectMetadataOf<TestRuntime>, Balance, Balance) {
+		let project_metadata = default_project_metadata(ISSUER_1);
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+		let project_id = inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, evaluations);
+
+		let bid = BidParams::from((
+			BIDDER_1,
+			Retail,
+			SCHEDULED_CT_AMOUNT,
+			ParticipationMode::Classic(1u8),
+			AcceptedFundingAsset::USDT,
+		));
+		let necessary_plmc =
+			inst.calculate_auction_plmc_charged_with_given_price(&vec![bid.clone()], project_metadata.minimum_price);
+		let necessary_usdt =
+			inst.calculate_auction_funding_asset_charged_with_given_price(&vec![bid], project_metadata.minimum_price);
+		inst.mint_plmc_ed_if_required(necessary_plmc.accounts());
+		inst.mint_plmc_to(necessary_plmc.clone());
+		inst.mint_funding_asset_ed_if_required(necessary_usdt.to_account_asset_map());
+		inst.mint_funding_asset_to(necessary_usdt.clone());
+
+		(project_id, project_metadata, necessary_plmc[0].plmc_amount, necessary_usdt[0].asset_amount)
+	}
+
+	fn schedule_bid(
+		project_id: ProjectId,
+		project_metadata: &ProjectMetadataOf<TestRuntime>,
+		execution_block: frame_system::pallet_prelude::BlockNumberFor<TestRuntime>,
+	) -> sp_runtime::DispatchResult {
+		schedule_bid_for(BIDDER_1, project_id, project_metadata, execution_block)
+	}
+
+	fn schedule_bid_for(
+		bidder: AccountIdOf<TestRuntime>,
+		project_id: ProjectId,
+		project_metadata: &ProjectMetadataOf<TestRuntime>,
+		execution_block: frame_system::pallet_prelude::BlockNumberFor<TestRuntime>,
+	) -> sp_runtime::DispatchResult {
+		let jwt = get_mock_jwt_with_cid(
+			bidder,
+			InvestorType::Retail,
+			generate_did_from_account(bidder),
+			project_metadata.clone().policy_ipfs_cid.unwrap(),
 		);
+		PolimecFunding::schedule_bid(
+			RuntimeOrigin::signed(bidder),
+			jwt,
+			project_id,
+			SCHEDULED_CT_AMOUNT,
+			ParticipationMode::Classic(1u8),
+			AcceptedFundingAsset::USDT,
+			project_metadata.minimum_price,
+			execution_block,
+		)
+	}
 
-		inst.bid_for_users(project_id, vec![oversubscribing_bids[1].clone()]).unwrap();
+	#[cfg(test)]
+	mod success {
+		use super::*;
 
-		inst.process_oversubscribed_bids(project_id);
-		let pre_second_refund_bidder_plmc_balance = inst.get_free_plmc_balance_for(second_bid_to_refund.bidder);
-		let pre_second_refund_bidder_funding_asset_balance =
-			inst.get_free_funding_asset_balance_for(second_bid_to_refund.asset.id(), second_bid_to_refund.bidder);
+		#[test]
+		fn scheduled_bid_is_placed_at_the_execution_block() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, project_metadata, plmc_held, usdt_held) = create_project_and_fund_bidder(&mut inst);
+			let usdt_id = AcceptedFundingAsset::USDT.id().unwrap();
+			let free_usdt = inst.get_free_funding_asset_balance_for(usdt_id.clone(), BIDDER_1);
 
-		let second_bid = inst.execute(|| Bids::<TestRuntime>::get(project_id, 8)).unwrap();
-		assert!(matches!(second_bid.status, BidStatus::Rejected));
-		let third_bid = inst.execute(|| Bids::<TestRuntime>::get(project_id, 7)).unwrap();
-		assert!(matches!(third_bid.status, BidStatus::PartiallyAccepted(_)));
+			let execution_block = inst.current_block() + 5;
+			inst.execute(|| assert_ok!(schedule_bid(project_id, &project_metadata, execution_block)));
 
-		inst.execute(|| {
-			assert_ok!(Pallet::<TestRuntime>::do_settle_bid(project_id, 8));
-			assert_noop!(
-				Pallet::<TestRuntime>::do_settle_bid(project_id, 7),
-				Error::<TestRuntime>::SettlementNotStarted
+			// The funds are held until the execution block
+			assert_eq!(inst.get_reserved_plmc_balance_for(BIDDER_1, HoldReason::ScheduledBid.into()), plmc_held);
+			assert_eq!(inst.get_free_funding_asset_balance_for(usdt_id.clone(), BIDDER_1), free_usdt - usdt_held);
+			assert_eq!(inst.execute(|| ScheduledBidQueue::<TestRuntime>::get(execution_block)).to_vec(), vec![0]);
+			assert_eq!(inst.execute(|| Bids::<TestRuntime>::iter_prefix_values(project_id).count()), 0);
+
+			inst.advance_time(4);
+			assert_eq!(inst.execute(|| Bids::<TestRuntime>::iter_prefix_values(project_id).count()), 0);
+
+			inst.advance_time(1);
+			let stored_bids = inst.execute(|| Bids::<TestRuntime>::iter_prefix_values(project_id).collect_vec());
+			assert_eq!(stored_bids.len(), 1);
+			assert_eq!(stored_bids[0].bidder, BIDDER_1);
+			assert_eq!(stored_bids[0].original_ct_amount, SCHEDULED_CT_AMOUNT);
+			assert_eq!(stored_bids[0].original_ct_usd_price, project_metadata.minimum_price);
+			assert_eq!(stored_bids[0].plmc_bond, plmc_held);
+			assert_eq!(stored_bids[0].funding_asset_amount_locked, usdt_held);
+
+			// The held funds were moved to the placed bid
+			assert_eq!(inst.get_reserved_plmc_balance_for(BIDDER_1, HoldReason::ScheduledBid.into()), 0);
+			assert_eq!(inst.get_reserved_plmc_balance_for(BIDDER_1, HoldReason::Participation.into()), plmc_held);
+			assert_eq!(inst.get_free_funding_asset_balance_for(usdt_id, BIDDER_1), free_usdt - usdt_held);
+			assert!(inst.execute(|| ScheduledBids::<TestRuntime>::get(0)).is_none());
+
+			inst.execute(|| {
+				assert!(find_event!(
+					TestRuntime,
+					Event::<TestRuntime>::ScheduledBidExecuted { project_id: event_project_id, bidder, id },
+					event_project_id == project_id,
+					bidder == BIDDER_1,
+					id == 0
+				)
+				.is_some());
+			});
+		}
+
+		#[test]
+		fn scheduled_bid_can_be_cancelled() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, project_metadata, _plmc_held, _usdt_held) = create_project_and_fund_bidder(&mut inst);
+			let usdt_id = AcceptedFundingAsset::USDT.id().unwrap();
+			let free_plmc = inst.get_free_plmc_balance_for(BIDDER_1);
+			let free_usdt = inst.get_free_funding_asset_balance_for(usdt_id.clone(), BIDDER_1);
+
+			let execution_block = inst.current_block() + 5;
+			inst.execute(|| assert_ok!(schedule_bid(project_id, &project_metadata, execution_block)));
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::cancel_scheduled_bid(RuntimeOrigin::signed(BIDDER_1), 0));
+			});
+
+			assert_eq!(inst.get_free_plmc_balance_for(BIDDER_1), free_plmc);
+			assert_eq!(inst.get_reserved_plmc_balance_for(BIDDER_1, HoldReason::ScheduledBid.into()), 0);
+			assert_eq!(inst.get_free_funding_asset_balance_for(usdt_id, BIDDER_1), free_usdt);
+			assert!(inst.execute(|| ScheduledBids::<TestRuntime>::get(0)).is_none());
+			assert!(inst.execute(|| ScheduledBidQueue::<TestRuntime>::get(execution_block)).is_empty());
+
+			inst.jump_to_block(execution_block);
+			assert_eq!(inst.execute(|| Bids::<TestRuntime>::iter_prefix_values(project_id).count()), 0);
+		}
+
+		#[test]
+		fn failed_scheduled_bid_is_refunded() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, project_metadata, _plmc_held, _usdt_held) = create_project_and_fund_bidder(&mut inst);
+			let usdt_id = AcceptedFundingAsset::USDT.id().unwrap();
+			let free_plmc = inst.get_free_plmc_balance_for(BIDDER_1);
+			let free_usdt = inst.get_free_funding_asset_balance_for(usdt_id.clone(), BIDDER_1);
+
+			let execution_block = inst.current_block() + 5;
+			inst.execute(|| assert_ok!(schedule_bid(project_id, &project_metadata, execution_block)));
+
+			// Other bidders buy the whole first bucket, so the price goes above the cap of the scheduled bid
+			let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 100, 10);
+			inst.mint_necessary_tokens_for_bids(project_id, bids.clone());
+			inst.bid_for_users(project_id, bids).unwrap();
+
+			inst.jump_to_block(execution_block);
+			assert_eq!(
+				inst.execute(|| {
+					Bids::<TestRuntime>::iter_prefix_values(project_id).filter(|bid| bid.bidder == BIDDER_1).count()
+				}),
+				0
 			);
-		});
+			assert_eq!(inst.get_free_plmc_balance_for(BIDDER_1), free_plmc);
+			assert_eq!(inst.get_reserved_plmc_balance_for(BIDDER_1, HoldReason::ScheduledBid.into()), 0);
+			assert_eq!(inst.get_free_funding_asset_balance_for(usdt_id, BIDDER_1), free_usdt);
+			assert!(inst.execute(|| ScheduledBids::<TestRuntime>::get(0)).is_none());
 
-		let post_second_refund_bidder_plmc_balance = inst.get_free_plmc_balance_for(second_bid_to_refund.bidder);
-		let post_second_refund_bidder_funding_asset_balance =
-			inst.get_free_funding_asset_balance_for(second_bid_to_refund.asset.id(), second_bid_to_refund.bidder);
+			let expected_error: sp_runtime::DispatchError = Error::<TestRuntime>::PriceAboveMaxPrice.into();
+			inst.execute(|| {
+				assert!(find_event!(
+					TestRuntime,
+					Event::<TestRuntime>::ScheduledBidFailed { project_id: event_project_id, bidder, id, error },
+					event_project_id == project_id,
+					bidder == BIDDER_1,
+					id == 0,
+					error == expected_error
+				)
+				.is_some());
+			});
+		}
 
-		// Classic bid
-		assert_eq!(
-			post_second_refund_bidder_plmc_balance,
-			pre_second_refund_bidder_plmc_balance + second_bid.plmc_bond
-		);
-		assert_eq!(
-			post_second_refund_bidder_funding_asset_balance,
-			pre_second_refund_bidder_funding_asset_balance + second_bid.funding_asset_amount_locked
-		);
+		#[test]
+		fn scheduled_bids_of_skipped_blocks_are_carried_over() {
+			use frame_support::traits::Hooks;
+
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, project_metadata, plmc_held, usdt_held) = create_project_and_fund_bidder(&mut inst);
+			inst.mint_plmc_ed_if_required(vec![BIDDER_2]);
+			inst.mint_plmc_to(vec![(BIDDER_1, plmc_held).into(), (BIDDER_2, plmc_held * 2).into()]);
+			inst.mint_funding_asset_ed_if_required(vec![(BIDDER_2, AcceptedFundingAsset::USDT.id().unwrap())]);
+			inst.mint_funding_asset_to(vec![(BIDDER_1, usdt_held).into(), (BIDDER_2, usdt_held * 2).into()]);
+
+			// The mock only has weight for two scheduled bids per block
+			let skipped_block = inst.current_block() + 5;
+			let execution_block = skipped_block + 1;
+			for block in [skipped_block, execution_block] {
+				inst.execute(|| {
+					assert_ok!(schedule_bid_for(BIDDER_1, project_id, &project_metadata, block));
+					assert_ok!(schedule_bid_for(BIDDER_2, project_id, &project_metadata, block));
+				});
+			}
+
+			// The block number provider skips `skipped_block`. The bids of the current block are placed first.
+			inst.jump_to_block(skipped_block - 1);
+			inst.execute(|| {
+				frame_system::Pallet::<TestRuntime>::set_block_number(execution_block);
+				PolimecFunding::on_initialize(execution_block);
+			});
+			assert!(inst.execute(|| ScheduledBids::<TestRuntime>::get(2)).is_none());
+			assert!(inst.execute(|| ScheduledBids::<TestRuntime>::get(3)).is_none());
+			assert!(inst.execute(|| ScheduledBidQueue::<TestRuntime>::get(execution_block)).is_empty());
+			assert_eq!(inst.execute(|| ScheduledBidQueue::<TestRuntime>::get(skipped_block)).to_vec(), vec![0, 1]);
+			assert_eq!(inst.execute(|| LastScheduledBidBlockProcessed::<TestRuntime>::get()), Some(skipped_block - 1));
+			assert_eq!(inst.execute(|| Bids::<TestRuntime>::iter_prefix_values(project_id).count()), 2);
+
+			inst.advance_time(1);
+			assert_eq!(inst.execute(|| Bids::<TestRuntime>::iter_prefix_values(project_id).count()), 4);
+			assert!(inst.execute(|| ScheduledBidQueue::<TestRuntime>::get(skipped_block)).is_empty());
+			assert_eq!(
+				inst.execute(|| LastScheduledBidBlockProcessed::<TestRuntime>::get()),
+				Some(execution_block + 1)
+			);
+			assert_eq!(inst.get_reserved_plmc_balance_for(BIDDER_1, HoldReason::ScheduledBid.into()), 0);
+			assert_eq!(inst.get_reserved_plmc_balance_for(BIDDER_2, HoldReason::ScheduledBid.into()), 0);
+		}
+	}
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn execution_block_outside_the_auction() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, project_metadata, _plmc_held, _usdt_held) = create_project_and_fund_bidder(&mut inst);
+			let now = inst.current_block();
+			let round_end = inst.get_project_details(project_id).round_duration.end().unwrap();
+
+			inst.execute(|| {
+				assert_noop!(
+					schedule_bid(project_id, &project_metadata, now),
+					Error::<TestRuntime>::InvalidExecutionBlock
+				);
+				// Bids are no longer accepted on the end block
+				assert_noop!(
+					schedule_bid(project_id, &project_metadata, round_end),
+					Error::<TestRuntime>::InvalidExecutionBlock
+				);
+				assert_ok!(schedule_bid(project_id, &project_metadata, round_end - 1));
+			});
+		}
+
+		#[test]
+		fn execution_block_is_full() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, project_metadata, plmc_held, usdt_held) = create_project_and_fund_bidder(&mut inst);
+			inst.mint_plmc_ed_if_required(vec![BIDDER_2, BIDDER_3]);
+			inst.mint_plmc_to(vec![(BIDDER_2, plmc_held).into(), (BIDDER_3, plmc_held).into()]);
+			inst.mint_funding_asset_ed_if_required(vec![
+				(BIDDER_2, AcceptedFundingAsset::USDT.id().unwrap()),
+				(BIDDER_3, AcceptedFundingAsset::USDT.id().unwrap()),
+			]);
+			inst.mint_funding_asset_to(vec![(BIDDER_2, usdt_held).into(), (BIDDER_3, usdt_held).into()]);
+
+			// The mock only has weight for two scheduled bids per block
+			let execution_block = inst.current_block() + 5;
+			inst.execute(|| {
+				assert_eq!(PolimecFunding::scheduled_bids_per_block(), 2);
+				assert_ok!(schedule_bid_for(BIDDER_1, project_id, &project_metadata, execution_block));
+				assert_ok!(schedule_bid_for(BIDDER_2, project_id, &project_metadata, execution_block));
+				assert_noop!(
+					schedule_bid_for(BIDDER_3, project_id, &project_metadata, execution_block),
+					Error::<TestRuntime>::TooManyScheduledBids
+				);
+				assert_ok!(schedule_bid_for(BIDDER_3, project_id, &project_metadata, execution_block + 1));
+			});
+		}
+
+		#[test]
+		fn did_already_scheduled_at_block() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, project_metadata, plmc_held, usdt_held) = create_project_and_fund_bidder(&mut inst);
+			inst.mint_plmc_to(vec![(BIDDER_1, plmc_held).into()]);
+			inst.mint_funding_asset_to(vec![(BIDDER_1, usdt_held).into()]);
+
+			let execution_block = inst.current_block() + 5;
+			inst.execute(|| {
+				assert_ok!(schedule_bid(project_id, &project_metadata, execution_block));
+				assert_noop!(
+					schedule_bid(project_id, &project_metadata, execution_block),
+					Error::<TestRuntime>::DidAlreadyScheduledAtBlock
+				);
+				assert_ok!(schedule_bid(project_id, &project_metadata, execution_block + 1));
+
+				// Cancelling frees the slot of the DID
+				assert_ok!(PolimecFunding::cancel_scheduled_bid(RuntimeOrigin::signed(BIDDER_1), 0));
+				assert_ok!(schedule_bid(project_id, &project_metadata, execution_block));
+			});
+		}
+
+		#[test]
+		fn cannot_schedule_without_funds() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+			let project_id = inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, evaluations);
+			inst.mint_plmc_ed_if_required(vec![BIDDER_1]);
+
+			let execution_block = inst.current_block() + 5;
+			inst.execute(|| {
+				assert_noop!(
+					schedule_bid(project_id, &project_metadata, execution_block),
+					Error::<TestRuntime>::ParticipantNotEnoughFunds
+				);
+			});
+		}
+
+		#[test]
+		fn only_the_bidder_can_cancel() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, project_metadata, _plmc_held, _usdt_held) = create_project_and_fund_bidder(&mut inst);
+
+			let execution_block = inst.current_block() + 5;
+			inst.execute(|| assert_ok!(schedule_bid(project_id, &project_metadata, execution_block)));
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::cancel_scheduled_bid(RuntimeOrigin::signed(BIDDER_2), 0),
+					Error::<TestRuntime>::NotAllowed
+				);
+				assert_noop!(
+					PolimecFunding::cancel_scheduled_bid(RuntimeOrigin::signed(BIDDER_1), 1),
+					Error::<TestRuntime>::ParticipationNotFound
+				);
+			});
+		}
 	}
 }
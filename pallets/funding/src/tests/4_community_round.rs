@@ -0,0 +1,510 @@
+use super::*;
+
+#[cfg(test)]
+mod round_flow {
+	use super::*;
+
+	#[test]
+	fn community_round_starts_if_auction_did_not_sell_out() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let project_metadata = default_project_metadata(ISSUER_1);
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+		let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 60, 10);
+		let auction_bought_tokens = bids.iter().map(|bid| bid.amount).sum::<Balance>();
+
+		let project_id =
+			inst.create_community_contributing_project(project_metadata.clone(), ISSUER_1, None, evaluations, bids);
+
+		let project_details = inst.get_project_details(project_id);
+		assert_eq!(project_details.status, ProjectStatus::CommunityRound);
+		assert_eq!(
+			project_details.remaining_contribution_tokens,
+			project_metadata.total_allocation_size - auction_bought_tokens
+		);
+		assert_eq!(
+			project_details.round_duration.end().unwrap() - project_details.round_duration.start().unwrap() + 1,
+			<TestRuntime as Config>::CommunityRoundDuration::get()
+		);
+		assert_eq!(inst.get_community_round_price(project_id), project_metadata.minimum_price);
+	}
+
+	#[test]
+	fn community_round_is_skipped_if_auction_sold_out() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let project_metadata = default_project_metadata(ISSUER_1);
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+		let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 100, 10);
+
+		let project_id = inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, evaluations);
+		inst.mint_necessary_tokens_for_bids(project_id, bids.clone());
+		inst.bid_for_users(project_id, bids).unwrap();
+
+		assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::FundingSuccessful);
+		assert_eq!(inst.get_project_details(project_id).remaining_contribution_tokens, Zero::zero());
+	}
+
+	#[test]
+	fn contributions_count_towards_funding_success() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let project_metadata = default_project_metadata(ISSUER_1);
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+		// 20% sold in the auction is below the success threshold
+		let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 20, 5);
+		let auction_bought_tokens = bids.iter().map(|bid| bid.amount).sum::<Balance>();
+		let project_id =
+			inst.create_community_contributing_project(project_metadata.clone(), ISSUER_1, None, evaluations, bids);
+
+		let contributions = vec![
+			BidParams::from((BIDDER_1, Retail, 50_000 * CT_UNIT, ParticipationMode::Classic(1u8), USDT)),
+			BidParams::from((BIDDER_2, Professional, 50_000 * CT_UNIT, ParticipationMode::OTM, USDC)),
+		];
+		inst.mint_necessary_tokens_for_contributions(project_id, contributions.clone());
+		inst.contribute_for_users(project_id, contributions).unwrap();
+
+		assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::FundingSuccessful);
+		let total_bought_tokens = auction_bought_tokens + 100_000 * CT_UNIT;
+		let project_details = inst.get_project_details(project_id);
+		assert_eq!(
+			project_details.remaining_contribution_tokens,
+			project_metadata.total_allocation_size - total_bought_tokens
+		);
+		assert_close_enough!(
+			project_details.funding_amount_reached_usd,
+			project_metadata.minimum_price.saturating_mul_int(total_bought_tokens),
+			Perquintill::from_float(0.9999)
+		);
+	}
+
+	#[test]
+	fn funding_fails_if_contributions_are_not_enough() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let project_metadata = default_project_metadata(ISSUER_1);
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+		let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 10, 5);
+		let project_id =
+			inst.create_community_contributing_project(project_metadata.clone(), ISSUER_1, None, evaluations, bids);
+
+		let contributions =
+			vec![BidParams::from((BIDDER_1, Retail, 50_000 * CT_UNIT, ParticipationMode::Classic(1u8), USDT))];
+		inst.mint_necessary_tokens_for_contributions(project_id, contributions.clone());
+		inst.contribute_for_users(project_id, contributions).unwrap();
+
+		assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::FundingFailed);
+		assert_eq!(
+			inst.get_project_details(project_id).evaluation_round_info.evaluators_outcome,
+			Some(EvaluatorsOutcome::Slashed)
+		);
+	}
+}
+
+#[cfg(test)]
+mod contribute_extrinsic {
+	use super::*;
+
+	fn create_project_in_community_round(
+		inst: &mut MockInstantiator,
+		auction_percent: u8,
+	) -> (ProjectId, ProjectMetadataOf<TestRuntime>) {
+		let project_metadata = default_project_metadata(ISSUER_1);
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+		let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), auction_percent, 5);
+		let project_id =
+			inst.create_community_contributing_project(project_metadata.clone(), ISSUER_1, None, evaluations, bids);
+		(project_id, project_metadata)
+	}
+
+	#[cfg(test)]
+	mod success {
+		use super::*;
+
+		#[test]
+		fn contribution_is_made_at_the_auction_wap() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, project_metadata) = create_project_in_community_round(&mut inst, 50);
+
+			let ct_amount = 1_000 * CT_UNIT;
+			let contribution = BidParams::from((
+				BIDDER_1,
+				Retail,
+				ct_amount,
+				ParticipationMode::Classic(2u8),
+				AcceptedFundingAsset::USDT,
+			));
+			inst.mint_necessary_tokens_for_contributions(project_id, vec![contribution.clone()]);
+
+			let wap = inst.get_community_round_price(project_id);
+			let expected_usd = wap.saturating_mul_int(ct_amount);
+			let expected_plmc = inst.calculate_auction_plmc_charged_with_given_price(&vec![contribution.clone()], wap);
+			let expected_usdt =
+				inst.calculate_auction_funding_asset_charged_with_given_price(&vec![contribution.clone()], wap);
+			let prev_details = inst.get_project_details(project_id);
+			let prev_usdt = inst.get_free_funding_asset_balance_for(USDT.id().unwrap(), BIDDER_1);
+
+			let jwt = get_mock_jwt_with_cid(
+				BIDDER_1,
+				InvestorType::Retail,
+				generate_did_from_account(BIDDER_1),
+				project_metadata.policy_ipfs_cid.unwrap(),
+			);
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::contribute(
+					RuntimeOrigin::signed(BIDDER_1),
+					jwt,
+					project_id,
+					ct_amount,
+					contribution.mode,
+					contribution.asset,
+				));
+			});
+
+			let stored_contribution = inst.execute(|| Contributions::<TestRuntime>::get(project_id, 0)).unwrap();
+			assert_eq!(stored_contribution.contributor, BIDDER_1);
+			assert_eq!(stored_contribution.ct_amount, ct_amount);
+			assert_eq!(stored_contribution.ct_usd_price, wap);
+			assert_eq!(stored_contribution.usd_contribution_amount, expected_usd);
+			assert_eq!(stored_contribution.plmc_bond, expected_plmc[0].plmc_amount);
+			assert_eq!(stored_contribution.funding_asset_amount, expected_usdt[0].asset_amount);
+
+			let post_details = inst.get_project_details(project_id);
+			assert_eq!(
+				post_details.remaining_contribution_tokens,
+				prev_details.remaining_contribution_tokens - ct_amount
+			);
+			assert_eq!(post_details.funding_amount_reached_usd, prev_details.funding_amount_reached_usd + expected_usd);
+			assert_eq!(
+				inst.get_free_funding_asset_balance_for(USDT.id().unwrap(), BIDDER_1),
+				prev_usdt - expected_usdt[0].asset_amount
+			);
+			assert_eq!(
+				inst.get_reserved_plmc_balance_for(BIDDER_1, HoldReason::Participation.into()),
+				expected_plmc[0].plmc_amount
+			);
+			assert_eq!(
+				inst.execute(|| AuctionBoughtUSD::<TestRuntime>::get((
+					project_id,
+					generate_did_from_account(BIDDER_1)
+				))),
+				expected_usd
+			);
+		}
+
+		#[test]
+		fn contribution_can_buy_all_remaining_tokens() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, _) = create_project_in_community_round(&mut inst, 50);
+
+			let remaining = inst.get_project_details(project_id).remaining_contribution_tokens;
+			let contribution =
+				BidParams::from((BIDDER_1, Institutional, remaining, ParticipationMode::Classic(1u8), USDT));
+			inst.mint_necessary_tokens_for_contributions(project_id, vec![contribution.clone()]);
+			inst.contribute_for_users(project_id, vec![contribution]).unwrap();
+
+			assert_eq!(inst.get_project_details(project_id).remaining_contribution_tokens, Zero::zero());
+			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::FundingSuccessful);
+		}
+
+		#[test]
+		fn contribution_is_settled_on_funding_success() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, project_metadata) = create_project_in_community_round(&mut inst, 50);
+
+			let contribution =
+				BidParams::from((BIDDER_1, Retail, 2_000 * CT_UNIT, ParticipationMode::Classic(3u8), USDT));
+			inst.mint_necessary_tokens_for_contributions(project_id, vec![contribution.clone()]);
+			inst.contribute_for_users(project_id, vec![contribution]).unwrap();
+
+			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::FundingSuccessful);
+			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::SettlementStarted(FundingOutcome::Success));
+
+			let stored_contribution = inst.get_contributions(project_id)[0].clone();
+			let prev_issuer_usdt = inst
+				.get_free_funding_asset_balance_for(USDT.id().unwrap(), project_metadata.funding_destination_account);
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::settle_contribution(
+					RuntimeOrigin::signed(BIDDER_1),
+					project_id,
+					stored_contribution.id
+				));
+			});
+
+			assert!(inst.get_contributions(project_id).is_empty());
+			inst.assert_ct_balance(project_id, BIDDER_1, stored_contribution.ct_amount);
+			assert_eq!(
+				inst.get_free_funding_asset_balance_for(
+					USDT.id().unwrap(),
+					project_metadata.funding_destination_account
+				),
+				prev_issuer_usdt + stored_contribution.funding_asset_amount
+			);
+			inst.assert_migration(
+				project_id,
+				BIDDER_1,
+				stored_contribution.ct_amount,
+				ParticipationType::Bid,
+				stored_contribution.receiving_account,
+				true,
+			);
+			inst.execute(|| {
+				System::assert_last_event(
+					Event::ContributionSettled {
+						project_id,
+						account: BIDDER_1,
+						id: stored_contribution.id,
+						ct_amount: stored_contribution.ct_amount,
+					}
+					.into(),
+				)
+			});
+		}
+
+		#[test]
+		fn contribution_is_refunded_on_funding_failure() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, _) = create_project_in_community_round(&mut inst, 10);
+
+			let contribution =
+				BidParams::from((BIDDER_1, Retail, 2_000 * CT_UNIT, ParticipationMode::Classic(3u8), USDT));
+			inst.mint_necessary_tokens_for_contributions(project_id, vec![contribution.clone()]);
+			let prev_free_plmc = inst.get_free_plmc_balance_for(BIDDER_1);
+			let prev_usdt = inst.get_free_funding_asset_balance_for(USDT.id().unwrap(), BIDDER_1);
+			inst.contribute_for_users(project_id, vec![contribution]).unwrap();
+
+			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::FundingFailed);
+			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::SettlementStarted(FundingOutcome::Failure));
+
+			let stored_contribution = inst.get_contributions(project_id)[0].clone();
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::settle_contribution(
+					RuntimeOrigin::signed(BIDDER_1),
+					project_id,
+					stored_contribution.id
+				));
+			});
+
+			inst.assert_ct_balance(project_id, BIDDER_1, Zero::zero());
+			assert_eq!(inst.get_free_plmc_balance_for(BIDDER_1), prev_free_plmc);
+			assert_eq!(inst.get_reserved_plmc_balance_for(BIDDER_1, HoldReason::Participation.into()), Zero::zero());
+			assert_eq!(inst.get_free_funding_asset_balance_for(USDT.id().unwrap(), BIDDER_1), prev_usdt);
+		}
+	}
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn cannot_contribute_during_auction() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+			let project_id = inst.create_auctioning_project(project_metadata.clone(), ISSUER_1, None, evaluations);
+
+			let jwt = get_mock_jwt_with_cid(
+				BIDDER_1,
+				InvestorType::Retail,
+				generate_did_from_account(BIDDER_1),
+				project_metadata.policy_ipfs_cid.unwrap(),
+			);
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::contribute(
+						RuntimeOrigin::signed(BIDDER_1),
+						jwt,
+						project_id,
+						1_000 * CT_UNIT,
+						ParticipationMode::Classic(1u8),
+						USDT,
+					),
+					Error::<TestRuntime>::IncorrectRound
+				);
+			});
+		}
+
+		#[test]
+		fn cannot_contribute_after_round_end() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, _) = create_project_in_community_round(&mut inst, 50);
+
+			let contribution =
+				BidParams::from((BIDDER_1, Retail, 1_000 * CT_UNIT, ParticipationMode::Classic(1u8), USDT));
+			inst.mint_necessary_tokens_for_contributions(project_id, vec![contribution.clone()]);
+
+			let end_block = inst.get_project_details(project_id).round_duration.end().unwrap();
+			inst.jump_to_block(end_block + 1);
+
+			assert_noop!(
+				inst.contribute_for_users(project_id, vec![contribution]),
+				Error::<TestRuntime>::IncorrectRound
+			);
+		}
+
+		#[test]
+		fn cannot_contribute_more_than_remaining_tokens() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, _) = create_project_in_community_round(&mut inst, 50);
+
+			let remaining = inst.get_project_details(project_id).remaining_contribution_tokens;
+			let contribution =
+				BidParams::from((BIDDER_1, Institutional, remaining + 1, ParticipationMode::Classic(1u8), USDT));
+			inst.mint_necessary_tokens_for_contributions(project_id, vec![contribution.clone()]);
+
+			assert_noop!(inst.contribute_for_users(project_id, vec![contribution]), Error::<TestRuntime>::TooHigh);
+		}
+
+		#[test]
+		fn cannot_contribute_once_sold_out() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, _) = create_project_in_community_round(&mut inst, 50);
+
+			let remaining = inst.get_project_details(project_id).remaining_contribution_tokens;
+			let contributions = vec![
+				BidParams::from((BIDDER_1, Institutional, remaining, ParticipationMode::Classic(1u8), USDT)),
+				BidParams::from((BIDDER_2, Retail, 1_000 * CT_UNIT, ParticipationMode::Classic(1u8), USDT)),
+			];
+			inst.mint_necessary_tokens_for_contributions(project_id, contributions.clone());
+			inst.contribute_for_users(project_id, vec![contributions[0].clone()]).unwrap();
+
+			assert_noop!(
+				inst.contribute_for_users(project_id, vec![contributions[1].clone()]),
+				Error::<TestRuntime>::ProjectSoldOut
+			);
+		}
+
+		#[test]
+		fn auction_bids_count_towards_did_maximum() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let mut project_metadata = default_project_metadata(ISSUER_1);
+			project_metadata.bidding_ticket_sizes.retail = TicketSize::new(100 * USD_UNIT, Some(20_000 * USD_UNIT));
+			let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+			let bids = vec![
+				BidParams::from((BIDDER_2, Institutional, 100_000 * CT_UNIT, ParticipationMode::Classic(1u8), USDT)),
+				// 10k USD at the minimum price of 10 USD/CT
+				BidParams::from((BIDDER_1, Retail, 1_000 * CT_UNIT, ParticipationMode::Classic(1u8), USDT)),
+			];
+			let project_id =
+				inst.create_community_contributing_project(project_metadata.clone(), ISSUER_1, None, evaluations, bids);
+
+			let contribution_over_max =
+				BidParams::from((BIDDER_1, Retail, 1_001 * CT_UNIT, ParticipationMode::Classic(1u8), USDT));
+			let contribution_at_max =
+				BidParams::from((BIDDER_1, Retail, 1_000 * CT_UNIT, ParticipationMode::Classic(1u8), USDT));
+			inst.mint_necessary_tokens_for_contributions(project_id, vec![contribution_over_max.clone()]);
+
+			assert_noop!(
+				inst.contribute_for_users(project_id, vec![contribution_over_max]),
+				Error::<TestRuntime>::TooHigh
+			);
+			assert_ok!(inst.contribute_for_users(project_id, vec![contribution_at_max]));
+		}
+
+		#[test]
+		fn issuer_cannot_contribute_to_own_project() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, _) = create_project_in_community_round(&mut inst, 50);
+
+			let contribution =
+				BidParams::from((ISSUER_1, Retail, 1_000 * CT_UNIT, ParticipationMode::Classic(1u8), USDT));
+			inst.mint_necessary_tokens_for_contributions(project_id, vec![contribution.clone()]);
+
+			assert_noop!(
+				inst.contribute_for_users(project_id, vec![contribution]),
+				Error::<TestRuntime>::ParticipationToOwnProject
+			);
+		}
+
+		#[test]
+		fn project_cannot_be_marked_as_settled_with_unsettled_contributions() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, _) = create_project_in_community_round(&mut inst, 50);
+
+			let contribution =
+				BidParams::from((BIDDER_1, Retail, 1_000 * CT_UNIT, ParticipationMode::Classic(1u8), USDT));
+			inst.mint_necessary_tokens_for_contributions(project_id, vec![contribution.clone()]);
+			inst.contribute_for_users(project_id, vec![contribution]).unwrap();
+
+			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::FundingSuccessful);
+			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::SettlementStarted(FundingOutcome::Success));
+
+			inst.execute(|| {
+				Evaluations::<TestRuntime>::iter_prefix_values((project_id,))
+					.for_each(|evaluation| PolimecFunding::do_settle_evaluation(evaluation, project_id).unwrap());
+				Bids::<TestRuntime>::iter_prefix_values(project_id)
+					.for_each(|bid| PolimecFunding::do_settle_bid(project_id, bid.id).unwrap());
+
+				assert_noop!(
+					PolimecFunding::mark_project_as_settled(RuntimeOrigin::signed(42), project_id),
+					Error::<TestRuntime>::SettlementNotComplete
+				);
+
+				assert_ok!(PolimecFunding::settle_contribution(RuntimeOrigin::signed(BIDDER_1), project_id, 0));
+				assert_ok!(PolimecFunding::mark_project_as_settled(RuntimeOrigin::signed(42), project_id));
+			});
+		}
+	}
+}
+
+#[cfg(test)]
+mod contribute_with_allowlist_proof_extrinsic {
+	use super::*;
+
+	#[test]
+	fn contributions_are_limited_by_the_allowlist_cap() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let project_metadata = default_project_metadata(ISSUER_1);
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+		let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 50, 5);
+		let project_id =
+			inst.create_community_contributing_project(project_metadata.clone(), ISSUER_1, None, evaluations, bids);
+
+		let (allowlist_root, [proof_1, _]) =
+			two_member_allowlist([(BIDDER_1, 5000 * USD_UNIT), (BIDDER_2, 5000 * USD_UNIT)]);
+		inst.execute(|| ProjectAllowlists::<TestRuntime>::insert(project_id, allowlist_root));
+
+		let wap = inst.get_community_round_price(project_id);
+		let ct_amount = wap.reciprocal().unwrap().saturating_mul_int(3000 * USD_UNIT);
+		let contribution: BidParams<TestRuntime> = (BIDDER_1, Retail, ct_amount).into();
+		inst.mint_necessary_tokens_for_contributions(project_id, vec![contribution.clone(), contribution.clone()]);
+		let jwt = get_mock_jwt_with_cid(
+			BIDDER_1,
+			InvestorType::Retail,
+			generate_did_from_account(BIDDER_1),
+			project_metadata.policy_ipfs_cid.unwrap(),
+		);
+
+		inst.execute(|| {
+			assert_noop!(
+				PolimecFunding::contribute(
+					RuntimeOrigin::signed(BIDDER_1),
+					jwt.clone(),
+					project_id,
+					contribution.amount,
+					contribution.mode,
+					contribution.asset,
+				),
+				Error::<TestRuntime>::NotAllowlisted
+			);
+			assert_ok!(PolimecFunding::contribute_with_allowlist_proof(
+				RuntimeOrigin::signed(BIDDER_1),
+				jwt.clone(),
+				project_id,
+				contribution.amount,
+				contribution.mode,
+				contribution.asset,
+				proof_1
+			));
+			// The second contribution would take the DID over its cap
+			assert_noop!(
+				PolimecFunding::contribute(
+					RuntimeOrigin::signed(BIDDER_1),
+					jwt,
+					project_id,
+					contribution.amount,
+					contribution.mode,
+					contribution.asset,
+				),
+				Error::<TestRuntime>::AllowlistCapExceeded
+			);
+		});
+	}
+}
@@ -0,0 +1,647 @@
+use super::*;
+
+#[cfg(test)]
+mod end_funding_extrinsic {
+	use super::*;
+
+	#[cfg(test)]
+	mod success {
+		use super::*;
+		use frame_support::{traits::Hooks, weights::Weight};
+
+		#[test]
+		fn evaluator_reward_is_correct() {
+			let (mut inst, project_id) = create_project_with_funding_percentage(95, true);
+			assert_eq!(
+				inst.get_project_details(project_id).status,
+				ProjectStatus::SettlementStarted(FundingOutcome::Success)
+			);
+
+			// We want to test rewards over the 3 brackets, which means > 5MM USD funded
+			const USD_REACHED: u128 = 9_500_000 * USD_UNIT;
+			const FEE_1: Percent = Percent::from_percent(10u8);
+			const FEE_2: Percent = Percent::from_percent(8);
+			const FEE_3: Percent = Percent::from_percent(6);
+
+			let fee_1 = FEE_1 * 1_000_000 * USD_UNIT;
+			let fee_2 = FEE_2 * 4_000_000 * USD_UNIT;
+			let fee_3 = FEE_3 * 4_500_000 * USD_UNIT;
+
+			let total_fee = Perquintill::from_rational(fee_1 + fee_2 + fee_3, USD_REACHED);
+
+			let total_ct_fee = total_fee * 950_000 * CT_UNIT;
+
+			let total_evaluator_reward = Perquintill::from_percent(30) * total_ct_fee;
+
+			let early_evaluator_reward = Perquintill::from_percent(20u64) * total_evaluator_reward;
+
+			let normal_evaluator_reward = Perquintill::from_percent(80u64) * total_evaluator_reward;
+			const EARLY_EVALUATOR_TOTAL_USD_BONDED: u128 = 1_000_000 * USD_UNIT;
+			// The function that generates the successful evaluation does the full usd target amount as evaluation
+			const NORMAL_EVALUATOR_TOTAL_USD_BONDED: u128 = 10_000_000 * USD_UNIT;
+
+			let expected_reward_info = RewardInfo {
+				early_evaluator_reward_pot: early_evaluator_reward,
+				normal_evaluator_reward_pot: normal_evaluator_reward,
+				early_evaluator_total_bonded_usd: EARLY_EVALUATOR_TOTAL_USD_BONDED,
+				normal_evaluator_total_bonded_usd: NORMAL_EVALUATOR_TOTAL_USD_BONDED,
+			};
+
+			let EvaluatorsOutcome::Rewarded(stored_reward_info) =
+				inst.get_project_details(project_id).evaluation_round_info.evaluators_outcome.unwrap()
+			else {
+				panic!("Unexpected Evaluator Outcome")
+			};
+
+			assert_close_enough!(
+				stored_reward_info.early_evaluator_reward_pot,
+				expected_reward_info.early_evaluator_reward_pot,
+				Perquintill::from_float(0.999)
+			);
+			assert_close_enough!(
+				stored_reward_info.normal_evaluator_reward_pot,
+				expected_reward_info.normal_evaluator_reward_pot,
+				Perquintill::from_float(0.999)
+			);
+			assert_close_enough!(
+				stored_reward_info.early_evaluator_total_bonded_usd,
+				expected_reward_info.early_evaluator_total_bonded_usd,
+				Perquintill::from_float(0.999)
+			);
+			assert_close_enough!(
+				stored_reward_info.normal_evaluator_total_bonded_usd,
+				expected_reward_info.normal_evaluator_total_bonded_usd,
+				Perquintill::from_float(0.999)
+			);
+		}
+
+		#[test]
+		fn evaluator_outcome_bounds() {
+			let try_for_percentage = |percentage: u8, should_slash: bool| {
+				let (mut inst, project_id) = create_project_with_funding_percentage(percentage.into(), true);
+				if should_slash {
+					assert_eq!(
+						inst.get_project_details(project_id).status,
+						ProjectStatus::SettlementStarted(FundingOutcome::Failure)
+					);
+					assert_eq!(
+						inst.get_project_details(project_id).evaluation_round_info.evaluators_outcome,
+						Some(EvaluatorsOutcome::Slashed)
+					);
+				} else {
+					assert_eq!(
+						inst.get_project_details(project_id).status,
+						ProjectStatus::SettlementStarted(FundingOutcome::Success)
+					);
+					assert!(matches!(
+						inst.get_project_details(project_id).evaluation_round_info.evaluators_outcome,
+						Some(EvaluatorsOutcome::Rewarded(..))
+					));
+				}
+			};
+			for i in 1..=24u8 {
+				try_for_percentage(i, true);
+			}
+			// From 25% to 32% the issuer decides, and the mock accepts the funding by default
+			for i in 25..130u8 {
+				try_for_percentage(i, false);
+			}
+		}
+
+		#[test]
+		fn rounds_end_automatically() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id = inst.create_evaluating_project(project_metadata.clone(), ISSUER_1, None);
+			let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+			let plmc_for_evaluations = inst.calculate_evaluation_plmc_spent(evaluations.clone());
+			inst.mint_plmc_ed_if_required(evaluations.accounts());
+			inst.mint_plmc_to(plmc_for_evaluations);
+			inst.evaluate_for_users(project_id, evaluations).unwrap();
+
+			// Each round is closed once the block after its end is idle
+			let evaluation_end = inst.get_project_details(project_id).round_duration.end().unwrap();
+			inst.jump_to_block(evaluation_end + 1);
+			assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::EvaluationRound);
+			inst.advance_time(1);
+			assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::AuctionRound);
+
+			let bids = inst.generate_bids_from_total_ct_percent(project_metadata, 100, 10);
+			inst.mint_necessary_tokens_for_bids(project_id, bids.clone());
+			inst.bid_for_users(project_id, bids).unwrap();
+
+			let auction_end = inst.get_project_details(project_id).round_duration.end().unwrap();
+			inst.jump_to_block(auction_end + 2);
+			assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::FundingSuccessful);
+
+			inst.advance_time(1);
+			assert_eq!(
+				inst.get_project_details(project_id).status,
+				ProjectStatus::SettlementStarted(FundingOutcome::Success)
+			);
+		}
+
+		#[test]
+		fn transitions_over_the_block_limit_are_carried_over() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let max_transitions = <TestRuntime as Config>::MaxTransitionsPerBlock::get();
+			let now = inst.current_block();
+
+			// Projects that don't exist are not transitioned, but still count towards the limit
+			inst.execute(|| {
+				for project_id in 0..max_transitions + 2 {
+					ProjectTransitionQueue::<TestRuntime>::insert(now + 1, project_id, ());
+				}
+			});
+
+			inst.advance_time(2);
+			inst.execute(|| {
+				assert_eq!(ProjectTransitionQueue::<TestRuntime>::iter_key_prefix(now + 1).count(), 2);
+				assert_eq!(LastTransitionBlockProcessed::<TestRuntime>::get(), Some(now));
+			});
+
+			inst.advance_time(1);
+			inst.execute(|| {
+				assert_eq!(ProjectTransitionQueue::<TestRuntime>::iter_key_prefix(now + 1).count(), 0);
+				assert_eq!(LastTransitionBlockProcessed::<TestRuntime>::get(), Some(now + 2));
+			});
+		}
+
+		#[test]
+		fn failed_transitions_are_tried_again() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id = inst.create_evaluating_project(project_metadata.clone(), ISSUER_1, None);
+			let evaluation_end = inst.get_project_details(project_id).round_duration.end().unwrap();
+			inst.jump_to_block(evaluation_end + 1);
+
+			// The evaluation cannot end without the project metadata
+			inst.execute(|| ProjectsMetadata::<TestRuntime>::remove(project_id));
+			inst.advance_time(1);
+			inst.execute(|| {
+				System::assert_has_event(
+					Event::ProjectTransitionFailed {
+						project_id,
+						error: Error::<TestRuntime>::ProjectMetadataNotFound.into(),
+					}
+					.into(),
+				);
+				assert!(
+					ProjectTransitionQueue::<TestRuntime>::iter_keys().any(|(_, queued_id)| queued_id == project_id)
+				);
+			});
+			assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::EvaluationRound);
+
+			inst.execute(|| ProjectsMetadata::<TestRuntime>::insert(project_id, project_metadata));
+			inst.advance_time(1);
+			assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::FundingFailed);
+		}
+
+		#[test]
+		fn transitions_wait_for_idle_weight() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id = inst.create_evaluating_project(project_metadata, ISSUER_1, None);
+			let evaluation_end = inst.get_project_details(project_id).round_duration.end().unwrap();
+			inst.jump_to_block(evaluation_end + 1);
+
+			inst.execute(|| {
+				PolimecFunding::on_idle(evaluation_end + 1, Weight::zero());
+				assert!(ProjectTransitionQueue::<TestRuntime>::contains_key(evaluation_end + 1, project_id));
+			});
+			assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::EvaluationRound);
+
+			inst.execute(|| {
+				PolimecFunding::on_idle(evaluation_end + 1, Weight::MAX);
+				assert!(!ProjectTransitionQueue::<TestRuntime>::contains_key(evaluation_end + 1, project_id));
+			});
+			assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::FundingFailed);
+		}
+
+		#[test]
+		fn round_end_is_set() {
+			let (mut inst, project_id) = create_project_with_funding_percentage(95, true);
+			let project_details = inst.get_project_details(project_id);
+			assert_eq!(
+				inst.get_project_details(project_id).status,
+				ProjectStatus::SettlementStarted(FundingOutcome::Success)
+			);
+			assert_eq!(
+				project_details.funding_end_block,
+				Some(EvaluationRoundDuration::get() + AuctionRoundDuration::get() + CommunityRoundDuration::get() + 1)
+			);
+		}
+
+		#[test]
+		fn project_funding_threshold_is_used() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let mut project_metadata = ProjectMetadataOf::<TestRuntime> {
+				funding_success_threshold: Some(Perquintill::from_percent(80)),
+				..default_project_metadata(ISSUER_1)
+			};
+			project_metadata.total_allocation_size = 1_000_000 * CT_UNIT;
+			let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+
+			// Above the default threshold of the pallet, but below the one of the project.
+			let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 60, 30);
+			let project_id =
+				inst.create_finished_project(project_metadata.clone(), ISSUER_1, None, evaluations.clone(), bids);
+			assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::AwaitingIssuerDecision);
+
+			let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 85, 30);
+			let project_id = inst.create_finished_project(project_metadata, ISSUER_2, None, evaluations, bids);
+			assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::FundingSuccessful);
+		}
+	}
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn called_too_early() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id = inst.create_auctioning_project(
+				project_metadata.clone(),
+				ISSUER_1,
+				None,
+				inst.generate_successful_evaluations(project_metadata.clone(), 5),
+			);
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::end_funding(RuntimeOrigin::signed(42), project_id),
+					Error::<TestRuntime>::TooEarlyForRound
+				);
+			});
+		}
+
+		#[test]
+		fn called_twice() {
+			let (mut inst, project_id) = create_project_with_funding_percentage(95, false);
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::end_funding(RuntimeOrigin::signed(42), project_id),
+					// We don't expect a specific previous state for this transition, so we cannot assert on IncorrectRound error.
+					Error::<TestRuntime>::TooEarlyForRound
+				);
+			});
+		}
+
+		#[test]
+		fn project_fails_if_not_enough_funding() {
+			let funding_threshold = <TestRuntime as Config>::IssuerDecisionThreshold::get();
+			let funding_threshold: u128 =
+				funding_threshold.deconstruct() as u128 * 100u128 / Perquintill::ACCURACY as u128;
+
+			let (mut inst, project_id) = create_project_with_funding_percentage(funding_threshold as u8 - 1, true);
+			assert_eq!(
+				inst.get_project_details(project_id).status,
+				ProjectStatus::SettlementStarted(FundingOutcome::Failure)
+			);
+		}
+	}
+}
+
+#[cfg(test)]
+mod decide_project_outcome_extrinsic {
+	use super::*;
+
+	// Between `IssuerDecisionThreshold` and `FundingSuccessThreshold`
+	const GREY_ZONE_PERCENTAGE: u8 = 30;
+
+	fn issuer_jwt(inst: &mut MockInstantiator, project_id: ProjectId, issuer: AccountId) -> UntrustedToken {
+		let project_metadata = inst.get_project_metadata(project_id);
+		get_mock_jwt_with_cid(
+			issuer,
+			InvestorType::Institutional,
+			generate_did_from_account(issuer),
+			project_metadata.policy_ipfs_cid.unwrap(),
+		)
+	}
+
+	#[cfg(test)]
+	mod success {
+		use super::*;
+
+		#[test]
+		fn raise_in_grey_zone_awaits_issuer_decision() {
+			let (mut inst, project_id) = create_project_with_funding_percentage(GREY_ZONE_PERCENTAGE, false);
+			let project_details = inst.get_project_details(project_id);
+			assert_eq!(project_details.status, ProjectStatus::AwaitingIssuerDecision);
+			assert_eq!(project_details.evaluation_round_info.evaluators_outcome, None);
+			assert_eq!(
+				project_details.round_duration.end(),
+				Some(inst.current_block() + <TestRuntime as Config>::IssuerDecisionDuration::get() - 1)
+			);
+		}
+
+		#[test]
+		fn issuer_accepts_funding() {
+			let (mut inst, project_id) = create_project_with_funding_percentage(GREY_ZONE_PERCENTAGE, false);
+			let jwt = issuer_jwt(&mut inst, project_id, ISSUER_1);
+			let deadline = inst.get_project_details(project_id).round_duration.end().unwrap();
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::decide_project_outcome(
+					RuntimeOrigin::signed(ISSUER_1),
+					jwt,
+					project_id,
+					FundingOutcomeDecision::AcceptFunding
+				));
+				System::assert_last_event(
+					Event::<TestRuntime>::ProjectOutcomeDecided {
+						project_id,
+						decision: FundingOutcomeDecision::AcceptFunding,
+					}
+					.into(),
+				);
+			});
+			let project_details = inst.get_project_details(project_id);
+			assert_eq!(project_details.status, ProjectStatus::FundingSuccessful);
+			assert!(matches!(
+				project_details.evaluation_round_info.evaluators_outcome,
+				Some(EvaluatorsOutcome::Rewarded(_))
+			));
+
+			// Settlement starts on the next block, and the default decision is not applied afterwards
+			inst.advance_time(2);
+			assert_eq!(
+				inst.get_project_details(project_id).status,
+				ProjectStatus::SettlementStarted(FundingOutcome::Success)
+			);
+			inst.jump_to_block(deadline + 2);
+			assert_eq!(
+				inst.get_project_details(project_id).status,
+				ProjectStatus::SettlementStarted(FundingOutcome::Success)
+			);
+		}
+
+		#[test]
+		fn issuer_rejects_funding() {
+			let (mut inst, project_id) = create_project_with_funding_percentage(GREY_ZONE_PERCENTAGE, false);
+			let jwt = issuer_jwt(&mut inst, project_id, ISSUER_1);
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::decide_project_outcome(
+					RuntimeOrigin::signed(ISSUER_1),
+					jwt,
+					project_id,
+					FundingOutcomeDecision::RejectFunding
+				));
+			});
+			let project_details = inst.get_project_details(project_id);
+			assert_eq!(project_details.status, ProjectStatus::FundingFailed);
+			assert_eq!(project_details.evaluation_round_info.evaluators_outcome, Some(EvaluatorsOutcome::Slashed));
+
+			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::SettlementStarted(FundingOutcome::Failure));
+			let bids = inst.get_bids(project_id);
+			inst.settle_project(project_id, true);
+			inst.assert_bids_migrations_created(project_id, bids, false);
+		}
+
+		#[test]
+		fn funding_is_accepted_by_default_after_the_deadline() {
+			let (mut inst, project_id) = create_project_with_funding_percentage(GREY_ZONE_PERCENTAGE, false);
+
+			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::FundingSuccessful);
+			inst.execute(|| {
+				System::assert_has_event(
+					Event::<TestRuntime>::ProjectOutcomeDecided {
+						project_id,
+						decision: FundingOutcomeDecision::AcceptFunding,
+					}
+					.into(),
+				);
+			});
+			assert!(matches!(
+				inst.get_project_details(project_id).evaluation_round_info.evaluators_outcome,
+				Some(EvaluatorsOutcome::Rewarded(_))
+			));
+			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::SettlementStarted(FundingOutcome::Success));
+		}
+
+		#[test]
+		fn default_outcome_is_configurable() {
+			DefaultFundingOutcomeDecision::set(FundingOutcomeDecision::RejectFunding);
+			let (mut inst, project_id) = create_project_with_funding_percentage(GREY_ZONE_PERCENTAGE, false);
+
+			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::FundingFailed);
+			inst.execute(|| {
+				System::assert_has_event(
+					Event::<TestRuntime>::ProjectOutcomeDecided {
+						project_id,
+						decision: FundingOutcomeDecision::RejectFunding,
+					}
+					.into(),
+				);
+			});
+			assert_eq!(
+				inst.get_project_details(project_id).evaluation_round_info.evaluators_outcome,
+				Some(EvaluatorsOutcome::Slashed)
+			);
+			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::SettlementStarted(FundingOutcome::Failure));
+		}
+	}
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn called_by_different_issuer() {
+			let (mut inst, project_id) = create_project_with_funding_percentage(GREY_ZONE_PERCENTAGE, false);
+			let jwt = issuer_jwt(&mut inst, project_id, ISSUER_2);
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::decide_project_outcome(
+						RuntimeOrigin::signed(ISSUER_2),
+						jwt,
+						project_id,
+						FundingOutcomeDecision::RejectFunding
+					),
+					Error::<TestRuntime>::NotIssuer
+				);
+			});
+		}
+
+		#[test]
+		fn project_not_awaiting_decision() {
+			let (mut inst, project_id) = create_project_with_funding_percentage(95, false);
+			let jwt = issuer_jwt(&mut inst, project_id, ISSUER_1);
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::decide_project_outcome(
+						RuntimeOrigin::signed(ISSUER_1),
+						jwt,
+						project_id,
+						FundingOutcomeDecision::RejectFunding
+					),
+					Error::<TestRuntime>::IncorrectRound
+				);
+			});
+		}
+
+		#[test]
+		fn called_after_the_deadline() {
+			let (mut inst, project_id) = create_project_with_funding_percentage(GREY_ZONE_PERCENTAGE, false);
+			let jwt = issuer_jwt(&mut inst, project_id, ISSUER_1);
+			let deadline = inst.get_project_details(project_id).round_duration.end().unwrap();
+			inst.jump_to_block(deadline + 1);
+
+			// The default outcome is only applied once the block is idle, but the deadline already passed
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::decide_project_outcome(
+						RuntimeOrigin::signed(ISSUER_1),
+						jwt.clone(),
+						project_id,
+						FundingOutcomeDecision::RejectFunding
+					),
+					Error::<TestRuntime>::TooLateForRound
+				);
+			});
+
+			inst.advance_time(1);
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::decide_project_outcome(
+						RuntimeOrigin::signed(ISSUER_1),
+						jwt,
+						project_id,
+						FundingOutcomeDecision::RejectFunding
+					),
+					Error::<TestRuntime>::IncorrectRound
+				);
+			});
+			assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::FundingSuccessful);
+		}
+	}
+}
+
+#[cfg(test)]
+mod emergency_halt_extrinsics {
+	use super::*;
+
+	fn reason() -> BoundedVec<u8, StringLimitOf<TestRuntime>> {
+		b"fraudulent issuer".to_vec().try_into().unwrap()
+	}
+
+	#[cfg(test)]
+	mod success {
+		use super::*;
+
+		#[test]
+		fn halted_project_rejects_participations_until_resumed() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id = inst.create_evaluating_project(project_metadata.clone(), ISSUER_1, None);
+			let evaluation = EvaluationParams::from((EVALUATOR_1, 1_000 * USD_UNIT));
+			inst.mint_plmc_ed_if_required(vec![EVALUATOR_1]);
+			let evaluation_plmc = inst.calculate_evaluation_plmc_spent(vec![evaluation.clone()]);
+			inst.mint_plmc_to(evaluation_plmc);
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::halt_project(RuntimeOrigin::root(), project_id, reason()));
+				System::assert_last_event(Event::<TestRuntime>::ProjectHalted { project_id, reason: reason() }.into());
+			});
+			assert_err!(
+				inst.evaluate_for_users(project_id, vec![evaluation.clone()]),
+				Error::<TestRuntime>::ProjectHalted
+			);
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::resume_project(RuntimeOrigin::root(), project_id));
+				System::assert_last_event(Event::<TestRuntime>::ProjectResumed { project_id }.into());
+			});
+			assert_ok!(inst.evaluate_for_users(project_id, vec![evaluation]));
+		}
+
+		#[test]
+		fn forced_failure_refunds_participants_without_slashing_evaluators() {
+			let (mut inst, project_id) = create_project_with_funding_percentage(95, false);
+			assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::FundingSuccessful);
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::force_project_failure(RuntimeOrigin::root(), project_id, reason()));
+				System::assert_last_event(
+					Event::<TestRuntime>::ProjectForcedToFail { project_id, reason: reason() }.into(),
+				);
+			});
+			let project_details = inst.get_project_details(project_id);
+			assert_eq!(project_details.status, ProjectStatus::FundingFailed);
+			assert_eq!(project_details.evaluation_round_info.evaluators_outcome, None);
+
+			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::SettlementStarted(FundingOutcome::Failure));
+			let evaluations = inst.get_evaluations(project_id);
+			let evaluators = evaluations.iter().map(|evaluation| evaluation.evaluator).collect_vec();
+			let prev_free_plmc = inst.get_free_plmc_balances_for(evaluators.clone());
+			let bids = inst.get_bids(project_id);
+			inst.settle_project(project_id, true);
+
+			for (evaluation, prev_balance) in zip(evaluations, prev_free_plmc) {
+				assert_eq!(
+					inst.get_free_plmc_balance_for(evaluation.evaluator),
+					prev_balance.plmc_amount + evaluation.current_plmc_bond
+				);
+			}
+			for evaluator in evaluators {
+				assert!(inst.execute(|| UserMigrations::<TestRuntime>::get((project_id, evaluator))).is_none());
+			}
+			inst.assert_bids_migrations_created(project_id, bids, false);
+		}
+	}
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn called_by_non_emergency_origin() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = inst.create_evaluating_project(default_project_metadata(ISSUER_1), ISSUER_1, None);
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::halt_project(RuntimeOrigin::signed(ISSUER_1), project_id, reason()),
+					sp_runtime::DispatchError::BadOrigin
+				);
+				assert_noop!(
+					PolimecFunding::force_project_failure(RuntimeOrigin::signed(ISSUER_1), project_id, reason()),
+					sp_runtime::DispatchError::BadOrigin
+				);
+			});
+		}
+
+		#[test]
+		fn resume_project_not_halted() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = inst.create_evaluating_project(default_project_metadata(ISSUER_1), ISSUER_1, None);
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::resume_project(RuntimeOrigin::root(), project_id),
+					Error::<TestRuntime>::ProjectNotHalted
+				);
+			});
+		}
+
+		#[test]
+		fn settlement_already_started() {
+			let (mut inst, project_id) = create_project_with_funding_percentage(95, true);
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::halt_project(RuntimeOrigin::root(), project_id, reason()),
+					Error::<TestRuntime>::IncorrectRound
+				);
+				assert_noop!(
+					PolimecFunding::force_project_failure(RuntimeOrigin::root(), project_id, reason()),
+					Error::<TestRuntime>::IncorrectRound
+				);
+			});
+		}
+	}
+}
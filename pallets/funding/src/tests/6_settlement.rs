@@ -24,7 +24,7 @@ mod round_flow {
 
 		#[test]
 		fn can_fully_settle_failed_project() {
-			let percentage = 32u8;
+			let percentage = 20u8;
 			let (mut inst, project_id) = create_project_with_funding_percentage(percentage, true);
 			let evaluations = inst.get_evaluations(project_id);
 			let bids = inst.get_bids(project_id);
@@ -192,7 +192,7 @@ mod start_settlement_extrinsic {
 
 		#[test]
 		fn funding_failed_settlement() {
-			let (mut inst, project_id) = create_project_with_funding_percentage(32, false);
+			let (mut inst, project_id) = create_project_with_funding_percentage(20, false);
 			let project_details = inst.get_project_details(project_id);
 
 			assert_eq!(project_details.funding_end_block, None);
@@ -242,6 +242,61 @@ mod start_settlement_extrinsic {
 	}
 }
 
+#[cfg(test)]
+mod on_idle_settlement {
+	use super::*;
+	use frame_support::{traits::Hooks, weights::Weight};
+
+	#[test]
+	fn on_idle_settles_all_participations_and_marks_project_as_settled() {
+		let (mut inst, project_id) = create_project_with_funding_percentage(95, true);
+		assert!(inst.execute(|| ProjectsInSettlement::<TestRuntime>::get(project_id)).is_some());
+
+		inst.advance_time(1);
+
+		assert_eq!(
+			inst.get_project_details(project_id).status,
+			ProjectStatus::SettlementFinished(FundingOutcome::Success)
+		);
+		inst.execute(|| {
+			assert_eq!(Evaluations::<TestRuntime>::iter_prefix((project_id,)).count(), 0);
+			assert_eq!(Bids::<TestRuntime>::iter_prefix(project_id).count(), 0);
+			assert!(ProjectsInSettlement::<TestRuntime>::get(project_id).is_none());
+		});
+	}
+
+	#[test]
+	fn on_idle_resumes_settlement_from_cursor() {
+		let (mut inst, project_id) = create_project_with_funding_percentage(95, true);
+		let evaluations_count = inst.execute(|| Evaluations::<TestRuntime>::iter_prefix((project_id,)).count());
+		let db_weight = <TestRuntime as frame_system::Config>::DbWeight::get();
+		// Enough weight to check for oversubscribed bids, settle exactly one evaluation and store the cursor
+		let available_weight = <TestRuntime as Config>::WeightInfo::settle_rewarded_evaluation()
+			.saturating_add(db_weight.reads(3))
+			.saturating_add(db_weight.writes(1));
+
+		inst.execute(|| PolimecFunding::on_idle(System::block_number(), available_weight));
+
+		inst.execute(|| {
+			assert_eq!(Evaluations::<TestRuntime>::iter_prefix((project_id,)).count(), evaluations_count - 1);
+			let cursor = ProjectsInSettlement::<TestRuntime>::get(project_id).unwrap();
+			assert_eq!(cursor.phase, SettlementPhase::Evaluations);
+			assert!(cursor.last_key.is_some());
+		});
+		assert_eq!(
+			inst.get_project_details(project_id).status,
+			ProjectStatus::SettlementStarted(FundingOutcome::Success)
+		);
+
+		inst.execute(|| PolimecFunding::on_idle(System::block_number(), Weight::MAX));
+
+		assert_eq!(
+			inst.get_project_details(project_id).status,
+			ProjectStatus::SettlementFinished(FundingOutcome::Success)
+		);
+	}
+}
+
 #[cfg(test)]
 mod settle_evaluation_extrinsic {
 	use super::*;
@@ -482,7 +537,7 @@ mod settle_bid_extrinsic {
 		fn accepted_bid_with_refund_on_project_success() {
 			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
 			let ed = inst.get_ed();
-			let usdt_ed = inst.get_funding_asset_ed(AcceptedFundingAsset::USDT.id());
+			let usdt_ed = inst.get_funding_asset_ed(AcceptedFundingAsset::USDT.id().unwrap());
 			let mut project_metadata = default_project_metadata(ISSUER_1);
 			let base_price = PriceOf::<TestRuntime>::from_float(1.0);
 			let decimal_aware_price = <TestRuntime as Config>::PriceProvider::calculate_decimals_aware_price(
@@ -533,20 +588,20 @@ mod settle_bid_extrinsic {
 			let expected_usdt_refund = partial_amount_bid_stored.funding_asset_amount_locked - expected_final_usdt_paid;
 
 			let pre_issuer_usdt_balance = inst.get_free_funding_asset_balance_for(
-				AcceptedFundingAsset::USDT.id(),
+				AcceptedFundingAsset::USDT.id().unwrap(),
 				project_metadata.funding_destination_account,
 			);
 
 			inst.settle_project(project_id, true);
 
 			let post_issuer_usdt_balance = inst.get_free_funding_asset_balance_for(
-				AcceptedFundingAsset::USDT.id(),
+				AcceptedFundingAsset::USDT.id().unwrap(),
 				project_metadata.funding_destination_account,
 			);
 
 			inst.assert_funding_asset_free_balance(
 				BIDDER_1,
-				AcceptedFundingAsset::USDT.id(),
+				AcceptedFundingAsset::USDT.id().unwrap(),
 				expected_usdt_refund + usdt_ed,
 			);
 			assert_eq!(post_issuer_usdt_balance, pre_issuer_usdt_balance + expected_final_usdt_paid);
@@ -576,7 +631,7 @@ mod settle_bid_extrinsic {
 		fn accepted_bid_without_refund_on_project_success() {
 			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
 			let ed = inst.get_ed();
-			let usdt_ed = inst.get_funding_asset_ed(AcceptedFundingAsset::USDT.id());
+			let usdt_ed = inst.get_funding_asset_ed(AcceptedFundingAsset::USDT.id().unwrap());
 
 			let mut project_metadata = default_project_metadata(ISSUER_1);
 			let base_price = PriceOf::<TestRuntime>::from_float(1.0);
@@ -610,7 +665,7 @@ mod settle_bid_extrinsic {
 			let no_refund_bid_stored = inst.execute(|| Bids::<TestRuntime>::get(project_id, 0)).unwrap();
 
 			let pre_issuer_usdc_balance = inst.get_free_funding_asset_balance_for(
-				AcceptedFundingAsset::USDT.id(),
+				AcceptedFundingAsset::USDT.id().unwrap(),
 				project_metadata.funding_destination_account,
 			);
 
@@ -619,11 +674,11 @@ mod settle_bid_extrinsic {
 			});
 
 			let post_issuer_usdc_balance = inst.get_free_funding_asset_balance_for(
-				AcceptedFundingAsset::USDT.id(),
+				AcceptedFundingAsset::USDT.id().unwrap(),
 				project_metadata.funding_destination_account,
 			);
 
-			inst.assert_funding_asset_free_balance(BIDDER_1, AcceptedFundingAsset::USDT.id(), usdt_ed);
+			inst.assert_funding_asset_free_balance(BIDDER_1, AcceptedFundingAsset::USDT.id().unwrap(), usdt_ed);
 			assert_eq!(
 				post_issuer_usdc_balance,
 				pre_issuer_usdc_balance + no_refund_bid_stored.funding_asset_amount_locked
@@ -661,7 +716,7 @@ mod settle_bid_extrinsic {
 		fn accepted_bid_without_refund_on_project_failure() {
 			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
 			let ed = inst.get_ed();
-			let usdt_ed = inst.get_funding_asset_ed(AcceptedFundingAsset::USDT.id());
+			let usdt_ed = inst.get_funding_asset_ed(AcceptedFundingAsset::USDT.id().unwrap());
 			let mut project_metadata = default_project_metadata(ISSUER_1);
 			project_metadata.participation_currencies =
 				bounded_vec![AcceptedFundingAsset::USDT, AcceptedFundingAsset::DOT];
@@ -687,7 +742,7 @@ mod settle_bid_extrinsic {
 			let no_refund_bid_stored = inst.execute(|| Bids::<TestRuntime>::get(project_id, 0)).unwrap();
 
 			let pre_issuer_usdc_balance = inst.get_free_funding_asset_balance_for(
-				AcceptedFundingAsset::USDT.id(),
+				AcceptedFundingAsset::USDT.id().unwrap(),
 				project_metadata.funding_destination_account,
 			);
 
@@ -696,13 +751,13 @@ mod settle_bid_extrinsic {
 			});
 
 			let post_issuer_usdc_balance = inst.get_free_funding_asset_balance_for(
-				AcceptedFundingAsset::USDT.id(),
+				AcceptedFundingAsset::USDT.id().unwrap(),
 				project_metadata.funding_destination_account,
 			);
 
 			inst.assert_funding_asset_free_balance(
 				BIDDER_1,
-				AcceptedFundingAsset::USDT.id(),
+				AcceptedFundingAsset::USDT.id().unwrap(),
 				no_refund_bid_stored.funding_asset_amount_locked + usdt_ed,
 			);
 			assert_eq!(post_issuer_usdc_balance, pre_issuer_usdc_balance);
@@ -723,7 +778,7 @@ mod settle_bid_extrinsic {
 		fn rejected_bid_on_project_success() {
 			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
 			let ed = inst.get_ed();
-			let usdt_ed = inst.get_funding_asset_ed(AcceptedFundingAsset::USDT.id());
+			let usdt_ed = inst.get_funding_asset_ed(AcceptedFundingAsset::USDT.id().unwrap());
 			let mut project_metadata = default_project_metadata(ISSUER_1);
 			let base_price = PriceOf::<TestRuntime>::from_float(0.5);
 			let decimal_aware_price = <TestRuntime as Config>::PriceProvider::calculate_decimals_aware_price(
@@ -759,20 +814,20 @@ mod settle_bid_extrinsic {
 			let rejected_bid_stored = inst.execute(|| Bids::<TestRuntime>::get(project_id, 0)).unwrap();
 
 			let pre_issuer_usdt_balance = inst.get_free_funding_asset_balance_for(
-				AcceptedFundingAsset::USDT.id(),
+				AcceptedFundingAsset::USDT.id().unwrap(),
 				project_metadata.funding_destination_account,
 			);
 
 			inst.settle_project(project_id, true);
 
 			let post_issuer_usdt_balance = inst.get_free_funding_asset_balance_for(
-				AcceptedFundingAsset::USDT.id(),
+				AcceptedFundingAsset::USDT.id().unwrap(),
 				project_metadata.funding_destination_account,
 			);
 
 			inst.assert_funding_asset_free_balance(
 				BIDDER_1,
-				AcceptedFundingAsset::USDT.id(),
+				AcceptedFundingAsset::USDT.id().unwrap(),
 				rejected_bid_stored.funding_asset_amount_locked + usdt_ed,
 			);
 			assert_eq!(post_issuer_usdt_balance, pre_issuer_usdt_balance);
@@ -829,6 +884,304 @@ mod settle_bid_extrinsic {
 	}
 }
 
+#[cfg(test)]
+mod claim_vested_contribution_tokens_extrinsic {
+	use super::*;
+
+	const CT_VESTING: ContributionTokenVesting = ContributionTokenVesting { cliff: 10, duration: 100 };
+
+	/// Project vesting its CTs, where `BIDDER_1` has a settled bid for half of the allocation.
+	fn create_project_with_settled_vested_bid(inst: &mut MockInstantiator) -> (ProjectId, Balance) {
+		let project_metadata =
+			ProjectMetadataOf::<TestRuntime> { ct_vesting: Some(CT_VESTING), ..default_project_metadata(ISSUER_1) };
+		let ct_amount = project_metadata.total_allocation_size / 2;
+		let bid = BidParams::from((
+			BIDDER_1,
+			Institutional,
+			ct_amount,
+			ParticipationMode::Classic(1u8),
+			AcceptedFundingAsset::USDT,
+		));
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+		let project_id = inst.create_finished_project(project_metadata, ISSUER_1, None, evaluations, vec![bid]);
+		assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::SettlementStarted(FundingOutcome::Success));
+		inst.execute(|| {
+			assert_ok!(PolimecFunding::settle_bid(RuntimeOrigin::signed(BIDDER_1), project_id, 0));
+		});
+
+		(project_id, ct_amount)
+	}
+
+	fn frozen_ct_balance(inst: &mut MockInstantiator, project_id: ProjectId, account: AccountId) -> Balance {
+		inst.execute(|| {
+			<ContributionTokensFreezer as fungibles::InspectFreeze<AccountId>>::balance_frozen(
+				project_id,
+				&FreezeReason::ContributionTokenVesting.into(),
+				&account,
+			)
+		})
+	}
+
+	#[cfg(test)]
+	mod success {
+		use super::*;
+
+		#[test]
+		fn bought_tokens_are_unfrozen_as_they_vest() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, ct_amount) = create_project_with_settled_vested_bid(&mut inst);
+			let funding_end_block = inst.get_project_details(project_id).funding_end_block.unwrap();
+
+			// The tokens are frozen in the bidder account, and mainnet has to keep them locked for the whole vesting
+			inst.assert_ct_balance(project_id, BIDDER_1, ct_amount);
+			assert_eq!(frozen_ct_balance(&mut inst, project_id, BIDDER_1), ct_amount);
+			inst.execute(|| {
+				assert_eq!(ContributionTokenVestings::<TestRuntime>::get(project_id, BIDDER_1), ct_amount);
+				let (_, migrations) = UserMigrations::<TestRuntime>::get((project_id, BIDDER_1)).unwrap();
+				assert_eq!(migrations[0].info.vesting_time, CT_VESTING.total_duration() as u64);
+				assert!(<ContributionTokens as Mutate<AccountId>>::transfer(
+					project_id,
+					&BIDDER_1,
+					&ISSUER_1,
+					ct_amount,
+					Preservation::Expendable
+				)
+				.is_err());
+			});
+
+			inst.jump_to_block(funding_end_block + 60);
+			let half_vested_amount = CT_VESTING.vested_amount(ct_amount, 60);
+			assert_eq!(half_vested_amount, ct_amount / 2);
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::claim_vested_contribution_tokens(
+					RuntimeOrigin::signed(BIDDER_1),
+					project_id
+				));
+			});
+			inst.assert_ct_balance(project_id, BIDDER_1, ct_amount);
+			assert_eq!(frozen_ct_balance(&mut inst, project_id, BIDDER_1), ct_amount - half_vested_amount);
+
+			inst.jump_to_block(funding_end_block + 200);
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::claim_vested_contribution_tokens(
+					RuntimeOrigin::signed(BIDDER_1),
+					project_id
+				));
+				System::assert_last_event(
+					Event::VestedContributionTokensClaimed {
+						project_id,
+						account: BIDDER_1,
+						amount: ct_amount - half_vested_amount,
+					}
+					.into(),
+				);
+				assert!(!ContributionTokenVestings::<TestRuntime>::contains_key(project_id, BIDDER_1));
+				assert_ok!(<ContributionTokens as Mutate<AccountId>>::transfer(
+					project_id,
+					&BIDDER_1,
+					&ISSUER_1,
+					ct_amount,
+					Preservation::Expendable
+				));
+			});
+			assert_eq!(frozen_ct_balance(&mut inst, project_id, BIDDER_1), 0);
+		}
+	}
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn cannot_claim_during_the_cliff() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, _) = create_project_with_settled_vested_bid(&mut inst);
+			let funding_end_block = inst.get_project_details(project_id).funding_end_block.unwrap();
+
+			inst.jump_to_block(funding_end_block + CT_VESTING.cliff as u64);
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::claim_vested_contribution_tokens(RuntimeOrigin::signed(BIDDER_1), project_id),
+					Error::<TestRuntime>::NoVestedContributionTokens
+				);
+			});
+		}
+
+		#[test]
+		fn cannot_claim_without_vested_tokens() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let (project_id, _) = create_project_with_settled_vested_bid(&mut inst);
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::claim_vested_contribution_tokens(RuntimeOrigin::signed(BIDDER_2), project_id),
+					Error::<TestRuntime>::NoVestedContributionTokens
+				);
+			});
+		}
+	}
+}
+
+#[cfg(test)]
+mod funding_milestones {
+	use super::*;
+
+	/// Settled project releasing its funds in a 30% and a 70% tranche, where `BIDDER_1` paid for half of the allocation.
+	fn create_settled_project_with_milestones(inst: &mut MockInstantiator) -> ProjectId {
+		let project_metadata = ProjectMetadataOf::<TestRuntime> {
+			funding_milestones: bounded_vec![Percent::from_percent(30), Percent::from_percent(70)],
+			..default_project_metadata(ISSUER_1)
+		};
+		let bid = BidParams::from((
+			BIDDER_1,
+			Institutional,
+			project_metadata.total_allocation_size / 2,
+			ParticipationMode::Classic(1u8),
+			AcceptedFundingAsset::USDT,
+		));
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+		let project_id = inst.create_settled_project(project_metadata, ISSUER_1, None, evaluations, vec![bid], true);
+		assert_eq!(
+			inst.get_project_details(project_id).status,
+			ProjectStatus::SettlementFinished(FundingOutcome::Success)
+		);
+
+		project_id
+	}
+
+	#[cfg(test)]
+	mod success {
+		use super::*;
+
+		#[test]
+		fn approved_milestones_pay_out_the_issuer() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = create_settled_project_with_milestones(&mut inst);
+			let funding_destination_account = inst.get_project_metadata(project_id).funding_destination_account;
+			let escrowed_usdt = inst.execute(|| MilestoneEscrowTotals::<TestRuntime>::get(project_id, USDT));
+			assert!(escrowed_usdt > 0);
+			assert_eq!(
+				inst.execute(|| MilestoneEscrowContributions::<TestRuntime>::get((project_id, BIDDER_1, USDT))),
+				escrowed_usdt
+			);
+			let pre_issuer_usdt_balance =
+				inst.get_free_funding_asset_balance_for(USDT.id().unwrap(), funding_destination_account);
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::decide_funding_milestone(RuntimeOrigin::root(), project_id, 0, true));
+				System::assert_last_event(
+					Event::FundingMilestoneDecided {
+						project_id,
+						milestone_index: 0,
+						outcome: MilestoneOutcome::Released,
+					}
+					.into(),
+				);
+			});
+			let first_tranche = Percent::from_percent(30).mul_floor(escrowed_usdt);
+			inst.assert_funding_asset_free_balance(
+				funding_destination_account,
+				USDT.id().unwrap(),
+				pre_issuer_usdt_balance + first_tranche,
+			);
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::decide_funding_milestone(RuntimeOrigin::root(), project_id, 1, true));
+			});
+			// The final tranche releases the rounding dust left by the first one.
+			inst.assert_funding_asset_free_balance(
+				funding_destination_account,
+				USDT.id().unwrap(),
+				pre_issuer_usdt_balance + escrowed_usdt,
+			);
+		}
+
+		#[test]
+		fn rejected_milestone_is_refunded_pro_rata() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = create_settled_project_with_milestones(&mut inst);
+			let escrowed_usdt =
+				inst.execute(|| MilestoneEscrowContributions::<TestRuntime>::get((project_id, BIDDER_1, USDT)));
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::decide_funding_milestone(RuntimeOrigin::root(), project_id, 0, true));
+				assert_ok!(PolimecFunding::decide_funding_milestone(RuntimeOrigin::root(), project_id, 1, false));
+			});
+
+			let pre_bidder_usdt_balance = inst.get_free_funding_asset_balance_for(USDT.id().unwrap(), BIDDER_1);
+			let refund = escrowed_usdt - Percent::from_percent(30).mul_floor(escrowed_usdt);
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::claim_milestone_refund(RuntimeOrigin::signed(BIDDER_1), project_id, 1));
+				System::assert_last_event(
+					Event::MilestoneRefundClaimed {
+						project_id,
+						milestone_index: 1,
+						account: BIDDER_1,
+						funding_asset: USDT,
+						amount: refund,
+					}
+					.into(),
+				);
+				assert_noop!(
+					PolimecFunding::claim_milestone_refund(RuntimeOrigin::signed(BIDDER_1), project_id, 1),
+					Error::<TestRuntime>::MilestoneRefundAlreadyClaimed
+				);
+			});
+			inst.assert_funding_asset_free_balance(BIDDER_1, USDT.id().unwrap(), pre_bidder_usdt_balance + refund);
+		}
+	}
+
+	#[cfg(test)]
+	mod failure {
+		use super::*;
+
+		#[test]
+		fn milestones_are_decided_in_order_by_the_milestone_origin() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = create_settled_project_with_milestones(&mut inst);
+
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::decide_funding_milestone(RuntimeOrigin::signed(ISSUER_1), project_id, 0, true),
+					sp_runtime::DispatchError::BadOrigin
+				);
+				assert_noop!(
+					PolimecFunding::decide_funding_milestone(RuntimeOrigin::root(), project_id, 1, true),
+					Error::<TestRuntime>::PreviousMilestoneNotDecided
+				);
+				assert_noop!(
+					PolimecFunding::decide_funding_milestone(RuntimeOrigin::root(), project_id, 2, true),
+					Error::<TestRuntime>::MilestoneNotFound
+				);
+				assert_ok!(PolimecFunding::decide_funding_milestone(RuntimeOrigin::root(), project_id, 0, true));
+				assert_noop!(
+					PolimecFunding::decide_funding_milestone(RuntimeOrigin::root(), project_id, 0, false),
+					Error::<TestRuntime>::MilestoneAlreadyDecided
+				);
+			});
+		}
+
+		#[test]
+		fn only_rejected_milestones_can_be_refunded() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = create_settled_project_with_milestones(&mut inst);
+
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::decide_funding_milestone(RuntimeOrigin::root(), project_id, 0, true));
+				assert_noop!(
+					PolimecFunding::claim_milestone_refund(RuntimeOrigin::signed(BIDDER_1), project_id, 0),
+					Error::<TestRuntime>::MilestoneNotRejected
+				);
+				assert_ok!(PolimecFunding::decide_funding_milestone(RuntimeOrigin::root(), project_id, 1, false));
+				assert_noop!(
+					PolimecFunding::claim_milestone_refund(RuntimeOrigin::signed(BIDDER_2), project_id, 1),
+					Error::<TestRuntime>::NoMilestoneRefund
+				);
+			});
+		}
+	}
+}
+
 #[cfg(test)]
 mod mark_project_as_settled_extrinsic {
 	use super::*;
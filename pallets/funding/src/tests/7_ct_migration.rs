@@ -0,0 +1,703 @@
+use super::*;
+
+mod offchain_migration {
+	use super::*;
+	use frame_support::traits::ConstU32;
+
+	#[test]
+	fn start_offchain_migration() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let evaluations = inst.generate_successful_evaluations(default_project_metadata(ISSUER_1), 5);
+		let bids = inst.generate_bids_from_total_ct_percent(default_project_metadata(ISSUER_1), 90, 10);
+		// Create migrations for 2 projects, to check the `remaining_participants` is unaffected by other projects
+		let project_id = inst.create_finished_project(
+			default_project_metadata(ISSUER_1),
+			ISSUER_1,
+			None,
+			evaluations.clone(),
+			bids.clone(),
+		);
+		assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::SettlementStarted(FundingOutcome::Success));
+		inst.settle_project(project_id, true);
+
+		let project_id =
+			inst.create_finished_project(default_project_metadata(ISSUER_1), ISSUER_1, None, evaluations, bids);
+		assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::SettlementStarted(FundingOutcome::Success));
+		inst.settle_project(project_id, true);
+
+		inst.execute(|| {
+			assert_err!(
+				crate::Pallet::<TestRuntime>::do_start_offchain_migration(project_id, EVALUATOR_1,),
+				Error::<TestRuntime>::NotIssuer
+			);
+
+			assert_ok!(crate::Pallet::<TestRuntime>::do_start_offchain_migration(project_id, ISSUER_1,));
+		});
+
+		let project_details = inst.get_project_details(project_id);
+		assert_eq!(inst.execute(|| UnmigratedCounter::<TestRuntime>::get(project_id)), 15);
+		assert_eq!(project_details.status, ProjectStatus::CTMigrationStarted);
+	}
+
+	fn create_offchain_migration_project(mut inst: MockInstantiator) -> (ProjectId, MockInstantiator) {
+		let project_metadata = default_project_metadata(ISSUER_1);
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+		let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 90, 10);
+		let project_id = inst.create_finished_project(project_metadata, ISSUER_1, None, evaluations, bids);
+		assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::SettlementStarted(FundingOutcome::Success));
+		inst.settle_project(project_id, true);
+		inst.execute(|| {
+			assert_ok!(crate::Pallet::<TestRuntime>::do_start_offchain_migration(project_id, ISSUER_1,));
+		});
+		(project_id, inst)
+	}
+
+	#[test]
+	fn confirm_offchain_migration() {
+		let inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let (project_id, mut inst) = create_offchain_migration_project(inst);
+		let bidder_1 = inst.account_from_u32(0, "BIDDER");
+
+		let bidder_1_migrations = inst.execute(|| UserMigrations::<TestRuntime>::get((project_id, bidder_1))).unwrap();
+		assert_eq!(bidder_1_migrations.0, MigrationStatus::NotStarted);
+
+		inst.execute(|| {
+			assert_ok!(crate::Pallet::<TestRuntime>::do_confirm_offchain_migration(project_id, ISSUER_1, bidder_1));
+		});
+
+		let bidder_1_migrations = inst.execute(|| UserMigrations::<TestRuntime>::get((project_id, bidder_1))).unwrap();
+		assert_eq!(bidder_1_migrations.0, MigrationStatus::Confirmed);
+	}
+
+	#[test]
+	fn mark_project_migration_as_finished() {
+		let inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let (project_id, mut inst) = create_offchain_migration_project(inst);
+
+		let participants = inst.execute(|| UserMigrations::<TestRuntime>::iter_key_prefix((project_id,)).collect_vec());
+		for participant in participants {
+			inst.execute(|| {
+				assert_ok!(crate::Pallet::<TestRuntime>::do_confirm_offchain_migration(
+					project_id,
+					ISSUER_1,
+					participant
+				));
+			});
+		}
+		inst.advance_time(<TestRuntime as Config>::MigrationDisputeWindow::get() + 1);
+
+		inst.execute(|| {
+			assert_ok!(crate::Pallet::<TestRuntime>::do_mark_project_ct_migration_as_finished(project_id));
+		});
+	}
+
+	#[test]
+	fn migration_cannot_be_finished_within_the_dispute_window() {
+		let inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let (project_id, mut inst) = create_offchain_migration_project(inst);
+		let participants = inst.execute(|| UserMigrations::<TestRuntime>::iter_key_prefix((project_id,)).collect_vec());
+
+		inst.execute(|| {
+			for participant in participants.iter() {
+				assert_ok!(PolimecFunding::do_confirm_offchain_migration(project_id, ISSUER_1, *participant));
+			}
+			assert_noop!(
+				PolimecFunding::mark_project_ct_migration_as_finished(RuntimeOrigin::signed(ISSUER_1), project_id),
+				Error::<TestRuntime>::MigrationDisputeWindowOpen
+			);
+		});
+
+		// The last confirmed participant can still dispute at the end of the window.
+		inst.advance_time(<TestRuntime as Config>::MigrationDisputeWindow::get());
+		inst.execute(|| {
+			assert_noop!(
+				PolimecFunding::mark_project_ct_migration_as_finished(RuntimeOrigin::signed(ISSUER_1), project_id),
+				Error::<TestRuntime>::MigrationDisputeWindowOpen
+			);
+		});
+
+		inst.advance_time(1);
+		inst.execute(|| {
+			assert_ok!(PolimecFunding::mark_project_ct_migration_as_finished(
+				RuntimeOrigin::signed(ISSUER_1),
+				project_id
+			));
+		});
+	}
+
+	#[test]
+	fn dispute_confirmed_migration() {
+		let inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let (project_id, mut inst) = create_offchain_migration_project(inst);
+		let participants = inst.execute(|| UserMigrations::<TestRuntime>::iter_key_prefix((project_id,)).collect_vec());
+		let (disputer, late_disputer) = (participants[0], participants[1]);
+
+		inst.execute(|| {
+			assert_noop!(
+				PolimecFunding::dispute_migration(RuntimeOrigin::signed(disputer), project_id),
+				Error::<TestRuntime>::MigrationNotConfirmed
+			);
+			assert_ok!(PolimecFunding::do_confirm_offchain_migration(project_id, ISSUER_1, late_disputer));
+		});
+		inst.advance_time(<TestRuntime as Config>::MigrationDisputeWindow::get() + 1);
+
+		for participant in participants.iter().filter(|participant| **participant != late_disputer) {
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::do_confirm_offchain_migration(project_id, ISSUER_1, *participant));
+			});
+		}
+
+		inst.execute(|| {
+			assert_noop!(
+				PolimecFunding::dispute_migration(RuntimeOrigin::signed(late_disputer), project_id),
+				Error::<TestRuntime>::MigrationDisputeWindowClosed
+			);
+
+			assert_ok!(PolimecFunding::dispute_migration(RuntimeOrigin::signed(disputer), project_id));
+			assert_eq!(
+				UserMigrations::<TestRuntime>::get((project_id, disputer)).unwrap().0,
+				MigrationStatus::Disputed
+			);
+			assert_eq!(OpenMigrationDisputes::<TestRuntime>::get(project_id), 1);
+			assert_noop!(
+				PolimecFunding::mark_project_ct_migration_as_finished(RuntimeOrigin::signed(ISSUER_1), project_id),
+				Error::<TestRuntime>::MigrationDisputesOpen
+			);
+
+			// Only governance resolves disputes.
+			assert_noop!(
+				PolimecFunding::resolve_migration_dispute(RuntimeOrigin::signed(ISSUER_1), project_id, disputer, false),
+				sp_runtime::DispatchError::BadOrigin
+			);
+			assert_ok!(PolimecFunding::resolve_migration_dispute(RuntimeOrigin::root(), project_id, disputer, false));
+			assert_eq!(
+				UserMigrations::<TestRuntime>::get((project_id, disputer)).unwrap().0,
+				MigrationStatus::Confirmed
+			);
+			assert_eq!(OpenMigrationDisputes::<TestRuntime>::get(project_id), 0);
+
+			// A migration can only be disputed once.
+			assert_noop!(
+				PolimecFunding::dispute_migration(RuntimeOrigin::signed(disputer), project_id),
+				Error::<TestRuntime>::MigrationDisputeWindowClosed
+			);
+		});
+		inst.advance_time(<TestRuntime as Config>::MigrationDisputeWindow::get() + 1);
+
+		inst.execute(|| {
+			assert_ok!(PolimecFunding::mark_project_ct_migration_as_finished(
+				RuntimeOrigin::signed(ISSUER_1),
+				project_id
+			));
+		});
+	}
+
+	#[test]
+	fn upheld_migration_dispute_reopens_the_migration() {
+		let inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let (project_id, mut inst) = create_offchain_migration_project(inst);
+		let participants = inst.execute(|| UserMigrations::<TestRuntime>::iter_key_prefix((project_id,)).collect_vec());
+		let disputer = participants[0];
+
+		inst.execute(|| {
+			for participant in participants.iter() {
+				assert_ok!(PolimecFunding::do_confirm_offchain_migration(project_id, ISSUER_1, *participant));
+			}
+			assert_eq!(UnmigratedCounter::<TestRuntime>::get(project_id), 0);
+
+			assert_ok!(PolimecFunding::dispute_migration(RuntimeOrigin::signed(disputer), project_id));
+			assert_noop!(
+				PolimecFunding::do_confirm_offchain_migration(project_id, ISSUER_1, disputer),
+				Error::<TestRuntime>::MigrationAlreadyConfirmed
+			);
+			assert_ok!(PolimecFunding::resolve_migration_dispute(RuntimeOrigin::root(), project_id, disputer, true));
+			System::assert_last_event(
+				Event::MigrationStatusUpdated { project_id, account: disputer, status: MigrationStatus::NotStarted }
+					.into(),
+			);
+
+			// The migration has to be done again before the project can finish its migration, and is sent with a new
+			// nonce so the receiver pallet does not skip it.
+			assert_eq!(UnmigratedCounter::<TestRuntime>::get(project_id), 1);
+			assert_eq!(MigrationResendNonces::<TestRuntime>::get(project_id, disputer), 1);
+			assert_noop!(
+				PolimecFunding::mark_project_ct_migration_as_finished(RuntimeOrigin::signed(ISSUER_1), project_id),
+				Error::<TestRuntime>::MigrationsStillPending
+			);
+			assert_ok!(PolimecFunding::do_confirm_offchain_migration(project_id, ISSUER_1, disputer));
+		});
+		inst.advance_time(<TestRuntime as Config>::MigrationDisputeWindow::get() + 1);
+
+		inst.execute(|| {
+			assert_ok!(PolimecFunding::mark_project_ct_migration_as_finished(
+				RuntimeOrigin::signed(ISSUER_1),
+				project_id
+			));
+		});
+	}
+
+	/// Root of a tree with the migration confirmation leaves of the given participants, with the proof of each one.
+	fn migration_confirmation_tree(
+		inst: &mut MockInstantiator,
+		project_id: ProjectId,
+		participants: Vec<AccountId>,
+	) -> ([u8; 32], Vec<BoundedVec<[u8; 32], ConstU32<MAX_MIGRATION_PROOF_LENGTH>>>) {
+		let leaves = inst.execute(|| {
+			let expected_leaves = PolimecFunding::migration_confirmation_leaves(project_id);
+			participants
+				.iter()
+				.map(|participant| {
+					let (_, migration_info) =
+						expected_leaves.iter().find(|(account, _)| account == participant).unwrap();
+					PolimecFunding::migration_confirmation_leaf(participant, migration_info)
+				})
+				.collect_vec()
+		});
+
+		// Build the tree level by level, remembering the path of each leaf.
+		let mut proofs = vec![vec![]; leaves.len()];
+		let mut positions = (0..leaves.len()).collect_vec();
+		let mut level = leaves;
+		while level.len() > 1 {
+			for (proof, position) in proofs.iter_mut().zip(positions.iter_mut()) {
+				let sibling = *position ^ 1;
+				if sibling < level.len() {
+					proof.push(level[sibling]);
+				}
+				*position /= 2;
+			}
+			level = level
+				.chunks(2)
+				.map(|pair| match pair {
+					[left, right] => PolimecFunding::merkle_root(*left, &[*right]),
+					[single] => *single,
+					_ => unreachable!(),
+				})
+				.collect_vec();
+		}
+
+		(level[0], proofs.into_iter().map(|proof| proof.try_into().unwrap()).collect())
+	}
+
+	#[test]
+	fn confirm_offchain_migrations_with_merkle_root() {
+		let inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let (project_id, mut inst) = create_offchain_migration_project(inst);
+
+		let participants = inst.execute(|| UserMigrations::<TestRuntime>::iter_key_prefix((project_id,)).collect_vec());
+		let batch = participants[..5].to_vec();
+		let (root, proofs) = migration_confirmation_tree(&mut inst, project_id, batch.clone());
+
+		inst.execute(|| {
+			assert_noop!(
+				PolimecFunding::submit_migration_confirmation_root(
+					RuntimeOrigin::signed(EVALUATOR_1),
+					project_id,
+					root,
+					5
+				),
+				Error::<TestRuntime>::NotIssuer
+			);
+			assert_noop!(
+				PolimecFunding::submit_migration_confirmation_root(
+					RuntimeOrigin::signed(ISSUER_1),
+					project_id,
+					root,
+					participants.len() as u32 + 1
+				),
+				Error::<TestRuntime>::InvalidMigrationConfirmationCount
+			);
+			assert_ok!(PolimecFunding::submit_migration_confirmation_root(
+				RuntimeOrigin::signed(ISSUER_1),
+				project_id,
+				root,
+				5
+			));
+			System::assert_last_event(Event::MigrationConfirmationRootSubmitted { project_id, root, count: 5 }.into());
+		});
+
+		let unmigrated_before = inst.execute(|| UnmigratedCounter::<TestRuntime>::get(project_id));
+		for (participant, proof) in batch.iter().zip(proofs.iter()) {
+			inst.execute(|| {
+				// Anyone can submit the proof of a participant
+				assert_ok!(PolimecFunding::prove_offchain_migration(
+					RuntimeOrigin::signed(EVALUATOR_1),
+					project_id,
+					*participant,
+					proof.clone()
+				));
+				assert_eq!(
+					UserMigrations::<TestRuntime>::get((project_id, participant)).unwrap().0,
+					MigrationStatus::Confirmed
+				);
+			});
+		}
+
+		inst.execute(|| {
+			assert_eq!(UnmigratedCounter::<TestRuntime>::get(project_id), unmigrated_before - 5);
+			// The root is removed once all the participants of the batch are confirmed.
+			assert_eq!(MigrationConfirmationRoots::<TestRuntime>::get(project_id, root), None);
+			assert_noop!(
+				PolimecFunding::prove_offchain_migration(
+					RuntimeOrigin::signed(EVALUATOR_1),
+					project_id,
+					batch[0],
+					proofs[0].clone()
+				),
+				Error::<TestRuntime>::InvalidMigrationConfirmationProof
+			);
+		});
+	}
+
+	#[test]
+	fn prove_offchain_migration_fails_with_wrong_proof() {
+		let inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let (project_id, mut inst) = create_offchain_migration_project(inst);
+
+		let participants = inst.execute(|| UserMigrations::<TestRuntime>::iter_key_prefix((project_id,)).collect_vec());
+		let (root, proofs) = migration_confirmation_tree(&mut inst, project_id, participants[..2].to_vec());
+
+		inst.execute(|| {
+			assert_ok!(PolimecFunding::submit_migration_confirmation_root(
+				RuntimeOrigin::signed(ISSUER_1),
+				project_id,
+				root,
+				2
+			));
+			// A participant outside of the batch cannot use the proof of another one.
+			assert_noop!(
+				PolimecFunding::prove_offchain_migration(
+					RuntimeOrigin::signed(EVALUATOR_1),
+					project_id,
+					participants[2],
+					proofs[0].clone()
+				),
+				Error::<TestRuntime>::InvalidMigrationConfirmationProof
+			);
+			// A participant confirmed one by one cannot be confirmed again through the batch.
+			assert_ok!(PolimecFunding::confirm_offchain_migration(
+				RuntimeOrigin::signed(ISSUER_1),
+				project_id,
+				participants[0]
+			));
+			assert_noop!(
+				PolimecFunding::prove_offchain_migration(
+					RuntimeOrigin::signed(EVALUATOR_1),
+					project_id,
+					participants[0],
+					proofs[0].clone()
+				),
+				Error::<TestRuntime>::MigrationAlreadyConfirmed
+			);
+		});
+	}
+}
+
+mod pallet_migration {
+	use super::*;
+	use crate::{ActiveMigrationQueue, ChannelStatus, CheckOutcome, HRMPChannelStatus, PalletMigrations};
+	use polimec_common::migration_types::MigrationStatus;
+	use polkadot_parachain_primitives::primitives::Id as ParaId;
+	use xcm::v5::{Asset, Assets, Error as XcmError, Junction::Parachain, Location, MaybeErrorCode, Response};
+
+	const PROJECT_PARA_ID: u32 = 6969;
+
+	fn project_location() -> Location {
+		Location::new(1, [Parachain(PROJECT_PARA_ID)])
+	}
+
+	fn create_pallet_migration_project(mut inst: MockInstantiator) -> (ProjectId, MockInstantiator) {
+		let project_metadata = default_project_metadata(ISSUER_1);
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+		let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 90, 10);
+		let project_id = inst.create_finished_project(project_metadata, ISSUER_1, None, evaluations, bids);
+		assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::SettlementStarted(FundingOutcome::Success));
+		inst.settle_project(project_id, true);
+		inst.execute(|| {
+			assert_ok!(crate::Pallet::<TestRuntime>::do_start_pallet_migration(
+				&ISSUER_1,
+				project_id,
+				ParaId::from(PROJECT_PARA_ID)
+			));
+		});
+		(project_id, inst)
+	}
+
+	fn open_channels(inst: &mut MockInstantiator) {
+		inst.execute(|| {
+			assert_ok!(crate::Pallet::<TestRuntime>::do_handle_channel_open_request(PROJECT_PARA_ID, 102_400, 1000));
+			assert_ok!(crate::Pallet::<TestRuntime>::do_handle_channel_accepted(PROJECT_PARA_ID));
+		});
+	}
+
+	fn pass_readiness_check(inst: &mut MockInstantiator, project_id: ProjectId) {
+		inst.execute(|| {
+			assert_ok!(crate::Pallet::<TestRuntime>::do_start_pallet_migration_readiness_check(&ISSUER_1, project_id));
+			let check = PalletMigrations::<TestRuntime>::get(project_id).unwrap().migration_readiness_check.unwrap();
+			let total_cts = ContributionTokens::total_issuance(project_id);
+
+			let holding_response = Response::Assets(Assets::from(Asset::from((project_location(), total_cts))));
+			assert_ok!(crate::Pallet::<TestRuntime>::do_pallet_migration_readiness_response(
+				project_location(),
+				check.holding_check.0,
+				holding_response
+			));
+			let pallets_info = vec![PolimecReceiverInfo::get()].try_into().unwrap();
+			assert_ok!(crate::Pallet::<TestRuntime>::do_pallet_migration_readiness_response(
+				project_location(),
+				check.pallet_check.0,
+				Response::PalletsInfo(pallets_info)
+			));
+		});
+	}
+
+	#[test]
+	fn start_pallet_migration() {
+		let inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let (project_id, mut inst) = create_pallet_migration_project(inst);
+
+		let project_details = inst.get_project_details(project_id);
+		assert_eq!(project_details.status, ProjectStatus::CTMigrationStarted);
+		let migration_info = inst.execute(|| PalletMigrations::<TestRuntime>::get(project_id)).unwrap();
+		assert_eq!(migration_info.parachain_id, ParaId::from(PROJECT_PARA_ID));
+		assert_eq!(
+			migration_info.hrmp_channel_status,
+			HRMPChannelStatus { project_to_polimec: ChannelStatus::Closed, polimec_to_project: ChannelStatus::Closed }
+		);
+
+		// Offchain migrations cannot be confirmed for a pallet migration project.
+		let bidder_1 = inst.account_from_u32(0, "BIDDER");
+		inst.execute(|| {
+			assert_noop!(
+				crate::Pallet::<TestRuntime>::do_confirm_offchain_migration(project_id, ISSUER_1, bidder_1),
+				Error::<TestRuntime>::NotAllowed
+			);
+		});
+	}
+
+	#[test]
+	fn cannot_start_pallet_migration_with_used_para_id() {
+		let inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let (_, mut inst) = create_pallet_migration_project(inst);
+
+		let project_metadata = default_project_metadata(ISSUER_2);
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+		let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 90, 10);
+		let project_id = inst.create_finished_project(project_metadata, ISSUER_2, None, evaluations, bids);
+		assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::SettlementStarted(FundingOutcome::Success));
+		inst.settle_project(project_id, true);
+
+		inst.execute(|| {
+			assert_noop!(
+				crate::Pallet::<TestRuntime>::do_start_pallet_migration(
+					&ISSUER_1,
+					project_id,
+					ParaId::from(PROJECT_PARA_ID + 1)
+				),
+				Error::<TestRuntime>::NotIssuer
+			);
+			assert_noop!(
+				crate::Pallet::<TestRuntime>::do_start_pallet_migration(
+					&ISSUER_2,
+					project_id,
+					ParaId::from(PROJECT_PARA_ID)
+				),
+				Error::<TestRuntime>::WrongParaId
+			);
+		});
+	}
+
+	#[test]
+	fn hrmp_channels_are_opened() {
+		let inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let (project_id, mut inst) = create_pallet_migration_project(inst);
+
+		inst.execute(|| {
+			// Channel requests outside the thresholds are rejected.
+			assert_eq!(
+				crate::Pallet::<TestRuntime>::do_handle_channel_open_request(PROJECT_PARA_ID, 1024, 1000),
+				Err(XcmError::Unimplemented)
+			);
+			// Channel requests from unknown parachains are rejected.
+			assert_eq!(
+				crate::Pallet::<TestRuntime>::do_handle_channel_open_request(PROJECT_PARA_ID + 1, 102_400, 1000),
+				Err(XcmError::BadOrigin)
+			);
+
+			assert_ok!(crate::Pallet::<TestRuntime>::do_handle_channel_open_request(PROJECT_PARA_ID, 102_400, 1000));
+			let sent_xcm = take_sent_xcm();
+			assert_eq!(sent_xcm.len(), 1);
+			assert_eq!(sent_xcm[0].0, Location::parent());
+			assert_eq!(
+				PalletMigrations::<TestRuntime>::get(project_id).unwrap().hrmp_channel_status,
+				HRMPChannelStatus {
+					project_to_polimec: ChannelStatus::Open,
+					polimec_to_project: ChannelStatus::AwaitingAcceptance
+				}
+			);
+
+			assert_ok!(crate::Pallet::<TestRuntime>::do_handle_channel_accepted(PROJECT_PARA_ID));
+			assert_eq!(
+				PalletMigrations::<TestRuntime>::get(project_id).unwrap().hrmp_channel_status,
+				HRMPChannelStatus { project_to_polimec: ChannelStatus::Open, polimec_to_project: ChannelStatus::Open }
+			);
+			System::assert_last_event(
+				Event::HrmpChannelEstablished { project_id, para_id: ParaId::from(PROJECT_PARA_ID) }.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn readiness_check() {
+		let inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let (project_id, mut inst) = create_pallet_migration_project(inst);
+
+		inst.execute(|| {
+			assert_noop!(
+				crate::Pallet::<TestRuntime>::do_start_pallet_migration_readiness_check(&ISSUER_1, project_id),
+				Error::<TestRuntime>::ChannelNotOpen
+			);
+		});
+		open_channels(&mut inst);
+
+		// A parachain not holding enough tokens fails the check.
+		inst.execute(|| {
+			take_sent_xcm();
+			assert_ok!(crate::Pallet::<TestRuntime>::do_start_pallet_migration_readiness_check(&ISSUER_1, project_id));
+			let sent_xcm = take_sent_xcm();
+			assert_eq!(sent_xcm.len(), 1);
+			assert_eq!(sent_xcm[0].0, project_location());
+
+			let check = PalletMigrations::<TestRuntime>::get(project_id).unwrap().migration_readiness_check.unwrap();
+			let holding_response = Response::Assets(Assets::from(Asset::from((project_location(), 1u128))));
+			assert_ok!(crate::Pallet::<TestRuntime>::do_pallet_migration_readiness_response(
+				project_location(),
+				check.holding_check.0,
+				holding_response
+			));
+			let check = PalletMigrations::<TestRuntime>::get(project_id).unwrap().migration_readiness_check.unwrap();
+			assert_eq!(check.holding_check.1, CheckOutcome::Failed);
+			assert!(!check.is_ready());
+		});
+
+		// The check can be started again, and pass.
+		pass_readiness_check(&mut inst, project_id);
+		inst.execute(|| {
+			let check = PalletMigrations::<TestRuntime>::get(project_id).unwrap().migration_readiness_check.unwrap();
+			assert_eq!(check.holding_check.1, CheckOutcome::Passed(None));
+			assert_eq!(check.pallet_check.1, CheckOutcome::Passed(Some(51)));
+			assert!(check.is_ready());
+
+			assert_noop!(
+				crate::Pallet::<TestRuntime>::do_start_pallet_migration_readiness_check(&ISSUER_1, project_id),
+				Error::<TestRuntime>::NotAllowed
+			);
+		});
+	}
+
+	#[test]
+	fn send_and_confirm_pallet_migrations() {
+		let inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let (project_id, mut inst) = create_pallet_migration_project(inst);
+		let bidder_1 = inst.account_from_u32(0, "BIDDER");
+		let bidder_2 = inst.account_from_u32(1, "BIDDER");
+
+		inst.execute(|| {
+			assert_noop!(
+				crate::Pallet::<TestRuntime>::do_send_pallet_migration_for(project_id, bidder_1),
+				Error::<TestRuntime>::ChannelNotReady
+			);
+		});
+		open_channels(&mut inst);
+		pass_readiness_check(&mut inst, project_id);
+
+		inst.execute(|| {
+			take_sent_xcm();
+			assert_ok!(crate::Pallet::<TestRuntime>::do_send_pallet_migration_for(project_id, bidder_1));
+			assert_ok!(crate::Pallet::<TestRuntime>::do_send_pallet_migration_for(project_id, bidder_2));
+			let sent_xcm = take_sent_xcm();
+			assert_eq!(sent_xcm.len(), 2);
+			assert!(sent_xcm.iter().all(|(destination, _)| destination == &project_location()));
+			assert_eq!(UserMigrations::<TestRuntime>::get((project_id, bidder_1)).unwrap().0, MigrationStatus::Sent);
+			// Migrations awaiting the response of the receiver pallet cannot be sent twice.
+			assert_noop!(
+				crate::Pallet::<TestRuntime>::do_send_pallet_migration_for(project_id, bidder_1),
+				Error::<TestRuntime>::MigrationAlreadySent
+			);
+
+			let mut queries = ActiveMigrationQueue::<TestRuntime>::iter().collect_vec();
+			queries.sort_by_key(|(query_id, _)| *query_id);
+			assert_eq!(queries.len(), 2);
+			let (query_1, (_, account_1, _)) = queries[0];
+			let (query_2, (_, account_2, _)) = queries[1];
+
+			// A successful migration is confirmed.
+			assert_ok!(crate::Pallet::<TestRuntime>::do_confirm_pallet_migrations(
+				project_location(),
+				query_1,
+				Response::DispatchResult(MaybeErrorCode::Success)
+			));
+			assert_eq!(
+				UserMigrations::<TestRuntime>::get((project_id, account_1)).unwrap().0,
+				MigrationStatus::Confirmed
+			);
+
+			// A failed migration can be sent again.
+			assert_ok!(crate::Pallet::<TestRuntime>::do_confirm_pallet_migrations(
+				project_location(),
+				query_2,
+				Response::DispatchResult(MaybeErrorCode::Error(Default::default()))
+			));
+			assert_eq!(UserMigrations::<TestRuntime>::get((project_id, account_2)).unwrap().0, MigrationStatus::Failed);
+			assert!(ActiveMigrationQueue::<TestRuntime>::iter().next().is_none());
+
+			assert_noop!(
+				crate::Pallet::<TestRuntime>::do_send_pallet_migration_for(project_id, account_1),
+				Error::<TestRuntime>::MigrationAlreadyConfirmed
+			);
+			assert_ok!(crate::Pallet::<TestRuntime>::do_send_pallet_migration_for(project_id, account_2));
+		});
+	}
+
+	#[test]
+	fn timed_out_pallet_migrations_can_be_sent_again() {
+		let inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let (project_id, mut inst) = create_pallet_migration_project(inst);
+		let bidder_1 = inst.account_from_u32(0, "BIDDER");
+		open_channels(&mut inst);
+		pass_readiness_check(&mut inst, project_id);
+
+		let query_id = inst.execute(|| {
+			assert_ok!(crate::Pallet::<TestRuntime>::do_send_pallet_migration_for(project_id, bidder_1));
+			let (query_id, (_, _, timeout)) = ActiveMigrationQueue::<TestRuntime>::iter().next().unwrap();
+			assert_eq!(timeout, System::block_number() + 20);
+
+			assert_noop!(
+				PolimecFunding::fail_timed_out_pallet_migration(RuntimeOrigin::signed(EVALUATOR_1), query_id),
+				Error::<TestRuntime>::MigrationQueryNotTimedOut
+			);
+			query_id
+		});
+
+		// The receiver pallet never responds.
+		inst.advance_time(21);
+		inst.execute(|| {
+			assert_ok!(PolimecFunding::fail_timed_out_pallet_migration(RuntimeOrigin::signed(EVALUATOR_1), query_id));
+			assert_eq!(UserMigrations::<TestRuntime>::get((project_id, bidder_1)).unwrap().0, MigrationStatus::Failed);
+			assert!(!ActiveMigrationQueue::<TestRuntime>::contains_key(query_id));
+
+			// A late response is not processed.
+			assert_noop!(
+				crate::Pallet::<TestRuntime>::do_confirm_pallet_migrations(
+					project_location(),
+					query_id,
+					Response::DispatchResult(MaybeErrorCode::Success)
+				),
+				Error::<TestRuntime>::NoActiveMigrationsFound
+			);
+
+			assert_ok!(crate::Pallet::<TestRuntime>::do_send_pallet_migration_for(project_id, bidder_1));
+			assert_eq!(UserMigrations::<TestRuntime>::get((project_id, bidder_1)).unwrap().0, MigrationStatus::Sent);
+		});
+	}
+}
@@ -26,6 +26,27 @@ mod helper_functions {
 		);
 	}
 
+	#[test]
+	fn bech32_encode() {
+		use crate::functions::misc::cosmos_adr_036::bech32_encode;
+
+		assert_eq!(bech32_encode(b"a", &[]), "a12uel5l");
+		assert_eq!(bech32_encode(b"cosmos", &[0u8; 20]), "cosmos1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqnrql8a");
+	}
+
+	#[test]
+	fn solana_offchain_message() {
+		use crate::functions::misc::solana_offchain_message::get_offchain_message;
+
+		let message = get_offchain_message(b"Polimec").unwrap();
+		assert_eq!(&message[..16], b"\xffsolana offchain");
+		assert_eq!(&message[16..20], &[0, 0, 7, 0]);
+		assert_eq!(&message[20..], b"Polimec");
+
+		assert_eq!(get_offchain_message(b""), None);
+		assert_eq!(get_offchain_message("Polimec €".as_bytes()), None);
+	}
+
 	#[test]
 	fn test_convert_from_decimal_aware_back_to_normal() {
 		// Test with an asset with less decimals than USD
@@ -231,6 +252,7 @@ fn project_state_transition_event() {
 	let mut desired_transitions = vec![
 		ProjectStatus::EvaluationRound,
 		ProjectStatus::AuctionRound,
+		ProjectStatus::CommunityRound,
 		ProjectStatus::FundingSuccessful,
 		ProjectStatus::SettlementStarted(FundingOutcome::Success),
 		ProjectStatus::SettlementFinished(FundingOutcome::Success),
@@ -241,3 +263,218 @@ fn project_state_transition_event() {
 		assert_eq!(event, Event::ProjectPhaseTransition { project_id, phase: desired_transitions.next().unwrap() });
 	});
 }
+
+mod set_funding_asset_extrinsic {
+	use super::*;
+	use polimec_common::assets::{FundingAssetInfo, MAX_FUNDING_ASSETS};
+	use xcm::v4::prelude::{GeneralIndex, Location, PalletInstance, Parachain};
+
+	fn new_asset_info(index: u128) -> FundingAssetInfo {
+		let location = Location::new(1, [Parachain(1000), PalletInstance(50), GeneralIndex(index)]);
+		FundingAssetInfo { oracle_key: location.clone(), location, decimals: 6, enabled: true }
+	}
+
+	#[test]
+	fn register_and_disable_funding_asset() {
+		new_test_ext().execute_with(|| {
+			let new_asset = AcceptedFundingAsset(4);
+			let info = new_asset_info(4);
+			assert_ok!(PolimecFunding::set_funding_asset(RuntimeOrigin::root(), new_asset, info.clone()));
+			assert_eq!(FundingAssets::<TestRuntime>::get(new_asset), Some(info.clone()));
+			System::assert_last_event(Event::<TestRuntime>::FundingAssetSet { asset: new_asset, info }.into());
+
+			let disabled_usdt = FundingAssetInfo { enabled: false, ..USDT.builtin_info().unwrap() };
+			assert_ok!(PolimecFunding::set_funding_asset(RuntimeOrigin::root(), USDT, disabled_usdt));
+			assert!(!PolimecFunding::is_funding_asset_enabled(USDT));
+			assert!(!PolimecFunding::enabled_funding_asset_locations().contains(&USDT.id().unwrap()));
+		});
+	}
+
+	#[test]
+	fn non_governance_origin() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				PolimecFunding::set_funding_asset(
+					RuntimeOrigin::signed(ISSUER_1),
+					AcceptedFundingAsset(4),
+					new_asset_info(4)
+				),
+				sp_runtime::DispatchError::BadOrigin
+			);
+		});
+	}
+
+	#[test]
+	fn too_many_funding_assets() {
+		new_test_ext().execute_with(|| {
+			let builtin_count = AcceptedFundingAsset::builtin_assets().len() as u8;
+			for index in builtin_count..MAX_FUNDING_ASSETS as u8 {
+				assert_ok!(PolimecFunding::set_funding_asset(
+					RuntimeOrigin::root(),
+					AcceptedFundingAsset(index),
+					new_asset_info(index.into())
+				));
+			}
+			let new_asset = AcceptedFundingAsset(MAX_FUNDING_ASSETS as u8);
+			assert_noop!(
+				PolimecFunding::set_funding_asset(RuntimeOrigin::root(), new_asset, new_asset_info(new_asset.0.into())),
+				Error::<TestRuntime>::TooManyFundingAssets
+			);
+
+			// Existing entries can still be updated once the registry is full
+			let disabled_dot = FundingAssetInfo { enabled: false, ..DOT.builtin_info().unwrap() };
+			assert_ok!(PolimecFunding::set_funding_asset(RuntimeOrigin::root(), DOT, disabled_dot));
+		});
+	}
+
+	#[test]
+	fn location_and_decimals_of_registered_assets_cannot_change() {
+		new_test_ext().execute_with(|| {
+			let usdt_info = USDT.builtin_info().unwrap();
+			let moved_usdt = FundingAssetInfo { location: new_asset_info(4).location, ..usdt_info.clone() };
+			assert_noop!(
+				PolimecFunding::set_funding_asset(RuntimeOrigin::root(), USDT, moved_usdt),
+				Error::<TestRuntime>::FundingAssetImmutable
+			);
+			let redenominated_usdt = FundingAssetInfo { decimals: 18, ..usdt_info.clone() };
+			assert_noop!(
+				PolimecFunding::set_funding_asset(RuntimeOrigin::root(), USDT, redenominated_usdt),
+				Error::<TestRuntime>::FundingAssetImmutable
+			);
+
+			let new_oracle_key = new_asset_info(4).oracle_key;
+			let updated_usdt = FundingAssetInfo { oracle_key: new_oracle_key, enabled: false, ..usdt_info };
+			assert_ok!(PolimecFunding::set_funding_asset(RuntimeOrigin::root(), USDT, updated_usdt.clone()));
+			assert_eq!(FundingAssets::<TestRuntime>::get(USDT), Some(updated_usdt));
+		});
+	}
+
+	#[test]
+	fn location_registered_under_another_asset() {
+		new_test_ext().execute_with(|| {
+			let usdt_location_info = FundingAssetInfo { decimals: 6, ..USDT.builtin_info().unwrap() };
+			assert_noop!(
+				PolimecFunding::set_funding_asset(RuntimeOrigin::root(), AcceptedFundingAsset(4), usdt_location_info),
+				Error::<TestRuntime>::FundingAssetLocationTaken
+			);
+		});
+	}
+}
+
+mod set_fee_config_extrinsic {
+	use super::*;
+
+	#[test]
+	fn set_fee_config() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(FeeConfiguration::<TestRuntime>::get(), FeeConfig::default());
+
+			let fee_config = FeeConfig {
+				fee_brackets: bounded_vec![
+					(Percent::from_percent(7), 2_000_000 * USD_UNIT),
+					(Percent::from_percent(5), u128::MAX)
+				],
+				liquidity_pools: Perquintill::from_percent(40),
+				..Default::default()
+			};
+			assert_ok!(PolimecFunding::set_fee_config(RuntimeOrigin::root(), fee_config.clone()));
+			assert_eq!(FeeConfiguration::<TestRuntime>::get(), fee_config.clone());
+			System::assert_last_event(Event::<TestRuntime>::FeeConfigSet { fee_config }.into());
+		});
+	}
+
+	#[test]
+	fn live_projects_keep_their_fee_config() {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let project_metadata = default_project_metadata(ISSUER_1);
+		let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+		let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 95, 10);
+
+		let live_project_id = inst.create_evaluating_project(project_metadata.clone(), ISSUER_1, None);
+		let new_fee_config = FeeConfig { evaluator_rewards: Perquintill::from_percent(10), ..Default::default() };
+		inst.execute(|| assert_ok!(PolimecFunding::set_fee_config(RuntimeOrigin::root(), new_fee_config.clone())));
+
+		inst.mint_necessary_tokens_for_evaluations(evaluations.clone());
+		inst.evaluate_for_users(live_project_id, evaluations.clone()).unwrap();
+		assert_eq!(inst.go_to_next_state(live_project_id), ProjectStatus::AuctionRound);
+		inst.mint_necessary_tokens_for_bids(live_project_id, bids.clone());
+		inst.bid_for_users(live_project_id, bids.clone()).unwrap();
+		assert_eq!(inst.go_to_next_state(live_project_id), ProjectStatus::CommunityRound);
+		assert_eq!(inst.go_to_next_state(live_project_id), ProjectStatus::FundingSuccessful);
+
+		let new_project_id = inst.create_finished_project(project_metadata, ISSUER_2, None, evaluations, bids);
+
+		assert_eq!(inst.execute(|| PolimecFunding::project_fee_config(live_project_id)), FeeConfig::default());
+		assert_eq!(inst.execute(|| PolimecFunding::project_fee_config(new_project_id)), new_fee_config);
+
+		// Both raises are the same, but the evaluators of the live project keep the 30% share of the fees they
+		// evaluated with, instead of the new 10%.
+		let reward_info = |inst: &mut MockInstantiator, project_id| {
+			let Some(EvaluatorsOutcome::Rewarded(reward_info)) =
+				inst.get_project_details(project_id).evaluation_round_info.evaluators_outcome
+			else {
+				panic!("Unexpected Evaluator Outcome")
+			};
+			reward_info
+		};
+		let live_reward_info = reward_info(&mut inst, live_project_id);
+		let new_reward_info = reward_info(&mut inst, new_project_id);
+		assert_close_enough!(
+			live_reward_info.early_evaluator_reward_pot,
+			new_reward_info.early_evaluator_reward_pot * 3,
+			Perquintill::from_float(0.9999)
+		);
+		assert_close_enough!(
+			live_reward_info.normal_evaluator_reward_pot,
+			new_reward_info.normal_evaluator_reward_pot * 3,
+			Perquintill::from_float(0.9999)
+		);
+	}
+
+	#[test]
+	fn non_governance_origin() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				PolimecFunding::set_fee_config(RuntimeOrigin::signed(ISSUER_1), FeeConfig::default()),
+				sp_runtime::DispatchError::BadOrigin
+			);
+		});
+	}
+
+	#[test]
+	fn invalid_fee_config() {
+		new_test_ext().execute_with(|| {
+			let invalid_fee_configs = vec![
+				// The fee increases from one bracket to the next
+				FeeConfig {
+					fee_brackets: bounded_vec![
+						(Percent::from_percent(5), 1_000_000 * USD_UNIT),
+						(Percent::from_percent(10), u128::MAX)
+					],
+					..Default::default()
+				},
+				// Empty bracket
+				FeeConfig {
+					fee_brackets: bounded_vec![(Percent::from_percent(10), 0), (Percent::from_percent(6), u128::MAX)],
+					..Default::default()
+				},
+				// Funds above the last bracket are not charged
+				FeeConfig {
+					fee_brackets: bounded_vec![(Percent::from_percent(10), 1_000_000 * USD_UNIT)],
+					..Default::default()
+				},
+				// The shares of the fees add up to more than 100%
+				FeeConfig { evaluator_rewards: Perquintill::from_percent(40), ..Default::default() },
+				// The shares of the evaluator rewards add up to more than 100%
+				FeeConfig { early_evaluator_rewards: Perquintill::from_percent(30), ..Default::default() },
+			];
+
+			for fee_config in invalid_fee_configs {
+				assert_noop!(
+					PolimecFunding::set_fee_config(RuntimeOrigin::root(), fee_config),
+					Error::<TestRuntime>::FeeConfigError
+				);
+			}
+		});
+	}
+}
@@ -14,16 +14,14 @@ use frame_support::{
 	assert_err, assert_noop, assert_ok,
 	traits::{
 		fungible::{InspectFreeze, MutateFreeze, MutateHold},
-		fungibles::{metadata::Inspect as MetadataInspect, Inspect, Mutate},
+		fungibles::{self, metadata::Inspect as MetadataInspect, Inspect, Mutate},
+		tokens::Preservation,
 	},
 };
 use itertools::Itertools;
 use pallet_balances::AccountData;
 use polimec_common::{
-	assets::{
-		AcceptedFundingAsset,
-		AcceptedFundingAsset::{DOT, ETH, USDC, USDT},
-	},
+	assets::{AcceptedFundingAsset, DOT, ETH, USDC, USDT},
 	ProvideAssetPrice, USD_DECIMALS, USD_UNIT,
 };
 use polimec_common_test_utils::{generate_did_from_account, get_mock_jwt, get_mock_jwt_with_cid};
@@ -39,15 +37,17 @@ use InvestorType::{self, *};
 mod application;
 #[path = "3_auction.rs"]
 mod auction;
-#[path = "6_ct_migration.rs"]
+#[path = "4_community_round.rs"]
+mod community_round;
+#[path = "7_ct_migration.rs"]
 mod ct_migration;
 #[path = "2_evaluation.rs"]
 mod evaluation;
-#[path = "4_funding_end.rs"]
+#[path = "5_funding_end.rs"]
 mod funding_end;
 mod misc;
 mod runtime_api;
-#[path = "5_settlement.rs"]
+#[path = "6_settlement.rs"]
 mod settlement;
 
 pub type MockInstantiator =
@@ -101,7 +101,7 @@ const fn default_accounts() -> [AccountId; 18] {
 
 pub mod defaults {
 	use super::*;
-	use polimec_common::assets::AcceptedFundingAsset::{DOT, ETH, USDC, USDT};
+	use polimec_common::assets::{DOT, ETH, USDC, USDT};
 
 	pub fn default_token_information() -> CurrencyMetadata<BoundedVec<u8, StringLimitOf<TestRuntime>>> {
 		CurrencyMetadata { name: bounded_name(), symbol: bounded_symbol(), decimals: CT_DECIMALS }
@@ -132,6 +132,11 @@ pub mod defaults {
 			funding_destination_account: issuer,
 			policy_ipfs_cid: Some(metadata_hash),
 			participants_account_type: ParticipantsAccountType::Polkadot,
+			sale_mode: SaleMode::Auction,
+			ct_vesting: None,
+			funding_milestones: BoundedVec::new(),
+			evaluation_success_threshold: None,
+			funding_success_threshold: None,
 		}
 	}
 
@@ -154,7 +159,11 @@ pub mod defaults {
 		let accounts = default_accounts().to_vec();
 		accounts
 			.iter()
-			.map(|acc| UserToFundingAsset { account: *acc, asset_amount: 1_000_000 * USD_UNIT, asset_id: USDT.id() })
+			.map(|acc| UserToFundingAsset {
+				account: *acc,
+				asset_amount: 1_000_000 * USD_UNIT,
+				asset_id: USDT.id().unwrap(),
+			})
 			.collect()
 	}
 
@@ -186,6 +195,10 @@ pub fn create_project_with_funding_percentage(percentage: u8, start_settlement:
 	let project_id = inst.create_finished_project(project_metadata, ISSUER_1, None, evaluations, bids);
 
 	if start_settlement {
+		// Let the default decision apply if the issuer has to decide the outcome
+		if inst.get_project_details(project_id).status == ProjectStatus::AwaitingIssuerDecision {
+			assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::FundingSuccessful);
+		}
 		assert!(matches!(inst.go_to_next_state(project_id), ProjectStatus::SettlementStarted(_)));
 	}
 
@@ -224,6 +237,21 @@ pub fn create_finished_project_with_usd_raised(
 	(inst, project_id)
 }
 
+/// Build an allowlist of two members, and return its root together with the proof of each member.
+pub fn two_member_allowlist(members: [(AccountId, Balance); 2]) -> ([u8; 32], [AllowlistProof; 2]) {
+	let leaves =
+		members.map(|(account, usd_cap)| PolimecFunding::allowlist_leaf(&generate_did_from_account(account), usd_cap));
+	let root = if leaves[0] <= leaves[1] {
+		sp_core::keccak_256(&[leaves[0], leaves[1]].concat())
+	} else {
+		sp_core::keccak_256(&[leaves[1], leaves[0]].concat())
+	};
+	let proofs = [(members[0].1, leaves[1]), (members[1].1, leaves[0])]
+		.map(|(usd_cap, sibling)| AllowlistProof { usd_cap, proof: bounded_vec![sibling] });
+
+	(root, proofs)
+}
+
 macro_rules! polkadot_junction {
     // Case 1: Explicit `[u8; 32]` literal with 32 values
     ([ $($byte:literal),* ]) => {{
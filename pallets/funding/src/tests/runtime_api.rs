@@ -280,6 +280,24 @@ fn funding_asset_to_ct_amount_classic() {
 		.unwrap();
 		assert_close_enough!(ct_amount, expected_ct_amount, Perquintill::from_float(0.9999));
 	});
+
+	// Same amount with a price cap of 17 USD/CT. Only the 50k at 16 and the 50k at 17 are bought.
+	let max_price = current_bucket.current_price + current_bucket.delta_price;
+	let expected_ct_amount = 100_000 * CT_UNIT;
+
+	inst.execute(|| {
+		let block_hash = System::block_hash(System::block_number());
+		let ct_amount = TestRuntime::funding_asset_to_ct_amount_classic_with_max_price(
+			&TestRuntime,
+			block_hash,
+			project_id_3,
+			AcceptedFundingAsset::DOT,
+			dot_amount,
+			Some(max_price),
+		)
+		.unwrap();
+		assert_eq!(ct_amount, expected_ct_amount);
+	});
 }
 
 #[test]
@@ -289,7 +307,7 @@ fn funding_asset_to_ct_amount_otm() {
 	// We want to use a funding asset that is not equal to 1 USD
 	// Sanity check
 	assert_eq!(
-		PriceProviderOf::<TestRuntime>::get_price(&AcceptedFundingAsset::DOT.id()).unwrap(),
+		PriceProviderOf::<TestRuntime>::get_price(&AcceptedFundingAsset::DOT.id().unwrap()).unwrap(),
 		PriceOf::<TestRuntime>::from_float(69.0f64)
 	);
 
@@ -366,13 +384,14 @@ fn get_message_to_sign_by_receiving_account() {
 	let project_id_2 = inst.create_new_project(default_project_metadata(ISSUER_3), ISSUER_3, None);
 	let block_hash = inst.execute(|| System::block_hash(System::block_number()));
 	let message = inst.execute(|| {
-		TestRuntime::get_message_to_sign_by_receiving_account(&TestRuntime, block_hash, project_id_2, BIDDER_1)
+		TestRuntime::get_message_to_sign_by_receiving_account(&TestRuntime, block_hash, project_id_2, BIDDER_1, 100)
 			.unwrap()
 			.unwrap()
 	});
 
-	const EXPECTED_MESSAGE: &str =
-		"Polimec account: 56yh5mops2XZvxVUjHTvFWjxoaJ5QjfAo9xhNCP1aMG4sY9X - project id: 2 - nonce: 0";
+	// The domain is the genesis hash of the mock chain
+	const EXPECTED_MESSAGE: &str = "Polimec account: 56yh5mops2XZvxVUjHTvFWjxoaJ5QjfAo9xhNCP1aMG4sY9X - project id: 2 - \
+	                                expiry: 100 - domain: 0x4545454545454545454545454545454545454545454545454545454545454545";
 
 	assert_eq!(&message, EXPECTED_MESSAGE);
 }
@@ -466,7 +485,7 @@ fn calculate_otm_fee() {
 	project_metadata.minimum_price = decimal_aware_price;
 	project_metadata.participation_currencies = bounded_vec![AcceptedFundingAsset::DOT];
 
-	let dot_id = AcceptedFundingAsset::DOT.id();
+	let dot_id = AcceptedFundingAsset::DOT.id().unwrap();
 	let dot_decimals = inst.execute(|| ForeignAssets::decimals(dot_id.clone()));
 	let dot_unit = 10u128.pow(dot_decimals as u32);
 	let dot_ticket = 1000 * dot_unit;
@@ -523,9 +542,12 @@ fn calculate_otm_fee() {
 
 #[test]
 fn get_funding_asset_min_max_amounts() {
-	ConstPriceProvider::set_price(AcceptedFundingAsset::USDT.id(), PriceOf::<TestRuntime>::from_float(1.0f64));
-	ConstPriceProvider::set_price(AcceptedFundingAsset::DOT.id(), PriceOf::<TestRuntime>::from_float(10.0f64));
-	ConstPriceProvider::set_price(AcceptedFundingAsset::ETH.id(), PriceOf::<TestRuntime>::from_float(100.0f64));
+	ConstPriceProvider::set_price(AcceptedFundingAsset::USDT.id().unwrap(), PriceOf::<TestRuntime>::from_float(1.0f64));
+	ConstPriceProvider::set_price(AcceptedFundingAsset::DOT.id().unwrap(), PriceOf::<TestRuntime>::from_float(10.0f64));
+	ConstPriceProvider::set_price(
+		AcceptedFundingAsset::ETH.id().unwrap(),
+		PriceOf::<TestRuntime>::from_float(100.0f64),
+	);
 	ConstPriceProvider::set_price(Location::here(), PriceOf::<TestRuntime>::from_float(0.5f64));
 	const DOT_UNIT: u128 = 10u128.pow(10u32);
 	const ETH_UNIT: u128 = 10u128.pow(18u32);
@@ -689,6 +711,7 @@ fn all_project_participations_by_did() {
 				.unwrap();
 		});
 	}
+	assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::CommunityRound);
 	assert_eq!(inst.go_to_next_state(project_id), ProjectStatus::FundingSuccessful);
 }
 
@@ -781,9 +804,101 @@ fn projects_by_did() {
 		true,
 	);
 
+	// Projects that are still running are returned alongside the finished ones.
+	let project_id_5 =
+		inst.create_evaluating_project(default_project_metadata(ISSUER_4), ISSUER_4, Some(did_user.clone()));
+	let project_id_6 = inst.create_new_project(default_project_metadata(ISSUER_4), ISSUER_4, Some(did_user.clone()));
+
 	inst.execute(|| {
 		let block_hash = System::block_hash(System::block_number());
 		let project_ids = TestRuntime::projects_by_did(&TestRuntime, block_hash, did_user).unwrap();
-		assert_eq!(project_ids, vec![project_id_1, project_id_3]);
+		assert_eq!(project_ids, vec![project_id_1, project_id_3, project_id_5, project_id_6]);
+	});
+}
+
+#[test]
+fn active_projects_by_did() {
+	let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+	let did_issuer = generate_did_from_account(ISSUER_1);
+
+	let evaluations = inst.generate_successful_evaluations(default_project_metadata(ISSUER_1), 5);
+	let bids = inst.generate_bids_from_total_ct_percent(default_project_metadata(ISSUER_1), 80, 10);
+	let _finished_project_id =
+		inst.create_finished_project(default_project_metadata(ISSUER_1), ISSUER_1, None, evaluations.clone(), bids);
+	let auctioning_project_id =
+		inst.create_auctioning_project(default_project_metadata(ISSUER_1), ISSUER_1, None, evaluations);
+	let new_project_id = inst.create_new_project(default_project_metadata(ISSUER_1), ISSUER_1, None);
+
+	inst.execute(|| {
+		let block_hash = System::block_hash(System::block_number());
+		let project_ids = TestRuntime::active_projects_by_did(&TestRuntime, block_hash, did_issuer).unwrap();
+		assert_eq!(project_ids, vec![auctioning_project_id, new_project_id]);
+	});
+}
+
+#[test]
+fn project_success_thresholds() {
+	let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+	let default_project_id = inst.create_new_project(default_project_metadata(ISSUER_1), ISSUER_1, None);
+	let project_metadata = ProjectMetadataOf::<TestRuntime> {
+		evaluation_success_threshold: Some(Percent::from_percent(20)),
+		funding_success_threshold: Some(Perquintill::from_percent(50)),
+		..default_project_metadata(ISSUER_2)
+	};
+	let custom_project_id = inst.create_new_project(project_metadata, ISSUER_2, None);
+
+	inst.execute(|| {
+		let block_hash = System::block_hash(System::block_number());
+		let default_thresholds =
+			TestRuntime::project_success_thresholds(&TestRuntime, block_hash, default_project_id).unwrap();
+		assert_eq!(
+			default_thresholds,
+			Some((
+				<TestRuntime as Config>::EvaluationSuccessThreshold::get(),
+				<TestRuntime as Config>::FundingSuccessThreshold::get()
+			))
+		);
+
+		let custom_thresholds =
+			TestRuntime::project_success_thresholds(&TestRuntime, block_hash, custom_project_id).unwrap();
+		assert_eq!(custom_thresholds, Some((Percent::from_percent(20), Perquintill::from_percent(50))));
+
+		let missing_thresholds = TestRuntime::project_success_thresholds(&TestRuntime, block_hash, 42).unwrap();
+		assert_eq!(missing_thresholds, None);
+	});
+}
+
+#[test]
+fn migration_confirmation_leaves() {
+	let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+	let project_metadata = default_project_metadata(ISSUER_1);
+	let evaluations = inst.generate_successful_evaluations(project_metadata.clone(), 5);
+	let bids = inst.generate_bids_from_total_ct_percent(project_metadata.clone(), 90, 10);
+	let project_id = inst.create_settled_project(project_metadata, ISSUER_1, None, evaluations, bids, true);
+	inst.execute(|| {
+		assert_ok!(PolimecFunding::do_start_offchain_migration(project_id, ISSUER_1));
+	});
+
+	let participants = inst.execute(|| UserMigrations::<TestRuntime>::iter_key_prefix((project_id,)).collect_vec());
+	inst.execute(|| {
+		assert_ok!(PolimecFunding::do_confirm_offchain_migration(project_id, ISSUER_1, participants[0]));
+	});
+
+	inst.execute(|| {
+		let block_hash = System::block_hash(System::block_number());
+		let leaves = TestRuntime::migration_confirmation_leaves(&TestRuntime, block_hash, project_id).unwrap();
+
+		// Confirmed participants are not part of the leaves anymore.
+		assert_eq!(leaves.len(), participants.len() - 1);
+		assert!(leaves.iter().all(|(account, _)| *account != participants[0]));
+
+		for (account, migration_info) in leaves {
+			let (_, migrations) = UserMigrations::<TestRuntime>::get((project_id, account)).unwrap();
+			let total_ct_amount =
+				migrations.iter().map(|migration| migration.info.contribution_token_amount).sum::<u128>();
+			let biggest_vesting_time = migrations.iter().map(|migration| migration.info.vesting_time).max().unwrap();
+			assert_eq!(migration_info.contribution_token_amount, total_ct_amount);
+			assert_eq!(migration_info.vesting_time, biggest_vesting_time);
+		}
 	});
 }
@@ -20,19 +20,19 @@
 use crate::traits::BondingRequirementCalculation;
 use alloc::{vec, vec::Vec};
 pub use config::*;
-use core::cmp::Eq;
+use core::{cmp::Eq, ops::RangeInclusive};
 pub use extrinsic::*;
 use frame_support::pallet_prelude::*;
 pub use inner::*;
-use polimec_common::{DAYS, USD_DECIMALS};
+use polimec_common::{DAYS, USD_DECIMALS, USD_UNIT};
 use serde::{Deserialize, Serialize};
-use sp_arithmetic::{traits::Saturating, FixedPointNumber, FixedU128};
+use sp_arithmetic::{traits::Saturating, FixedPointNumber, FixedU128, PerThing, Percent, Perquintill};
 use sp_runtime::traits::{Convert, One};
 pub use storage::*;
 
 use crate::{traits::VestingDurationCalculation, Config};
 
-use polimec_common::assets::AcceptedFundingAsset;
+use polimec_common::assets::{AcceptedFundingAsset, MAX_FUNDING_ASSETS};
 use sp_runtime::traits::Zero;
 
 pub mod config {
@@ -151,6 +151,20 @@ pub mod config {
 	pub const RETAIL_MAX_MULTIPLIER: u8 = 5u8;
 	pub const PROFESSIONAL_MAX_MULTIPLIER: u8 = 10u8;
 	pub const INSTITUTIONAL_MAX_MULTIPLIER: u8 = 25u8;
+	/// Maximum depth of the merkle tree of a project allowlist. Enough for 2^32 entries.
+	pub const MAX_ALLOWLIST_PROOF_LENGTH: u32 = 32u32;
+	/// Maximum depth of the merkle tree of a batch of migration confirmations. Enough for 2^32 participants.
+	pub const MAX_MIGRATION_PROOF_LENGTH: u32 = 32u32;
+	/// Maximum amount of tranches the funds of a project can be released in.
+	pub const MAX_FUNDING_MILESTONES: u32 = 10u32;
+	/// Maximum amount of brackets the fees of a project can be charged in.
+	pub const MAX_FEE_BRACKETS: u32 = 10u32;
+	/// Maximum length of the human readable part of the Cosmos addresses a project delivers its tokens to.
+	pub const MAX_BECH32_PREFIX_LENGTH: u32 = 16u32;
+	/// Maximum amount of bids that can be placed in a single `bid_batch` call.
+	pub const MAX_BID_BATCH_SIZE: u32 = 10u32;
+	/// Maximum amount of scheduled bids that can be executed at the same block.
+	pub const MAX_SCHEDULED_BIDS_PER_BLOCK: u32 = 16u32;
 
 	parameter_types! {
 		pub HereLocationGetter: Location = Location::here();
@@ -161,7 +175,9 @@ pub mod storage {
 	#[allow(clippy::wildcard_imports)]
 	use super::*;
 	use crate::Balance;
-	use xcm::v4::Junction;
+	use polimec_common::credentials::{Cid, InvestorType};
+	use polkadot_parachain_primitives::primitives::Id as ParaId;
+	use xcm::v4::{Junction, QueryId};
 
 	#[derive(
 		Clone,
@@ -190,12 +206,24 @@ pub mod storage {
 		/// Participation currencies (e.g stablecoin, DOT, KSM)
 		/// e.g. https://github.com/paritytech/substrate/blob/427fd09bcb193c1e79dec85b1e207c718b686c35/frame/uniques/src/types.rs#L110
 		/// For now is easier to handle the case where only just one Currency is accepted
-		pub participation_currencies:
-			BoundedVec<AcceptedFundingAsset, ConstU32<{ AcceptedFundingAsset::VARIANT_COUNT as u32 }>>,
+		pub participation_currencies: BoundedVec<AcceptedFundingAsset, ConstU32<MAX_FUNDING_ASSETS>>,
 		pub funding_destination_account: AccountId,
 		/// Additional metadata
 		pub policy_ipfs_cid: Option<Cid>,
 		pub participants_account_type: ParticipantsAccountType,
+		/// How the tokens are allocated to the bidders of the auction round
+		pub sale_mode: SaleMode<Price>,
+		/// Vesting of the contribution tokens bought in the auction and community rounds. `None` mints them liquid.
+		pub ct_vesting: Option<ContributionTokenVesting>,
+		/// Share of the raised funds released to the issuer on each milestone approval. The funds stay in the project
+		/// account until then. Empty pays the issuer out during the settlement.
+		pub funding_milestones: BoundedVec<Percent, ConstU32<MAX_FUNDING_MILESTONES>>,
+		/// Share of the funding target the evaluators have to bond for the project to reach the auction. Evaluations
+		/// bonded below it get the early evaluator rewards. `None` uses the `EvaluationSuccessThreshold` of the pallet.
+		pub evaluation_success_threshold: Option<Percent>,
+		/// Share of the allocation that has to be sold for the funding to succeed, i.e. the soft cap of the raise.
+		/// `None` uses the `FundingSuccessThreshold` of the pallet.
+		pub funding_success_threshold: Option<Perquintill>,
 	}
 
 	impl<BoundedString, Price: FixedPointNumber, AccountId, Cid> ProjectMetadata<BoundedString, Price, AccountId, Cid> {
@@ -203,7 +231,16 @@ pub mod storage {
 		/// - Minimum price is not zero
 		/// - Minimum bidding ticket sizes are higher than 5k USD
 		/// - Specified participation currencies are unique
-		pub fn is_valid(&self) -> Result<(), MetadataError> {
+		/// - A descending auction starts above the minimum price, and its price actually moves
+		/// - The contribution token vesting releases the tokens over at least one block
+		/// - The funding milestones release all the funds, and each of them releases something
+		/// - The success thresholds declared by the issuer are within the bounds set by governance
+		/// - The bech32 prefix of a Cosmos project is a valid human readable part
+		pub fn is_valid(
+			&self,
+			evaluation_threshold_bounds: RangeInclusive<Percent>,
+			funding_threshold_bounds: RangeInclusive<Perquintill>,
+		) -> Result<(), MetadataError> {
 			if self.minimum_price == Price::zero() {
 				return Err(MetadataError::PriceTooLow);
 			}
@@ -259,6 +296,38 @@ pub mod storage {
 				return Err(MetadataError::BadTokenomics);
 			}
 
+			if !self.sale_mode.is_valid(self.minimum_price) {
+				return Err(MetadataError::SaleModeError);
+			}
+
+			if self.ct_vesting.is_some_and(|vesting| !vesting.is_valid()) {
+				return Err(MetadataError::CtVestingError);
+			}
+
+			if !self.funding_milestones.is_empty() {
+				let total_released = self
+					.funding_milestones
+					.iter()
+					.fold(0u32, |acc, tranche| acc.saturating_add(tranche.deconstruct().into()));
+				if total_released != 100 || self.funding_milestones.iter().any(|tranche| tranche.is_zero()) {
+					return Err(MetadataError::FundingMilestonesError);
+				}
+			}
+
+			if self
+				.evaluation_success_threshold
+				.is_some_and(|threshold| !evaluation_threshold_bounds.contains(&threshold))
+			{
+				return Err(MetadataError::SuccessThresholdError);
+			}
+			if self.funding_success_threshold.is_some_and(|threshold| !funding_threshold_bounds.contains(&threshold)) {
+				return Err(MetadataError::SuccessThresholdError);
+			}
+
+			if !self.participants_account_type.is_valid() {
+				return Err(MetadataError::ParticipantsAccountTypeError);
+			}
+
 			Ok(())
 		}
 	}
@@ -338,6 +407,27 @@ pub mod storage {
 		pub receiving_account: Junction,
 	}
 
+	/// A bid committed with `schedule_bid`, to be placed automatically at `execution_block`. The funds needed to pay
+	/// the whole amount at `max_price` are held until then.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	pub struct ScheduledBidInfo<ProjectId, Did, Price: FixedPointNumber, AccountId, BlockNumber> {
+		pub id: u32,
+		pub project_id: ProjectId,
+		pub bidder: AccountId,
+		pub did: Did,
+		pub investor_type: InvestorType,
+		pub whitelisted_policy: Cid,
+		pub ct_amount: Balance,
+		pub max_price: Price,
+		pub mode: ParticipationMode,
+		pub funding_asset: AcceptedFundingAsset,
+		pub execution_block: BlockNumber,
+		/// PLMC held with the `ScheduledBid` reason. Zero for OTM bids.
+		pub plmc_held: Balance,
+		/// Funding assets moved to the project pot. Includes the OTM fee for OTM bids.
+		pub funding_asset_held: Balance,
+	}
+
 	impl<ProjectId: Eq, Did: Eq, Price: FixedPointNumber, AccountId: Eq, BlockNumber: Eq + Ord> Ord
 		for BidInfo<ProjectId, Did, Price, AccountId, BlockNumber>
 	{
@@ -357,6 +447,24 @@ pub mod storage {
 		}
 	}
 
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	pub struct ContributionInfo<ProjectId, Did, Price, AccountId, BlockNumber> {
+		pub id: u32,
+		pub project_id: ProjectId,
+		pub contributor: AccountId,
+		pub did: Did,
+		pub ct_amount: Balance,
+		pub usd_contribution_amount: Balance,
+		/// The weighted average price of the auction, which is the fixed price of the community round
+		pub ct_usd_price: Price,
+		pub funding_asset: AcceptedFundingAsset,
+		pub funding_asset_amount: Balance,
+		pub mode: ParticipationMode,
+		pub plmc_bond: Balance,
+		pub when: BlockNumber,
+		pub receiving_account: Junction,
+	}
+
 	/// Represents a bucket that holds a specific amount of tokens at a given price.
 	/// Each bucket has a unique ID, an amount of tokens left, a current price, an initial price,
 	/// and constants to define price and amount increments for the next buckets.
@@ -439,6 +547,50 @@ pub mod storage {
 
 			usd_raised
 		}
+
+		/// Calculate the weighted average price paid for the tokens sold in the auction.
+		/// If nothing was sold, the initial price is used instead.
+		pub fn calculate_wap(self, allocation_size: Balance) -> Price {
+			// If the auction never moved past the first bucket, every token was bought at the initial price
+			if self.current_price == self.initial_price {
+				return self.initial_price;
+			}
+			let usd_raised = self.calculate_usd_raised(allocation_size);
+			Price::checked_from_rational(usd_raised, allocation_size).unwrap_or(self.initial_price)
+		}
+	}
+
+	/// How the price of a `Bucket` moves during the auction round.
+	#[derive(Clone, Copy, Eq, PartialEq, RuntimeDebug)]
+	pub enum PriceStrategy<Price> {
+		/// The price goes up by `delta_price` every time `delta_amount` tokens are sold.
+		Ascending,
+		/// The price starts at `ceiling_price` and goes down by `price_step` every `blocks_per_step` blocks, until the
+		/// `initial_price` of the bucket. Buying tokens never moves the price.
+		Descending { ceiling_price: Price, price_step: Price, blocks_per_step: u32 },
+	}
+
+	impl<Price: FixedPointNumber> PriceStrategy<Price> {
+		/// Remove the bought tokens from the bucket, and return the price of the next tokens.
+		pub fn update(&self, bucket: &mut Bucket<Price>, removed_amount: Balance) -> Price {
+			match self {
+				PriceStrategy::Ascending => bucket.update(removed_amount),
+				PriceStrategy::Descending { .. } => {
+					bucket.amount_left.saturating_reduce(removed_amount);
+					bucket.current_price
+				},
+			}
+		}
+
+		/// Move the price of the bucket to where it should be `blocks_elapsed` blocks after the auction started.
+		/// Ascending buckets only move with the tokens sold, so they are left untouched.
+		pub fn refresh(&self, bucket: &mut Bucket<Price>, blocks_elapsed: u32) {
+			if let PriceStrategy::Descending { ceiling_price, price_step, blocks_per_step } = self {
+				let steps = blocks_elapsed.checked_div(*blocks_per_step).unwrap_or_default();
+				let price_drop = price_step.saturating_mul(Price::saturating_from_integer(steps));
+				bucket.current_price = ceiling_price.saturating_sub(price_drop).max(bucket.initial_price);
+			}
+		}
 	}
 
 	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
@@ -446,11 +598,107 @@ pub mod storage {
 		pub first_bid_index: u32,
 		pub last_bid_index: u32,
 	}
+	#[derive(Clone, Copy, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	pub struct BidBucketNeighbours {
+		pub previous_bid_index: Option<u32>,
+		pub next_bid_index: Option<u32>,
+	}
 	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
 	pub struct OutbidBidsCutoff<Price> {
 		pub bid_price: Price,
 		pub bid_index: u32,
 	}
+
+	/// The kind of participation that `on_idle` is currently settling for a project.
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	pub enum SettlementPhase {
+		Evaluations,
+		Bids,
+		Contributions,
+	}
+
+	/// Where the automatic settlement of a project should resume from.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	pub struct SettlementCursor {
+		pub phase: SettlementPhase,
+		/// Raw storage key of the last participation tried in this phase. Participations that fail to settle are
+		/// left in storage, so we need this to skip over them instead of retrying them on every block.
+		pub last_key: Option<BoundedVec<u8, ConstU32<256>>>,
+	}
+
+	impl Default for SettlementCursor {
+		fn default() -> Self {
+			Self { phase: SettlementPhase::Evaluations, last_key: None }
+		}
+	}
+
+	/// Decision taken on a funding milestone of a project.
+	#[derive(
+		Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, DecodeWithMemTracking,
+	)]
+	pub enum MilestoneOutcome {
+		/// The tranche of the milestone was paid out to the issuer.
+		Released,
+		/// The tranche of the milestone can be claimed back by the participants.
+		Rejected,
+	}
+
+	/// Index of a pallet in the runtime of a project's parachain.
+	pub type PalletIndex = u8;
+
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum ChannelStatus {
+		Closed,
+		Open,
+		AwaitingAcceptance,
+	}
+
+	/// Status of the two HRMP channels needed to migrate the CTs of a project through XCM.
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct HRMPChannelStatus {
+		pub project_to_polimec: ChannelStatus,
+		pub polimec_to_project: ChannelStatus,
+	}
+
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum CheckOutcome {
+		AwaitingResponse,
+		/// The pallet check also stores the index of the receiver pallet, which the migrations are sent to.
+		Passed(Option<PalletIndex>),
+		Failed,
+	}
+
+	/// Queries sent to the project's parachain to check that it can receive the migrations.
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct PalletMigrationReadinessCheck {
+		/// The sovereign account of Polimec holds the mainnet tokens to distribute.
+		pub holding_check: (QueryId, CheckOutcome),
+		/// The receiver pallet is part of the runtime.
+		pub pallet_check: (QueryId, CheckOutcome),
+	}
+	impl PalletMigrationReadinessCheck {
+		pub fn is_ready(&self) -> bool {
+			self.holding_check.1 == CheckOutcome::Passed(None) &&
+				matches!(self.pallet_check.1, CheckOutcome::Passed(Some(_)))
+		}
+
+		pub fn receiver_pallet_index(&self) -> Option<PalletIndex> {
+			match self.pallet_check.1 {
+				CheckOutcome::Passed(maybe_index) => maybe_index,
+				_ => None,
+			}
+		}
+	}
+
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	pub struct PalletMigrationInfo {
+		/// ParaId of project
+		pub parachain_id: ParaId,
+		/// HRMP Channel status
+		pub hrmp_channel_status: HRMPChannelStatus,
+		/// Migration readiness check
+		pub migration_readiness_check: Option<PalletMigrationReadinessCheck>,
+	}
 }
 
 pub mod inner {
@@ -481,6 +729,16 @@ pub mod inner {
 		// The combination of decimals and price of this project is not representable within our 6 decimals USD system,
 		// and integer space of 128 bits.
 		BadTokenomics,
+		/// The parameters of the sale mode are not valid.
+		SaleModeError,
+		/// The contribution token vesting is not valid.
+		CtVestingError,
+		/// The funding milestones are not valid.
+		FundingMilestonesError,
+		/// The success thresholds are outside the bounds set by governance.
+		SuccessThresholdError,
+		/// The account type of the participants is not valid.
+		ParticipantsAccountTypeError,
 	}
 
 	#[derive(
@@ -622,12 +880,16 @@ pub mod inner {
 		Application,
 		EvaluationRound,
 		AuctionRound,
+		CommunityRound,
 		FundingFailed,
 		FundingSuccessful,
 		SettlementStarted(FundingOutcome),
 		SettlementFinished(FundingOutcome),
 		CTMigrationStarted,
 		CTMigrationFinished,
+		/// The raise ended between `IssuerDecisionThreshold` and `FundingSuccessThreshold`. The issuer has until the
+		/// end of the round to accept or reject the funding.
+		AwaitingIssuerDecision,
 	}
 
 	#[derive(
@@ -733,7 +995,66 @@ pub mod inner {
 		pub normal_evaluator_total_bonded_usd: Balance,
 	}
 
-	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	/// Fees charged on the funds raised by a project, and how the contribution tokens they are paid with are shared.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, DecodeWithMemTracking)]
+	pub struct FeeConfig {
+		/// Fee charged on each bracket of the funds raised, with the size of the bracket in USD. The last bracket
+		/// charges the rest of the funds, so its size is `Balance::MAX`.
+		pub fee_brackets: BoundedVec<(Percent, Balance), ConstU32<MAX_FEE_BRACKETS>>,
+		/// Share of the fees rewarded to the evaluators.
+		pub evaluator_rewards: Perquintill,
+		/// Share of the evaluator rewards that only goes to the early evaluators.
+		pub early_evaluator_rewards: Perquintill,
+		/// Share of the evaluator rewards that goes to all the evaluators.
+		pub normal_evaluator_rewards: Perquintill,
+		/// Share of the fees that goes to the liquidity pools.
+		pub liquidity_pools: Perquintill,
+		/// Share of the fees that goes to the long term holders.
+		pub long_term_holders: Perquintill,
+	}
+
+	impl Default for FeeConfig {
+		fn default() -> Self {
+			Self {
+				fee_brackets: BoundedVec::truncate_from(vec![
+					(Percent::from_percent(10), 1_000_000 * USD_UNIT),
+					(Percent::from_percent(8), 4_000_000 * USD_UNIT),
+					(Percent::from_percent(6), Balance::MAX),
+				]),
+				evaluator_rewards: Perquintill::from_percent(30),
+				early_evaluator_rewards: Perquintill::from_percent(20),
+				normal_evaluator_rewards: Perquintill::from_percent(80),
+				liquidity_pools: Perquintill::from_percent(50),
+				long_term_holders: Perquintill::from_percent(20),
+			}
+		}
+	}
+
+	impl FeeConfig {
+		/// Validate the fee configuration for the following checks:
+		/// - The fees don't increase from one bracket to the next, and no bracket is empty
+		/// - The last bracket charges all the remaining funds
+		/// - The shares of the fees, and the shares of the evaluator rewards, add up to 100% at most
+		pub fn is_valid(&self) -> bool {
+			let brackets_descend = self.fee_brackets.windows(2).all(|pair| pair[0].0 >= pair[1].0);
+			let brackets_not_empty = self.fee_brackets.iter().all(|(_fee, size)| !size.is_zero());
+			let last_bracket_unbounded = self.fee_brackets.last().is_some_and(|(_fee, size)| *size == Balance::MAX);
+
+			let total_share =
+				|shares: &[Perquintill]| shares.iter().map(|share| u128::from(share.deconstruct())).sum::<u128>();
+			let accuracy = u128::from(Perquintill::ACCURACY);
+			let fee_shares_fit =
+				total_share(&[self.evaluator_rewards, self.liquidity_pools, self.long_term_holders]) <= accuracy;
+			let evaluator_shares_fit =
+				total_share(&[self.early_evaluator_rewards, self.normal_evaluator_rewards]) <= accuracy;
+
+			brackets_descend && brackets_not_empty && last_bracket_unbounded && fee_shares_fit && evaluator_shares_fit
+		}
+	}
+
+	#[derive(
+		Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, DecodeWithMemTracking,
+	)]
 	pub enum FundingOutcomeDecision {
 		AcceptFunding,
 		RejectFunding,
@@ -775,9 +1096,18 @@ pub mod inner {
 		}
 	}
 
+	/// A single bid of a batch placed with `bid_batch`. All the entries of a batch share the credential of the caller.
+	#[derive(
+		Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, DecodeWithMemTracking,
+	)]
+	pub struct BidBatchEntry {
+		pub ct_amount: Balance,
+		pub mode: ParticipationMode,
+		pub funding_asset: AcceptedFundingAsset,
+	}
+
 	#[derive(
 		Clone,
-		Copy,
 		Encode,
 		Decode,
 		Eq,
@@ -792,7 +1122,13 @@ pub mod inner {
 	pub enum ParticipantsAccountType {
 		Polkadot,
 		Ethereum,
+		Solana,
+		/// Cosmos SDK chain, with the human readable part of its bech32 addresses (e.g. `cosmos`, `osmo`).
+		Cosmos {
+			bech32_prefix: BoundedVec<u8, ConstU32<MAX_BECH32_PREFIX_LENGTH>>,
+		},
 	}
+
 	impl ParticipantsAccountType {
 		pub const fn junction_is_supported(&self, junction: &Junction) -> bool {
 			match self {
@@ -800,6 +1136,126 @@ pub mod inner {
 				ParticipantsAccountType::Polkadot => matches!(junction, Junction::AccountId32 { .. }),
 				// This project expects users to submit a 20 byte account, and sign it with ECDSA secp256k1 crypto
 				ParticipantsAccountType::Ethereum => matches!(junction, Junction::AccountKey20 { .. }),
+				// This project expects users to submit a 32 byte ed25519 public key, and sign an off-chain message
+				ParticipantsAccountType::Solana => matches!(junction, Junction::AccountId32 { .. }),
+				// This project expects users to submit the 20 bytes behind a bech32 address, and sign an ADR-036
+				// document with secp256k1 crypto
+				ParticipantsAccountType::Cosmos { .. } => matches!(junction, Junction::AccountKey20 { .. }),
+			}
+		}
+
+		/// The human readable part of a bech32 address has to be non-empty and lowercase alphanumeric.
+		pub fn is_valid(&self) -> bool {
+			match self {
+				ParticipantsAccountType::Cosmos { bech32_prefix } =>
+					!bech32_prefix.is_empty() &&
+						bech32_prefix.iter().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit()),
+				_ => true,
+			}
+		}
+	}
+
+	/// Proof that a DID is part of the allowlist of a project. The leaf is the keccak hash of the SCALE encoded
+	/// `(did, usd_cap)`, and pairs of nodes are sorted before being hashed together.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, DecodeWithMemTracking)]
+	pub struct AllowlistProof {
+		/// Maximum USD amount the DID can spend on bids for this project.
+		pub usd_cap: Balance,
+		/// Sibling hashes from the leaf up to the root.
+		pub proof: BoundedVec<[u8; 32], ConstU32<MAX_ALLOWLIST_PROOF_LENGTH>>,
+	}
+
+	/// Cliff and linear vesting of the contribution tokens bought by participants. Both durations are in blocks,
+	/// counted from the project's `funding_end_block`, which is the block its settlement started.
+	#[derive(
+		Clone,
+		Copy,
+		Encode,
+		Decode,
+		Eq,
+		PartialEq,
+		RuntimeDebug,
+		TypeInfo,
+		MaxEncodedLen,
+		Serialize,
+		Deserialize,
+		DecodeWithMemTracking,
+	)]
+	pub struct ContributionTokenVesting {
+		/// Blocks during which nothing can be claimed.
+		pub cliff: u32,
+		/// Blocks after the cliff over which the tokens are released linearly.
+		pub duration: u32,
+	}
+
+	impl ContributionTokenVesting {
+		pub fn is_valid(&self) -> bool {
+			self.duration > 0
+		}
+
+		/// Blocks until all the tokens are vested.
+		pub fn total_duration(&self) -> u32 {
+			self.cliff.saturating_add(self.duration)
+		}
+
+		/// Amount of `total_amount` that is vested after `blocks_elapsed` blocks since the start of the vesting.
+		pub fn vested_amount(&self, total_amount: Balance, blocks_elapsed: u32) -> Balance {
+			if blocks_elapsed <= self.cliff {
+				return Zero::zero();
+			}
+			let vesting_blocks_elapsed = blocks_elapsed.saturating_sub(self.cliff).min(self.duration);
+			Perquintill::from_rational(vesting_blocks_elapsed, self.duration) * total_amount
+		}
+	}
+
+	/// The allocation model used in the auction round.
+	#[derive(
+		Clone,
+		Copy,
+		Default,
+		Encode,
+		Decode,
+		Eq,
+		PartialEq,
+		RuntimeDebug,
+		TypeInfo,
+		MaxEncodedLen,
+		Serialize,
+		Deserialize,
+		DecodeWithMemTracking,
+	)]
+	pub enum SaleMode<Price> {
+		/// Ascending price auction. Bids move the price up through buckets, and the lowest bids get outbid once the
+		/// allocation is sold out.
+		#[default]
+		Auction,
+		/// Fixed price sale at the minimum price. If more tokens are bid than available, every bidder gets
+		/// `allocation * their_usd / total_usd` tokens and the rest is refunded.
+		ProRata,
+		/// Descending price auction. The price starts at `ceiling_price` and drops by `price_step` every
+		/// `blocks_per_step` blocks until the minimum price. The round ends as soon as the allocation is sold out, and
+		/// every bidder pays the price at that point.
+		DutchAuction { ceiling_price: Price, price_step: Price, blocks_per_step: u32 },
+	}
+
+	impl<Price: FixedPointNumber> SaleMode<Price> {
+		pub fn is_valid(&self, minimum_price: Price) -> bool {
+			match self {
+				SaleMode::Auction | SaleMode::ProRata => true,
+				SaleMode::DutchAuction { ceiling_price, price_step, blocks_per_step } =>
+					*ceiling_price > minimum_price && !price_step.is_zero() && *blocks_per_step > 0,
+			}
+		}
+
+		/// How the price of the auction round moves for this sale mode.
+		pub fn price_strategy(&self) -> PriceStrategy<Price> {
+			match self {
+				SaleMode::Auction | SaleMode::ProRata => PriceStrategy::Ascending,
+				SaleMode::DutchAuction { ceiling_price, price_step, blocks_per_step } => PriceStrategy::Descending {
+					ceiling_price: *ceiling_price,
+					price_step: *price_step,
+					blocks_per_step: *blocks_per_step,
+				},
 			}
 		}
 	}
@@ -821,6 +1277,30 @@ pub mod extrinsic {
 		pub investor_type: InvestorType,
 		pub whitelisted_policy: Cid,
 		pub receiving_account: Junction,
+		pub max_price: Option<PriceOf<T>>,
+	}
+
+	pub struct DoBidBatchParams<T: Config> {
+		pub bidder: AccountIdOf<T>,
+		pub project_id: ProjectId,
+		pub entries: BoundedVec<BidBatchEntry, ConstU32<MAX_BID_BATCH_SIZE>>,
+		pub did: Did,
+		pub investor_type: InvestorType,
+		pub whitelisted_policy: Cid,
+		pub receiving_account: Junction,
+	}
+
+	pub struct DoScheduleBidParams<T: Config> {
+		pub bidder: AccountIdOf<T>,
+		pub project_id: ProjectId,
+		pub ct_amount: Balance,
+		pub mode: ParticipationMode,
+		pub funding_asset: AcceptedFundingAsset,
+		pub did: Did,
+		pub investor_type: InvestorType,
+		pub whitelisted_policy: Cid,
+		pub max_price: PriceOf<T>,
+		pub execution_block: BlockNumberFor<T>,
 	}
 
 	pub struct DoPerformBidParams<T: Config> {
@@ -838,6 +1318,18 @@ pub mod extrinsic {
 		pub auction_oversubscribed: bool,
 	}
 
+	pub struct DoContributeParams<T: Config> {
+		pub contributor: AccountIdOf<T>,
+		pub project_id: ProjectId,
+		pub ct_amount: Balance,
+		pub mode: ParticipationMode,
+		pub funding_asset: AcceptedFundingAsset,
+		pub did: Did,
+		pub investor_type: InvestorType,
+		pub whitelisted_policy: Cid,
+		pub receiving_account: Junction,
+	}
+
 	pub struct BidRefund {
 		pub final_ct_amount: Balance,
 		pub refunded_plmc: Balance,
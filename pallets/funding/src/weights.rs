@@ -56,19 +56,48 @@ pub trait WeightInfo {
 	fn create_project() -> Weight;
 	fn remove_project() -> Weight;
 	fn edit_project() -> Weight;
+	fn set_allowlist() -> Weight;
 	fn start_evaluation() -> Weight;
 	fn evaluate() -> Weight;
+	fn withdraw_evaluation(x: u32, ) -> Weight;
 	fn end_evaluation_failure() -> Weight;
 	fn bid(x: u32, ) -> Weight;
+	fn contribute() -> Weight;
+	fn cancel_bid(x: u32, ) -> Weight;
 	fn process_next_oversubscribed_bid() -> Weight;
 	fn end_funding_project_successful() -> Weight;
+	fn decide_project_outcome() -> Weight;
 	fn start_settlement() -> Weight;
 	fn settle_rewarded_evaluation() -> Weight;
 	fn settle_accepted_bid_with_refund() -> Weight;
+	fn settle_contribution() -> Weight;
+	fn claim_vested_contribution_tokens() -> Weight;
+	fn decide_funding_milestone() -> Weight;
+	fn claim_milestone_refund() -> Weight;
 	fn mark_project_as_settled() -> Weight;
 	fn start_offchain_migration() -> Weight;
 	fn confirm_offchain_migration(x: u32, ) -> Weight;
 	fn mark_project_ct_migration_as_finished() -> Weight;
+	fn start_pallet_migration() -> Weight;
+	fn start_pallet_migration_readiness_check() -> Weight;
+	fn pallet_migration_readiness_response() -> Weight;
+	fn send_pallet_migration_for() -> Weight;
+	fn confirm_pallet_migrations() -> Weight;
+	fn fail_timed_out_pallet_migration() -> Weight;
+	fn submit_migration_confirmation_root() -> Weight;
+	fn prove_offchain_migration() -> Weight;
+	fn dispute_migration() -> Weight;
+	fn resolve_migration_dispute() -> Weight;
+	fn set_funding_asset() -> Weight;
+	fn propose_project_transfer() -> Weight;
+	fn accept_project_transfer() -> Weight;
+	fn halt_project() -> Weight;
+	fn resume_project() -> Weight;
+	fn force_project_failure() -> Weight;
+	fn set_fee_config() -> Weight;
+	fn bind_receiving_account() -> Weight;
+	fn schedule_bid() -> Weight;
+	fn cancel_scheduled_bid() -> Weight;
 }
 
 /// Weights for `pallet_funding` using the Substrate node and recommended hardware.
@@ -79,7 +108,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Funding::NextProjectId` (r:1 w:1)
 	/// Proof: `Funding::NextProjectId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::DidWithActiveProjects` (r:1 w:1)
-	/// Proof: `Funding::DidWithActiveProjects` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Proof: `Funding::DidWithActiveProjects` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::Buckets` (r:0 w:1)
@@ -106,7 +135,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Funding::ProjectsMetadata` (r:0 w:1)
 	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::DidWithActiveProjects` (r:0 w:1)
-	/// Proof: `Funding::DidWithActiveProjects` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Proof: `Funding::DidWithActiveProjects` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
 	fn remove_project() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `477`
@@ -133,20 +162,37 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectAllowlists` (r:0 w:1)
+	/// Proof: `Funding::ProjectAllowlists` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn set_allowlist() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `477`
+		//  Estimated: `3764`
+		// Minimum execution time: 41_230_000 picoseconds.
+		Weight::from_parts(42_110_000, 3764)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
 	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
 	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::FeeConfiguration` (r:1 w:0)
+	/// Proof: `Funding::FeeConfiguration` (`max_values`: Some(1), `max_size`: Some(211), added: 706, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectFeeConfigs` (r:0 w:1)
+	/// Proof: `Funding::ProjectFeeConfigs` (`max_values`: None, `max_size`: Some(231), added: 2706, mode: `MaxEncodedLen`)
 	fn start_evaluation() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `741`
 		//  Estimated: `3902`
 		// Minimum execution time: 128_341_000 picoseconds.
 		Weight::from_parts(129_920_000, 3902)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
@@ -162,26 +208,57 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(175), added: 2650, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::Evaluations` (r:0 w:1)
 	/// Proof: `Funding::Evaluations` (`max_values`: None, `max_size`: Some(337), added: 2812, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ReceivingAccounts` (r:1 w:0)
+	/// Proof: `Funding::ReceivingAccounts` (`max_values`: None, `max_size`: Some(136), added: 2611, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::EvaluationBondedUSD` (r:1 w:1)
+	/// Proof: `Funding::EvaluationBondedUSD` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
 	fn evaluate() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1128`
 		//  Estimated: `4099`
 		// Minimum execution time: 176_340_000 picoseconds.
 		Weight::from_parts(178_631_000, 4099)
-			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Evaluations` (r:101 w:101)
+	/// Proof: `Funding::Evaluations` (`max_values`: None, `max_size`: Some(337), added: 2812, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(175), added: 2650, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::EvaluationBondedUSD` (r:1 w:1)
+	/// Proof: `Funding::EvaluationBondedUSD` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 100]`.
+	fn withdraw_evaluation(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1386 + x * (201 ±0)`
+		//  Estimated: `4099 + x * (2812 ±0)`
+		// Minimum execution time: 63_410_000 picoseconds.
+		Weight::from_parts(57_854_102, 4099)
+			// Standard Error: 7_236
+			.saturating_add(Weight::from_parts(9_214_883, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2812).saturating_mul(x.into()))
 	}
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
 	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::DidWithActiveProjects` (r:0 w:1)
-	/// Proof: `Funding::DidWithActiveProjects` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Proof: `Funding::DidWithActiveProjects` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
 	fn end_evaluation_failure() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `442`
-		//  Estimated: `3764`
+		//  Estimated: `3902`
 		// Minimum execution time: 11_460_000 picoseconds.
-		Weight::from_parts(11_790_000, 3764)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
+		Weight::from_parts(11_790_000, 3902)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Timestamp::Now` (r:1 w:0)
@@ -215,6 +292,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Funding::Bids` (r:0 w:10)
 	/// Proof: `Funding::Bids` (`max_values`: None, `max_size`: Some(309), added: 2784, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[1, 10]`.
+	/// Storage: `Funding::ReceivingAccounts` (r:1 w:0)
+	/// Proof: `Funding::ReceivingAccounts` (`max_values`: None, `max_size`: Some(136), added: 2611, mode: `MaxEncodedLen`)
 	fn bid(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2895`
@@ -223,7 +302,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(171_401_794, 7404)
 			// Standard Error: 69_499
 			.saturating_add(Weight::from_parts(78_333_423, 0).saturating_mul(x.into()))
-			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().reads(16_u64))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
 			.saturating_add(T::DbWeight::get().writes(8_u64))
 			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(x.into())))
@@ -231,6 +310,78 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
 	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Buckets` (r:1 w:0)
+	/// Proof: `Funding::Buckets` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::NextContributionId` (r:1 w:1)
+	/// Proof: `Funding::NextContributionId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::AuctionBoughtUSD` (r:1 w:1)
+	/// Proof: `Funding::AuctionBoughtUSD` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Oracle::Values` (r:2 w:0)
+	/// Proof: `Oracle::Values` (`max_values`: None, `max_size`: Some(634), added: 3109, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Metadata` (r:1 w:0)
+	/// Proof: `ForeignAssets::Metadata` (`max_values`: None, `max_size`: Some(738), added: 3213, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Evaluations` (r:1 w:0)
+	/// Proof: `Funding::Evaluations` (`max_values`: None, `max_size`: Some(337), added: 2812, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(175), added: 2650, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:2 w:2)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Contributions` (r:0 w:1)
+	/// Proof: `Funding::Contributions` (`max_values`: None, `max_size`: Some(305), added: 2780, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ReceivingAccounts` (r:1 w:0)
+	/// Proof: `Funding::ReceivingAccounts` (`max_values`: None, `max_size`: Some(136), added: 2611, mode: `MaxEncodedLen`)
+	fn contribute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2410`
+		//  Estimated: `7404`
+		// Minimum execution time: 151_320_000 picoseconds.
+		Weight::from_parts(154_880_000, 7404)
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Buckets` (r:1 w:1)
+	/// Proof: `Funding::Buckets` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Bids` (r:11 w:11)
+	/// Proof: `Funding::Bids` (`max_values`: None, `max_size`: Some(309), added: 2784, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:2 w:2)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(175), added: 2650, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::AuctionBoughtUSD` (r:1 w:1)
+	/// Proof: `Funding::AuctionBoughtUSD` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::CTAmountOversubscribed` (r:1 w:1)
+	/// Proof: `Funding::CTAmountOversubscribed` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::OutbidBidsCutoffs` (r:10 w:10)
+	/// Proof: `Funding::OutbidBidsCutoffs` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::BidsBucketBounds` (r:11 w:1)
+	/// Proof: `Funding::BidsBucketBounds` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 10]`.
+	fn cancel_bid(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3412`
+		//  Estimated: `7404 + x * (2784 ±0)`
+		// Minimum execution time: 98_410_000 picoseconds.
+		Weight::from_parts(101_522_317, 7404)
+			// Standard Error: 41_274
+			.saturating_add(Weight::from_parts(21_845_902, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2784).saturating_mul(x.into()))
+	}
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::Buckets` (r:1 w:0)
 	/// Proof: `Funding::Buckets` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::CTAmountOversubscribed` (r:1 w:1)
@@ -261,14 +412,33 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Funding::ProjectsInAuctionRound` (r:0 w:1)
 	/// Proof: `Funding::ProjectsInAuctionRound` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::DidWithActiveProjects` (r:0 w:1)
-	/// Proof: `Funding::DidWithActiveProjects` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Proof: `Funding::DidWithActiveProjects` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectFeeConfigs` (r:1 w:0)
+	/// Proof: `Funding::ProjectFeeConfigs` (`max_values`: None, `max_size`: Some(231), added: 2706, mode: `MaxEncodedLen`)
 	fn end_funding_project_successful() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `932`
 		//  Estimated: `3902`
 		// Minimum execution time: 32_650_000 picoseconds.
 		Weight::from_parts(33_770_000, 3902)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Evaluations` (r:1 w:0)
+	/// Proof: `Funding::Evaluations` (`max_values`: None, `max_size`: Some(337), added: 2812, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectTransitionQueue` (r:0 w:2)
+	/// Proof: `Funding::ProjectTransitionQueue` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectFeeConfigs` (r:1 w:0)
+	/// Proof: `Funding::ProjectFeeConfigs` (`max_values`: None, `max_size`: Some(231), added: 2706, mode: `MaxEncodedLen`)
+	fn decide_project_outcome() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1094`
+		//  Estimated: `3802`
+		// Minimum execution time: 31_270_000 picoseconds.
+		Weight::from_parts(32_441_000, 3802)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
@@ -285,13 +455,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Funding::Buckets` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `ProxyBonding::Releases` (r:0 w:1)
 	/// Proof: `ProxyBonding::Releases` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectFeeConfigs` (r:1 w:0)
+	/// Proof: `Funding::ProjectFeeConfigs` (`max_values`: None, `max_size`: Some(231), added: 2706, mode: `MaxEncodedLen`)
 	fn start_settlement() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `964`
 		//  Estimated: `3902`
 		// Minimum execution time: 74_790_000 picoseconds.
 		Weight::from_parts(76_751_000, 3902)
-			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Funding::Evaluations` (r:1 w:1)
@@ -352,6 +524,96 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(16_u64))
 			.saturating_add(T::DbWeight::get().writes(11_u64))
 	}
+	/// Storage: `Funding::Contributions` (r:1 w:1)
+	/// Proof: `Funding::Contributions` (`max_values`: None, `max_size`: Some(305), added: 2780, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:2 w:2)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(175), added: 2650, mode: `MaxEncodedLen`)
+	/// Storage: `ContributionTokens::Account` (r:1 w:1)
+	/// Proof: `ContributionTokens::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `ContributionTokens::Asset` (r:1 w:1)
+	/// Proof: `ContributionTokens::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::UserMigrations` (r:1 w:1)
+	/// Proof: `Funding::UserMigrations` (`max_values`: None, `max_size`: Some(1000071), added: 1002546, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::UnmigratedCounter` (r:1 w:1)
+	/// Proof: `Funding::UnmigratedCounter` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn settle_contribution() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3512`
+		//  Estimated: `1003536`
+		// Minimum execution time: 187_430_000 picoseconds.
+		Weight::from_parts(191_020_000, 1003536)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ContributionTokenVestings` (r:1 w:1)
+	/// Proof: `Funding::ContributionTokenVestings` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `ContributionTokens::Account` (r:2 w:2)
+	/// Proof: `ContributionTokens::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `ContributionTokens::Asset` (r:1 w:1)
+	/// Proof: `ContributionTokens::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
+	fn claim_vested_contribution_tokens() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1402`
+		//  Estimated: `6208`
+		// Minimum execution time: 98_341_000 picoseconds.
+		Weight::from_parts(100_120_000, 6208)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::MilestoneOutcomes` (r:2 w:1)
+	/// Proof: `Funding::MilestoneOutcomes` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::MilestoneEscrowTotals` (r:4 w:0)
+	/// Proof: `Funding::MilestoneEscrowTotals` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:4 w:4)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(840), added: 3315, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:8 w:8)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	fn decide_funding_milestone() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3418`
+		//  Estimated: `26646`
+		// Minimum execution time: 412_130_000 picoseconds.
+		Weight::from_parts(418_902_000, 26646)
+			.saturating_add(T::DbWeight::get().reads(20_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
+	}
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::MilestoneEscrowContributions` (r:5 w:0)
+	/// Proof: `Funding::MilestoneEscrowContributions` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::MilestoneOutcomes` (r:1 w:0)
+	/// Proof: `Funding::MilestoneOutcomes` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::MilestoneRefundsClaimed` (r:1 w:1)
+	/// Proof: `Funding::MilestoneRefundsClaimed` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:4 w:4)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(840), added: 3315, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:8 w:8)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	fn claim_milestone_refund() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3602`
+		//  Estimated: `26646`
+		// Minimum execution time: 421_560_000 picoseconds.
+		Weight::from_parts(427_311_000, 26646)
+			.saturating_add(T::DbWeight::get().reads(20_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
+	}
 	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
 	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::Evaluations` (r:1 w:0)
@@ -411,128 +673,511 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-}
-
-// For backwards compatibility and tests.
-impl WeightInfo for () {
-	/// Storage: `Timestamp::Now` (r:1 w:0)
-	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-	/// Storage: `Funding::NextProjectId` (r:1 w:1)
-	/// Proof: `Funding::NextProjectId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-	/// Storage: `Funding::DidWithActiveProjects` (r:1 w:1)
-	/// Proof: `Funding::DidWithActiveProjects` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
-	/// Storage: `System::Account` (r:1 w:1)
-	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// Storage: `Funding::Buckets` (r:0 w:1)
-	/// Proof: `Funding::Buckets` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
-	/// Storage: `Funding::ProjectsMetadata` (r:0 w:1)
-	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
-	/// Storage: `Funding::ProjectsDetails` (r:0 w:1)
-	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
-	fn create_project() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `288`
-		//  Estimated: `3593`
-		// Minimum execution time: 162_070_000 picoseconds.
-		Weight::from_parts(164_050_000, 3593)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
-	}
-	/// Storage: `Timestamp::Now` (r:1 w:0)
-	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
 	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
-	/// Storage: `Funding::Buckets` (r:0 w:1)
-	/// Proof: `Funding::Buckets` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
-	/// Storage: `Funding::ProjectsMetadata` (r:0 w:1)
-	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
-	/// Storage: `Funding::DidWithActiveProjects` (r:0 w:1)
-	/// Proof: `Funding::DidWithActiveProjects` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
-	fn remove_project() -> Weight {
+	/// Storage: `Funding::PalletMigrations` (r:1 w:1)
+	/// Proof: `Funding::PalletMigrations` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn start_pallet_migration() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `477`
+		//  Measured:  `710`
 		//  Estimated: `3764`
-		// Minimum execution time: 129_501_000 picoseconds.
-		Weight::from_parts(130_701_000, 3764)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+		// Minimum execution time: 37_250_000 picoseconds.
+		Weight::from_parts(38_930_000, 3764)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
-	/// Storage: `Timestamp::Now` (r:1 w:0)
-	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
 	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
-	/// Storage: `Funding::Buckets` (r:0 w:1)
-	/// Proof: `Funding::Buckets` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
-	/// Storage: `Funding::ProjectsMetadata` (r:0 w:1)
-	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
-	fn edit_project() -> Weight {
+	/// Storage: `Funding::PalletMigrations` (r:1 w:1)
+	/// Proof: `Funding::PalletMigrations` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `PolkadotXcm::QueryCounter` (r:1 w:1)
+	/// Proof: `PolkadotXcm::QueryCounter` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ContributionTokens::Asset` (r:1 w:0)
+	/// Proof: `ContributionTokens::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `PolkadotXcm::SupportedVersion` (r:1 w:0)
+	/// Proof: `PolkadotXcm::SupportedVersion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PolkadotXcm::Queries` (r:0 w:2)
+	/// Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn start_pallet_migration_readiness_check() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `477`
-		//  Estimated: `3764`
-		// Minimum execution time: 130_171_000 picoseconds.
-		Weight::from_parts(132_020_000, 3764)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		//  Measured:  `1162`
+		//  Estimated: `4627`
+		// Minimum execution time: 61_480_000 picoseconds.
+		Weight::from_parts(63_710_000, 4627)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
-	/// Storage: `Timestamp::Now` (r:1 w:0)
-	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
-	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
-	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Storage: `Funding::PalletMigrations` (r:1 w:1)
+	/// Proof: `Funding::PalletMigrations` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `ContributionTokens::Asset` (r:1 w:0)
+	/// Proof: `ContributionTokens::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	fn pallet_migration_readiness_response() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `802`
+		//  Estimated: `3675`
+		// Minimum execution time: 24_370_000 picoseconds.
+		Weight::from_parts(25_410_000, 3675)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
 	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
-	fn start_evaluation() -> Weight {
+	/// Storage: `Funding::PalletMigrations` (r:1 w:0)
+	/// Proof: `Funding::PalletMigrations` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::UserMigrations` (r:1 w:0)
+	/// Proof: `Funding::UserMigrations` (`max_values`: None, `max_size`: Some(1000071), added: 1002546, mode: `MaxEncodedLen`)
+	/// Storage: `PolkadotXcm::QueryCounter` (r:1 w:1)
+	/// Proof: `PolkadotXcm::QueryCounter` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `PolkadotXcm::SupportedVersion` (r:1 w:0)
+	/// Proof: `PolkadotXcm::SupportedVersion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PolkadotXcm::Queries` (r:0 w:1)
+	/// Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::ActiveMigrationQueue` (r:0 w:1)
+	/// Proof: `Funding::ActiveMigrationQueue` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn send_pallet_migration_for() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `741`
-		//  Estimated: `3902`
-		// Minimum execution time: 128_341_000 picoseconds.
-		Weight::from_parts(129_920_000, 3902)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Measured:  `1596`
+		//  Estimated: `1003536`
+		// Minimum execution time: 58_120_000 picoseconds.
+		Weight::from_parts(60_390_000, 1003536)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
-	/// Storage: `Timestamp::Now` (r:1 w:0)
-	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
-	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ActiveMigrationQueue` (r:1 w:1)
+	/// Proof: `Funding::ActiveMigrationQueue` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
 	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
-	/// Storage: `Funding::NextEvaluationId` (r:1 w:1)
-	/// Proof: `Funding::NextEvaluationId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-	/// Storage: `Oracle::Values` (r:1 w:0)
-	/// Proof: `Oracle::Values` (`max_values`: None, `max_size`: Some(634), added: 3109, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Holds` (r:1 w:1)
-	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(175), added: 2650, mode: `MaxEncodedLen`)
-	/// Storage: `Funding::Evaluations` (r:0 w:1)
-	/// Proof: `Funding::Evaluations` (`max_values`: None, `max_size`: Some(337), added: 2812, mode: `MaxEncodedLen`)
-	fn evaluate() -> Weight {
+	/// Storage: `Funding::PalletMigrations` (r:1 w:0)
+	/// Proof: `Funding::PalletMigrations` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::UserMigrations` (r:1 w:1)
+	/// Proof: `Funding::UserMigrations` (`max_values`: None, `max_size`: Some(1000071), added: 1002546, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::UnmigratedCounter` (r:1 w:1)
+	/// Proof: `Funding::UnmigratedCounter` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn confirm_pallet_migrations() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1128`
-		//  Estimated: `4099`
-		// Minimum execution time: 176_340_000 picoseconds.
-		Weight::from_parts(178_631_000, 4099)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+		//  Measured:  `1488`
+		//  Estimated: `1003536`
+		// Minimum execution time: 36_940_000 picoseconds.
+		Weight::from_parts(38_260_000, 1003536)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	/// Storage: `Funding::ActiveMigrationQueue` (r:1 w:1)
+	/// Proof: `Funding::ActiveMigrationQueue` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
 	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
-	/// Storage: `Funding::DidWithActiveProjects` (r:0 w:1)
-	/// Proof: `Funding::DidWithActiveProjects` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
-	fn end_evaluation_failure() -> Weight {
+	/// Storage: `Funding::UserMigrations` (r:1 w:1)
+	/// Proof: `Funding::UserMigrations` (`max_values`: None, `max_size`: Some(1000071), added: 1002546, mode: `MaxEncodedLen`)
+	fn fail_timed_out_pallet_migration() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `442`
-		//  Estimated: `3764`
-		// Minimum execution time: 11_460_000 picoseconds.
-		Weight::from_parts(11_790_000, 3764)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		//  Measured:  `1452`
+		//  Estimated: `1003536`
+		// Minimum execution time: 31_150_000 picoseconds.
+		Weight::from_parts(32_540_000, 1003536)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
-	/// Storage: `Timestamp::Now` (r:1 w:0)
-	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
-	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
 	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
-	/// Storage: `Funding::Buckets` (r:1 w:1)
-	/// Proof: `Funding::Buckets` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::UnmigratedCounter` (r:1 w:0)
+	/// Proof: `Funding::UnmigratedCounter` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::MigrationConfirmationRoots` (r:2 w:1)
+	/// Proof: `Funding::MigrationConfirmationRoots` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::PalletMigrations` (r:1 w:0)
+	/// Proof: `Funding::PalletMigrations` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn submit_migration_confirmation_root() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1042`
+		//  Estimated: `6039`
+		// Minimum execution time: 27_310_000 picoseconds.
+		Weight::from_parts(28_640_000, 6039)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::UserMigrations` (r:1 w:1)
+	/// Proof: `Funding::UserMigrations` (`max_values`: None, `max_size`: Some(1000071), added: 1002546, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::MigrationConfirmationRoots` (r:1 w:1)
+	/// Proof: `Funding::MigrationConfirmationRoots` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::UnmigratedCounter` (r:1 w:1)
+	/// Proof: `Funding::UnmigratedCounter` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn prove_offchain_migration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1403`
+		//  Estimated: `1003536`
+		// Minimum execution time: 41_820_000 picoseconds.
+		Weight::from_parts(43_570_000, 1003536)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::UserMigrations` (r:1 w:1)
+	/// Proof: `Funding::UserMigrations` (`max_values`: None, `max_size`: Some(1000071), added: 1002546, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::MigrationConfirmedAt` (r:1 w:1)
+	/// Proof: `Funding::MigrationConfirmedAt` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::OpenMigrationDisputes` (r:1 w:1)
+	/// Proof: `Funding::OpenMigrationDisputes` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn dispute_migration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1398`
+		//  Estimated: `1003536`
+		// Minimum execution time: 32_460_000 picoseconds.
+		Weight::from_parts(33_810_000, 1003536)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Funding::UserMigrations` (r:1 w:1)
+	/// Proof: `Funding::UserMigrations` (`max_values`: None, `max_size`: Some(1000071), added: 1002546, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::UnmigratedCounter` (r:1 w:1)
+	/// Proof: `Funding::UnmigratedCounter` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::OpenMigrationDisputes` (r:1 w:1)
+	/// Proof: `Funding::OpenMigrationDisputes` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn resolve_migration_dispute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1322`
+		//  Estimated: `1003536`
+		// Minimum execution time: 29_170_000 picoseconds.
+		Weight::from_parts(30_520_000, 1003536)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Funding::FundingAssets` (r:17 w:1)
+	/// Proof: `Funding::FundingAssets` (`max_values`: None, `max_size`: Some(1081), added: 3556, mode: `MaxEncodedLen`)
+	fn set_funding_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1236`
+		//  Estimated: `61462`
+		// Minimum execution time: 41_330_000 picoseconds.
+		Weight::from_parts(43_050_000, 61462)
+			.saturating_add(T::DbWeight::get().reads(17_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::PendingProjectTransfers` (r:0 w:1)
+	/// Proof: `Funding::PendingProjectTransfers` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn propose_project_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1254`
+		//  Estimated: `3764`
+		// Minimum execution time: 23_410_000 picoseconds.
+		Weight::from_parts(24_380_000, 3764)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:1)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::PendingProjectTransfers` (r:1 w:1)
+	/// Proof: `Funding::PendingProjectTransfers` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::DidWithActiveProjects` (r:2 w:2)
+	/// Proof: `Funding::DidWithActiveProjects` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::EvaluationBondedUSD` (r:1 w:0)
+	/// Proof: `Funding::EvaluationBondedUSD` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::AuctionBoughtUSD` (r:1 w:0)
+	/// Proof: `Funding::AuctionBoughtUSD` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	fn accept_project_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1810`
+		//  Estimated: `6558`
+		// Minimum execution time: 44_630_000 picoseconds.
+		Weight::from_parts(46_150_000, 6558)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::HaltedProjects` (r:0 w:1)
+	/// Proof: `Funding::HaltedProjects` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	fn halt_project() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1254`
+		//  Estimated: `3764`
+		// Minimum execution time: 20_130_000 picoseconds.
+		Weight::from_parts(21_020_000, 3764)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Funding::HaltedProjects` (r:1 w:1)
+	/// Proof: `Funding::HaltedProjects` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	fn resume_project() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `178`
+		//  Estimated: `3551`
+		// Minimum execution time: 14_570_000 picoseconds.
+		Weight::from_parts(15_190_000, 3551)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::DidWithActiveProjects` (r:1 w:1)
+	/// Proof: `Funding::DidWithActiveProjects` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsInAuctionRound` (r:0 w:1)
+	/// Proof: `Funding::ProjectsInAuctionRound` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::HaltedProjects` (r:0 w:1)
+	/// Proof: `Funding::HaltedProjects` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectTransitionQueue` (r:0 w:1)
+	/// Proof: `Funding::ProjectTransitionQueue` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn force_project_failure() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1391`
+		//  Estimated: `3764`
+		// Minimum execution time: 36_850_000 picoseconds.
+		Weight::from_parts(38_110_000, 3764)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Funding::FeeConfiguration` (r:0 w:1)
+	/// Proof: `Funding::FeeConfiguration` (`max_values`: Some(1), `max_size`: Some(211), added: 706, mode: `MaxEncodedLen`)
+	fn set_fee_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_410_000 picoseconds.
+		Weight::from_parts(8_720_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ReceivingAccounts` (r:0 w:1)
+	/// Proof: `Funding::ReceivingAccounts` (`max_values`: None, `max_size`: Some(136), added: 2611, mode: `MaxEncodedLen`)
+	fn bind_receiving_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `574`
+		//  Estimated: `3902`
+		// Minimum execution time: 74_120_000 picoseconds.
+		Weight::from_parts(75_840_000, 3902)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::HaltedProjects` (r:1 w:0)
+	/// Proof: `Funding::HaltedProjects` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::FundingAssets` (r:1 w:0)
+	/// Proof: `Funding::FundingAssets` (`max_values`: None, `max_size`: Some(1081), added: 3556, mode: `MaxEncodedLen`)
+	/// Storage: `Oracle::Values` (r:2 w:0)
+	/// Proof: `Oracle::Values` (`max_values`: None, `max_size`: Some(634), added: 3109, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Metadata` (r:1 w:0)
+	/// Proof: `ForeignAssets::Metadata` (`max_values`: None, `max_size`: Some(738), added: 3213, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(175), added: 2650, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:2 w:2)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::NextScheduledBidId` (r:1 w:1)
+	/// Proof: `Funding::NextScheduledBidId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ScheduledBidQueue` (r:1 w:1)
+	/// Proof: `Funding::ScheduledBidQueue` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ScheduledBids` (r:15 w:1)
+	/// Proof: `Funding::ScheduledBids` (`max_values`: None, `max_size`: Some(294), added: 2769, mode: `MaxEncodedLen`)
+	fn schedule_bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7268`
+		//  Estimated: `48939`
+		// Minimum execution time: 160_720_000 picoseconds.
+		Weight::from_parts(163_480_000, 48939)
+			.saturating_add(T::DbWeight::get().reads(29_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Funding::ScheduledBids` (r:1 w:1)
+	/// Proof: `Funding::ScheduledBids` (`max_values`: None, `max_size`: Some(294), added: 2769, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ScheduledBidQueue` (r:1 w:1)
+	/// Proof: `Funding::ScheduledBidQueue` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::FundingAssets` (r:1 w:0)
+	/// Proof: `Funding::FundingAssets` (`max_values`: None, `max_size`: Some(1081), added: 3556, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:2 w:2)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(175), added: 2650, mode: `MaxEncodedLen`)
+	fn cancel_scheduled_bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1902`
+		//  Estimated: `7404`
+		// Minimum execution time: 96_210_000 picoseconds.
+		Weight::from_parts(98_040_000, 7404)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::NextProjectId` (r:1 w:1)
+	/// Proof: `Funding::NextProjectId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::DidWithActiveProjects` (r:1 w:1)
+	/// Proof: `Funding::DidWithActiveProjects` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Buckets` (r:0 w:1)
+	/// Proof: `Funding::Buckets` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsMetadata` (r:0 w:1)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsDetails` (r:0 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	fn create_project() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `288`
+		//  Estimated: `3593`
+		// Minimum execution time: 162_070_000 picoseconds.
+		Weight::from_parts(164_050_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Buckets` (r:0 w:1)
+	/// Proof: `Funding::Buckets` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsMetadata` (r:0 w:1)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::DidWithActiveProjects` (r:0 w:1)
+	/// Proof: `Funding::DidWithActiveProjects` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	fn remove_project() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `477`
+		//  Estimated: `3764`
+		// Minimum execution time: 129_501_000 picoseconds.
+		Weight::from_parts(130_701_000, 3764)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Buckets` (r:0 w:1)
+	/// Proof: `Funding::Buckets` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsMetadata` (r:0 w:1)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
+	fn edit_project() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `477`
+		//  Estimated: `3764`
+		// Minimum execution time: 130_171_000 picoseconds.
+		Weight::from_parts(132_020_000, 3764)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectAllowlists` (r:0 w:1)
+	/// Proof: `Funding::ProjectAllowlists` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn set_allowlist() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `477`
+		//  Estimated: `3764`
+		// Minimum execution time: 41_230_000 picoseconds.
+		Weight::from_parts(42_110_000, 3764)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::FeeConfiguration` (r:1 w:0)
+	/// Proof: `Funding::FeeConfiguration` (`max_values`: Some(1), `max_size`: Some(211), added: 706, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectFeeConfigs` (r:0 w:1)
+	/// Proof: `Funding::ProjectFeeConfigs` (`max_values`: None, `max_size`: Some(231), added: 2706, mode: `MaxEncodedLen`)
+	fn start_evaluation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `741`
+		//  Estimated: `3902`
+		// Minimum execution time: 128_341_000 picoseconds.
+		Weight::from_parts(129_920_000, 3902)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::NextEvaluationId` (r:1 w:1)
+	/// Proof: `Funding::NextEvaluationId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Oracle::Values` (r:1 w:0)
+	/// Proof: `Oracle::Values` (`max_values`: None, `max_size`: Some(634), added: 3109, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(175), added: 2650, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Evaluations` (r:0 w:1)
+	/// Proof: `Funding::Evaluations` (`max_values`: None, `max_size`: Some(337), added: 2812, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ReceivingAccounts` (r:1 w:0)
+	/// Proof: `Funding::ReceivingAccounts` (`max_values`: None, `max_size`: Some(136), added: 2611, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::EvaluationBondedUSD` (r:1 w:1)
+	/// Proof: `Funding::EvaluationBondedUSD` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	fn evaluate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1128`
+		//  Estimated: `4099`
+		// Minimum execution time: 176_340_000 picoseconds.
+		Weight::from_parts(178_631_000, 4099)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Evaluations` (r:101 w:101)
+	/// Proof: `Funding::Evaluations` (`max_values`: None, `max_size`: Some(337), added: 2812, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(175), added: 2650, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::EvaluationBondedUSD` (r:1 w:1)
+	/// Proof: `Funding::EvaluationBondedUSD` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 100]`.
+	fn withdraw_evaluation(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1386 + x * (201 ±0)`
+		//  Estimated: `4099 + x * (2812 ±0)`
+		// Minimum execution time: 63_410_000 picoseconds.
+		Weight::from_parts(57_854_102, 4099)
+			// Standard Error: 7_236
+			.saturating_add(Weight::from_parts(9_214_883, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2812).saturating_mul(x.into()))
+	}
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::DidWithActiveProjects` (r:0 w:1)
+	/// Proof: `Funding::DidWithActiveProjects` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	fn end_evaluation_failure() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `442`
+		//  Estimated: `3902`
+		// Minimum execution time: 11_460_000 picoseconds.
+		Weight::from_parts(11_790_000, 3902)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Buckets` (r:1 w:1)
+	/// Proof: `Funding::Buckets` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::NextBidId` (r:1 w:1)
 	/// Proof: `Funding::NextBidId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::BidsBucketBounds` (r:10 w:10)
@@ -556,6 +1201,8 @@ impl WeightInfo for () {
 	/// Storage: `Funding::Bids` (r:0 w:10)
 	/// Proof: `Funding::Bids` (`max_values`: None, `max_size`: Some(309), added: 2784, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[1, 10]`.
+	/// Storage: `Funding::ReceivingAccounts` (r:1 w:0)
+	/// Proof: `Funding::ReceivingAccounts` (`max_values`: None, `max_size`: Some(136), added: 2611, mode: `MaxEncodedLen`)
 	fn bid(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2895`
@@ -564,7 +1211,7 @@ impl WeightInfo for () {
 		Weight::from_parts(171_401_794, 7404)
 			// Standard Error: 69_499
 			.saturating_add(Weight::from_parts(78_333_423, 0).saturating_mul(x.into()))
-			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(x.into())))
 			.saturating_add(RocksDbWeight::get().writes(8_u64))
 			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(x.into())))
@@ -572,6 +1219,78 @@ impl WeightInfo for () {
 	}
 	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
 	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Buckets` (r:1 w:0)
+	/// Proof: `Funding::Buckets` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::NextContributionId` (r:1 w:1)
+	/// Proof: `Funding::NextContributionId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::AuctionBoughtUSD` (r:1 w:1)
+	/// Proof: `Funding::AuctionBoughtUSD` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Oracle::Values` (r:2 w:0)
+	/// Proof: `Oracle::Values` (`max_values`: None, `max_size`: Some(634), added: 3109, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Metadata` (r:1 w:0)
+	/// Proof: `ForeignAssets::Metadata` (`max_values`: None, `max_size`: Some(738), added: 3213, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Evaluations` (r:1 w:0)
+	/// Proof: `Funding::Evaluations` (`max_values`: None, `max_size`: Some(337), added: 2812, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(175), added: 2650, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:2 w:2)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Contributions` (r:0 w:1)
+	/// Proof: `Funding::Contributions` (`max_values`: None, `max_size`: Some(305), added: 2780, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ReceivingAccounts` (r:1 w:0)
+	/// Proof: `Funding::ReceivingAccounts` (`max_values`: None, `max_size`: Some(136), added: 2611, mode: `MaxEncodedLen`)
+	fn contribute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2410`
+		//  Estimated: `7404`
+		// Minimum execution time: 151_320_000 picoseconds.
+		Weight::from_parts(154_880_000, 7404)
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Buckets` (r:1 w:1)
+	/// Proof: `Funding::Buckets` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Bids` (r:11 w:11)
+	/// Proof: `Funding::Bids` (`max_values`: None, `max_size`: Some(309), added: 2784, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:2 w:2)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(175), added: 2650, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::AuctionBoughtUSD` (r:1 w:1)
+	/// Proof: `Funding::AuctionBoughtUSD` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::CTAmountOversubscribed` (r:1 w:1)
+	/// Proof: `Funding::CTAmountOversubscribed` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::OutbidBidsCutoffs` (r:10 w:10)
+	/// Proof: `Funding::OutbidBidsCutoffs` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::BidsBucketBounds` (r:11 w:1)
+	/// Proof: `Funding::BidsBucketBounds` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 10]`.
+	fn cancel_bid(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3412`
+		//  Estimated: `7404 + x * (2784 ±0)`
+		// Minimum execution time: 98_410_000 picoseconds.
+		Weight::from_parts(101_522_317, 7404)
+			// Standard Error: 41_274
+			.saturating_add(Weight::from_parts(21_845_902, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2784).saturating_mul(x.into()))
+	}
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::Buckets` (r:1 w:0)
 	/// Proof: `Funding::Buckets` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::CTAmountOversubscribed` (r:1 w:1)
@@ -602,14 +1321,33 @@ impl WeightInfo for () {
 	/// Storage: `Funding::ProjectsInAuctionRound` (r:0 w:1)
 	/// Proof: `Funding::ProjectsInAuctionRound` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::DidWithActiveProjects` (r:0 w:1)
-	/// Proof: `Funding::DidWithActiveProjects` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Proof: `Funding::DidWithActiveProjects` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectFeeConfigs` (r:1 w:0)
+	/// Proof: `Funding::ProjectFeeConfigs` (`max_values`: None, `max_size`: Some(231), added: 2706, mode: `MaxEncodedLen`)
 	fn end_funding_project_successful() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `932`
 		//  Estimated: `3902`
 		// Minimum execution time: 32_650_000 picoseconds.
 		Weight::from_parts(33_770_000, 3902)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Evaluations` (r:1 w:0)
+	/// Proof: `Funding::Evaluations` (`max_values`: None, `max_size`: Some(337), added: 2812, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectTransitionQueue` (r:0 w:2)
+	/// Proof: `Funding::ProjectTransitionQueue` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectFeeConfigs` (r:1 w:0)
+	/// Proof: `Funding::ProjectFeeConfigs` (`max_values`: None, `max_size`: Some(231), added: 2706, mode: `MaxEncodedLen`)
+	fn decide_project_outcome() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1094`
+		//  Estimated: `3802`
+		// Minimum execution time: 31_270_000 picoseconds.
+		Weight::from_parts(32_441_000, 3802)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
@@ -626,13 +1364,15 @@ impl WeightInfo for () {
 	/// Proof: `Funding::Buckets` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `ProxyBonding::Releases` (r:0 w:1)
 	/// Proof: `ProxyBonding::Releases` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectFeeConfigs` (r:1 w:0)
+	/// Proof: `Funding::ProjectFeeConfigs` (`max_values`: None, `max_size`: Some(231), added: 2706, mode: `MaxEncodedLen`)
 	fn start_settlement() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `964`
 		//  Estimated: `3902`
 		// Minimum execution time: 74_790_000 picoseconds.
 		Weight::from_parts(76_751_000, 3902)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Funding::Evaluations` (r:1 w:1)
@@ -693,6 +1433,96 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(16_u64))
 			.saturating_add(RocksDbWeight::get().writes(11_u64))
 	}
+	/// Storage: `Funding::Contributions` (r:1 w:1)
+	/// Proof: `Funding::Contributions` (`max_values`: None, `max_size`: Some(305), added: 2780, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:2 w:2)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(175), added: 2650, mode: `MaxEncodedLen`)
+	/// Storage: `ContributionTokens::Account` (r:1 w:1)
+	/// Proof: `ContributionTokens::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `ContributionTokens::Asset` (r:1 w:1)
+	/// Proof: `ContributionTokens::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::UserMigrations` (r:1 w:1)
+	/// Proof: `Funding::UserMigrations` (`max_values`: None, `max_size`: Some(1000071), added: 1002546, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::UnmigratedCounter` (r:1 w:1)
+	/// Proof: `Funding::UnmigratedCounter` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn settle_contribution() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3512`
+		//  Estimated: `1003536`
+		// Minimum execution time: 187_430_000 picoseconds.
+		Weight::from_parts(191_020_000, 1003536)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ContributionTokenVestings` (r:1 w:1)
+	/// Proof: `Funding::ContributionTokenVestings` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `ContributionTokens::Account` (r:2 w:2)
+	/// Proof: `ContributionTokens::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `ContributionTokens::Asset` (r:1 w:1)
+	/// Proof: `ContributionTokens::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
+	fn claim_vested_contribution_tokens() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1402`
+		//  Estimated: `6208`
+		// Minimum execution time: 98_341_000 picoseconds.
+		Weight::from_parts(100_120_000, 6208)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::MilestoneOutcomes` (r:2 w:1)
+	/// Proof: `Funding::MilestoneOutcomes` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::MilestoneEscrowTotals` (r:4 w:0)
+	/// Proof: `Funding::MilestoneEscrowTotals` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:4 w:4)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(840), added: 3315, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:8 w:8)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	fn decide_funding_milestone() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3418`
+		//  Estimated: `26646`
+		// Minimum execution time: 412_130_000 picoseconds.
+		Weight::from_parts(418_902_000, 26646)
+			.saturating_add(RocksDbWeight::get().reads(20_u64))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
+	}
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::MilestoneEscrowContributions` (r:5 w:0)
+	/// Proof: `Funding::MilestoneEscrowContributions` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::MilestoneOutcomes` (r:1 w:0)
+	/// Proof: `Funding::MilestoneOutcomes` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::MilestoneRefundsClaimed` (r:1 w:1)
+	/// Proof: `Funding::MilestoneRefundsClaimed` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:4 w:4)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(840), added: 3315, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:8 w:8)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	fn claim_milestone_refund() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3602`
+		//  Estimated: `26646`
+		// Minimum execution time: 421_560_000 picoseconds.
+		Weight::from_parts(427_311_000, 26646)
+			.saturating_add(RocksDbWeight::get().reads(20_u64))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
+	}
 	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
 	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::Evaluations` (r:1 w:0)
@@ -752,4 +1582,339 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::PalletMigrations` (r:1 w:1)
+	/// Proof: `Funding::PalletMigrations` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn start_pallet_migration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `710`
+		//  Estimated: `3764`
+		// Minimum execution time: 37_250_000 picoseconds.
+		Weight::from_parts(38_930_000, 3764)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::PalletMigrations` (r:1 w:1)
+	/// Proof: `Funding::PalletMigrations` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `PolkadotXcm::QueryCounter` (r:1 w:1)
+	/// Proof: `PolkadotXcm::QueryCounter` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ContributionTokens::Asset` (r:1 w:0)
+	/// Proof: `ContributionTokens::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `PolkadotXcm::SupportedVersion` (r:1 w:0)
+	/// Proof: `PolkadotXcm::SupportedVersion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PolkadotXcm::Queries` (r:0 w:2)
+	/// Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn start_pallet_migration_readiness_check() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1162`
+		//  Estimated: `4627`
+		// Minimum execution time: 61_480_000 picoseconds.
+		Weight::from_parts(63_710_000, 4627)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Funding::PalletMigrations` (r:1 w:1)
+	/// Proof: `Funding::PalletMigrations` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `ContributionTokens::Asset` (r:1 w:0)
+	/// Proof: `ContributionTokens::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	fn pallet_migration_readiness_response() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `802`
+		//  Estimated: `3675`
+		// Minimum execution time: 24_370_000 picoseconds.
+		Weight::from_parts(25_410_000, 3675)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::PalletMigrations` (r:1 w:0)
+	/// Proof: `Funding::PalletMigrations` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::UserMigrations` (r:1 w:0)
+	/// Proof: `Funding::UserMigrations` (`max_values`: None, `max_size`: Some(1000071), added: 1002546, mode: `MaxEncodedLen`)
+	/// Storage: `PolkadotXcm::QueryCounter` (r:1 w:1)
+	/// Proof: `PolkadotXcm::QueryCounter` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `PolkadotXcm::SupportedVersion` (r:1 w:0)
+	/// Proof: `PolkadotXcm::SupportedVersion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PolkadotXcm::Queries` (r:0 w:1)
+	/// Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::ActiveMigrationQueue` (r:0 w:1)
+	/// Proof: `Funding::ActiveMigrationQueue` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn send_pallet_migration_for() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1596`
+		//  Estimated: `1003536`
+		// Minimum execution time: 58_120_000 picoseconds.
+		Weight::from_parts(60_390_000, 1003536)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Funding::ActiveMigrationQueue` (r:1 w:1)
+	/// Proof: `Funding::ActiveMigrationQueue` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::PalletMigrations` (r:1 w:0)
+	/// Proof: `Funding::PalletMigrations` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::UserMigrations` (r:1 w:1)
+	/// Proof: `Funding::UserMigrations` (`max_values`: None, `max_size`: Some(1000071), added: 1002546, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::UnmigratedCounter` (r:1 w:1)
+	/// Proof: `Funding::UnmigratedCounter` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn confirm_pallet_migrations() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1488`
+		//  Estimated: `1003536`
+		// Minimum execution time: 36_940_000 picoseconds.
+		Weight::from_parts(38_260_000, 1003536)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Funding::ActiveMigrationQueue` (r:1 w:1)
+	/// Proof: `Funding::ActiveMigrationQueue` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::UserMigrations` (r:1 w:1)
+	/// Proof: `Funding::UserMigrations` (`max_values`: None, `max_size`: Some(1000071), added: 1002546, mode: `MaxEncodedLen`)
+	fn fail_timed_out_pallet_migration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1452`
+		//  Estimated: `1003536`
+		// Minimum execution time: 31_150_000 picoseconds.
+		Weight::from_parts(32_540_000, 1003536)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::UnmigratedCounter` (r:1 w:0)
+	/// Proof: `Funding::UnmigratedCounter` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::MigrationConfirmationRoots` (r:2 w:1)
+	/// Proof: `Funding::MigrationConfirmationRoots` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::PalletMigrations` (r:1 w:0)
+	/// Proof: `Funding::PalletMigrations` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn submit_migration_confirmation_root() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1042`
+		//  Estimated: `6039`
+		// Minimum execution time: 27_310_000 picoseconds.
+		Weight::from_parts(28_640_000, 6039)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::UserMigrations` (r:1 w:1)
+	/// Proof: `Funding::UserMigrations` (`max_values`: None, `max_size`: Some(1000071), added: 1002546, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::MigrationConfirmationRoots` (r:1 w:1)
+	/// Proof: `Funding::MigrationConfirmationRoots` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::UnmigratedCounter` (r:1 w:1)
+	/// Proof: `Funding::UnmigratedCounter` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn prove_offchain_migration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1403`
+		//  Estimated: `1003536`
+		// Minimum execution time: 41_820_000 picoseconds.
+		Weight::from_parts(43_570_000, 1003536)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::UserMigrations` (r:1 w:1)
+	/// Proof: `Funding::UserMigrations` (`max_values`: None, `max_size`: Some(1000071), added: 1002546, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::MigrationConfirmedAt` (r:1 w:1)
+	/// Proof: `Funding::MigrationConfirmedAt` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::OpenMigrationDisputes` (r:1 w:1)
+	/// Proof: `Funding::OpenMigrationDisputes` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn dispute_migration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1398`
+		//  Estimated: `1003536`
+		// Minimum execution time: 32_460_000 picoseconds.
+		Weight::from_parts(33_810_000, 1003536)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Funding::UserMigrations` (r:1 w:1)
+	/// Proof: `Funding::UserMigrations` (`max_values`: None, `max_size`: Some(1000071), added: 1002546, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::UnmigratedCounter` (r:1 w:1)
+	/// Proof: `Funding::UnmigratedCounter` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::OpenMigrationDisputes` (r:1 w:1)
+	/// Proof: `Funding::OpenMigrationDisputes` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn resolve_migration_dispute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1322`
+		//  Estimated: `1003536`
+		// Minimum execution time: 29_170_000 picoseconds.
+		Weight::from_parts(30_520_000, 1003536)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Funding::FundingAssets` (r:17 w:1)
+	/// Proof: `Funding::FundingAssets` (`max_values`: None, `max_size`: Some(1081), added: 3556, mode: `MaxEncodedLen`)
+	fn set_funding_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1236`
+		//  Estimated: `61462`
+		// Minimum execution time: 41_330_000 picoseconds.
+		Weight::from_parts(43_050_000, 61462)
+			.saturating_add(RocksDbWeight::get().reads(17_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::PendingProjectTransfers` (r:0 w:1)
+	/// Proof: `Funding::PendingProjectTransfers` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn propose_project_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1254`
+		//  Estimated: `3764`
+		// Minimum execution time: 23_410_000 picoseconds.
+		Weight::from_parts(24_380_000, 3764)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:1)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::PendingProjectTransfers` (r:1 w:1)
+	/// Proof: `Funding::PendingProjectTransfers` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::DidWithActiveProjects` (r:2 w:2)
+	/// Proof: `Funding::DidWithActiveProjects` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::EvaluationBondedUSD` (r:1 w:0)
+	/// Proof: `Funding::EvaluationBondedUSD` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::AuctionBoughtUSD` (r:1 w:0)
+	/// Proof: `Funding::AuctionBoughtUSD` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	fn accept_project_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1810`
+		//  Estimated: `6558`
+		// Minimum execution time: 44_630_000 picoseconds.
+		Weight::from_parts(46_150_000, 6558)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::HaltedProjects` (r:0 w:1)
+	/// Proof: `Funding::HaltedProjects` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	fn halt_project() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1254`
+		//  Estimated: `3764`
+		// Minimum execution time: 20_130_000 picoseconds.
+		Weight::from_parts(21_020_000, 3764)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Funding::HaltedProjects` (r:1 w:1)
+	/// Proof: `Funding::HaltedProjects` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	fn resume_project() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `178`
+		//  Estimated: `3551`
+		// Minimum execution time: 14_570_000 picoseconds.
+		Weight::from_parts(15_190_000, 3551)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::DidWithActiveProjects` (r:1 w:1)
+	/// Proof: `Funding::DidWithActiveProjects` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsInAuctionRound` (r:0 w:1)
+	/// Proof: `Funding::ProjectsInAuctionRound` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::HaltedProjects` (r:0 w:1)
+	/// Proof: `Funding::HaltedProjects` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectTransitionQueue` (r:0 w:1)
+	/// Proof: `Funding::ProjectTransitionQueue` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn force_project_failure() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1391`
+		//  Estimated: `3764`
+		// Minimum execution time: 36_850_000 picoseconds.
+		Weight::from_parts(38_110_000, 3764)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Funding::FeeConfiguration` (r:0 w:1)
+	/// Proof: `Funding::FeeConfiguration` (`max_values`: Some(1), `max_size`: Some(211), added: 706, mode: `MaxEncodedLen`)
+	fn set_fee_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_410_000 picoseconds.
+		Weight::from_parts(8_720_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ReceivingAccounts` (r:0 w:1)
+	/// Proof: `Funding::ReceivingAccounts` (`max_values`: None, `max_size`: Some(136), added: 2611, mode: `MaxEncodedLen`)
+	fn bind_receiving_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `574`
+		//  Estimated: `3902`
+		// Minimum execution time: 74_120_000 picoseconds.
+		Weight::from_parts(75_840_000, 3902)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::HaltedProjects` (r:1 w:0)
+	/// Proof: `Funding::HaltedProjects` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::FundingAssets` (r:1 w:0)
+	/// Proof: `Funding::FundingAssets` (`max_values`: None, `max_size`: Some(1081), added: 3556, mode: `MaxEncodedLen`)
+	/// Storage: `Oracle::Values` (r:2 w:0)
+	/// Proof: `Oracle::Values` (`max_values`: None, `max_size`: Some(634), added: 3109, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Metadata` (r:1 w:0)
+	/// Proof: `ForeignAssets::Metadata` (`max_values`: None, `max_size`: Some(738), added: 3213, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(175), added: 2650, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:2 w:2)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::NextScheduledBidId` (r:1 w:1)
+	/// Proof: `Funding::NextScheduledBidId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ScheduledBidQueue` (r:1 w:1)
+	/// Proof: `Funding::ScheduledBidQueue` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ScheduledBids` (r:15 w:1)
+	/// Proof: `Funding::ScheduledBids` (`max_values`: None, `max_size`: Some(294), added: 2769, mode: `MaxEncodedLen`)
+	fn schedule_bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7268`
+		//  Estimated: `48939`
+		// Minimum execution time: 160_720_000 picoseconds.
+		Weight::from_parts(163_480_000, 48939)
+			.saturating_add(RocksDbWeight::get().reads(29_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Funding::ScheduledBids` (r:1 w:1)
+	/// Proof: `Funding::ScheduledBids` (`max_values`: None, `max_size`: Some(294), added: 2769, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ScheduledBidQueue` (r:1 w:1)
+	/// Proof: `Funding::ScheduledBidQueue` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::FundingAssets` (r:1 w:0)
+	/// Proof: `Funding::FundingAssets` (`max_values`: None, `max_size`: Some(1081), added: 3556, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:2 w:2)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(175), added: 2650, mode: `MaxEncodedLen`)
+	fn cancel_scheduled_bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1902`
+		//  Estimated: `7404`
+		// Minimum execution time: 96_210_000 picoseconds.
+		Weight::from_parts(98_040_000, 7404)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
 }
\ No newline at end of file
@@ -0,0 +1,18 @@
+//! Handlers plugged into the XCM executor of the runtime, to open the HRMP channels used by the CT pallet migration.
+use crate::{Config, Pallet};
+use core::marker::PhantomData;
+use xcm_executor::traits::{HandleHrmpChannelAccepted, HandleHrmpNewChannelOpenRequest};
+
+pub struct HrmpHandler<T: Config>(PhantomData<T>);
+
+impl<T: Config> HandleHrmpNewChannelOpenRequest for HrmpHandler<T> {
+	fn handle(sender: u32, max_message_size: u32, max_capacity: u32) -> xcm::v5::Result {
+		Pallet::<T>::do_handle_channel_open_request(sender, max_message_size, max_capacity)
+	}
+}
+
+impl<T: Config> HandleHrmpChannelAccepted for HrmpHandler<T> {
+	fn handle(recipient: u32) -> xcm::v5::Result {
+		Pallet::<T>::do_handle_channel_accepted(recipient)
+	}
+}
@@ -97,8 +97,13 @@ pub mod pallet {
 		/// Example: FetchInterval = 10, FetchWindow = 5 => Ocw will try to fetch prices once
 		/// for the next windows: [0, 5), [10, 15), [20, 25), ...
 		type FetchWindow: Get<BlockNumberFor<Self>>;
-		/// Convert AssetName and FixedU128 to OracleKey and OracleValue
-		type ConvertAssetPricePair: Convert<(AssetName, FixedU128), (Self::OracleKey, Self::OracleValue)>;
+		/// Fetched price each OracleKey is fed with. Several keys can share one, and keys with none are not fed.
+		type OracleKeyFeed: Convert<Self::OracleKey, Option<AssetName>>;
+		/// Convert a fetched price to an OracleValue.
+		type ConvertPrice: Convert<FixedU128, Self::OracleValue>;
+		/// Keys of the prices to feed, e.g. the oracle keys of the funding asset registry.
+		/// Only the prices of these keys are fetched.
+		type OracleKeys: Get<Vec<Self::OracleKey>>;
 	}
 
 	#[pallet::event]
@@ -154,20 +159,17 @@ pub mod pallet {
 					> = val.get();
 					let mut last_send_for_assets = match last_send_for_assets_result {
 						Ok(Some(v)) => v,
-						_ => BTreeMap::from([
-							(AssetName::USDT, Zero::zero()),
-							(AssetName::USDC, Zero::zero()),
-							(AssetName::DOT, Zero::zero()),
-							(AssetName::PLMC, Zero::zero()),
-							(AssetName::ETH, Zero::zero()),
-						]),
+						_ => BTreeMap::new(),
 					};
 					log::trace!(target: LOG_TARGET, "Last send for assets: {:?}", last_send_for_assets);
 
-					// Fix for missing PLMC in last_send_for_assets for old nodes, that did not have PLMC in the list.
-					last_send_for_assets.entry(AssetName::PLMC).or_insert_with(Zero::zero);
-					// Fix for missing ETH in last_send_for_assets for old nodes, that did not have PLMC in the list.
-					last_send_for_assets.entry(AssetName::ETH).or_insert_with(Zero::zero);
+					// Only fetch the assets whose key is currently fed. The list changes with the funding asset
+					// registry, so assets can be added or removed since the last send.
+					let assets_to_feed = Self::assets_to_feed();
+					last_send_for_assets.retain(|asset_name, _| assets_to_feed.contains(asset_name));
+					for asset_name in assets_to_feed {
+						last_send_for_assets.entry(asset_name).or_insert_with(Zero::zero);
+					}
 
 					let assets = last_send_for_assets
 						.iter()
@@ -221,6 +223,20 @@ pub mod pallet {
 	}
 
 	impl<T: Config> Pallet<T> {
+		fn keys_to_feed() -> Vec<(T::OracleKey, AssetName)> {
+			T::OracleKeys::get()
+				.into_iter()
+				.filter_map(|oracle_key| T::OracleKeyFeed::convert(oracle_key.clone()).map(|feed| (oracle_key, feed)))
+				.collect()
+		}
+
+		fn assets_to_feed() -> Vec<AssetName> {
+			let mut assets: Vec<AssetName> = Self::keys_to_feed().into_iter().map(|(_, feed)| feed).collect();
+			assets.sort();
+			assets.dedup();
+			assets
+		}
+
 		fn fetch_prices(assets: Vec<AssetName>) -> BTreeMap<AssetName, FixedU128> {
 			let fetchers = vec![
 				BitFinexFetcher::get_moving_average,
@@ -266,9 +282,11 @@ pub mod pallet {
 
 		fn send_signed_transaction(prices: BTreeMap<AssetName, FixedU128>) -> Result<(), ()> {
 			let signer = Signer::<T, T::AppCrypto>::any_account();
-			let prices = prices
+			let prices = Self::keys_to_feed()
 				.into_iter()
-				.map(|(asset_name, price)| T::ConvertAssetPricePair::convert((asset_name, price)))
+				.filter_map(|(oracle_key, feed)| {
+					prices.get(&feed).map(|price| (oracle_key, T::ConvertPrice::convert(*price)))
+				})
 				.collect::<Vec<(T::OracleKey, T::OracleValue)>>();
 
 			let call = OracleCall::<T, ()>::feed_values { values: BoundedVec::<_, _>::truncate_from(prices) };
@@ -35,7 +35,7 @@ use sp_core::{
 use sp_keystore::{testing::MemoryKeystore, Keystore, KeystoreExt};
 use sp_runtime::{
 	testing::TestXt,
-	traits::{IdentifyAccount, IdentityLookup, Verify},
+	traits::{ConvertInto, IdentifyAccount, IdentityLookup, Verify},
 	BuildStorage,
 };
 use std::sync::Arc;
@@ -95,20 +95,22 @@ impl orml_oracle::Config for Test {
 	type WeightInfo = ();
 }
 
-pub struct AssetPriceConverter;
-impl Convert<(AssetName, FixedU128), (OracleKey, OracleValue)> for AssetPriceConverter {
-	fn convert((asset, price): (AssetName, FixedU128)) -> (OracleKey, OracleValue) {
-		match asset {
-			AssetName::DOT => (10, price),
-			AssetName::USDC => (1337, price),
-			AssetName::USDT => (1984, price),
-			AssetName::PLMC => (3344, price),
-			AssetName::ETH => (10_000, price),
+pub struct OracleKeyFeed;
+impl Convert<OracleKey, Option<AssetName>> for OracleKeyFeed {
+	fn convert(oracle_key: OracleKey) -> Option<AssetName> {
+		match oracle_key {
+			10 => Some(AssetName::DOT),
+			1337 => Some(AssetName::USDC),
+			1984 => Some(AssetName::USDT),
+			3344 => Some(AssetName::PLMC),
+			10_000 => Some(AssetName::ETH),
+			_ => None,
 		}
 	}
 }
 
 parameter_types! {
+	pub static OracleKeys: Vec<OracleKey> = vec![10, 1337, 1984, 3344, 10_000];
 	pub static Members: Vec<AccountId> = vec![
 		sp_keyring::Sr25519Keyring::Alice.to_raw_public().into(),
 		sp_keyring::Sr25519Keyring::Bob.to_raw_public().into(),
@@ -117,10 +119,12 @@ parameter_types! {
 }
 impl Config for Test {
 	type AppCrypto = crate::crypto::Polimec;
-	type ConvertAssetPricePair = AssetPriceConverter;
+	type ConvertPrice = ConvertInto;
 	type FetchInterval = ConstU64<5u64>;
 	type FetchWindow = ConstU64<1u64>;
 	type Members = IsInVec<Members>;
+	type OracleKeyFeed = OracleKeyFeed;
+	type OracleKeys = OracleKeys;
 	type RuntimeEvent = RuntimeEvent;
 }
 
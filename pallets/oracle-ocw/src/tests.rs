@@ -53,8 +53,39 @@ fn call_offchain_worker() {
 	});
 }
 
+#[test]
+fn only_the_configured_oracle_keys_are_fed() {
+	OracleKeys::set(vec![]);
+	let (mut ext, _offchain_state, pool_state) = new_test_ext_with_offchain_storage();
+	ext.execute_with(|| {
+		run_to_block(1);
+
+		assert!(pool_state.read().transactions.is_empty());
+	});
+}
+
+#[test]
+fn oracle_keys_without_a_feed_are_not_fed() {
+	OracleKeys::set(vec![10, 1337, 1984, 3344, 10_000, 42]);
+	let (mut ext, offchain_state, pool_state) = new_test_ext_with_offchain_storage();
+	price_oracle_response(&mut offchain_state.write());
+	ext.execute_with(|| {
+		run_to_block(1);
+
+		let tx = pool_state.write().transactions.pop().unwrap();
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		match tx.function {
+			RuntimeCall::Oracle(orml_oracle::Call::feed_values { values }) => {
+				assert_eq!(values.len(), 5);
+				assert!(values.iter().all(|(oracle_key, _)| *oracle_key != 42));
+			},
+			_ => panic!("Unexpected call"),
+		}
+	});
+}
+
 fn test_fetcher_against_real_api<F: FetchPrice>() {
-	for asset in [AssetName::DOT, AssetName::USDC, AssetName::USDT, AssetName::PLMC, AssetName::ETH] {
+	for asset in AssetName::ALL {
 		let url = F::get_url(asset);
 		if url.is_empty() {
 			continue;
@@ -35,6 +35,11 @@ pub enum AssetName {
 	ETH,
 }
 
+impl AssetName {
+	/// All the assets the fetchers can get a price for.
+	pub const ALL: [AssetName; 5] = [AssetName::USDT, AssetName::USDC, AssetName::DOT, AssetName::PLMC, AssetName::ETH];
+}
+
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct AssetRequest {
 	pub asset: AssetName,
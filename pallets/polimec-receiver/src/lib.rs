@@ -0,0 +1,140 @@
+// Polimec Blockchain – https://www.polimec.org/
+// Copyright (C) Polimec 2022. All rights reserved.
+
+// The Polimec Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Polimec Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Pallet that projects add to their parachain runtime to receive the contribution token migrations sent by
+//! Polimec with `pallet-funding`. Polimec checks that this pallet is part of the runtime before sending any
+//! migration, and each batch of migrations of a user is executed once per resend nonce. The mainnet tokens are paid
+//! out of the Polimec sovereign account, with a vesting schedule if the migration has a vesting time.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// Needed due to empty sections raising the warning
+#![allow(unreachable_patterns)]
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::{
+		pallet_prelude::{Weight, *},
+		traits::{Currency, ExistenceRequirement, VestedTransfer},
+	};
+	use frame_system::pallet_prelude::*;
+	use polimec_common::migration_types::{Migration, MigrationInfo, MigrationOrigin, Migrations};
+	use sp_runtime::{traits::TrailingZeroInput, SaturatedConversion};
+	use xcm::v4::Junction;
+
+	pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+	pub type CurrencyOf<T> = <<T as Config>::Vesting as VestedTransfer<AccountIdOf<T>>>::Currency;
+	pub type BalanceOf<T> = <CurrencyOf<T> as Currency<AccountIdOf<T>>>::Balance;
+
+	/// Configure the pallet by specifying the parameters and types on which it depends.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Because this pallet emits events, it depends on the runtime's definition of an event.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin of the migrations sent by Polimec. Normally an XCM origin matching the Polimec parachain.
+		type PolimecOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The account holding the mainnet tokens to distribute. Normally the Polimec sovereign account.
+		#[pallet::constant]
+		type PolimecSovereignAccount: Get<Self::AccountId>;
+
+		/// Pays out the mainnet tokens of migrations with a vesting time.
+		type Vesting: VestedTransfer<Self::AccountId, Moment = BlockNumberFor<Self>>;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// Hashes of the batches of migrations already executed, with their resend nonce. Used to skip a batch sent again
+	/// by Polimec in case the response of the first execution was lost. Polimec bumps the nonce when the tokens of a
+	/// batch have to be delivered again, so that batch is executed once more.
+	#[pallet::storage]
+	pub type ExecutedMigrations<T: Config> = StorageMap<_, Identity, [u8; 32], (), OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The mainnet tokens of a migration were paid out to the user.
+		MigrationExecuted { migration: Migration },
+		/// A batch of migrations was already executed, and was skipped.
+		DuplicatedMigrationsSkipped { migrations_hash: [u8; 32] },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The user of a migration is not represented by an `AccountId32` junction.
+		UnsupportedAccountJunction,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Pay out the mainnet tokens of a batch of migrations of a user. Called by Polimec through XCM.
+		#[pallet::call_index(0)]
+		#[pallet::weight(Weight::from_parts(100_000_000, 10_000).saturating_mul(migrations.len() as u64))]
+		pub fn migrate_for_user(origin: OriginFor<T>, migrations: Migrations, resend_nonce: u32) -> DispatchResult {
+			T::PolimecOrigin::ensure_origin(origin)?;
+
+			let migrations_hash = sp_io::hashing::blake2_256(&(&migrations, resend_nonce).encode());
+			if ExecutedMigrations::<T>::contains_key(migrations_hash) {
+				Self::deposit_event(Event::DuplicatedMigrationsSkipped { migrations_hash });
+				return Ok(());
+			}
+
+			for migration in migrations.inner() {
+				Self::execute_migration(&migration)?;
+				Self::deposit_event(Event::MigrationExecuted { migration });
+			}
+			ExecutedMigrations::<T>::insert(migrations_hash, ());
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		fn execute_migration(migration: &Migration) -> DispatchResult {
+			let Migration {
+				origin: MigrationOrigin { user, .. },
+				info: MigrationInfo { contribution_token_amount, vesting_time },
+			} = migration;
+
+			let Junction::AccountId32 { id, .. } = user else {
+				return Err(Error::<T>::UnsupportedAccountJunction.into());
+			};
+			let user = T::AccountId::decode(&mut TrailingZeroInput::new(id.as_slice()))
+				.map_err(|_| Error::<T>::UnsupportedAccountJunction)?;
+			let source = T::PolimecSovereignAccount::get();
+			let amount: BalanceOf<T> = (*contribution_token_amount).saturated_into();
+
+			if *vesting_time == 0 {
+				return CurrencyOf::<T>::transfer(&source, &user, amount, ExistenceRequirement::KeepAlive);
+			}
+
+			let vesting_time: BalanceOf<T> = (*vesting_time).saturated_into();
+			let per_block = (amount / vesting_time).max(1u32.into());
+			let now = frame_system::Pallet::<T>::block_number();
+			T::Vesting::vested_transfer(&source, &user, amount, per_block, now)
+		}
+	}
+}
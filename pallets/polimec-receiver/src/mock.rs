@@ -0,0 +1,106 @@
+use frame_support::{
+	derive_impl, ord_parameter_types, parameter_types,
+	traits::{ConstU64, WithdrawReasons},
+};
+use frame_system::{mocking::MockBlock, EnsureSignedBy, GenesisConfig};
+use sp_runtime::{
+	traits::{ConvertInto, IdentityLookup},
+	BuildStorage,
+};
+
+pub const NATIVE_DECIMALS: u8 = 10;
+pub const NATIVE_UNIT: u64 = 1 * 10u64.pow(NATIVE_DECIMALS as u32);
+pub const MILLI_NATIVE_UNIT: u64 = NATIVE_UNIT / 1_000;
+
+// Configure a mock runtime to test the pallet.
+#[frame_support::runtime]
+mod test_runtime {
+	#[runtime::runtime]
+	#[runtime::derive(
+		RuntimeCall,
+		RuntimeEvent,
+		RuntimeError,
+		RuntimeOrigin,
+		RuntimeFreezeReason,
+		RuntimeHoldReason,
+		RuntimeSlashReason,
+		RuntimeLockId,
+		RuntimeTask
+	)]
+	pub struct TestRuntime;
+
+	#[runtime::pallet_index(0)]
+	pub type System = frame_system;
+
+	#[runtime::pallet_index(1)]
+	pub type Balances = pallet_balances;
+
+	#[runtime::pallet_index(2)]
+	pub type Vesting = pallet_vesting;
+
+	#[runtime::pallet_index(3)]
+	pub type PolimecReceiver = crate;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for TestRuntime {
+	type AccountData = pallet_balances::AccountData<u64>;
+	type AccountId = u64;
+	type Block = MockBlock<TestRuntime>;
+	type Lookup = IdentityLookup<Self::AccountId>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for TestRuntime {
+	type AccountStore = System;
+	type ExistentialDeposit = ConstU64<MILLI_NATIVE_UNIT>;
+}
+
+parameter_types! {
+	pub const MinVestedTransfer: u64 = 256 * 2;
+	pub UnvestedFundsAllowedWithdrawReasons: WithdrawReasons =
+		WithdrawReasons::except(WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE);
+}
+impl pallet_vesting::Config for TestRuntime {
+	type BlockNumberProvider = System;
+	type BlockNumberToBalance = ConvertInto;
+	type Currency = Balances;
+	type MinVestedTransfer = MinVestedTransfer;
+	type RuntimeEvent = RuntimeEvent;
+	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
+	type WeightInfo = ();
+
+	const MAX_VESTING_SCHEDULES: u32 = 10;
+}
+
+ord_parameter_types! {
+	pub const Polimec: u64 = 3344;
+}
+parameter_types! {
+	pub PolimecSovereignAccount: u64 = 2_003_344;
+}
+impl crate::Config for TestRuntime {
+	type PolimecOrigin = EnsureSignedBy<Polimec, u64>;
+	type PolimecSovereignAccount = PolimecSovereignAccount;
+	type RuntimeEvent = RuntimeEvent;
+	type Vesting = Vesting;
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut storage = GenesisConfig::<TestRuntime>::default().build_storage().unwrap();
+	RuntimeGenesisConfig {
+		balances: BalancesConfig {
+			balances: vec![(PolimecSovereignAccount::get(), 1_000_000 * NATIVE_UNIT)],
+			dev_accounts: None,
+		},
+		..Default::default()
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(storage);
+	// In order to emit events the block number must be more than 0
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
@@ -0,0 +1,117 @@
+use crate::{mock::*, Error, Event, ExecutedMigrations};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{
+		fungible::Inspect,
+		tokens::{Fortitude, Preservation},
+		Get,
+	},
+};
+use parity_scale_codec::Encode;
+use polimec_common::migration_types::{Migration, MigrationInfo, MigrationOrigin, Migrations, ParticipationType};
+use sp_runtime::DispatchError;
+use xcm::v4::Junction;
+
+fn account_junction(account: u64) -> Junction {
+	let mut id = [0u8; 32];
+	id[..8].copy_from_slice(&account.to_le_bytes());
+	Junction::AccountId32 { network: None, id }
+}
+
+fn migration(user: Junction, participation_type: ParticipationType, amount: u64, vesting_time: u64) -> Migration {
+	Migration::new(
+		MigrationOrigin { user, participation_type },
+		MigrationInfo { contribution_token_amount: amount as u128, vesting_time },
+	)
+}
+
+#[test]
+fn migrate_for_user_pays_out_the_migrations() {
+	new_test_ext().execute_with(|| {
+		let user = 42u64;
+		let sovereign_account = <TestRuntime as crate::Config>::PolimecSovereignAccount::get();
+		let sovereign_balance = Balances::balance(&sovereign_account);
+
+		let migrations = Migrations::from(vec![
+			migration(account_junction(user), ParticipationType::Evaluation, 1_000 * NATIVE_UNIT, 0),
+			migration(account_junction(user), ParticipationType::Bid, 5_000 * NATIVE_UNIT, 100),
+		]);
+		assert_ok!(PolimecReceiver::migrate_for_user(RuntimeOrigin::signed(Polimec::get()), migrations.clone(), 0));
+
+		assert_eq!(Balances::balance(&user), 6_000 * NATIVE_UNIT);
+		assert_eq!(Balances::balance(&sovereign_account), sovereign_balance - 6_000 * NATIVE_UNIT);
+		// Only the tokens with no vesting time are transferable right away.
+		assert_eq!(
+			Balances::reducible_balance(&user, Preservation::Expendable, Fortitude::Polite),
+			1_000 * NATIVE_UNIT
+		);
+		assert_eq!(pallet_vesting::Vesting::<TestRuntime>::get(user).unwrap().len(), 1);
+
+		System::assert_has_event(Event::MigrationExecuted { migration: migrations.clone().inner()[0].clone() }.into());
+		System::assert_has_event(Event::MigrationExecuted { migration: migrations.clone().inner()[1].clone() }.into());
+		let migrations_hash = sp_io::hashing::blake2_256(&(&migrations, 0u32).encode());
+		assert!(ExecutedMigrations::<TestRuntime>::contains_key(migrations_hash));
+
+		// The vested tokens are released over the vesting time.
+		System::set_block_number(101);
+		assert_ok!(Vesting::vest(RuntimeOrigin::signed(user)));
+		assert_eq!(
+			Balances::reducible_balance(&user, Preservation::Expendable, Fortitude::Polite),
+			6_000 * NATIVE_UNIT
+		);
+	});
+}
+
+#[test]
+fn migrate_for_user_skips_executed_migrations() {
+	new_test_ext().execute_with(|| {
+		let user = 42u64;
+		let migrations =
+			Migrations::from(vec![migration(account_junction(user), ParticipationType::Bid, 1_000 * NATIVE_UNIT, 0)]);
+		assert_ok!(PolimecReceiver::migrate_for_user(RuntimeOrigin::signed(Polimec::get()), migrations.clone(), 0));
+		assert_ok!(PolimecReceiver::migrate_for_user(RuntimeOrigin::signed(Polimec::get()), migrations.clone(), 0));
+
+		assert_eq!(Balances::balance(&user), 1_000 * NATIVE_UNIT);
+		let migrations_hash = sp_io::hashing::blake2_256(&(&migrations, 0u32).encode());
+		System::assert_last_event(Event::DuplicatedMigrationsSkipped { migrations_hash }.into());
+	});
+}
+
+#[test]
+fn migrate_for_user_executes_migrations_sent_with_a_new_nonce() {
+	new_test_ext().execute_with(|| {
+		let user = 42u64;
+		let migrations =
+			Migrations::from(vec![migration(account_junction(user), ParticipationType::Bid, 1_000 * NATIVE_UNIT, 0)]);
+		assert_ok!(PolimecReceiver::migrate_for_user(RuntimeOrigin::signed(Polimec::get()), migrations.clone(), 0));
+		assert_ok!(PolimecReceiver::migrate_for_user(RuntimeOrigin::signed(Polimec::get()), migrations.clone(), 1));
+
+		assert_eq!(Balances::balance(&user), 2_000 * NATIVE_UNIT);
+		let migrations_hash = sp_io::hashing::blake2_256(&(&migrations, 1u32).encode());
+		assert!(ExecutedMigrations::<TestRuntime>::contains_key(migrations_hash));
+	});
+}
+
+#[test]
+fn migrate_for_user_fails_with_wrong_origin() {
+	new_test_ext().execute_with(|| {
+		let migrations =
+			Migrations::from(vec![migration(account_junction(42), ParticipationType::Bid, 1_000 * NATIVE_UNIT, 0)]);
+		assert_noop!(
+			PolimecReceiver::migrate_for_user(RuntimeOrigin::signed(42), migrations, 0),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn migrate_for_user_fails_with_unsupported_junction() {
+	new_test_ext().execute_with(|| {
+		let user = Junction::AccountKey20 { network: None, key: [1u8; 20] };
+		let migrations = Migrations::from(vec![migration(user, ParticipationType::Bid, 1_000 * NATIVE_UNIT, 0)]);
+		assert_noop!(
+			PolimecReceiver::migrate_for_user(RuntimeOrigin::signed(Polimec::get()), migrations, 0),
+			Error::<TestRuntime>::UnsupportedAccountJunction
+		);
+	});
+}
@@ -1,13 +1,15 @@
 use crate::{AccountIdOf, AssetId, BalanceOf, Config, Error, Pallet, PriceProviderOf, ReleaseType, Releases};
-use frame_support::traits::{
-	fungible,
-	fungible::{Inspect, Mutate, MutateHold},
-	fungibles,
-	fungibles::Mutate as FungiblesMutate,
-	tokens::{Fortitude, Precision, Preservation},
+use frame_support::{
+	ensure,
+	traits::{
+		fungible,
+		fungible::{Inspect, Mutate, MutateHold},
+		fungibles::Mutate as FungiblesMutate,
+		tokens::{Fortitude, Precision, Preservation},
+	},
 };
 use frame_system::pallet_prelude::BlockNumberFor;
-use polimec_common::ProvideAssetPrice;
+use polimec_common::{assets::ProvideFundingAssets, ProvideAssetPrice};
 use sp_runtime::{
 	traits::{AccountIdConversion, Get},
 	DispatchError, FixedPointNumber,
@@ -22,10 +24,11 @@ impl<T: Config> Pallet<T> {
 			<PriceProviderOf<T>>::get_decimals_aware_price(&T::BondingTokenId::get(), T::BondingTokenDecimals::get())
 				.ok_or(Error::<T>::PriceNotAvailable)?;
 
-		let fee_asset_decimals =
-			<T::FeeToken as fungibles::metadata::Inspect<AccountIdOf<T>>>::decimals(fee_asset.clone());
-		let fee_token_price = <PriceProviderOf<T>>::get_decimals_aware_price(&fee_asset, fee_asset_decimals)
-			.ok_or(Error::<T>::PriceNotAvailable)?;
+		let (_, fee_asset_info) =
+			T::FundingAssets::funding_asset_by_location(&fee_asset).ok_or(Error::<T>::FeeAssetNotAccepted)?;
+		let fee_token_price =
+			<PriceProviderOf<T>>::get_decimals_aware_price(&fee_asset_info.oracle_key, fee_asset_info.decimals)
+				.ok_or(Error::<T>::PriceNotAvailable)?;
 
 		let bonded_in_usd = bonding_token_price.saturating_mul_int(bond_amount);
 		let fee_in_usd = T::FeePercentage::get() * bonded_in_usd;
@@ -53,6 +56,10 @@ impl<T: Config> Pallet<T> {
 		let bonding_account: AccountIdOf<T> = Self::get_bonding_account(derivation_path);
 		let existential_deposit = <T::BondingToken as fungible::Inspect<T::AccountId>>::minimum_balance();
 
+		ensure!(
+			T::FundingAssets::enabled_funding_asset_locations().contains(&fee_asset),
+			Error::<T>::FeeAssetNotAccepted
+		);
 		let fee_in_fee_asset = Self::calculate_fee(bond_amount, fee_asset.clone())?;
 
 		// Pay the fee from the user to the bonding account. It awaits either a full transfer to the T::FeeRecipient, or a refund to each user
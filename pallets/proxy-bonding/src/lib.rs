@@ -39,7 +39,7 @@ pub mod pallet {
 		},
 	};
 	use frame_system::pallet_prelude::*;
-	use polimec_common::ProvideAssetPrice;
+	use polimec_common::{assets::ProvideFundingAssets, ProvideAssetPrice};
 	use sp_runtime::{Perbill, TypeId};
 
 	pub type AssetId = xcm::v4::Location;
@@ -79,6 +79,9 @@ pub mod pallet {
 			+ fungibles::Mutate<Self::AccountId, Balance = BalanceOf<Self>, AssetId = AssetId>
 			+ fungibles::metadata::Inspect<Self::AccountId, Balance = BalanceOf<Self>, AssetId = AssetId>;
 
+		/// Registry of the assets accepted to pay the fees, with their decimals and oracle keys.
+		type FundingAssets: ProvideFundingAssets;
+
 		/// The percentage of the bonded amount in USD that will be taken as a fee in the fee asset.
 		#[pallet::constant]
 		type FeePercentage: Get<Perbill>;
@@ -142,6 +145,8 @@ pub mod pallet {
 		FeeRefundDisallowed,
 		/// The price of a fee asset or the native token could not be retrieved
 		PriceNotAvailable,
+		/// The fee asset is not a registered funding asset, or it is disabled for new bonds
+		FeeAssetNotAccepted,
 	}
 
 	#[pallet::hooks]
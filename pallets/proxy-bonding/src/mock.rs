@@ -6,7 +6,10 @@ use frame_support::{
 };
 use frame_system::{mocking::MockBlock, GenesisConfig};
 use parity_scale_codec::{Decode, DecodeWithMemTracking, Encode};
-use polimec_common::ProvideAssetPrice;
+use polimec_common::{
+	assets::{AcceptedFundingAsset, FundingAssetInfo, ProvideFundingAssets},
+	ProvideAssetPrice,
+};
 use scale_info::TypeInfo;
 use serde::{Deserialize, Serialize};
 use sp_runtime::{
@@ -173,6 +176,25 @@ impl VariantCount for MockRuntimeHoldReason {
 	const VARIANT_COUNT: u32 = 2;
 }
 
+pub struct MockFundingAssets;
+impl ProvideFundingAssets for MockFundingAssets {
+	fn funding_asset(asset: AcceptedFundingAsset) -> Option<FundingAssetInfo> {
+		Self::funding_assets().into_iter().find(|(id, _)| *id == asset).map(|(_, info)| info)
+	}
+
+	fn funding_assets() -> Vec<(AcceptedFundingAsset, FundingAssetInfo)> {
+		vec![(
+			AcceptedFundingAsset::USDT,
+			FundingAssetInfo {
+				location: mock_fee_asset_id(),
+				decimals: MOCK_FEE_ASSET_DECIMALS,
+				oracle_key: mock_fee_asset_id(),
+				enabled: true,
+			},
+		)]
+	}
+}
+
 parameter_types! {
 	pub HereLocationGetter: Location = Location::here();
 }
@@ -183,6 +205,7 @@ impl crate::Config for TestRuntime {
 	type FeePercentage = FeePercentage;
 	type FeeRecipient = FeeRecipient;
 	type FeeToken = Assets;
+	type FundingAssets = MockFundingAssets;
 	type Id = PalletId;
 	type PriceProvider = ConstPriceProvider;
 	type RootId = RootId;
@@ -7,7 +7,8 @@ use frame_support::{
 		Get,
 	},
 };
-use sp_runtime::traits::AccountIdConversion;
+use sp_runtime::{traits::AccountIdConversion, FixedU128};
+use xcm::v4::Location;
 
 #[test]
 fn locked_outcome() {
@@ -148,3 +149,23 @@ fn refunded_outcome() {
 		assert_eq!(<Assets as FungiblesInspect<u64>>::balance(fee_asset, &user), 100 + expected_fee);
 	});
 }
+
+#[test]
+fn fee_asset_not_in_registry() {
+	new_test_ext().execute_with(|| {
+		let user = 42u64;
+		let treasury = <TestRuntime as crate::Config>::Treasury::get();
+		let ed: u64 = <TestRuntime as pallet_balances::Config>::ExistentialDeposit::get();
+		let bond_amount = 200 * NATIVE_UNIT;
+		<Balances as FungibleMutate<u64>>::set_balance(&treasury, bond_amount + ed * 2);
+
+		// A location with a price, but not registered as a funding asset
+		let fee_asset = Location::parent();
+		ConstPriceProvider::set_price(fee_asset.clone(), FixedU128::from_float(1f64));
+
+		assert_noop!(
+			ProxyBonding::bond_on_behalf_of(0, user, bond_amount, fee_asset, MockRuntimeHoldReason::Reason),
+			Error::<TestRuntime>::FeeAssetNotAccepted
+		);
+	});
+}
@@ -1,12 +1,16 @@
+use alloc::vec::Vec;
 use parity_scale_codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use serde::{Deserialize, Serialize};
 use sp_runtime::RuntimeDebug;
-use variant_count::VariantCount;
 use xcm::v4::prelude::{Ethereum, GeneralIndex, GlobalConsensus, Location, PalletInstance, Parachain};
 
+/// Maximum number of funding assets that can be registered. Also bounds the participation currencies of a project.
+pub const MAX_FUNDING_ASSETS: u32 = 16;
+
+/// Identifier of a funding asset in the registry of `pallet-funding`. The built-in assets keep the indexes they had
+/// as enum variants, so stored bids and contributions decode the same.
 #[derive(
-	VariantCount,
 	Clone,
 	Copy,
 	Encode,
@@ -22,42 +26,100 @@ use xcm::v4::prelude::{Ethereum, GeneralIndex, GlobalConsensus, Location, Pallet
 	Deserialize,
 	DecodeWithMemTracking,
 )]
-pub enum AcceptedFundingAsset {
-	#[codec(index = 0)]
-	USDT,
-	#[codec(index = 1)]
-	USDC,
-	#[codec(index = 2)]
-	DOT,
-	#[codec(index = 3)]
-	ETH,
-}
+pub struct AcceptedFundingAsset(pub u8);
+
+pub const USDT: AcceptedFundingAsset = AcceptedFundingAsset(0);
+pub const USDC: AcceptedFundingAsset = AcceptedFundingAsset(1);
+pub const DOT: AcceptedFundingAsset = AcceptedFundingAsset(2);
+pub const ETH: AcceptedFundingAsset = AcceptedFundingAsset(3);
+
 impl AcceptedFundingAsset {
-	// Note: this should be synced with the AssetId in the Pallet Assets.
-	pub fn id(&self) -> Location {
-		match self {
-			Self::USDT => Location::new(1, [Parachain(1000), PalletInstance(50), GeneralIndex(1984)]),
-			Self::DOT => Location::parent(),
-			Self::USDC => Location::new(1, [Parachain(1000), PalletInstance(50), GeneralIndex(1337)]),
-			Self::ETH => Location::new(2, [GlobalConsensus(Ethereum { chain_id: 1 })]),
-		}
+	pub const DOT: Self = DOT;
+	pub const ETH: Self = ETH;
+	pub const USDC: Self = USDC;
+	pub const USDT: Self = USDT;
+
+	/// Registry entry of the assets accepted since genesis. Assets added later by governance only exist in the
+	/// registry.
+	pub fn builtin_info(&self) -> Option<FundingAssetInfo> {
+		// Note: this should be synced with the AssetId and decimals in the Pallet Assets.
+		let (location, decimals) = match *self {
+			USDT => (Location::new(1, [Parachain(1000), PalletInstance(50), GeneralIndex(1984)]), 6),
+			USDC => (Location::new(1, [Parachain(1000), PalletInstance(50), GeneralIndex(1337)]), 6),
+			DOT => (Location::parent(), 10),
+			ETH => (Location::new(2, [GlobalConsensus(Ethereum { chain_id: 1 })]), 18),
+			_ => return None,
+		};
+		Some(FundingAssetInfo { oracle_key: location.clone(), location, decimals, enabled: true })
+	}
+
+	/// All the assets accepted since genesis, used to initialize the registry.
+	pub fn builtin_assets() -> Vec<(AcceptedFundingAsset, FundingAssetInfo)> {
+		[USDT, USDC, DOT, ETH].into_iter().filter_map(|asset| asset.builtin_info().map(|info| (asset, info))).collect()
+	}
+
+	/// Location of a built-in asset. `None` for assets that are only known to the registry.
+	pub fn id(&self) -> Option<Location> {
+		self.builtin_info().map(|info| info.location)
+	}
+
+	/// Decimals of a built-in asset. `None` for assets that are only known to the registry.
+	pub fn decimals(&self) -> Option<u8> {
+		self.builtin_info().map(|info| info.decimals)
+	}
+}
+
+/// Registry entry of a funding asset.
+#[derive(
+	Clone,
+	Encode,
+	Decode,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+	Serialize,
+	Deserialize,
+	DecodeWithMemTracking,
+)]
+pub struct FundingAssetInfo {
+	/// Location of the asset in `pallet-assets`, and in XCM messages.
+	pub location: Location,
+	/// Decimals of one unit of the asset. Should be synced with the metadata in `pallet-assets`.
+	pub decimals: u8,
+	/// Key of the asset price in the oracle.
+	pub oracle_key: Location,
+	/// Disabled assets cannot be used for new participations, but existing ones are still settled with them.
+	pub enabled: bool,
+}
+
+/// Gives access to the funding asset registry.
+pub trait ProvideFundingAssets {
+	/// Registry entry of an asset.
+	fn funding_asset(asset: AcceptedFundingAsset) -> Option<FundingAssetInfo>;
+
+	/// All the registered assets, enabled or not.
+	fn funding_assets() -> Vec<(AcceptedFundingAsset, FundingAssetInfo)>;
+
+	/// Registry entry of the asset at `location`.
+	fn funding_asset_by_location(location: &Location) -> Option<(AcceptedFundingAsset, FundingAssetInfo)> {
+		Self::funding_assets().into_iter().find(|(_, info)| info.location == *location)
 	}
 
-	// Note: this should be synced with the decimals in the Pallet Assets.
-	pub const fn decimals(&self) -> u8 {
-		match self {
-			Self::USDT => 6,
-			Self::USDC => 6,
-			Self::DOT => 10,
-			Self::ETH => 18,
-		}
+	/// Locations of the enabled assets.
+	fn enabled_funding_asset_locations() -> Vec<Location> {
+		Self::funding_assets().into_iter().filter(|(_, info)| info.enabled).map(|(_, info)| info.location).collect()
 	}
+}
 
-	pub fn all_ids() -> [Location; AcceptedFundingAsset::VARIANT_COUNT] {
-		[Self::USDT.id(), Self::USDC.id(), Self::DOT.id(), Self::ETH.id()]
+/// Registry holding only the built-in assets.
+impl ProvideFundingAssets for () {
+	fn funding_asset(asset: AcceptedFundingAsset) -> Option<FundingAssetInfo> {
+		asset.builtin_info()
 	}
 
-	pub fn all_ids_and_plmc() -> [Location; AcceptedFundingAsset::VARIANT_COUNT + 1] {
-		[Self::USDT.id(), Self::USDC.id(), Self::DOT.id(), Self::ETH.id(), Location::here()]
+	fn funding_assets() -> Vec<(AcceptedFundingAsset, FundingAssetInfo)> {
+		AcceptedFundingAsset::builtin_assets()
 	}
 }
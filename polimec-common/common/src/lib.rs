@@ -126,7 +126,7 @@ pub mod migration_types {
 	use super::*;
 	use xcm::v4::Junction;
 
-	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[derive(Clone, Encode, Decode, DecodeWithMemTracking, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 	pub struct MigrationOrigin {
 		pub user: Junction,
 		pub participation_type: ParticipationType,
@@ -146,13 +146,26 @@ pub mod migration_types {
 		}
 	}
 
-	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, Ord, PartialOrd, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[derive(
+		Clone,
+		Copy,
+		Encode,
+		Decode,
+		DecodeWithMemTracking,
+		Eq,
+		PartialEq,
+		Ord,
+		PartialOrd,
+		RuntimeDebug,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
 	pub enum ParticipationType {
 		Evaluation,
 		Bid,
 	}
 
-	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[derive(Clone, Encode, Decode, DecodeWithMemTracking, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 	pub struct MigrationInfo {
 		pub contribution_token_amount: u128,
 		pub vesting_time: u64,
@@ -176,12 +189,21 @@ pub mod migration_types {
 		MaxEncodedLen,
 		DecodeWithMemTracking,
 	)]
+	// New variants are appended, to keep the encoding of the stored statuses.
 	pub enum MigrationStatus {
+		/// The mainnet tokens were not sent yet.
 		NotStarted,
+		/// The mainnet tokens were received by the user.
 		Confirmed,
+		/// The mainnet tokens were sent to the project's parachain, awaiting its confirmation.
+		Sent,
+		/// The project's parachain could not pay out the mainnet tokens. The migration can be sent again.
+		Failed,
+		/// The user disputes the confirmation of the migration, awaiting the governance resolution.
+		Disputed,
 	}
 
-	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[derive(Clone, Encode, Decode, DecodeWithMemTracking, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 	pub struct Migration {
 		pub origin: MigrationOrigin,
 		pub info: MigrationInfo,
@@ -193,7 +215,7 @@ pub mod migration_types {
 		}
 	}
 
-	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, Default)]
+	#[derive(Clone, Encode, Decode, DecodeWithMemTracking, Eq, PartialEq, RuntimeDebug, TypeInfo, Default)]
 	pub struct Migrations(Vec<Migration>);
 	impl FromIterator<Migration> for Migrations {
 		fn from_iter<T: IntoIterator<Item = Migration>>(iter: T) -> Self {
@@ -11,10 +11,10 @@ use xcm::v4::Location;
 pub struct SetOraclePrices;
 impl SetPrices for SetOraclePrices {
 	fn set_prices() {
-		let dot = (AcceptedFundingAsset::DOT.id(), FixedU128::from_rational(69, 1));
-		let usdc = (AcceptedFundingAsset::USDC.id(), FixedU128::from_rational(1, 1));
-		let usdt = (AcceptedFundingAsset::USDT.id(), FixedU128::from_rational(1, 1));
-		let eth = (AcceptedFundingAsset::ETH.id(), FixedU128::from_rational(20_000, 1));
+		let dot = (AcceptedFundingAsset::DOT.id().unwrap(), FixedU128::from_rational(69, 1));
+		let usdc = (AcceptedFundingAsset::USDC.id().unwrap(), FixedU128::from_rational(1, 1));
+		let usdt = (AcceptedFundingAsset::USDT.id().unwrap(), FixedU128::from_rational(1, 1));
+		let eth = (AcceptedFundingAsset::ETH.id().unwrap(), FixedU128::from_rational(20_000, 1));
 		let plmc = (Location::here(), FixedU128::from_rational(840, 100));
 
 		let values: BoundedVec<(Location, FixedU128), <Runtime as orml_oracle::Config>::MaxFeedValues> =
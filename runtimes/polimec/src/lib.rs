@@ -35,7 +35,6 @@ use frame_support::{
 	parameter_types,
 	traits::{
 		fungible::{Credit, HoldConsideration, Inspect},
-		fungibles,
 		tokens::{self, ConversionToAssetBalance, PayFromAccount, UnityAssetBalanceConversion},
 		AsEnsureOriginWithArg, ConstU32, EitherOfDiverse, Everything, InstanceFilter, LinearStoragePrice, PrivilegeCmp,
 		TransformOrigin,
@@ -56,8 +55,9 @@ use parachains_common::{
 };
 use parity_scale_codec::Encode;
 use polimec_common::{
-	assets::AcceptedFundingAsset,
+	assets::{AcceptedFundingAsset, ProvideFundingAssets, MAX_FUNDING_ASSETS},
 	credentials::{Did, EnsureInvestor, InvestorType},
+	migration_types::MigrationInfo,
 	ProvideAssetPrice, DAYS, PLMC_DECIMALS, SLOT_DURATION, USD_DECIMALS, USD_UNIT,
 };
 use polkadot_runtime_common::{BlockHashCount, CurrencyToVote, SlowAdjustingFeeUpdate};
@@ -71,7 +71,7 @@ use sp_runtime::{
 		IdentifyAccount, IdentityLookup, OpaqueKeys, Verify,
 	},
 	transaction_validity::{InvalidTransaction, TransactionSource, TransactionValidity},
-	ApplyExtrinsicResult, FixedPointNumber, FixedU128, MultiSignature, SaturatedConversion,
+	ApplyExtrinsicResult, FixedPointNumber, FixedU128, MultiSignature, Percent, Perquintill, SaturatedConversion,
 };
 use sp_version::RuntimeVersion;
 
@@ -193,6 +193,17 @@ pub mod migrations {
 			pallet_session::migrations::v1::InitOffenceSeverity<Runtime>,
 		>,
 		cumulus_pallet_aura_ext::migration::MigrateV0ToV1<Runtime>,
+		pallet_funding::migrations::community_round::v8::MigrationToV8<Runtime>,
+		pallet_funding::migrations::transition_queue::v9::MigrationToV9<Runtime>,
+		pallet_funding::migrations::sale_mode::v10::MigrationToV10<Runtime>,
+		pallet_funding::migrations::ct_vesting::v11::MigrationToV11<Runtime>,
+		pallet_funding::migrations::funding_milestones::v12::MigrationToV12<Runtime>,
+		pallet_funding::migrations::funding_assets::v13::MigrationToV13<Runtime>,
+		pallet_funding::migrations::active_projects::v14::MigrationToV14<Runtime>,
+		pallet_funding::migrations::success_thresholds::v15::MigrationToV15<Runtime>,
+		pallet_funding::migrations::settlement_queue::v16::MigrationToV16<Runtime>,
+		pallet_funding::migrations::evaluation_bonded_usd::v17::MigrationToV17<Runtime>,
+		pallet_funding::migrations::bid_bucket_neighbours::v18::MigrationToV18<Runtime>,
 	);
 }
 
@@ -242,7 +253,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_version: 1_001_001,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 9,
+	transaction_version: 10,
 	system_version: 1,
 };
 
@@ -815,7 +826,7 @@ parameter_types! {
 	pub const ExpiresIn: Moment = 1000 * 60; // 1 mins
 	pub const MaxHasDispatchedSize: u32 = 20;
 	pub RootOperatorAccountId: AccountId = AccountId::from([0xffu8; 32]);
-	pub const MaxFeedValues: u32 = AcceptedFundingAsset::VARIANT_COUNT as u32 + 1; // Funding asset prices + PLMC
+	pub const MaxFeedValues: u32 = MAX_FUNDING_ASSETS + 1; // Funding asset prices + PLMC
 }
 
 impl orml_oracle::Config for Runtime {
@@ -842,10 +853,12 @@ parameter_types! {
 
 impl pallet_oracle_ocw::Config for Runtime {
 	type AppCrypto = pallet_oracle_ocw::crypto::Polimec;
-	type ConvertAssetPricePair = AssetPriceConverter;
+	type ConvertPrice = ConvertInto;
 	type FetchInterval = FetchInterval;
 	type FetchWindow = FetchWindow;
 	type Members = OracleProvidersMembership;
+	type OracleKeyFeed = OracleKeyFeed;
+	type OracleKeys = FundingAssetOracleKeys<Funding>;
 	type RuntimeEvent = RuntimeEvent;
 }
 
@@ -976,7 +989,7 @@ impl pallet_assets::Config<ContributionTokensInstance> for Runtime {
 	type Currency = Balances;
 	type Extra = ();
 	type ForceOrigin = EnsureRoot<AccountId>;
-	type Freezer = ();
+	type Freezer = ContributionTokensFreezer;
 	type Holder = ();
 	type MetadataDepositBase = ZeroDeposit;
 	type MetadataDepositPerByte = ZeroDeposit;
@@ -986,6 +999,11 @@ impl pallet_assets::Config<ContributionTokensInstance> for Runtime {
 	type WeightInfo = ();
 }
 
+impl pallet_assets_freezer::Config<ContributionTokensInstance> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+}
+
 parameter_types! {
 	pub ContributionTreasuryAccount: AccountId = FundingPalletId::get().into_account_truncating();
 	pub MinUsdPerEvaluation: Balance = 100 * USD_UNIT;
@@ -1018,24 +1036,61 @@ impl pallet_funding::Config for Runtime {
 	type BlockNumber = BlockNumber;
 	type BlockNumberProvider = RelaychainDataProvider<Runtime>;
 	type BlockchainOperationTreasury = BlockchainOperationTreasury;
+	type CommunityRoundDuration = CommunityRoundDuration;
 	type ContributionTokenCurrency = ContributionTokens;
+	type ContributionTokenFreezer = ContributionTokensFreezer;
 	type ContributionTreasury = ContributionTreasuryAccount;
 	type DaysToBlocks = DaysToBlocks;
+	type DefaultFundingOutcomeDecision = DefaultFundingOutcomeDecision;
+	type EmergencyOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 2>,
+	>;
 	type EvaluationRoundDuration = EvaluationRoundDuration;
 	type EvaluationSuccessThreshold = EarlyEvaluationThreshold;
+	type EvaluationSuccessThresholdBounds = EvaluationSuccessThresholdBounds;
 	type EvaluatorSlash = EvaluatorSlash;
-	type FeeBrackets = FeeBrackets;
+	type FeeConfigOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 2>,
+	>;
+	type FundingAssetOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 2>,
+	>;
 	type FundingCurrency = ForeignAssets;
 	type FundingSuccessThreshold = FundingSuccessThreshold;
+	type FundingSuccessThresholdBounds = FundingSuccessThresholdBounds;
 	type InvestorOrigin = EnsureInvestor<Runtime>;
+	type IssuerDecisionDuration = IssuerDecisionDuration;
+	type IssuerDecisionThreshold = IssuerDecisionThreshold;
+	type MaxActiveProjectsPerDid = MaxActiveProjectsPerDid;
+	type MaxCapacityThresholds = MaxCapacityThresholds;
+	type MaxMessageSizeThresholds = MaxMessageSizeThresholds;
+	type MaxScheduledBidsWeightPerBlock = MaxScheduledBidsWeightPerBlock;
+	type MaxTransitionsPerBlock = MaxTransitionsPerBlock;
+	type MigrationDisputeOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 2>,
+	>;
+	type MigrationDisputeWindow = MigrationDisputeWindow;
+	type MilestoneOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 2>,
+	>;
 	type MinUsdPerEvaluation = MinUsdPerEvaluation;
 	type Multiplier = pallet_funding::types::Multiplier;
 	type NativeCurrency = Balances;
 	type OnSlash = Vesting;
 	type PalletId = FundingPalletId;
+	type PolimecReceiverInfo = PolimecReceiverInfo;
 	type Price = Price;
 	type PriceProvider = OraclePriceProvider<xcm::v4::Location, Price, Oracle>;
+	type RequiredMaxCapacity = RequiredMaxCapacity;
+	type RequiredMaxMessageSize = RequiredMaxMessageSize;
+	type RuntimeCall = RuntimeCall;
 	type RuntimeEvent = RuntimeEvent;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type RuntimeOrigin = RuntimeOrigin;
 	type SS58Conversion = SS58Converter;
@@ -1061,6 +1116,7 @@ impl pallet_proxy_bonding::Config for Runtime {
 	type FeePercentage = FeePercentage;
 	type FeeRecipient = FeeRecipient;
 	type FeeToken = ForeignAssets;
+	type FundingAssets = Funding;
 	type Id = PalletId;
 	type PriceProvider = OraclePriceProvider<xcm::v4::Location, Price, Oracle>;
 	type RootId = TreasuryId;
@@ -1118,13 +1174,14 @@ impl ConversionToAssetBalance<Balance, Location, Balance> for HereToForeignAsset
 		if asset_id == Location::here() {
 			return Ok(plmc_balance);
 		}
+		let (_, asset_info) = Funding::funding_asset_by_location(&asset_id).ok_or(InvalidTransaction::Payment)?;
 
 		// 1. Get nominal price of PLMC in USD (e.g., USD per 1 PLMC).
 		let nominal_plmc_price_usd =
 			<PriceProviderOf<Runtime>>::get_price(&Location::here()).ok_or(InvalidTransaction::Payment)?;
 
 		// 2. Get nominal price of the target asset in USD (e.g., USD per 1 TargetAsset).
-		let nominal_target_asset_price_usd = <PriceProviderOf<Runtime>>::get_price(&asset_id)
+		let nominal_target_asset_price_usd = <PriceProviderOf<Runtime>>::get_price(&asset_info.oracle_key)
 			.ok_or(InvalidTransaction::Payment)?;
 
 		// 3. Calculate nominal price of PLMC in terms of the target asset.
@@ -1134,7 +1191,7 @@ impl ConversionToAssetBalance<Balance, Location, Balance> for HereToForeignAsset
 			nominal_plmc_price_usd.checked_div(&nominal_target_asset_price_usd).ok_or(InvalidTransaction::Payment)?;
 
 		// 4. Get decimals for the target asset.
-		let target_asset_decimals = <ForeignAssets as fungibles::metadata::Inspect<AccountId>>::decimals(asset_id);
+		let target_asset_decimals = asset_info.decimals;
 
 		// 5. Calculate the decimals-aware price for converting PLMC amounts (smallest units)
 		//    to target asset amounts (smallest units).
@@ -1211,6 +1268,7 @@ construct_runtime!(
 		ForeignAssets: pallet_assets::<Instance2> = 14,
 		Dispenser: pallet_dispenser = 15,
 		AssetTransactionPayment: pallet_asset_tx_payment = 16,
+		ContributionTokensFreezer: pallet_assets_freezer::<Instance1> = 17,
 
 		// Collator support. the order of these 5 are important and shall not change.
 		Authorship: pallet_authorship::{Pallet, Storage} = 20,
@@ -1508,6 +1566,7 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(5)]
 	impl pallet_funding::functions::runtime_api::ProjectInformation<Block, Runtime> for Runtime {
 		fn usd_target_percent_reached(project_id: ProjectId) -> FixedU128 {
 			Funding::usd_target_percent_reached(project_id)
@@ -1516,12 +1575,36 @@ impl_runtime_apis! {
 		fn projects_by_did(did: Did) -> Vec<ProjectId> {
 			Funding::projects_by_did(did)
 		}
+
+		fn active_projects_by_did(did: Did) -> Vec<ProjectId> {
+			Funding::active_projects_by_did(did)
+		}
+
+		fn project_success_thresholds(project_id: ProjectId) -> Option<(Percent, Perquintill)> {
+			Funding::project_success_thresholds(project_id)
+		}
+
+		fn pro_rata_ct_amount_of_bid(project_id: ProjectId, bid_id: u32) -> Option<Balance> {
+			Funding::pro_rata_ct_amount_of_bid(project_id, bid_id)
+		}
+
+		fn current_auction_price(project_id: ProjectId) -> Option<Price> {
+			Funding::current_auction_price(project_id)
+		}
+
+		fn migration_confirmation_leaves(project_id: ProjectId) -> Vec<(AccountId, MigrationInfo)> {
+			Funding::migration_confirmation_leaves(project_id)
+		}
 	}
 
 	impl pallet_funding::functions::runtime_api::ExtrinsicHelpers<Block, Runtime> for Runtime {
 		fn funding_asset_to_ct_amount_classic(project_id: ProjectId, asset: AcceptedFundingAsset, asset_amount: Balance) -> Balance {
 			Funding::funding_asset_to_ct_amount_classic(project_id, asset, asset_amount)
 		}
+
+		fn funding_asset_to_ct_amount_classic_with_max_price(project_id: ProjectId, asset: AcceptedFundingAsset, asset_amount: Balance, max_price: Option<Price>) -> Balance {
+			Funding::funding_asset_to_ct_amount_classic_with_max_price(project_id, asset, asset_amount, max_price)
+		}
 		fn funding_asset_to_ct_amount_otm(project_id: ProjectId, asset: AcceptedFundingAsset, asset_amount: Balance) -> (Balance, Balance) {
 			Funding::funding_asset_to_ct_amount_otm(project_id, asset, asset_amount)
 		}
@@ -1534,8 +1617,8 @@ impl_runtime_apis! {
 		fn get_funding_asset_min_max_amounts(project_id: ProjectId, did: Did, funding_asset: AcceptedFundingAsset, investor_type: InvestorType) -> Option<(Balance, Balance)> {
 			Funding::get_funding_asset_min_max_amounts(project_id, did, funding_asset, investor_type)
 		}
-		fn get_message_to_sign_by_receiving_account(project_id: ProjectId, polimec_account: AccountId) -> Option<String> {
-			Funding::get_message_to_sign_by_receiving_account(project_id, polimec_account)
+		fn get_message_to_sign_by_receiving_account(project_id: ProjectId, polimec_account: AccountId, expiry: BlockNumber) -> Option<String> {
+			Funding::get_message_to_sign_by_receiving_account(project_id, polimec_account, expiry)
 		}
 	}
 
@@ -1680,7 +1763,8 @@ impl_runtime_apis! {
 
 	impl xcm_runtime_apis::fees::XcmPaymentApi<Block> for Runtime {
 		fn query_acceptable_payment_assets(xcm_version: xcm::Version) -> Result<Vec<VersionedAssetId>, XcmPaymentApiError> {
-			let acceptable_assets = AcceptedFundingAsset::all_ids_and_plmc();
+			let mut acceptable_assets = Funding::enabled_funding_asset_locations();
+			acceptable_assets.push(Location::here());
 
 			let acceptable_assets_v5 = acceptable_assets
 				.iter()
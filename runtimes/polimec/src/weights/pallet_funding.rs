@@ -35,7 +35,7 @@ impl<T: frame_system::Config> pallet_funding::WeightInfo for WeightInfo<T> {
 	/// Storage: `Funding::NextProjectId` (r:1 w:1)
 	/// Proof: `Funding::NextProjectId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::DidWithActiveProjects` (r:1 w:1)
-	/// Proof: `Funding::DidWithActiveProjects` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Proof: `Funding::DidWithActiveProjects` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::Buckets` (r:0 w:1)
@@ -63,7 +63,7 @@ impl<T: frame_system::Config> pallet_funding::WeightInfo for WeightInfo<T> {
 	/// Storage: `Funding::ProjectsMetadata` (r:0 w:1)
 	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::DidWithActiveProjects` (r:0 w:1)
-	/// Proof: `Funding::DidWithActiveProjects` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Proof: `Funding::DidWithActiveProjects` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
 	fn remove_project() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `477`
@@ -92,12 +92,30 @@ impl<T: frame_system::Config> pallet_funding::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectAllowlists` (r:0 w:1)
+	/// Proof: `Funding::ProjectAllowlists` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn set_allowlist() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `477`
+		//  Estimated: `3764`
+		// Minimum execution time: 41_403_000 picoseconds.
+		Weight::from_parts(42_303_000, 0)
+			.saturating_add(Weight::from_parts(0, 3764))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
 	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
 	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::FeeConfiguration` (r:1 w:0)
+	/// Proof: `Funding::FeeConfiguration` (`max_values`: Some(1), `max_size`: Some(211), added: 706, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectFeeConfigs` (r:0 w:1)
+	/// Proof: `Funding::ProjectFeeConfigs` (`max_values`: None, `max_size`: Some(231), added: 2706, mode: `MaxEncodedLen`)
 	fn start_evaluation() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `741`
@@ -105,8 +123,8 @@ impl<T: frame_system::Config> pallet_funding::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 129_342_000 picoseconds.
 		Weight::from_parts(130_873_000, 0)
 			.saturating_add(Weight::from_parts(0, 3902))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
@@ -122,6 +140,10 @@ impl<T: frame_system::Config> pallet_funding::WeightInfo for WeightInfo<T> {
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(175), added: 2650, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::Evaluations` (r:0 w:1)
 	/// Proof: `Funding::Evaluations` (`max_values`: None, `max_size`: Some(337), added: 2812, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ReceivingAccounts` (r:1 w:0)
+	/// Proof: `Funding::ReceivingAccounts` (`max_values`: None, `max_size`: Some(136), added: 2611, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::EvaluationBondedUSD` (r:1 w:1)
+	/// Proof: `Funding::EvaluationBondedUSD` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
 	fn evaluate() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1128`
@@ -129,21 +151,49 @@ impl<T: frame_system::Config> pallet_funding::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 178_694_000 picoseconds.
 		Weight::from_parts(180_904_000, 0)
 			.saturating_add(Weight::from_parts(0, 4099))
-			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Evaluations` (r:101 w:101)
+	/// Proof: `Funding::Evaluations` (`max_values`: None, `max_size`: Some(337), added: 2812, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(175), added: 2650, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::EvaluationBondedUSD` (r:1 w:1)
+	/// Proof: `Funding::EvaluationBondedUSD` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 100]`.
+	fn withdraw_evaluation(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1386 + x * (201 ±0)`
+		//  Estimated: `4099 + x * (2812 ±0)`
+		// Minimum execution time: 64_180_000 picoseconds.
+		Weight::from_parts(58_912_440, 0)
+			.saturating_add(Weight::from_parts(0, 4099))
+			// Standard Error: 6_913
+			.saturating_add(Weight::from_parts(9_377_519, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
 			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2812).saturating_mul(x.into()))
 	}
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
 	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::DidWithActiveProjects` (r:0 w:1)
-	/// Proof: `Funding::DidWithActiveProjects` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Proof: `Funding::DidWithActiveProjects` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
 	fn end_evaluation_failure() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `442`
-		//  Estimated: `3764`
+		//  Estimated: `3902`
 		// Minimum execution time: 11_830_000 picoseconds.
 		Weight::from_parts(12_340_000, 0)
-			.saturating_add(Weight::from_parts(0, 3764))
-			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(Weight::from_parts(0, 3902))
+			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Timestamp::Now` (r:1 w:0)
@@ -177,6 +227,8 @@ impl<T: frame_system::Config> pallet_funding::WeightInfo for WeightInfo<T> {
 	/// Storage: `Funding::Bids` (r:0 w:10)
 	/// Proof: `Funding::Bids` (`max_values`: None, `max_size`: Some(309), added: 2784, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[1, 10]`.
+	/// Storage: `Funding::ReceivingAccounts` (r:1 w:0)
+	/// Proof: `Funding::ReceivingAccounts` (`max_values`: None, `max_size`: Some(136), added: 2611, mode: `MaxEncodedLen`)
 	fn bid(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2895`
@@ -186,7 +238,7 @@ impl<T: frame_system::Config> pallet_funding::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 7404))
 			// Standard Error: 45_614
 			.saturating_add(Weight::from_parts(79_564_477, 0).saturating_mul(x.into()))
-			.saturating_add(T::DbWeight::get().reads(15))
+			.saturating_add(T::DbWeight::get().reads(16))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
 			.saturating_add(T::DbWeight::get().writes(8))
 			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(x.into())))
@@ -194,6 +246,80 @@ impl<T: frame_system::Config> pallet_funding::WeightInfo for WeightInfo<T> {
 	}
 	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
 	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Buckets` (r:1 w:0)
+	/// Proof: `Funding::Buckets` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::NextContributionId` (r:1 w:1)
+	/// Proof: `Funding::NextContributionId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::AuctionBoughtUSD` (r:1 w:1)
+	/// Proof: `Funding::AuctionBoughtUSD` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Oracle::Values` (r:2 w:0)
+	/// Proof: `Oracle::Values` (`max_values`: None, `max_size`: Some(634), added: 3109, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Metadata` (r:1 w:0)
+	/// Proof: `ForeignAssets::Metadata` (`max_values`: None, `max_size`: Some(738), added: 3213, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Evaluations` (r:1 w:0)
+	/// Proof: `Funding::Evaluations` (`max_values`: None, `max_size`: Some(337), added: 2812, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(175), added: 2650, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:2 w:2)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Contributions` (r:0 w:1)
+	/// Proof: `Funding::Contributions` (`max_values`: None, `max_size`: Some(305), added: 2780, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ReceivingAccounts` (r:1 w:0)
+	/// Proof: `Funding::ReceivingAccounts` (`max_values`: None, `max_size`: Some(136), added: 2611, mode: `MaxEncodedLen`)
+	fn contribute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2410`
+		//  Estimated: `7404`
+		// Minimum execution time: 153_870_000 picoseconds.
+		Weight::from_parts(157_245_000, 0)
+			.saturating_add(Weight::from_parts(0, 7404))
+			.saturating_add(T::DbWeight::get().reads(14))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Buckets` (r:1 w:1)
+	/// Proof: `Funding::Buckets` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Bids` (r:11 w:11)
+	/// Proof: `Funding::Bids` (`max_values`: None, `max_size`: Some(309), added: 2784, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:2 w:2)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(175), added: 2650, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::AuctionBoughtUSD` (r:1 w:1)
+	/// Proof: `Funding::AuctionBoughtUSD` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::CTAmountOversubscribed` (r:1 w:1)
+	/// Proof: `Funding::CTAmountOversubscribed` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::OutbidBidsCutoffs` (r:10 w:10)
+	/// Proof: `Funding::OutbidBidsCutoffs` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::BidsBucketBounds` (r:11 w:1)
+	/// Proof: `Funding::BidsBucketBounds` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 10]`.
+	fn cancel_bid(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3412`
+		//  Estimated: `7404 + x * (2784 ±0)`
+		// Minimum execution time: 99_130_000 picoseconds.
+		Weight::from_parts(102_876_541, 0)
+			.saturating_add(Weight::from_parts(0, 7404))
+			// Standard Error: 43_109
+			.saturating_add(Weight::from_parts(22_103_377, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(9))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2784).saturating_mul(x.into()))
+	}
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::Buckets` (r:1 w:0)
 	/// Proof: `Funding::Buckets` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::CTAmountOversubscribed` (r:1 w:1)
@@ -225,7 +351,9 @@ impl<T: frame_system::Config> pallet_funding::WeightInfo for WeightInfo<T> {
 	/// Storage: `Funding::ProjectsInAuctionRound` (r:0 w:1)
 	/// Proof: `Funding::ProjectsInAuctionRound` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::DidWithActiveProjects` (r:0 w:1)
-	/// Proof: `Funding::DidWithActiveProjects` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Proof: `Funding::DidWithActiveProjects` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectFeeConfigs` (r:1 w:0)
+	/// Proof: `Funding::ProjectFeeConfigs` (`max_values`: None, `max_size`: Some(231), added: 2706, mode: `MaxEncodedLen`)
 	fn end_funding_project_successful() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `932`
@@ -233,7 +361,25 @@ impl<T: frame_system::Config> pallet_funding::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 33_611_000 picoseconds.
 		Weight::from_parts(34_481_000, 0)
 			.saturating_add(Weight::from_parts(0, 3902))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Evaluations` (r:1 w:0)
+	/// Proof: `Funding::Evaluations` (`max_values`: None, `max_size`: Some(337), added: 2812, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectTransitionQueue` (r:0 w:2)
+	/// Proof: `Funding::ProjectTransitionQueue` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectFeeConfigs` (r:1 w:0)
+	/// Proof: `Funding::ProjectFeeConfigs` (`max_values`: None, `max_size`: Some(231), added: 2706, mode: `MaxEncodedLen`)
+	fn decide_project_outcome() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1094`
+		//  Estimated: `3802`
+		// Minimum execution time: 31_270_000 picoseconds.
+		Weight::from_parts(32_441_000, 0)
+			.saturating_add(Weight::from_parts(0, 3802))
+			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
@@ -250,6 +396,8 @@ impl<T: frame_system::Config> pallet_funding::WeightInfo for WeightInfo<T> {
 	/// Proof: `Funding::Buckets` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
 	/// Storage: `ProxyBonding::Releases` (r:0 w:1)
 	/// Proof: `ProxyBonding::Releases` (`max_values`: None, `max_size`: Some(43), added: 2518, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectFeeConfigs` (r:1 w:0)
+	/// Proof: `Funding::ProjectFeeConfigs` (`max_values`: None, `max_size`: Some(231), added: 2706, mode: `MaxEncodedLen`)
 	fn start_settlement() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `964`
@@ -257,7 +405,7 @@ impl<T: frame_system::Config> pallet_funding::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 75_512_000 picoseconds.
 		Weight::from_parts(77_501_000, 0)
 			.saturating_add(Weight::from_parts(0, 3902))
-			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads(7))
 			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `Funding::Evaluations` (r:1 w:1)
@@ -320,6 +468,100 @@ impl<T: frame_system::Config> pallet_funding::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(16))
 			.saturating_add(T::DbWeight::get().writes(11))
 	}
+	/// Storage: `Funding::Contributions` (r:1 w:1)
+	/// Proof: `Funding::Contributions` (`max_values`: None, `max_size`: Some(305), added: 2780, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:2 w:2)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(175), added: 2650, mode: `MaxEncodedLen`)
+	/// Storage: `ContributionTokens::Account` (r:1 w:1)
+	/// Proof: `ContributionTokens::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `ContributionTokens::Asset` (r:1 w:1)
+	/// Proof: `ContributionTokens::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::UserMigrations` (r:1 w:1)
+	/// Proof: `Funding::UserMigrations` (`max_values`: None, `max_size`: Some(1000071), added: 1002546, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::UnmigratedCounter` (r:1 w:1)
+	/// Proof: `Funding::UnmigratedCounter` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn settle_contribution() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3512`
+		//  Estimated: `1003536`
+		// Minimum execution time: 190_112_000 picoseconds.
+		Weight::from_parts(194_561_000, 0)
+			.saturating_add(Weight::from_parts(0, 1003536))
+			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ContributionTokenVestings` (r:1 w:1)
+	/// Proof: `Funding::ContributionTokenVestings` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `ContributionTokens::Account` (r:2 w:2)
+	/// Proof: `ContributionTokens::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `ContributionTokens::Asset` (r:1 w:1)
+	/// Proof: `ContributionTokens::Asset` (`max_values`: None, `max_size`: Some(222), added: 2697, mode: `MaxEncodedLen`)
+	fn claim_vested_contribution_tokens() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1402`
+		//  Estimated: `6208`
+		// Minimum execution time: 98_703_000 picoseconds.
+		Weight::from_parts(100_503_000, 0)
+			.saturating_add(Weight::from_parts(0, 6208))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::MilestoneOutcomes` (r:2 w:1)
+	/// Proof: `Funding::MilestoneOutcomes` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::MilestoneEscrowTotals` (r:4 w:0)
+	/// Proof: `Funding::MilestoneEscrowTotals` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:4 w:4)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(840), added: 3315, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:8 w:8)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	fn decide_funding_milestone() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3418`
+		//  Estimated: `26646`
+		// Minimum execution time: 412_130_000 picoseconds.
+		Weight::from_parts(418_902_000, 0)
+			.saturating_add(Weight::from_parts(0, 26646))
+			.saturating_add(T::DbWeight::get().reads(20))
+			.saturating_add(T::DbWeight::get().writes(13))
+	}
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::MilestoneEscrowContributions` (r:5 w:0)
+	/// Proof: `Funding::MilestoneEscrowContributions` (`max_values`: None, `max_size`: Some(109), added: 2584, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::MilestoneOutcomes` (r:1 w:0)
+	/// Proof: `Funding::MilestoneOutcomes` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::MilestoneRefundsClaimed` (r:1 w:1)
+	/// Proof: `Funding::MilestoneRefundsClaimed` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:4 w:4)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(840), added: 3315, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:8 w:8)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	fn claim_milestone_refund() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3602`
+		//  Estimated: `26646`
+		// Minimum execution time: 421_560_000 picoseconds.
+		Weight::from_parts(427_311_000, 0)
+			.saturating_add(Weight::from_parts(0, 26646))
+			.saturating_add(T::DbWeight::get().reads(20))
+			.saturating_add(T::DbWeight::get().writes(13))
+	}
 	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
 	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
 	/// Storage: `Funding::Evaluations` (r:1 w:0)
@@ -383,4 +625,359 @@ impl<T: frame_system::Config> pallet_funding::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::PalletMigrations` (r:1 w:1)
+	/// Proof: `Funding::PalletMigrations` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn start_pallet_migration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `710`
+		//  Estimated: `3764`
+		// Minimum execution time: 37_250_000 picoseconds.
+		Weight::from_parts(38_930_000, 0)
+			.saturating_add(Weight::from_parts(0, 3764))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::PalletMigrations` (r:1 w:1)
+	/// Proof: `Funding::PalletMigrations` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `PolkadotXcm::QueryCounter` (r:1 w:1)
+	/// Proof: `PolkadotXcm::QueryCounter` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ContributionTokens::Asset` (r:1 w:0)
+	/// Proof: `ContributionTokens::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `PolkadotXcm::SupportedVersion` (r:1 w:0)
+	/// Proof: `PolkadotXcm::SupportedVersion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PolkadotXcm::Queries` (r:0 w:2)
+	/// Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn start_pallet_migration_readiness_check() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1162`
+		//  Estimated: `4627`
+		// Minimum execution time: 61_480_000 picoseconds.
+		Weight::from_parts(63_710_000, 0)
+			.saturating_add(Weight::from_parts(0, 4627))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Funding::PalletMigrations` (r:1 w:1)
+	/// Proof: `Funding::PalletMigrations` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `ContributionTokens::Asset` (r:1 w:0)
+	/// Proof: `ContributionTokens::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	fn pallet_migration_readiness_response() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `802`
+		//  Estimated: `3675`
+		// Minimum execution time: 24_370_000 picoseconds.
+		Weight::from_parts(25_410_000, 0)
+			.saturating_add(Weight::from_parts(0, 3675))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::PalletMigrations` (r:1 w:0)
+	/// Proof: `Funding::PalletMigrations` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::UserMigrations` (r:1 w:0)
+	/// Proof: `Funding::UserMigrations` (`max_values`: None, `max_size`: Some(1000071), added: 1002546, mode: `MaxEncodedLen`)
+	/// Storage: `PolkadotXcm::QueryCounter` (r:1 w:1)
+	/// Proof: `PolkadotXcm::QueryCounter` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `PolkadotXcm::SupportedVersion` (r:1 w:0)
+	/// Proof: `PolkadotXcm::SupportedVersion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `PolkadotXcm::Queries` (r:0 w:1)
+	/// Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::ActiveMigrationQueue` (r:0 w:1)
+	/// Proof: `Funding::ActiveMigrationQueue` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn send_pallet_migration_for() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1596`
+		//  Estimated: `1003536`
+		// Minimum execution time: 58_120_000 picoseconds.
+		Weight::from_parts(60_390_000, 0)
+			.saturating_add(Weight::from_parts(0, 1003536))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Funding::ActiveMigrationQueue` (r:1 w:1)
+	/// Proof: `Funding::ActiveMigrationQueue` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::PalletMigrations` (r:1 w:0)
+	/// Proof: `Funding::PalletMigrations` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::UserMigrations` (r:1 w:1)
+	/// Proof: `Funding::UserMigrations` (`max_values`: None, `max_size`: Some(1000071), added: 1002546, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::UnmigratedCounter` (r:1 w:1)
+	/// Proof: `Funding::UnmigratedCounter` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn confirm_pallet_migrations() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1488`
+		//  Estimated: `1003536`
+		// Minimum execution time: 36_940_000 picoseconds.
+		Weight::from_parts(38_260_000, 0)
+			.saturating_add(Weight::from_parts(0, 1003536))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Funding::ActiveMigrationQueue` (r:1 w:1)
+	/// Proof: `Funding::ActiveMigrationQueue` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::UserMigrations` (r:1 w:1)
+	/// Proof: `Funding::UserMigrations` (`max_values`: None, `max_size`: Some(1000071), added: 1002546, mode: `MaxEncodedLen`)
+	fn fail_timed_out_pallet_migration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1452`
+		//  Estimated: `1003536`
+		// Minimum execution time: 31_150_000 picoseconds.
+		Weight::from_parts(32_540_000, 0)
+			.saturating_add(Weight::from_parts(0, 1003536))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::UnmigratedCounter` (r:1 w:0)
+	/// Proof: `Funding::UnmigratedCounter` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::MigrationConfirmationRoots` (r:2 w:1)
+	/// Proof: `Funding::MigrationConfirmationRoots` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::PalletMigrations` (r:1 w:0)
+	/// Proof: `Funding::PalletMigrations` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn submit_migration_confirmation_root() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1042`
+		//  Estimated: `6039`
+		// Minimum execution time: 27_310_000 picoseconds.
+		Weight::from_parts(28_640_000, 0)
+			.saturating_add(Weight::from_parts(0, 6039))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::UserMigrations` (r:1 w:1)
+	/// Proof: `Funding::UserMigrations` (`max_values`: None, `max_size`: Some(1000071), added: 1002546, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::MigrationConfirmationRoots` (r:1 w:1)
+	/// Proof: `Funding::MigrationConfirmationRoots` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::UnmigratedCounter` (r:1 w:1)
+	/// Proof: `Funding::UnmigratedCounter` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn prove_offchain_migration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1403`
+		//  Estimated: `1003536`
+		// Minimum execution time: 41_820_000 picoseconds.
+		Weight::from_parts(43_570_000, 0)
+			.saturating_add(Weight::from_parts(0, 1003536))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::UserMigrations` (r:1 w:1)
+	/// Proof: `Funding::UserMigrations` (`max_values`: None, `max_size`: Some(1000071), added: 1002546, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::MigrationConfirmedAt` (r:1 w:1)
+	/// Proof: `Funding::MigrationConfirmedAt` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::OpenMigrationDisputes` (r:1 w:1)
+	/// Proof: `Funding::OpenMigrationDisputes` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn dispute_migration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1398`
+		//  Estimated: `1003536`
+		// Minimum execution time: 32_460_000 picoseconds.
+		Weight::from_parts(33_810_000, 0)
+			.saturating_add(Weight::from_parts(0, 1003536))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Funding::UserMigrations` (r:1 w:1)
+	/// Proof: `Funding::UserMigrations` (`max_values`: None, `max_size`: Some(1000071), added: 1002546, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::UnmigratedCounter` (r:1 w:1)
+	/// Proof: `Funding::UnmigratedCounter` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::OpenMigrationDisputes` (r:1 w:1)
+	/// Proof: `Funding::OpenMigrationDisputes` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn resolve_migration_dispute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1322`
+		//  Estimated: `1003536`
+		// Minimum execution time: 29_170_000 picoseconds.
+		Weight::from_parts(30_520_000, 0)
+			.saturating_add(Weight::from_parts(0, 1003536))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Funding::FundingAssets` (r:17 w:1)
+	/// Proof: `Funding::FundingAssets` (`max_values`: None, `max_size`: Some(1081), added: 3556, mode: `MaxEncodedLen`)
+	fn set_funding_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1236`
+		//  Estimated: `61462`
+		// Minimum execution time: 41_330_000 picoseconds.
+		Weight::from_parts(43_050_000, 0)
+			.saturating_add(Weight::from_parts(0, 61462))
+			.saturating_add(T::DbWeight::get().reads(17))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::PendingProjectTransfers` (r:0 w:1)
+	/// Proof: `Funding::PendingProjectTransfers` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn propose_project_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1254`
+		//  Estimated: `3764`
+		// Minimum execution time: 23_410_000 picoseconds.
+		Weight::from_parts(24_380_000, 0)
+			.saturating_add(Weight::from_parts(0, 3764))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:1)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::PendingProjectTransfers` (r:1 w:1)
+	/// Proof: `Funding::PendingProjectTransfers` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::DidWithActiveProjects` (r:2 w:2)
+	/// Proof: `Funding::DidWithActiveProjects` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::EvaluationBondedUSD` (r:1 w:0)
+	/// Proof: `Funding::EvaluationBondedUSD` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::AuctionBoughtUSD` (r:1 w:0)
+	/// Proof: `Funding::AuctionBoughtUSD` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	fn accept_project_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1810`
+		//  Estimated: `6558`
+		// Minimum execution time: 44_630_000 picoseconds.
+		Weight::from_parts(46_150_000, 0)
+			.saturating_add(Weight::from_parts(0, 6558))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::HaltedProjects` (r:0 w:1)
+	/// Proof: `Funding::HaltedProjects` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	fn halt_project() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1254`
+		//  Estimated: `3764`
+		// Minimum execution time: 20_130_000 picoseconds.
+		Weight::from_parts(21_020_000, 0)
+			.saturating_add(Weight::from_parts(0, 3764))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Funding::HaltedProjects` (r:1 w:1)
+	/// Proof: `Funding::HaltedProjects` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	fn resume_project() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `178`
+		//  Estimated: `3551`
+		// Minimum execution time: 14_570_000 picoseconds.
+		Weight::from_parts(15_190_000, 0)
+			.saturating_add(Weight::from_parts(0, 3551))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::DidWithActiveProjects` (r:1 w:1)
+	/// Proof: `Funding::DidWithActiveProjects` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsInAuctionRound` (r:0 w:1)
+	/// Proof: `Funding::ProjectsInAuctionRound` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::HaltedProjects` (r:0 w:1)
+	/// Proof: `Funding::HaltedProjects` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectTransitionQueue` (r:0 w:1)
+	/// Proof: `Funding::ProjectTransitionQueue` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn force_project_failure() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1391`
+		//  Estimated: `3764`
+		// Minimum execution time: 36_850_000 picoseconds.
+		Weight::from_parts(38_110_000, 0)
+			.saturating_add(Weight::from_parts(0, 3764))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `Funding::FeeConfiguration` (r:0 w:1)
+	/// Proof: `Funding::FeeConfiguration` (`max_values`: Some(1), `max_size`: Some(211), added: 706, mode: `MaxEncodedLen`)
+	fn set_fee_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_410_000 picoseconds.
+		Weight::from_parts(8_720_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ReceivingAccounts` (r:0 w:1)
+	/// Proof: `Funding::ReceivingAccounts` (`max_values`: None, `max_size`: Some(136), added: 2611, mode: `MaxEncodedLen`)
+	fn bind_receiving_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `574`
+		//  Estimated: `3902`
+		// Minimum execution time: 74_950_000 picoseconds.
+		Weight::from_parts(76_310_000, 0)
+			.saturating_add(Weight::from_parts(0, 3902))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Funding::ProjectsMetadata` (r:1 w:0)
+	/// Proof: `Funding::ProjectsMetadata` (`max_values`: None, `max_size`: Some(437), added: 2912, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(299), added: 2774, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::HaltedProjects` (r:1 w:0)
+	/// Proof: `Funding::HaltedProjects` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::FundingAssets` (r:1 w:0)
+	/// Proof: `Funding::FundingAssets` (`max_values`: None, `max_size`: Some(1081), added: 3556, mode: `MaxEncodedLen`)
+	/// Storage: `Oracle::Values` (r:2 w:0)
+	/// Proof: `Oracle::Values` (`max_values`: None, `max_size`: Some(634), added: 3109, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Metadata` (r:1 w:0)
+	/// Proof: `ForeignAssets::Metadata` (`max_values`: None, `max_size`: Some(738), added: 3213, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(175), added: 2650, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:2 w:2)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::NextScheduledBidId` (r:1 w:1)
+	/// Proof: `Funding::NextScheduledBidId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ScheduledBidQueue` (r:1 w:1)
+	/// Proof: `Funding::ScheduledBidQueue` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ScheduledBids` (r:15 w:1)
+	/// Proof: `Funding::ScheduledBids` (`max_values`: None, `max_size`: Some(294), added: 2769, mode: `MaxEncodedLen`)
+	fn schedule_bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7268`
+		//  Estimated: `48939`
+		// Minimum execution time: 162_200_000 picoseconds.
+		Weight::from_parts(165_030_000, 0)
+			.saturating_add(Weight::from_parts(0, 48939))
+			.saturating_add(T::DbWeight::get().reads(29))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	/// Storage: `Funding::ScheduledBids` (r:1 w:1)
+	/// Proof: `Funding::ScheduledBids` (`max_values`: None, `max_size`: Some(294), added: 2769, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::ScheduledBidQueue` (r:1 w:1)
+	/// Proof: `Funding::ScheduledBidQueue` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::FundingAssets` (r:1 w:0)
+	/// Proof: `Funding::FundingAssets` (`max_values`: None, `max_size`: Some(1081), added: 3556, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:2 w:2)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(175), added: 2650, mode: `MaxEncodedLen`)
+	fn cancel_scheduled_bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1902`
+		//  Estimated: `7404`
+		// Minimum execution time: 97_450_000 picoseconds.
+		Weight::from_parts(99_380_000, 0)
+			.saturating_add(Weight::from_parts(0, 7404))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
 }
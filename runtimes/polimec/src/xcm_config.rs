@@ -15,7 +15,7 @@
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 use super::{
-	AccountId, AllPalletsWithSystem, Balance, Balances, ContributionTokens, EnsureRoot, ForeignAssets,
+	AccountId, AllPalletsWithSystem, Balance, Balances, ContributionTokens, EnsureRoot, ForeignAssets, Funding,
 	HereToForeignAsset, ParachainInfo, ParachainSystem, PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin,
 	TreasuryAccount, Vec, WeightToFee,
 };
@@ -33,20 +33,20 @@ use frame_support::{
 };
 use pallet_xcm::XcmPassthrough;
 use parachains_common::xcm_config::{AllSiblingSystemParachains, ParentRelayOrSiblingParachains};
-use polimec_common::assets::AcceptedFundingAsset;
+use polimec_common::assets::ProvideFundingAssets;
 use polkadot_parachain_primitives::primitives::Sibling;
 use polkadot_runtime_common::xcm_sender::NoPriceForMessageDelivery;
 use sp_runtime::traits::{TryConvertInto, Zero};
 use xcm::v5::prelude::*;
 use xcm_builder::{
-	AccountId32Aliases, AllowExplicitUnpaidExecutionFrom, AllowKnownQueryResponses, AllowSubscriptionsFrom,
-	AllowTopLevelPaidExecutionFrom, DenyReserveTransferToRelayChain, DenyThenTry, DescribeAllTerminal, DescribeFamily,
-	EnsureXcmOrigin, FixedWeightBounds, FrameTransactionalProcessor, FungibleAdapter, FungiblesAdapter,
-	GlobalConsensusParachainConvertsFor, HashedDescription, IsConcrete, MatchedConvertedConcreteId, MintLocation,
-	NoChecking, ParentIsPreset, RelayChainAsNative, SiblingParachainAsNative, SiblingParachainConvertsVia,
-	SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation, StartsWith,
-	StartsWithExplicitGlobalConsensus, TakeRevenue, TakeWeightCredit, TrailingSetTopicAsId, WithComputedOrigin,
-	WithLatestLocationConverter,
+	AccountId32Aliases, AllowExplicitUnpaidExecutionFrom, AllowHrmpNotificationsFromRelayChain,
+	AllowKnownQueryResponses, AllowSubscriptionsFrom, AllowTopLevelPaidExecutionFrom, DenyReserveTransferToRelayChain,
+	DenyThenTry, DescribeAllTerminal, DescribeFamily, EnsureXcmOrigin, FixedWeightBounds, FrameTransactionalProcessor,
+	FungibleAdapter, FungiblesAdapter, GlobalConsensusParachainConvertsFor, HashedDescription, IsConcrete,
+	MatchedConvertedConcreteId, MintLocation, NoChecking, ParentIsPreset, RelayChainAsNative, SiblingParachainAsNative,
+	SiblingParachainConvertsVia, SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation,
+	StartsWith, StartsWithExplicitGlobalConsensus, TakeRevenue, TakeWeightCredit, TrailingSetTopicAsId,
+	WithComputedOrigin, WithLatestLocationConverter,
 };
 use xcm_executor::{
 	traits::{Properties, ShouldExecute, WeightTrader},
@@ -123,18 +123,18 @@ pub type ForeignAssetsConvertedConcreteId = assets_common::ForeignAssetsConverte
 pub type ContributionTokensConvertedConcreteId =
 	assets_common::TrustBackedAssetsConvertedConcreteId<ContributionTokensPalletLocation, Balance>;
 
-// The `AssetIdPalletAssets` ids that are supported by this chain.
-// Currently, we only support DOT (10), USDT (1984) and USDC (1337).
+// The foreign assets supported by this chain are the ones in the funding asset registry. Disabled assets are still
+// supported, so participations made with them can be withdrawn.
 pub struct SupportedAssets;
 impl frame_support::traits::Contains<Location> for SupportedAssets {
 	fn contains(l: &Location) -> bool {
-		let funding_assets = AcceptedFundingAsset::all_ids();
+		let funding_assets = Funding::funding_assets().into_iter().map(|(_, info)| info.location).collect::<Vec<_>>();
 		l.clone().try_into().ok().is_some_and(|v4_location| funding_assets.contains(&v4_location))
 	}
 }
 
-/// Foreign assets adapter for supporting assets from other chains. Currently the only
-/// supported assets are DOT, USDT, and USDC.
+/// Foreign assets adapter for supporting assets from other chains. The supported assets are the ones in the
+/// funding asset registry.
 pub type ForeignAssetsAdapter = FungiblesAdapter<
 	// Use this fungibles implementation:
 	ForeignAssets,
@@ -264,6 +264,8 @@ pub type Barrier = TrailingSetTopicAsId<
 			AllowPaidDescendFromAssetHub,
 			// Expected responses are OK.
 			AllowKnownQueryResponses<PolkadotXcm>,
+			// HRMP notifications from the relay are used to open the channels for the CT pallet migrations.
+			AllowHrmpNotificationsFromRelayChain,
 			// Allow XCMs with some computed origins to pass through.
 			WithComputedOrigin<
 				(
@@ -327,9 +329,9 @@ impl xcm_executor::Config for XcmConfig {
 	type Barrier = Barrier;
 	type CallDispatcher = RuntimeCall;
 	type FeeManager = ();
-	type HrmpChannelAcceptedHandler = ();
+	type HrmpChannelAcceptedHandler = pallet_funding::xcm_executor_impl::HrmpHandler<Runtime>;
 	type HrmpChannelClosingHandler = ();
-	type HrmpNewChannelOpenRequestHandler = ();
+	type HrmpNewChannelOpenRequestHandler = pallet_funding::xcm_executor_impl::HrmpHandler<Runtime>;
 	/// Locations that we trust to act as reserves for specific assets.
 	type IsReserve = Reserves;
 	type IsTeleporter = HereToHub;
@@ -426,7 +428,8 @@ impl<Payee: TakeRevenue> WeightTrader for AssetTrader<Payee> {
 	) -> Result<AssetsInHolding, XcmError> {
 		log::trace!(target: "xcm::weight", "AssetsTrader::buy_weight weight: {:?}, payment: {:?}, context: {:?}", weight, payment, context);
 		let native_amount = WeightToFee::weight_to_fee(&weight);
-		let acceptable_assets = AcceptedFundingAsset::all_ids_and_plmc();
+		let mut acceptable_assets = Funding::enabled_funding_asset_locations();
+		acceptable_assets.push(polimec_common::Location::here());
 
 		// We know the executor always sends just one asset to pay for weight, even if the struct supports multiple.
 		let payment_fun = payment.fungible.clone();
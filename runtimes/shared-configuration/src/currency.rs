@@ -14,11 +14,15 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
+extern crate alloc;
 use crate::Balance;
-use frame_support::parameter_types;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use frame_support::{parameter_types, traits::Get};
 use pallet_oracle_ocw::types::AssetName;
-use polimec_common::{assets::AcceptedFundingAsset, Location};
+use polimec_common::{assets::ProvideFundingAssets, Location};
 use sp_runtime::{traits::Convert, FixedU128};
+use xcm::v4::prelude::{Ethereum, GeneralIndex, GlobalConsensus, PalletInstance, Parachain};
 
 /// One PLMC
 pub const PLMC: Balance = 10u128.pow(10);
@@ -77,15 +81,30 @@ pub type Price = FixedU128;
 
 pub type Moment = u64;
 
-pub struct AssetPriceConverter;
-impl Convert<(AssetName, FixedU128), (Location, Price)> for AssetPriceConverter {
-	fn convert((asset, price): (AssetName, FixedU128)) -> (Location, Price) {
-		match asset {
-			AssetName::DOT => (AcceptedFundingAsset::DOT.id(), price),
-			AssetName::USDC => (AcceptedFundingAsset::USDC.id(), price),
-			AssetName::USDT => (AcceptedFundingAsset::USDT.id(), price),
-			AssetName::PLMC => (Location::here(), price),
-			AssetName::ETH => (AcceptedFundingAsset::ETH.id(), price),
-		}
+/// Price fetched by the oracle for each oracle key of the funding asset registry. The keys are the locations of the
+/// assets priced, which the built-in funding assets share. A new asset priced like one of them, e.g. a USD stablecoin
+/// with the USDC key, is fed once registered.
+pub struct OracleKeyFeed;
+impl Convert<Location, Option<AssetName>> for OracleKeyFeed {
+	fn convert(oracle_key: Location) -> Option<AssetName> {
+		let feeds = [
+			(Location::parent(), AssetName::DOT),
+			(Location::new(1, [Parachain(1000), PalletInstance(50), GeneralIndex(1337)]), AssetName::USDC),
+			(Location::new(1, [Parachain(1000), PalletInstance(50), GeneralIndex(1984)]), AssetName::USDT),
+			(Location::new(2, [GlobalConsensus(Ethereum { chain_id: 1 })]), AssetName::ETH),
+			(Location::here(), AssetName::PLMC),
+		];
+		feeds.into_iter().find_map(|(feed_key, feed)| (feed_key == oracle_key).then_some(feed))
+	}
+}
+
+/// Oracle keys of the registered funding assets, enabled or not, and of PLMC. The oracle only fetches these prices.
+pub struct FundingAssetOracleKeys<FundingAssets>(PhantomData<FundingAssets>);
+impl<FundingAssets: ProvideFundingAssets> Get<Vec<Location>> for FundingAssetOracleKeys<FundingAssets> {
+	fn get() -> Vec<Location> {
+		let mut oracle_keys: Vec<Location> =
+			FundingAssets::funding_assets().into_iter().map(|(_, info)| info.oracle_key).collect();
+		oracle_keys.push(Location::here());
+		oracle_keys
 	}
 }
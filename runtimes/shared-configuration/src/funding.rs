@@ -15,12 +15,14 @@
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 extern crate alloc;
 
-use crate::{Balance, BlockNumber};
-use alloc::{collections::btree_map::BTreeMap, vec, vec::Vec};
-use frame_support::{parameter_types, PalletId};
-use polimec_common::{assets::AcceptedFundingAsset, USD_UNIT};
+use crate::{BlockNumber, RuntimeBlockWeights};
+use alloc::{collections::btree_map::BTreeMap, vec};
+use core::ops::RangeInclusive;
+use frame_support::{parameter_types, weights::Weight, PalletId};
+use pallet_funding::FundingOutcomeDecision;
+use polimec_common::assets::AcceptedFundingAsset;
 use sp_arithmetic::{FixedU128, Percent};
-use sp_runtime::Perquintill;
+use sp_runtime::{Perbill, Perquintill};
 use xcm::v4::Location;
 
 #[cfg(feature = "instant-mode")]
@@ -37,24 +39,59 @@ pub const AUCTION_ROUND_DURATION: BlockNumber = 120 * polimec_common::MINUTES;
 #[cfg(not(any(feature = "fast-mode", feature = "instant-mode")))]
 pub const AUCTION_ROUND_DURATION: BlockNumber = 14 * polimec_common::DAYS;
 
+#[cfg(feature = "instant-mode")]
+pub const COMMUNITY_ROUND_DURATION: BlockNumber = 3;
+#[cfg(feature = "fast-mode")]
+pub const COMMUNITY_ROUND_DURATION: BlockNumber = 60 * polimec_common::MINUTES;
+#[cfg(not(any(feature = "fast-mode", feature = "instant-mode")))]
+pub const COMMUNITY_ROUND_DURATION: BlockNumber = 5 * polimec_common::DAYS;
+
+#[cfg(feature = "instant-mode")]
+pub const ISSUER_DECISION_DURATION: BlockNumber = 3;
+#[cfg(feature = "fast-mode")]
+pub const ISSUER_DECISION_DURATION: BlockNumber = 30 * polimec_common::MINUTES;
+#[cfg(not(any(feature = "fast-mode", feature = "instant-mode")))]
+pub const ISSUER_DECISION_DURATION: BlockNumber = 3 * polimec_common::DAYS;
+
+#[cfg(feature = "instant-mode")]
+pub const MIGRATION_DISPUTE_WINDOW: BlockNumber = 5;
+#[cfg(feature = "fast-mode")]
+pub const MIGRATION_DISPUTE_WINDOW: BlockNumber = 60 * polimec_common::MINUTES;
+#[cfg(not(any(feature = "fast-mode", feature = "instant-mode")))]
+pub const MIGRATION_DISPUTE_WINDOW: BlockNumber = 14 * polimec_common::DAYS;
+
 pub type ProjectIdentifier = u32;
 
 parameter_types! {
 	pub const EvaluationRoundDuration: BlockNumber = EVALUATION_ROUND_DURATION;
 	pub const AuctionRoundDuration: BlockNumber = AUCTION_ROUND_DURATION;
+	pub const CommunityRoundDuration: BlockNumber = COMMUNITY_ROUND_DURATION;
+	pub const IssuerDecisionDuration: BlockNumber = ISSUER_DECISION_DURATION;
+	pub const DefaultFundingOutcomeDecision: FundingOutcomeDecision = FundingOutcomeDecision::RejectFunding;
+	pub const MigrationDisputeWindow: BlockNumber = MIGRATION_DISPUTE_WINDOW;
 	pub const FundingPalletId: PalletId = PalletId(*b"plmc/fun");
 	pub PriceMap: BTreeMap<Location, FixedU128> = BTreeMap::from_iter(vec![
-		(AcceptedFundingAsset::DOT.id(), FixedU128::from_rational(69, 1)), // DOT
-		(AcceptedFundingAsset::USDC.id(), FixedU128::from_rational(100, 100)), // USDC
-		(AcceptedFundingAsset::USDT.id(), FixedU128::from_rational(100, 100)), // USDT
+		(AcceptedFundingAsset::DOT.id().unwrap(), FixedU128::from_rational(69, 1)), // DOT
+		(AcceptedFundingAsset::USDC.id().unwrap(), FixedU128::from_rational(100, 100)), // USDC
+		(AcceptedFundingAsset::USDT.id().unwrap(), FixedU128::from_rational(100, 100)), // USDT
 		(Location::here(), FixedU128::from_rational(840, 100)), // PLMC
 	]);
-	pub FeeBrackets: Vec<(Percent, Balance)> = vec![
-		(Percent::from_percent(10), 1_000_000 * USD_UNIT),
-		(Percent::from_percent(8), 4_000_000 * USD_UNIT),
-		(Percent::from_percent(6), u128::MAX), // Making it max signifies the last bracket
-	];
 	pub EarlyEvaluationThreshold: Percent = Percent::from_percent(10);
+	pub EvaluationSuccessThresholdBounds: RangeInclusive<Percent> =
+		Percent::from_percent(5)..=Percent::from_percent(30);
 	pub EvaluatorSlash: Percent = Percent::from_percent(20);
 	pub FundingSuccessThreshold: Perquintill = Perquintill::from_percent(33);
+	pub FundingSuccessThresholdBounds: RangeInclusive<Perquintill> =
+		Perquintill::from_percent(20)..=Perquintill::from_percent(80);
+	pub IssuerDecisionThreshold: Perquintill = Perquintill::from_percent(20);
+	pub const MaxActiveProjectsPerDid: u32 = 5;
+	pub MaxMessageSizeThresholds: RangeInclusive<u32> = 50_000..=102_400;
+	pub MaxCapacityThresholds: RangeInclusive<u32> = 8..=1000;
+	pub MaxScheduledBidsWeightPerBlock: Weight = Perbill::from_percent(10) * RuntimeBlockWeights::get().max_block;
+	pub const MaxTransitionsPerBlock: u32 = 20;
+	pub const RequiredMaxCapacity: u32 = 1000;
+	pub const RequiredMaxMessageSize: u32 = 102_400;
+	pub PolimecReceiverInfo: xcm::v5::PalletInfo = xcm::v5::PalletInfo::new(
+		51, "PolimecReceiver".as_bytes().to_vec(), "polimec_receiver".as_bytes().to_vec(), 1, 1, 0
+	).expect("Valid pallet info");
 }